@@ -2,6 +2,9 @@ use napi_derive::napi;
 use napi::Error;
 use serde_json::{json, Value};
 use crate::gpu::types::GpuCapabilities;
+use crate::gpu::{adapter, context};
+use crate::gpu::context::GpuBackendType;
+use crate::gpu::settings as gpu_settings;
 use log::{debug, info};
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::sync::Mutex;
@@ -51,28 +54,40 @@ pub fn is_acceleration_enabled() -> bool {
 /// GPU 드라이버 버전 가져오기
 #[napi]
 pub fn get_driver_version() -> String {
-    "1.0.0".to_string() // 임시 구현, 실제로는 시스템에서 확인 필요
+    with_active_context(|ctx| ctx.driver_version.clone()).unwrap_or_default()
 }
 
 /// GPU 장치 이름 가져오기
 #[napi]
 pub fn get_device_name() -> String {
-    "Generic GPU Device".to_string() // 임시 구현, 실제로는 시스템에서 확인 필요
+    with_active_context(|ctx| ctx.device_name.clone()).unwrap_or_default()
 }
 
 /// GPU 벤더 이름 가져오기
 #[napi]
 pub fn get_vendor_name() -> String {
-    "Generic Vendor".to_string() // 임시 구현, 실제로는 시스템에서 확인 필요
+    with_active_context(|ctx| ctx.vendor_name.clone()).unwrap_or_default()
 }
 
 /// GPU 장치 유형 가져오기
 #[napi]
 pub fn get_device_type() -> i32 {
-    0 // 0: Integrated, 1: Discrete, 2: Software, 3: Unknown
+    // 0: Integrated, 1: Discrete, 2: Software, 3: Unknown
+    with_active_context(|ctx| {
+        if ctx.backend_type == GpuBackendType::Software {
+            2
+        } else if ctx.is_discrete {
+            1
+        } else {
+            0
+        }
+    }).unwrap_or(3)
 }
 
 /// GPU 초기화
+/// 
+/// 시스템의 실제 GPU 어댑터를 열거하고, 선택된 장치(`select_gpu_device`로 지정하거나
+/// 선호 장치 설정)가 있으면 그 장치로, 없으면 자동으로 선택한 장치로 컨텍스트를 생성합니다.
 #[napi]
 pub fn initialize_gpu() -> napi::Result<bool> {
     let mut initialized = GPU_INITIALIZED.lock().unwrap();
@@ -84,8 +99,7 @@ pub fn initialize_gpu() -> napi::Result<bool> {
     
     info!("GPU 초기화 시작");
     
-    // 실제 구현에서는 여기에 GPU 하드웨어 감지 및 초기화 코드가 들어갑니다
-    // 이 예제에서는 항상 성공한다고 가정합니다
+    context::initialize_gpu_context()?;
     *initialized = true;
     
     // 초기 상태로 가속화는 비활성화
@@ -94,10 +108,67 @@ pub fn initialize_gpu() -> napi::Result<bool> {
         *acceleration_enabled = false;
     }
     
-    info!("GPU 초기화 완료");
+    info!("GPU 초기화 완료: {}", get_device_name());
     Ok(true)
 }
 
+/// GPU 종료
+/// 
+/// GPU 컨텍스트와 계산 장치를 해제하고 초기화/가속 상태를 되돌립니다.
+/// 실행 중인 작업은 이미 가져간 장치로 끝까지 실행되며, 이후 `initialize_gpu`로 다시 초기화할 수 있습니다.
+pub fn shutdown_gpu() -> napi::Result<()> {
+    let mut initialized = GPU_INITIALIZED.lock().unwrap();
+    
+    info!("GPU 종료 시작");
+    context::cleanup_gpu_context()?;
+    *initialized = false;
+    *GPU_ACCELERATION_ENABLED.lock().unwrap() = false;
+    
+    info!("GPU 종료 완료");
+    Ok(())
+}
+
+/// 사용할 GPU 장치 선택
+/// 
+/// `index`는 `list_gpu_devices`가 반환한 장치의 `index`입니다.
+/// 선택은 선호 장치 설정으로 저장되어 이후 `initialize_gpu`에서 사용되며,
+/// GPU가 이미 초기화되어 있으면 선택한 장치로 컨텍스트를 다시 생성합니다.
+#[napi]
+pub fn select_gpu_device(index: u32) -> napi::Result<String> {
+    let devices = adapter::enumerate_adapters(false);
+    let device = devices.iter()
+        .find(|d| d.index == index)
+        .ok_or_else(|| Error::new(
+            napi::Status::InvalidArg,
+            format!("GPU 장치 인덱스가 범위를 벗어남: {} (장치 {}개)", index, devices.len()),
+        ))?;
+    
+    info!("GPU 장치 선택: [{}] {}", device.index, device.name);
+    gpu_settings::set_preferred_device(Some(device.key.clone()));
+    
+    if context::is_gpu_initialized() {
+        context::reinitialize_gpu_context()?;
+    }
+    
+    let result = json!({
+        "success": true,
+        "device": device,
+        "active_device": context::get_active_adapter().map(|(key, _)| key),
+        "timestamp": SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64
+    });
+    
+    Ok(result.to_string())
+}
+
+// 초기화된 GPU 컨텍스트에서 값 읽기
+fn with_active_context<T>(read: impl FnOnce(&context::GpuContext) -> T) -> Option<T> {
+    let guard = context::GPU_CONTEXT.read().ok()?;
+    guard.as_ref().map(read)
+}
+
 /// GPU 가속화 활성화
 #[napi]
 pub fn enable_gpu_acceleration() -> napi::Result<bool> {
@@ -277,7 +348,7 @@ fn perform_compute_operation(data: &str) -> Result<Value, Error> {
         Ok(json!({
             "sum": sum,
             "count": array.len(),
-            "average": if array.len() > 0 { sum / array.len() as f64 } else { 0.0 },
+            "average": if !array.is_empty() { sum / array.len() as f64 } else { 0.0 },
             "status": "success"
         }))
     } else {
@@ -0,0 +1,185 @@
+//! GPU 어댑터 열거 모듈
+//!
+//! wgpu를 통해 시스템의 실제 GPU 어댑터를 열거하고,
+//! 사용자 선호 장치와 전력 설정에 따라 사용할 어댑터를 선택합니다.
+
+use serde::Serialize;
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use log::{debug, info, warn};
+
+/// GPU 어댑터 요약 정보
+#[derive(Debug, Clone, Serialize)]
+pub struct GpuAdapterSummary {
+    pub index: u32,
+    /// 재시작 후에도 같은 장치를 가리키는 식별자 (백엔드:벤더:장치:이름)
+    pub key: String,
+    pub name: String,
+    pub vendor_id: u32,
+    pub vendor_name: String,
+    pub device_id: u32,
+    pub device_type: String,
+    pub backend: String,
+    pub driver: String,
+    pub driver_info: String,
+    pub is_discrete: bool,
+    pub compute_supported: bool,
+    pub max_buffer_size: u64,
+    pub max_storage_buffer_binding_size: u32,
+    pub max_compute_workgroups_per_dimension: u32,
+    pub max_compute_invocations_per_workgroup: u32,
+}
+
+/// 어댑터 선택 결과
+#[derive(Debug, Clone)]
+pub struct AdapterSelection {
+    pub adapter: GpuAdapterSummary,
+    /// 선호 장치가 지정되었지만 찾지 못해 다른 장치로 대체되었는지 여부
+    pub fallback: bool,
+}
+
+// 열거 결과 캐시 (어댑터 열거는 드라이버 로딩을 포함하므로 비용이 큼)
+static ADAPTER_CACHE: Lazy<RwLock<Option<Vec<GpuAdapterSummary>>>> = Lazy::new(|| RwLock::new(None));
+
+/// 어댑터 열거 결과 캐시 삭제 (다음 조회 때 다시 열거)
+pub fn clear_adapter_cache() {
+    *ADAPTER_CACHE.write() = None;
+}
+
+/// 모든 백엔드를 대상으로 하는 wgpu 인스턴스 생성
+pub fn create_instance() -> wgpu::Instance {
+    wgpu::Instance::new(wgpu::InstanceDescriptor {
+        backends: wgpu::Backends::all(),
+        ..Default::default()
+    })
+}
+
+/// 시스템의 GPU 어댑터 목록 가져오기
+///
+/// `refresh`가 false이면 이전 열거 결과를 재사용합니다.
+pub fn enumerate_adapters(refresh: bool) -> Vec<GpuAdapterSummary> {
+    if !refresh {
+        if let Some(cached) = ADAPTER_CACHE.read().as_ref() {
+            return cached.clone();
+        }
+    }
+
+    let instance = create_instance();
+    let adapters: Vec<GpuAdapterSummary> = instance
+        .enumerate_adapters(wgpu::Backends::all())
+        .enumerate()
+        .map(|(index, adapter)| summarize_adapter(index as u32, &adapter))
+        .collect();
+
+    info!("GPU 어댑터 {}개 감지됨", adapters.len());
+    for adapter in &adapters {
+        debug!("  [{}] {} ({}, {})", adapter.index, adapter.name, adapter.backend, adapter.device_type);
+    }
+
+    *ADAPTER_CACHE.write() = Some(adapters.clone());
+    adapters
+}
+
+/// 사용할 어댑터 선택
+///
+/// 선호 장치 키와 일치하는 어댑터가 있으면 그것을 사용하고,
+/// 없으면 전력 설정에 따라 가장 적합한 어댑터로 대체합니다.
+pub fn select_adapter(
+    adapters: &[GpuAdapterSummary],
+    preferred_key: Option<&str>,
+    power_preference: &str,
+) -> Option<AdapterSelection> {
+    if let Some(key) = preferred_key {
+        if let Some(adapter) = adapters.iter().find(|a| a.key == key) {
+            return Some(AdapterSelection { adapter: adapter.clone(), fallback: false });
+        }
+        warn!("선호 GPU 장치를 찾을 수 없음: {}, 자동 선택으로 대체", key);
+    }
+
+    let low_power = power_preference == "low-power";
+
+    adapters.iter()
+        .max_by_key(|a| (a.compute_supported, device_type_rank(&a.device_type, low_power), backend_rank(&a.backend)))
+        .map(|adapter| AdapterSelection {
+            adapter: adapter.clone(),
+            fallback: preferred_key.is_some(),
+        })
+}
+
+/// 키와 일치하는 wgpu 어댑터 찾기
+///
+/// 장치와 큐를 만들려면 요약 정보가 아닌 실제 어댑터 객체가 필요합니다.
+pub fn find_wgpu_adapter(instance: &wgpu::Instance, key: &str) -> Option<wgpu::Adapter> {
+    instance
+        .enumerate_adapters(wgpu::Backends::all())
+        .find(|adapter| adapter_key(&adapter.get_info()) == key)
+}
+
+/// PCI 벤더 ID로 벤더 이름 조회
+pub fn vendor_name(vendor_id: u32) -> &'static str {
+    match vendor_id {
+        0x10DE => "NVIDIA",
+        0x1002 | 0x1022 => "AMD",
+        0x8086 => "Intel",
+        0x106B => "Apple",
+        0x5143 => "Qualcomm",
+        0x13B5 => "ARM",
+        0x1010 => "Imagination Technologies",
+        0x14E4 => "Broadcom",
+        0x1414 => "Microsoft",
+        0x10005 => "Mesa",
+        _ => "Unknown",
+    }
+}
+
+// 어댑터 식별 키 생성
+fn adapter_key(info: &wgpu::AdapterInfo) -> String {
+    format!("{:?}:{:04x}:{:04x}:{}", info.backend, info.vendor, info.device, info.name)
+}
+
+// wgpu 어댑터를 요약 정보로 변환
+fn summarize_adapter(index: u32, adapter: &wgpu::Adapter) -> GpuAdapterSummary {
+    let info = adapter.get_info();
+    let limits = adapter.limits();
+    let downlevel = adapter.get_downlevel_capabilities();
+
+    GpuAdapterSummary {
+        index,
+        key: adapter_key(&info),
+        name: info.name.clone(),
+        vendor_id: info.vendor,
+        vendor_name: vendor_name(info.vendor).to_string(),
+        device_id: info.device,
+        device_type: format!("{:?}", info.device_type),
+        backend: format!("{:?}", info.backend),
+        driver: info.driver.clone(),
+        driver_info: info.driver_info.clone(),
+        is_discrete: info.device_type == wgpu::DeviceType::DiscreteGpu,
+        compute_supported: downlevel.flags.contains(wgpu::DownlevelFlags::COMPUTE_SHADERS),
+        max_buffer_size: limits.max_buffer_size,
+        max_storage_buffer_binding_size: limits.max_storage_buffer_binding_size,
+        max_compute_workgroups_per_dimension: limits.max_compute_workgroups_per_dimension,
+        max_compute_invocations_per_workgroup: limits.max_compute_invocations_per_workgroup,
+    }
+}
+
+// 장치 유형 우선순위 (저전력 모드에서는 내장 GPU 우선)
+fn device_type_rank(device_type: &str, low_power: bool) -> u8 {
+    match (device_type, low_power) {
+        ("DiscreteGpu", false) | ("IntegratedGpu", true) => 4,
+        ("IntegratedGpu", false) | ("DiscreteGpu", true) => 3,
+        ("VirtualGpu", _) => 2,
+        ("Cpu", _) => 1,
+        _ => 0,
+    }
+}
+
+// 백엔드 우선순위 (네이티브 API 우선)
+fn backend_rank(backend: &str) -> u8 {
+    match backend {
+        "Vulkan" | "Metal" | "Dx12" => 3,
+        "Dx11" => 2,
+        "Gl" => 1,
+        _ => 0,
+    }
+}
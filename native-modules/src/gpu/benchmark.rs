@@ -0,0 +1,275 @@
+//! CPU/GPU 경로 벤치마크 모듈
+//!
+//! GPU 경로가 있는 작업(패턴 감지, 키 히트맵)을 같은 입력으로 CPU와 GPU에서 각각 실행해 시간을 비교하고,
+//! 결과를 장치별로 셰이더 캐시 디렉터리에 저장합니다. 컴퓨팅 모드가 auto이면 이 기기에서
+//! CPU가 더 빨랐던 작업은 GPU를 거치지 않고 CPU 경로로 실행합니다.
+
+use napi::Error;
+use napi_derive::napi;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Serialize, Deserialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::time::Instant;
+use log::{info, warn};
+use crate::gpu::cache_file::{self, CacheFile, CacheFileStore};
+use crate::gpu::types::GpuCapabilities;
+use crate::gpu::{accelerator, computation, context, settings, shader_cache, GpuTaskType, Result};
+use crate::memory::analyzer;
+use crate::utils::clock;
+
+// 저장 파일 형식 버전
+const BENCHMARK_FORMAT_VERSION: u32 = 1;
+
+// 셰이더 캐시 디렉터리 안의 저장 파일 이름
+const BENCHMARK_FILE: &str = "gpu-benchmark.json";
+
+// 기본/최소/최대 작업 크기 (패턴 감지: 입력 간격 수, 히트맵: 키 입력 수)
+const DEFAULT_SIZE: u32 = 10_000;
+const MIN_SIZE: u32 = 100;
+const MAX_SIZE: u32 = 1_000_000;
+
+// 경로별 측정 횟수 (준비 실행 1회 제외, 중앙값 사용)
+const ITERATIONS: usize = 3;
+
+// 패턴 감지 워크로드의 패턴 수와 길이
+const BENCHMARK_PATTERN_COUNT: usize = 8;
+const BENCHMARK_PATTERN_LENGTH: usize = 16;
+
+// 히트맵 워크로드에 사용하는 키
+const BENCHMARK_KEYS: [&str; 12] = ["a", "s", "d", "f", "j", "k", "l", "e", "r", "u", "i", "space"];
+
+/// 작업 유형별 벤치마크 결과
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkRecord {
+    pub task_type: String,
+    pub size: u32,
+    pub cpu_ms: f64,
+    pub gpu_ms: f64,
+    pub speedup: f64,
+    /// 더 빠른 경로 (cpu, gpu)
+    pub preferred: String,
+    pub device_name: String,
+    pub device_fingerprint: String,
+    pub measured_at: u64,
+}
+
+// 저장 파일 (장치 지문|작업 유형 -> 결과)
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct BenchmarkFile {
+    version: u32,
+    records: BTreeMap<String, BenchmarkRecord>,
+}
+
+impl CacheFile for BenchmarkFile {
+    const FILE_NAME: &'static str = BENCHMARK_FILE;
+    const LABEL: &'static str = "GPU 벤치마크 결과";
+    const PRETTY: bool = true;
+
+    fn empty() -> Self {
+        BenchmarkFile { version: BENCHMARK_FORMAT_VERSION, records: BTreeMap::new() }
+    }
+
+    fn is_current(&self) -> bool {
+        self.version == BENCHMARK_FORMAT_VERSION
+    }
+}
+
+static STORE: Lazy<Mutex<CacheFileStore<BenchmarkFile>>> = Lazy::new(|| Mutex::new(CacheFileStore::default()));
+
+fn record_key(fingerprint: &str, task_type: GpuTaskType) -> String {
+    format!("{}|{:?}", fingerprint, task_type)
+}
+
+// 저장된 결과로 작업 실행 (셰이더 캐시 디렉터리가 없으면 메모리에만 보관)
+fn with_store<R>(f: impl FnOnce(&mut CacheFileStore<BenchmarkFile>) -> R) -> R {
+    cache_file::with_store(&STORE, f)
+}
+
+// 결과 저장 - 파일에 기록했는지 반환
+fn save_record(key: String, record: BenchmarkRecord) -> bool {
+    with_store(|store| {
+        store.file.records.insert(key, record);
+        store.save()
+    })
+}
+
+/// 이 기기에서 CPU 경로가 더 빨랐던 작업인지 확인
+///
+/// 컴퓨팅 모드가 auto이고 현재 GPU 장치로 측정한 벤치마크 결과가 있을 때만 true가 될 수 있습니다.
+pub fn prefers_cpu(task_type: GpuTaskType) -> bool {
+    if settings::get_compute_mode() != "auto" {
+        return false;
+    }
+    let key = record_key(&shader_cache::device_fingerprint(), task_type);
+    with_store(|store| store.file.records.get(&key).is_some_and(|record| record.preferred == "cpu"))
+}
+
+/// 불러온 벤치마크 결과 잊기 (파일은 남겨 두고 다음 조회 때 다시 불러옴)
+pub fn reset() {
+    *STORE.lock() = CacheFileStore::default();
+}
+
+// 재현 가능한 의사 난수 (워크로드 생성용)
+fn next_random(state: &mut u64) -> f64 {
+    *state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    (*state >> 33) as f64 / (1u64 << 31) as f64
+}
+
+// 작업 유형별 대표 워크로드 생성
+fn build_workload(task_type: GpuTaskType, size: usize) -> Result<String> {
+    let mut seed = 0x5eed_u64;
+    let workload = match task_type {
+        GpuTaskType::PatternDetection => {
+            let intervals: Vec<f64> = (0..size).map(|_| 50.0 + (next_random(&mut seed) * 350.0).round()).collect();
+            let patterns: Vec<&[f64]> = (0..BENCHMARK_PATTERN_COUNT)
+                .map(|i| {
+                    let start = (i * size / BENCHMARK_PATTERN_COUNT).min(size - BENCHMARK_PATTERN_LENGTH);
+                    &intervals[start..start + BENCHMARK_PATTERN_LENGTH]
+                })
+                .collect();
+            json!({ "intervals": intervals, "patterns": patterns })
+        },
+        GpuTaskType::KeyHeatmap => {
+            let mut timestamp = 0.0;
+            let events: Vec<Value> = (0..size)
+                .map(|_| {
+                    timestamp += 40.0 + (next_random(&mut seed) * 200.0).round();
+                    let key = BENCHMARK_KEYS[(next_random(&mut seed) * BENCHMARK_KEYS.len() as f64) as usize % BENCHMARK_KEYS.len()];
+                    let dwell = 30.0 + (next_random(&mut seed) * 90.0).round();
+                    json!({ "key": key, "timestamp": timestamp, "releaseTimestamp": timestamp + dwell })
+                })
+                .collect();
+            json!({ "events": events })
+        },
+        other => return Err(Error::from_reason(format!("GPU 경로가 없는 작업 유형입니다: {:?}", other))),
+    };
+    Ok(workload.to_string())
+}
+
+// 작업 유형의 계산 함수 실행 (capabilities가 없으면 CPU 경로)
+fn run_workload(task_type: GpuTaskType, data: &str, capabilities: Option<&GpuCapabilities>) -> Result<Value> {
+    match task_type {
+        GpuTaskType::PatternDetection => computation::pattern::perform_pattern_detection(data, capabilities),
+        GpuTaskType::KeyHeatmap => computation::heatmap::perform_key_heatmap(data, capabilities),
+        other => Err(Error::from_reason(format!("GPU 경로가 없는 작업 유형입니다: {:?}", other))),
+    }
+}
+
+// 한 경로 측정 (준비 실행 후 중앙값) - 시간, RSS 변화량, 마지막 결과 반환
+fn measure(task_type: GpuTaskType, data: &str, capabilities: Option<&GpuCapabilities>) -> Result<(f64, Option<i64>, Value)> {
+    let rss_before = analyzer::get_process_memory_info().ok().and_then(|info| info.rss);
+
+    let mut result = run_workload(task_type, data, capabilities)?;
+    let mut durations = Vec::with_capacity(ITERATIONS);
+    for _ in 0..ITERATIONS {
+        let started = Instant::now();
+        result = run_workload(task_type, data, capabilities)?;
+        durations.push(started.elapsed().as_secs_f64() * 1000.0);
+    }
+    durations.sort_by(|a, b| a.total_cmp(b));
+
+    let rss_after = analyzer::get_process_memory_info().ok().and_then(|info| info.rss);
+    let rss_delta = rss_before.zip(rss_after).map(|(before, after)| after as i64 - before as i64);
+    Ok((durations[durations.len() / 2], rss_delta, result))
+}
+
+// 벤치마크 실행 (블로킹)
+fn run_benchmark(task_type: GpuTaskType, size: u32) -> Result<Value> {
+    let _active = super::ActiveTaskGuard::enter();
+    let data = build_workload(task_type, size as usize)?;
+
+    let (cpu_ms, cpu_rss_delta, _) = measure(task_type, &data, None)?;
+
+    // GPU 경로 (사용할 수 없거나 실행 중 CPU로 대체되면 결과를 저장하지 않음)
+    let capabilities = if settings::is_hardware_acceleration_enabled() {
+        context::get_capabilities().map_err(|e| e.reason.clone())
+    } else {
+        Err("GPU 연산이 꺼져 있습니다".to_string())
+    };
+    let gpu = capabilities.and_then(|caps| {
+        if !caps.compute_supported {
+            return Err("GPU 장치가 컴퓨트 셰이더를 지원하지 않습니다".to_string());
+        }
+        let (gpu_ms, rss_delta, result) = measure(task_type, &data, Some(&caps)).map_err(|e| e.reason.clone())?;
+        if result["backend"] != "gpu" {
+            return Err(result["gpu_error"].as_str().unwrap_or("GPU 경로를 사용할 수 없습니다").to_string());
+        }
+        Ok((gpu_ms, rss_delta))
+    });
+
+    let (gpu_ms, gpu_rss_delta, gpu_error) = match gpu {
+        Ok((gpu_ms, rss_delta)) => (Some(gpu_ms), rss_delta, None),
+        Err(e) => (None, None, Some(e)),
+    };
+
+    let mut persisted = false;
+    let mut preferred = "cpu";
+    let speedup = gpu_ms.filter(|gpu_ms| *gpu_ms > 0.0).map(|gpu_ms| cpu_ms / gpu_ms);
+
+    if let Some(gpu_ms) = gpu_ms {
+        preferred = if gpu_ms < cpu_ms { "gpu" } else { "cpu" };
+        let fingerprint = shader_cache::device_fingerprint();
+        let record = BenchmarkRecord {
+            task_type: format!("{:?}", task_type),
+            size,
+            cpu_ms,
+            gpu_ms,
+            speedup: speedup.unwrap_or(0.0),
+            preferred: preferred.to_string(),
+            device_name: accelerator::get_device_name(),
+            device_fingerprint: fingerprint.clone(),
+            measured_at: clock::now_millis(),
+        };
+        persisted = save_record(record_key(&fingerprint, task_type), record);
+        info!("GPU 벤치마크 ({:?}, {}): CPU {:.2}ms, GPU {:.2}ms -> {}", task_type, size, cpu_ms, gpu_ms, preferred);
+    } else {
+        warn!("GPU 벤치마크 ({:?}): GPU 경로 측정 실패: {:?}", task_type, gpu_error);
+    }
+
+    Ok(json!({
+        "success": true,
+        "task_type": task_type as i32,
+        "size": size,
+        "iterations": ITERATIONS,
+        "input_bytes": data.len(),
+        "cpu": { "duration_ms": cpu_ms, "rss_delta_bytes": cpu_rss_delta },
+        "gpu": gpu_ms.map(|gpu_ms| json!({ "duration_ms": gpu_ms, "rss_delta_bytes": gpu_rss_delta })),
+        "gpu_error": gpu_error,
+        "speedup": speedup,
+        "preferred": preferred,
+        "persisted": persisted,
+        "device_name": accelerator::get_device_name(),
+        "timestamp": clock::now_millis()
+    }))
+}
+
+/// CPU/GPU 경로 벤치마크 실행
+///
+/// `task_type`(패턴 감지, 키 히트맵)의 대표 워크로드를 `size`(기본 10000) 크기로 만들어
+/// CPU와 GPU 경로에서 각각 실행하고 시간, 속도 향상 비율, 메모리(RSS) 변화를 JSON으로 반환합니다.
+/// 두 경로를 모두 측정하면 결과를 저장하고, 이후 auto 모드에서 더 빠른 경로를 선택합니다.
+#[napi]
+pub async fn run_gpu_benchmark(task_type: GpuTaskType, size: Option<u32>) -> napi::Result<String> {
+    let size = size.unwrap_or(DEFAULT_SIZE).clamp(MIN_SIZE, MAX_SIZE);
+
+    let result = tokio::task::spawn_blocking(move || run_benchmark(task_type, size))
+        .await
+        .map_err(|e| Error::from_reason(format!("GPU 벤치마크 실행 실패: {}", e)))??;
+
+    Ok(result.to_string())
+}
+
+/// 저장된 GPU 벤치마크 결과 조회
+///
+/// 모든 장치의 결과와 현재 장치 지문을 JSON으로 반환합니다.
+#[napi]
+pub fn get_gpu_benchmark_results() -> String {
+    let records: Vec<BenchmarkRecord> = with_store(|store| store.file.records.values().cloned().collect());
+    json!({
+        "device_fingerprint": shader_cache::device_fingerprint(),
+        "compute_mode": settings::get_compute_mode(),
+        "records": records
+    }).to_string()
+}
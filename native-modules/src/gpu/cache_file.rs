@@ -0,0 +1,144 @@
+//! 셰이더 캐시 디렉터리의 기록 파일 모듈
+//!
+//! GPU 분배 프로필, 벤치마크 결과처럼 셰이더 캐시 디렉터리에 두는 작은 JSON 파일을 불러와 메모리에 보관합니다.
+//! 셰이더 캐시 디렉터리가 바뀌면 저장하지 않은 변경을 먼저 쓴 뒤 새 디렉터리의 파일을 다시 불러오며,
+//! 디렉터리가 없으면 메모리에만 보관합니다.
+
+use std::fs;
+use std::path::PathBuf;
+use parking_lot::Mutex;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use log::warn;
+use crate::gpu::shader_cache;
+
+/// 셰이더 캐시 디렉터리에 저장하는 기록 파일 형식
+pub trait CacheFile: Serialize + DeserializeOwned + Default {
+    /// 파일 이름
+    const FILE_NAME: &'static str;
+    /// 로그에 쓰는 이름
+    const LABEL: &'static str;
+    /// 들여쓰기해 저장할지 여부
+    const PRETTY: bool = false;
+
+    /// 현재 형식 버전의 빈 파일
+    fn empty() -> Self;
+
+    /// 현재 형식 버전인지 확인 (아니면 버리고 빈 파일로 시작)
+    fn is_current(&self) -> bool;
+}
+
+/// 불러온 기록 파일과 불러온 디렉터리
+#[derive(Default)]
+pub struct CacheFileStore<F> {
+    dir: Option<PathBuf>,
+    loaded: bool,
+    /// 파일에 쓰지 않은 변경 수
+    pub unsaved: u64,
+    pub file: F,
+}
+
+impl<F: CacheFile> CacheFileStore<F> {
+    /// 기록 파일 경로 (셰이더 캐시 디렉터리가 없으면 None)
+    pub fn path(&self) -> Option<PathBuf> {
+        self.dir.as_ref().map(|dir| dir.join(F::FILE_NAME))
+    }
+
+    /// 파일에 저장 - 파일에 기록했는지 반환
+    pub fn save(&mut self) -> bool {
+        self.unsaved = 0;
+        let path = match self.path() {
+            Some(path) => path,
+            None => return false,
+        };
+        let content = if F::PRETTY {
+            serde_json::to_string_pretty(&self.file)
+        } else {
+            serde_json::to_string(&self.file)
+        };
+        let written = content
+            .map_err(|e| e.to_string())
+            .and_then(|content| fs::write(path, content).map_err(|e| e.to_string()));
+        if let Err(e) = &written {
+            warn!("{} 저장 실패: {}", F::LABEL, e);
+        }
+        written.is_ok()
+    }
+}
+
+/// 불러온 기록으로 작업 실행
+///
+/// 아직 불러오지 않았거나 셰이더 캐시 디렉터리가 바뀌었으면 먼저 (다시) 불러옵니다.
+pub fn with_store<F: CacheFile, R>(
+    store: &Mutex<CacheFileStore<F>>,
+    f: impl FnOnce(&mut CacheFileStore<F>) -> R,
+) -> R {
+    let dir = shader_cache::cache_dir();
+    let mut store = store.lock();
+
+    if !store.loaded || store.dir != dir {
+        if store.unsaved > 0 {
+            store.save();
+        }
+        store.file = dir.as_ref()
+            .and_then(|dir| fs::read_to_string(dir.join(F::FILE_NAME)).ok())
+            .and_then(|content| serde_json::from_str::<F>(&content).ok())
+            .filter(F::is_current)
+            .unwrap_or_else(F::empty);
+        store.dir = dir;
+        store.loaded = true;
+    }
+
+    f(&mut store)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Default, Serialize, Deserialize)]
+    struct TestFile {
+        version: u32,
+        values: BTreeMap<String, u32>,
+    }
+
+    impl CacheFile for TestFile {
+        const FILE_NAME: &'static str = "test-cache-file.json";
+        const LABEL: &'static str = "테스트 기록";
+
+        fn empty() -> Self {
+            TestFile { version: 1, values: BTreeMap::new() }
+        }
+
+        fn is_current(&self) -> bool {
+            self.version == 1
+        }
+    }
+
+    #[test]
+    fn saves_and_reloads_from_cache_dir() {
+        let dir = std::env::temp_dir().join(format!("cache-file-{}", std::process::id()));
+        shader_cache::set_cache_dir(&dir).unwrap();
+
+        let store: Mutex<CacheFileStore<TestFile>> = Mutex::new(CacheFileStore::default());
+        let saved = with_store(&store, |store| {
+            store.file.values.insert("a".to_string(), 1);
+            store.save()
+        });
+        assert!(saved);
+
+        // 새로 불러오면 저장한 값이 남아 있음
+        let store: Mutex<CacheFileStore<TestFile>> = Mutex::new(CacheFileStore::default());
+        assert_eq!(with_store(&store, |store| store.file.values.get("a").copied()), Some(1));
+
+        // 형식 버전이 다르면 버리고 빈 파일로 시작
+        fs::write(dir.join(TestFile::FILE_NAME), r#"{"version":0,"values":{"a":1}}"#).unwrap();
+        let store: Mutex<CacheFileStore<TestFile>> = Mutex::new(CacheFileStore::default());
+        assert!(with_store(&store, |store| store.file.values.is_empty()));
+
+        shader_cache::detach();
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
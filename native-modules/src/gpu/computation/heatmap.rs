@@ -0,0 +1,314 @@
+use napi::bindgen_prelude::Error as NapiError;
+use serde_json::{json, Value};
+use log::{debug, warn};
+use wgpu::util::DeviceExt;
+use crate::gpu::types::GpuCapabilities;
+use crate::gpu::{context, device, shader};
+
+// 셰이더의 워크그룹 크기 (get_key_heatmap_shader의 @workgroup_size와 같아야 함)
+const HEATMAP_WORKGROUP_SIZE: u32 = 256;
+
+// 키별 통계 항목 수 (입력 수, 누름 시간 합계/개수, 이동 시간 합계/개수)
+const STATS_PER_KEY: usize = 5;
+
+// 셰이더에 값이 없음을 알리는 표시 (WGSL의 MISSING 상수와 같아야 함)
+const MISSING_VALUE: u32 = u32::MAX;
+
+// 이 시간(ms)을 넘는 이동 시간은 타이핑 중단으로 보고 제외
+const MAX_FLIGHT_MS: f64 = 2000.0;
+
+// 표준 키보드(ANSI QWERTY) 배치, 행 단위
+const KEYBOARD_ROWS: [&[&str]; 5] = [
+    &["`", "1", "2", "3", "4", "5", "6", "7", "8", "9", "0", "-", "=", "backspace"],
+    &["tab", "q", "w", "e", "r", "t", "y", "u", "i", "o", "p", "[", "]", "\\"],
+    &["a", "s", "d", "f", "g", "h", "j", "k", "l", ";", "'", "enter"],
+    &["shift", "z", "x", "c", "v", "b", "n", "m", ",", ".", "/"],
+    &["space"],
+];
+
+// 셰이더 유니폼 (WGSL Uniforms 구조체와 같은 배치)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct HeatmapUniforms {
+    event_count: u32,
+    key_count: u32,
+    _padding: [u32; 2],
+}
+
+// 배치표 기준으로 변환된 키 입력 (시간은 ms 단위 정수)
+struct KeyEvent {
+    key_index: u32,
+    dwell_ms: Option<u32>,
+    flight_ms: Option<u32>,
+}
+
+// 키별 누적 통계
+#[derive(Debug, Clone, Copy, Default)]
+struct KeyStats {
+    count: u32,
+    dwell_total: u32,
+    dwell_count: u32,
+    flight_total: u32,
+    flight_count: u32,
+}
+
+/// 키 입력 히트맵 계산
+///
+/// 키 입력 기록(`events` 또는 배열 자체)의 각 항목은 `{ key, timestamp, releaseTimestamp? }` 형식입니다.
+/// 표준 키보드 배치의 키마다 입력 횟수와 평균 누름 시간(dwell), 평균 이동 시간(flight)을 계산합니다.
+/// 두벌식 한글 자모는 같은 자리의 영문 키로 집계합니다.
+pub fn perform_key_heatmap(data: &str, capabilities: Option<&GpuCapabilities>) -> Result<Value, NapiError> {
+    let input: Value = match serde_json::from_str(data) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            return Ok(json!({
+                "success": false,
+                "error": format!("JSON 파싱 실패: {}", e),
+                "result": null
+            }));
+        }
+    };
+
+    let raw_events = match input.get("events").unwrap_or(&input).as_array() {
+        Some(events) => events,
+        None => {
+            return Ok(json!({
+                "success": false,
+                "error": "키 입력 기록(events) 배열이 필요합니다",
+                "result": null
+            }));
+        }
+    };
+
+    let layout: Vec<&str> = KEYBOARD_ROWS.iter().flat_map(|row| row.iter().copied()).collect();
+    let (events, unmapped) = prepare_events(raw_events, &layout);
+
+    let gpu_usable = !events.is_empty()
+        && context::is_acceleration_enabled()
+        && capabilities.map(|c| c.compute_supported).unwrap_or(false);
+
+    let mut gpu_error = None;
+    let mut stats = None;
+
+    if gpu_usable {
+        match aggregate_gpu(&events, layout.len()) {
+            Ok(result) => stats = Some(result),
+            Err(e) => {
+                warn!("GPU 히트맵 계산 실패, CPU로 대체: {}", e);
+                gpu_error = Some(e.reason);
+            }
+        }
+    }
+
+    let backend = if stats.is_some() { "gpu" } else { "cpu" };
+    let stats = stats.unwrap_or_else(|| aggregate_cpu(&events, layout.len()));
+    debug!("키 히트맵 계산 완료: {}개 입력 ({})", events.len(), backend);
+
+    let total = events.len();
+    let max_count = stats.iter().map(|s| s.count).max().unwrap_or(0);
+
+    let mut keys = Vec::with_capacity(layout.len());
+    let mut index = 0;
+    for (row, row_keys) in KEYBOARD_ROWS.iter().enumerate() {
+        for (col, key) in row_keys.iter().enumerate() {
+            let s = stats[index];
+            keys.push(json!({
+                "key": key,
+                "row": row,
+                "col": col,
+                "count": s.count,
+                "frequency": if total > 0 { s.count as f64 / total as f64 } else { 0.0 },
+                "intensity": if max_count > 0 { s.count as f64 / max_count as f64 } else { 0.0 },
+                "avg_dwell_ms": average(s.dwell_total, s.dwell_count),
+                "avg_flight_ms": average(s.flight_total, s.flight_count)
+            }));
+            index += 1;
+        }
+    }
+
+    Ok(json!({
+        "success": true,
+        "layout": "ansi-qwerty",
+        "rows": KEYBOARD_ROWS,
+        "keys": keys,
+        "total_keystrokes": total,
+        "unmapped_keystrokes": unmapped,
+        "max_count": max_count,
+        "backend": backend,
+        "gpu_error": gpu_error
+    }))
+}
+
+// 키 입력 기록을 배치표 인덱스와 시간 값으로 변환
+//
+// 이동 시간은 이전 키를 뗀 시점(뗀 시점이 없으면 누른 시점)부터 현재 키를 누른 시점까지입니다.
+// 배치표에 없는 키도 이동 시간 계산의 기준점으로는 사용합니다.
+fn prepare_events(raw_events: &[Value], layout: &[&str]) -> (Vec<KeyEvent>, usize) {
+    let mut events = Vec::with_capacity(raw_events.len());
+    let mut unmapped = 0;
+    let mut previous_end: Option<f64> = None;
+
+    for raw in raw_events {
+        let (key, pressed_at) = match (raw["key"].as_str(), raw["timestamp"].as_f64()) {
+            (Some(key), Some(pressed_at)) => (key, pressed_at),
+            _ => continue,
+        };
+        let released_at = raw["releaseTimestamp"].as_f64().filter(|t| *t >= pressed_at);
+
+        let flight_ms = previous_end
+            .map(|end| pressed_at - end)
+            .filter(|flight| (0.0..=MAX_FLIGHT_MS).contains(flight))
+            .map(|flight| flight.round() as u32);
+        let dwell_ms = released_at.map(|t| (t - pressed_at).round().min((MISSING_VALUE - 1) as f64) as u32);
+        previous_end = Some(released_at.unwrap_or(pressed_at));
+
+        match normalize_key(key).and_then(|name| layout.iter().position(|k| *k == name)) {
+            Some(index) => events.push(KeyEvent { key_index: index as u32, dwell_ms, flight_ms }),
+            None => unmapped += 1,
+        }
+    }
+
+    (events, unmapped)
+}
+
+// 키 이름을 배치표 이름으로 변환 (대문자, Shift 조합 기호, 두벌식 자모 포함)
+fn normalize_key(key: &str) -> Option<&'static str> {
+    match key.to_lowercase().as_str() {
+        " " | "space" | "spacebar" => return Some("space"),
+        "backspace" => return Some("backspace"),
+        "enter" | "return" => return Some("enter"),
+        "tab" => return Some("tab"),
+        "shift" | "shiftleft" | "shiftright" => return Some("shift"),
+        _ => {}
+    }
+
+    let mut chars = key.chars();
+    let ch = chars.next()?;
+    if chars.next().is_some() {
+        return None;
+    }
+
+    let base = match ch.to_ascii_lowercase() {
+        '~' => '`', '!' => '1', '@' => '2', '#' => '3', '$' => '4', '%' => '5',
+        '^' => '6', '&' => '7', '*' => '8', '(' => '9', ')' => '0', '_' => '-',
+        '+' => '=', '{' => '[', '}' => ']', '|' => '\\', ':' => ';', '"' => '\'',
+        '<' => ',', '>' => '.', '?' => '/',
+        'ㅂ' | 'ㅃ' => 'q', 'ㅈ' | 'ㅉ' => 'w', 'ㄷ' | 'ㄸ' => 'e', 'ㄱ' | 'ㄲ' => 'r',
+        'ㅅ' | 'ㅆ' => 't', 'ㅛ' => 'y', 'ㅕ' => 'u', 'ㅑ' => 'i', 'ㅐ' | 'ㅒ' => 'o',
+        'ㅔ' | 'ㅖ' => 'p', 'ㅁ' => 'a', 'ㄴ' => 's', 'ㅇ' => 'd', 'ㄹ' => 'f',
+        'ㅎ' => 'g', 'ㅗ' => 'h', 'ㅓ' => 'j', 'ㅏ' => 'k', 'ㅣ' => 'l',
+        'ㅋ' => 'z', 'ㅌ' => 'x', 'ㅊ' => 'c', 'ㅍ' => 'v', 'ㅠ' => 'b', 'ㅜ' => 'n', 'ㅡ' => 'm',
+        other => other,
+    };
+
+    KEYBOARD_ROWS.iter()
+        .flat_map(|row| row.iter().copied())
+        .find(|k| k.len() == base.len_utf8() && k.starts_with(base))
+}
+
+// CPU 집계
+fn aggregate_cpu(events: &[KeyEvent], key_count: usize) -> Vec<KeyStats> {
+    let mut stats = vec![KeyStats::default(); key_count];
+
+    for event in events {
+        let s = &mut stats[event.key_index as usize];
+        s.count += 1;
+        if let Some(dwell) = event.dwell_ms {
+            s.dwell_total = s.dwell_total.wrapping_add(dwell);
+            s.dwell_count += 1;
+        }
+        if let Some(flight) = event.flight_ms {
+            s.flight_total = s.flight_total.wrapping_add(flight);
+            s.flight_count += 1;
+        }
+    }
+
+    stats
+}
+
+// GPU 집계 (입력마다 스레드 하나가 원자적 덧셈으로 키별 통계를 누적)
+fn aggregate_gpu(events: &[KeyEvent], key_count: usize) -> Result<Vec<KeyStats>, NapiError> {
+    let compute = device::acquire_compute_device()?;
+    let device = &compute.device;
+
+    let key_indices: Vec<u32> = events.iter().map(|e| e.key_index).collect();
+    let dwell_times: Vec<u32> = events.iter().map(|e| e.dwell_ms.unwrap_or(MISSING_VALUE)).collect();
+    let flight_times: Vec<u32> = events.iter().map(|e| e.flight_ms.unwrap_or(MISSING_VALUE)).collect();
+    let uniforms = HeatmapUniforms {
+        event_count: events.len() as u32,
+        key_count: key_count as u32,
+        _padding: [0; 2],
+    };
+    let stats_size = (key_count * STATS_PER_KEY * std::mem::size_of::<u32>()) as wgpu::BufferAddress;
+
+    let pipeline = compute.create_pipeline("key-heatmap", shader::get_key_heatmap_shader())?;
+
+    let key_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("heatmap-keys"),
+        contents: bytemuck::cast_slice(&key_indices),
+        usage: wgpu::BufferUsages::STORAGE,
+    });
+    let dwell_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("heatmap-dwell"),
+        contents: bytemuck::cast_slice(&dwell_times),
+        usage: wgpu::BufferUsages::STORAGE,
+    });
+    let flight_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("heatmap-flight"),
+        contents: bytemuck::cast_slice(&flight_times),
+        usage: wgpu::BufferUsages::STORAGE,
+    });
+    let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("heatmap-uniforms"),
+        contents: bytemuck::bytes_of(&uniforms),
+        usage: wgpu::BufferUsages::UNIFORM,
+    });
+    // 생성 시 0으로 초기화되므로 누적 버퍼로 바로 사용
+    let stats_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("heatmap-stats"),
+        size: stats_size,
+        usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+        mapped_at_creation: false,
+    });
+
+    let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("key-heatmap"),
+        layout: &pipeline.get_bind_group_layout(0),
+        entries: &[
+            wgpu::BindGroupEntry { binding: 0, resource: key_buffer.as_entire_binding() },
+            wgpu::BindGroupEntry { binding: 1, resource: dwell_buffer.as_entire_binding() },
+            wgpu::BindGroupEntry { binding: 2, resource: flight_buffer.as_entire_binding() },
+            wgpu::BindGroupEntry { binding: 3, resource: stats_buffer.as_entire_binding() },
+            wgpu::BindGroupEntry { binding: 4, resource: uniform_buffer.as_entire_binding() },
+        ],
+    });
+
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("key-heatmap"),
+    });
+    {
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("key-heatmap"),
+        });
+        pass.set_pipeline(&pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups((events.len() as u32).div_ceil(HEATMAP_WORKGROUP_SIZE), 1, 1);
+    }
+
+    let values: Vec<u32> = compute.submit_and_read(encoder, &stats_buffer, stats_size)?;
+    let stats = values.chunks_exact(STATS_PER_KEY)
+        .map(|s| KeyStats {
+            count: s[0],
+            dwell_total: s[1],
+            dwell_count: s[2],
+            flight_total: s[3],
+            flight_count: s[4],
+        })
+        .collect();
+
+    Ok(stats)
+}
+
+fn average(total: u32, count: u32) -> Option<f64> {
+    if count > 0 { Some(total as f64 / count as f64) } else { None }
+}
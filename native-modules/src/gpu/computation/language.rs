@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+use once_cell::sync::Lazy;
+
+// 언어 감지에 사용할 최대 문자 수
+const DETECTION_SAMPLE_CHARS: usize = 10_000;
+
+// 한글 비율 기준 (이상이면 한국어, 이하이면 영어, 그 사이는 혼합)
+const KOREAN_RATIO_THRESHOLD: f64 = 0.7;
+const ENGLISH_RATIO_THRESHOLD: f64 = 0.3;
+
+// 영어 불용어 목록
+const ENGLISH_STOPWORDS: &[&str] = &[
+    "a", "about", "after", "all", "also", "an", "and", "any", "are", "as", "at", "be",
+    "because", "been", "but", "by", "can", "could", "do", "does", "for", "from", "had",
+    "has", "have", "he", "her", "here", "his", "how", "i", "if", "in", "into", "is", "it",
+    "its", "just", "me", "more", "most", "my", "no", "not", "of", "on", "only", "or",
+    "other", "our", "out", "over", "she", "should", "so", "some", "such", "than", "that",
+    "the", "their", "them", "then", "there", "these", "they", "this", "those", "through",
+    "to", "up", "very", "was", "we", "were", "what", "when", "where", "which", "while",
+    "who", "will", "with", "would", "you", "your",
+];
+
+// 한국어 불용어 목록 (대명사, 접속사, 보조 용언, 단독으로 쓰인 조사)
+const KOREAN_STOPWORDS: &[&str] = &[
+    "그", "이", "저", "것", "수", "등", "및", "때", "더", "또", "좀", "잘", "안", "못", "각",
+    "그리고", "그러나", "하지만", "그래서", "그런데", "또는", "혹은", "때문", "위해", "대한",
+    "통해", "대해", "우리", "저희", "여기", "거기", "이런", "그런", "저런", "어떤", "모든",
+    "매우", "정말", "너무", "아주", "있다", "없다", "하다", "되다", "이다", "한다", "했다",
+    "합니다", "있습니다", "입니다", "됩니다", "했습니다",
+    "은", "는", "가", "을", "를", "의", "에", "도", "만", "와", "과", "로", "으로", "에서",
+];
+
+// 단어 끝에서 제거할 한국어 조사 (긴 것부터 검사)
+const KOREAN_PARTICLES: &[&str] = &[
+    "에서는", "으로는", "에게서", "까지는", "부터는",
+    "에서", "에게", "한테", "으로", "까지", "부터", "처럼", "보다", "이나", "에는", "와는", "과는",
+    "은", "는", "이", "가", "을", "를", "의", "에", "도", "만", "와", "과", "로", "나",
+];
+
+static ENGLISH_STOPWORD_SET: Lazy<HashSet<&'static str>> =
+    Lazy::new(|| ENGLISH_STOPWORDS.iter().copied().collect());
+
+static KOREAN_STOPWORD_SET: Lazy<HashSet<&'static str>> =
+    Lazy::new(|| KOREAN_STOPWORDS.iter().copied().collect());
+
+/// 문서 언어
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentLanguage {
+    Korean,
+    English,
+    Mixed,
+}
+
+impl DocumentLanguage {
+    /// 언어 코드 파싱 ("ko", "en", "mixed")
+    ///
+    /// "auto"나 알 수 없는 코드는 `None`을 반환하며, 이 경우 자동 감지를 사용합니다.
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.to_lowercase().as_str() {
+            "ko" | "kor" | "korean" => Some(DocumentLanguage::Korean),
+            "en" | "eng" | "english" => Some(DocumentLanguage::English),
+            "mixed" => Some(DocumentLanguage::Mixed),
+            _ => None,
+        }
+    }
+
+    /// 언어 코드
+    pub fn code(&self) -> &'static str {
+        match self {
+            DocumentLanguage::Korean => "ko",
+            DocumentLanguage::English => "en",
+            DocumentLanguage::Mixed => "mixed",
+        }
+    }
+}
+
+/// 문서 언어 감지
+///
+/// 앞부분 일부 문자의 한글/라틴 문자 비율로 판단합니다.
+pub fn detect_language(text: &str) -> DocumentLanguage {
+    let mut hangul = 0usize;
+    let mut latin = 0usize;
+
+    for c in text.chars().take(DETECTION_SAMPLE_CHARS) {
+        if is_hangul(c) {
+            hangul += 1;
+        } else if c.is_ascii_alphabetic() {
+            latin += 1;
+        }
+    }
+
+    let total = hangul + latin;
+    if total == 0 {
+        return DocumentLanguage::Mixed;
+    }
+
+    let korean_ratio = hangul as f64 / total as f64;
+    if korean_ratio >= KOREAN_RATIO_THRESHOLD {
+        DocumentLanguage::Korean
+    } else if korean_ratio <= ENGLISH_RATIO_THRESHOLD {
+        DocumentLanguage::English
+    } else {
+        DocumentLanguage::Mixed
+    }
+}
+
+/// 언어에 맞는 불용어인지 확인 (혼합 문서는 두 목록 모두 적용)
+pub fn is_stopword(word: &str, language: DocumentLanguage) -> bool {
+    match language {
+        DocumentLanguage::Korean => KOREAN_STOPWORD_SET.contains(word),
+        DocumentLanguage::English => ENGLISH_STOPWORD_SET.contains(word),
+        DocumentLanguage::Mixed => {
+            KOREAN_STOPWORD_SET.contains(word) || ENGLISH_STOPWORD_SET.contains(word)
+        }
+    }
+}
+
+/// 단어 정규화
+///
+/// 소문자로 변환하고, 한국어 단어는 끝에 붙은 조사를 제거합니다.
+/// 조사를 떼고 남는 부분이 두 글자 미만이면 그대로 둡니다 (예: "아이"의 "이").
+pub fn normalize_word(word: &str, language: DocumentLanguage) -> String {
+    let lower = word.to_lowercase();
+
+    if language == DocumentLanguage::English || !lower.chars().any(is_hangul) {
+        return lower;
+    }
+
+    for particle in KOREAN_PARTICLES {
+        if let Some(stem) = lower.strip_suffix(particle) {
+            if stem.chars().count() >= 2 {
+                return stem.to_string();
+            }
+        }
+    }
+
+    lower
+}
+
+// 한글 문자 여부 (음절, 자모, 호환 자모)
+fn is_hangul(c: char) -> bool {
+    matches!(c, '\u{AC00}'..='\u{D7A3}' | '\u{1100}'..='\u{11FF}' | '\u{3130}'..='\u{318F}')
+}
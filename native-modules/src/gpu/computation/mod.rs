@@ -5,6 +5,8 @@ pub mod pattern;
 pub mod image;
 pub mod data;
 pub mod typing;
+pub mod language;
+pub mod heatmap;
 
 // 모듈에서 공통 함수 재노출
 pub use text::perform_text_analysis;
@@ -12,3 +14,4 @@ pub use pattern::perform_pattern_detection;
 pub use image::perform_image_processing;
 pub use data::perform_data_aggregation;
 pub use typing::perform_typing_statistics;
+pub use heatmap::perform_key_heatmap;
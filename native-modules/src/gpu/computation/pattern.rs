@@ -1,27 +1,268 @@
 use napi::bindgen_prelude::Error as NapiError;
 use serde_json::{json, Value};
+use rayon::prelude::*;
+use log::{debug, warn};
+use wgpu::util::DeviceExt;
 use crate::gpu::types::GpuCapabilities;
+use crate::gpu::{context, device, shader};
+
+// 셰이더의 워크그룹 크기 (get_pattern_detection_shader의 @workgroup_size와 같아야 함)
+const PATTERN_WORKGROUP_SIZE: u32 = 256;
+
+// 기본 매칭 임계값 (유사도 0.0 ~ 1.0)
+const DEFAULT_MATCH_THRESHOLD: f32 = 0.8;
+
+// 셰이더 유니폼 (WGSL Uniforms 구조체와 같은 배치)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct PatternUniforms {
+    input_size: u32,
+    pattern_size: u32,
+    pattern_count: u32,
+    threshold: f32,
+}
+
+// 패턴별 최적 일치 결과
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PatternMatch {
+    similarity: f32,
+    position: u32,
+}
 
 /// 패턴 감지 수행
-/// 
-/// 입력 데이터를 분석하여 패턴을 감지합니다.
-pub fn perform_pattern_detection(_data: &str, _capabilities: Option<&GpuCapabilities>) -> Result<Value, NapiError> {
-    // 실제 패턴 감지 로직 (향후 구현)
-    let result = json!({
-        "detected": true,
-        "patterns": [],
-        "count": 0,
-        "confidence": 0.0,
-    });
-    
-    Ok(result)
+///
+/// 키 입력 간격 배열(`intervals`)에서 각 패턴(`patterns`)과 가장 비슷한 구간을 찾습니다.
+/// GPU 가속이 활성화되어 있고 계산 셰이더를 지원하면 GPU에서 실행하고,
+/// 실패하거나 사용할 수 없으면 CPU로 대체합니다.
+/// `benchmark`가 true이면 CPU로도 실행해 두 경로의 시간을 비교합니다.
+pub fn perform_pattern_detection(data: &str, capabilities: Option<&GpuCapabilities>) -> Result<Value, NapiError> {
+    let input: Value = match serde_json::from_str(data) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            return Ok(json!({
+                "success": false,
+                "error": format!("JSON 파싱 실패: {}", e),
+                "result": null
+            }));
+        }
+    };
+
+    let intervals = parse_f32_array(input.get("intervals").or_else(|| input.get("keyIntervals")));
+    let patterns: Vec<Vec<f32>> = input["patterns"].as_array()
+        .map(|arr| arr.iter().map(|p| parse_f32_array(Some(p))).filter(|p| !p.is_empty()).collect())
+        .unwrap_or_default();
+    let threshold = input["threshold"].as_f64().map(|t| t as f32).unwrap_or(DEFAULT_MATCH_THRESHOLD);
+    let benchmark = input["benchmark"].as_bool().unwrap_or(false);
+
+    if intervals.is_empty() || patterns.is_empty() {
+        return Ok(json!({
+            "success": false,
+            "error": "intervals와 patterns 배열이 필요합니다",
+            "result": null
+        }));
+    }
+
+    // 유사도는 차이를 1.0으로 잘라 계산하므로 모든 값을 같은 배율로 0~1 범위에 맞춤
+    let scale = intervals.iter().chain(patterns.iter().flatten())
+        .fold(0.0f32, |max, v| max.max(v.abs()));
+    let scale = if scale > 0.0 { scale } else { 1.0 };
+    let intervals: Vec<f32> = intervals.iter().map(|v| v / scale).collect();
+    let patterns: Vec<Vec<f32>> = patterns.iter().map(|p| p.iter().map(|v| v / scale).collect()).collect();
+
+    let gpu_usable = context::is_acceleration_enabled()
+        && capabilities.map(|c| c.compute_supported).unwrap_or(false);
+
+    let mut gpu_error = None;
+    let mut gpu_ms = None;
+    let mut matches = None;
+
+    if gpu_usable {
+        let started = std::time::Instant::now();
+        match detect_patterns_gpu(&intervals, &patterns, threshold) {
+            Ok(result) => {
+                gpu_ms = Some(started.elapsed().as_secs_f64() * 1000.0);
+                matches = Some(result);
+            },
+            Err(e) => {
+                warn!("GPU 패턴 감지 실패, CPU로 대체: {}", e);
+                gpu_error = Some(e.reason);
+            }
+        }
+    }
+
+    let mut cpu_ms = None;
+    let mut results_match = None;
+    let matches = match matches {
+        Some(result) if !benchmark => result,
+        gpu_result => {
+            let started = std::time::Instant::now();
+            let cpu_result = detect_patterns_cpu(&intervals, &patterns);
+            cpu_ms = Some(started.elapsed().as_secs_f64() * 1000.0);
+
+            match gpu_result {
+                Some(gpu_result) => {
+                    results_match = Some(same_matches(&gpu_result, &cpu_result));
+                    gpu_result
+                },
+                None => cpu_result,
+            }
+        }
+    };
+
+    let backend = if gpu_ms.is_some() { "gpu" } else { "cpu" };
+    debug!("패턴 감지 완료: {}개 패턴 ({})", patterns.len(), backend);
+
+    let pattern_results: Vec<Value> = matches.iter().enumerate()
+        .map(|(index, m)| json!({
+            "pattern_index": index,
+            "similarity": m.similarity,
+            "position": m.position,
+            "matched": m.similarity >= threshold
+        }))
+        .collect();
+    let detected_count = matches.iter().filter(|m| m.similarity >= threshold).count();
+    let confidence = matches.iter().map(|m| m.similarity).fold(0.0f32, f32::max);
+
+    Ok(json!({
+        "detected": detected_count > 0,
+        "patterns": pattern_results,
+        "count": detected_count,
+        "confidence": confidence,
+        "threshold": threshold,
+        "scale": scale,
+        "backend": backend,
+        "gpu_error": gpu_error,
+        "benchmark": {
+            "gpu_ms": gpu_ms,
+            "cpu_ms": cpu_ms,
+            "results_match": results_match,
+            "speedup": match (gpu_ms, cpu_ms) {
+                (Some(gpu), Some(cpu)) if gpu > 0.0 => Some(cpu / gpu),
+                _ => None,
+            }
+        }
+    }))
 }
 
 /// 패턴 분석을 위한 GPU 가속 함수
-/// 
+///
 /// 바이트 데이터를 받아 GPU를 활용하여 패턴 분석을 수행합니다.
 #[napi]
 pub fn analyze_patterns_with_gpu(_data: &[u8]) -> Result<Vec<u8>, NapiError> {
     // 구현 예정 - 향후 GPU를 활용한 패턴 분석 로직 추가
     Ok(Vec::new())
 }
+
+// CPU 패턴 매칭 (셰이더와 같은 알고리즘, 패턴 단위로 병렬 처리)
+fn detect_patterns_cpu(intervals: &[f32], patterns: &[Vec<f32>]) -> Vec<PatternMatch> {
+    patterns.par_iter()
+        .map(|pattern| {
+            let mut best = PatternMatch { similarity: 0.0, position: 0 };
+            if pattern.len() > intervals.len() {
+                return best;
+            }
+
+            for (position, window) in intervals.windows(pattern.len()).enumerate() {
+                let similarity = window.iter().zip(pattern)
+                    .map(|(a, b)| 1.0 - (a - b).abs().min(1.0))
+                    .sum::<f32>() / pattern.len() as f32;
+
+                if similarity > best.similarity {
+                    best = PatternMatch { similarity, position: position as u32 };
+                }
+            }
+
+            best
+        })
+        .collect()
+}
+
+// GPU 패턴 매칭 (버퍼 준비 → 디스패치 → 결과 읽기)
+fn detect_patterns_gpu(intervals: &[f32], patterns: &[Vec<f32>], threshold: f32) -> Result<Vec<PatternMatch>, NapiError> {
+    // 셰이더는 모든 패턴의 길이가 같다고 가정
+    let pattern_size = patterns[0].len();
+    if patterns.iter().any(|p| p.len() != pattern_size) {
+        return Err(NapiError::from_reason("GPU 경로는 길이가 같은 패턴만 지원합니다"));
+    }
+    if pattern_size > intervals.len() {
+        return Err(NapiError::from_reason("패턴이 입력보다 깁니다"));
+    }
+
+    let compute = device::acquire_compute_device()?;
+    let device = &compute.device;
+
+    let flat_patterns: Vec<f32> = patterns.iter().flatten().copied().collect();
+    let uniforms = PatternUniforms {
+        input_size: intervals.len() as u32,
+        pattern_size: pattern_size as u32,
+        pattern_count: patterns.len() as u32,
+        threshold,
+    };
+    let result_size = (patterns.len() * 2 * std::mem::size_of::<f32>()) as wgpu::BufferAddress;
+
+    let pipeline = compute.create_pipeline("pattern-detection", shader::get_pattern_detection_shader())?;
+
+    let input_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("pattern-input"),
+        contents: bytemuck::cast_slice(intervals),
+        usage: wgpu::BufferUsages::STORAGE,
+    });
+    let pattern_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("pattern-patterns"),
+        contents: bytemuck::cast_slice(&flat_patterns),
+        usage: wgpu::BufferUsages::STORAGE,
+    });
+    let uniform_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("pattern-uniforms"),
+        contents: bytemuck::bytes_of(&uniforms),
+        usage: wgpu::BufferUsages::UNIFORM,
+    });
+    let result_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("pattern-results"),
+        size: result_size,
+        usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+        mapped_at_creation: false,
+    });
+    let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("pattern-detection"),
+        layout: &pipeline.get_bind_group_layout(0),
+        entries: &[
+            wgpu::BindGroupEntry { binding: 0, resource: input_buffer.as_entire_binding() },
+            wgpu::BindGroupEntry { binding: 1, resource: pattern_buffer.as_entire_binding() },
+            wgpu::BindGroupEntry { binding: 2, resource: result_buffer.as_entire_binding() },
+            wgpu::BindGroupEntry { binding: 3, resource: uniform_buffer.as_entire_binding() },
+        ],
+    });
+
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+        label: Some("pattern-detection"),
+    });
+    {
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("pattern-detection"),
+        });
+        pass.set_pipeline(&pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups((patterns.len() as u32).div_ceil(PATTERN_WORKGROUP_SIZE), 1, 1);
+    }
+    let values: Vec<f32> = compute.submit_and_read(encoder, &result_buffer, result_size)?;
+    let matches = values.chunks_exact(2)
+        .map(|pair| PatternMatch { similarity: pair[0], position: pair[1] as u32 })
+        .collect();
+
+    Ok(matches)
+}
+
+// GPU/CPU 결과 비교 (부동소수점 오차 허용)
+fn same_matches(a: &[PatternMatch], b: &[PatternMatch]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(x, y)| {
+        (x.similarity - y.similarity).abs() < 1e-4 && x.position == y.position
+    })
+}
+
+// JSON 숫자 배열을 f32 벡터로 변환 (숫자가 아닌 값은 무시)
+fn parse_f32_array(value: Option<&Value>) -> Vec<f32> {
+    value.and_then(|v| v.as_array())
+        .map(|arr| arr.iter().filter_map(|v| v.as_f64()).map(|v| v as f32).collect())
+        .unwrap_or_default()
+}
@@ -1,6 +1,69 @@
 use napi::bindgen_prelude::Error as NapiError;
+use serde::Serialize;
 use serde_json::{json, Value};
+use std::collections::{HashMap, VecDeque};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use rayon::prelude::*;
 use crate::gpu::types::GpuCapabilities;
+use crate::gpu::computation::language::{self, DocumentLanguage};
+
+// 병렬 처리로 전환하는 단어 수 기준
+const PARALLEL_WORD_THRESHOLD: usize = 100_000;
+
+// IDF 계산 시 하나의 문서로 간주하는 구간 크기 (단어 수)
+const SEGMENT_WORD_COUNT: usize = 200;
+
+// 기본 키워드 개수
+const DEFAULT_KEYWORD_COUNT: usize = 10;
+
+// 단어 빈도 결과 캐시 최대 항목 수
+const TERM_FREQUENCY_CACHE_SIZE: usize = 32;
+
+// 기본 단어 빈도 항목 수 (워드 클라우드용)
+const DEFAULT_TERM_COUNT: usize = 50;
+
+// 단어 빈도 결과 캐시 (입력 해시 -> 결과), 오래된 항목부터 제거
+#[derive(Default)]
+struct TermFrequencyCache {
+    entries: HashMap<u64, Value>,
+    order: VecDeque<u64>,
+}
+
+impl TermFrequencyCache {
+    fn get(&self, key: u64) -> Option<&Value> {
+        self.entries.get(&key)
+    }
+    
+    fn insert(&mut self, key: u64, value: Value) {
+        if self.order.len() >= TERM_FREQUENCY_CACHE_SIZE {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+        if self.entries.insert(key, value).is_none() {
+            self.order.push_back(key);
+        }
+    }
+}
+
+static TERM_FREQUENCY_CACHE: Lazy<Mutex<TermFrequencyCache>> =
+    Lazy::new(|| Mutex::new(TermFrequencyCache::default()));
+
+/// 가중치가 부여된 키워드
+#[derive(Debug, Clone, Serialize)]
+pub struct WeightedKeyword {
+    pub word: String,
+    pub weight: f64,
+    pub count: usize,
+}
+
+/// 단어 빈도 계산 결과 캐시 삭제
+pub fn clear_term_frequency_cache() {
+    *TERM_FREQUENCY_CACHE.lock() = TermFrequencyCache::default();
+}
 
 /// 텍스트 분석 수행
 /// 
@@ -25,11 +88,17 @@ pub fn perform_text_analysis(data: &str, capabilities: Option<&GpuCapabilities>)
     // GPU 가속 여부에 따른 추가 정보
     let gpu_accelerated = capabilities.is_some();
     
+    let language = language::detect_language(data);
+    let keywords = extract_weighted_keywords(data, DEFAULT_KEYWORD_COUNT, language)?;
+    
     let result = json!({
         "analyzed": true,
         "word_count": word_count,
         "char_count": char_count,
         "complexity_score": avg_word_length,
+        "keywords": keywords,
+        "language": language.code(),
+        "parallel": word_count >= PARALLEL_WORD_THRESHOLD,
         "gpu_accelerated": gpu_accelerated,
     });
     
@@ -72,32 +141,222 @@ pub fn process_text_with_gpu(data: &[u8]) -> Result<Vec<u8>, NapiError> {
 
 /// 텍스트에서 중요 키워드 추출
 pub fn extract_keywords(text: &str) -> Result<Vec<String>, NapiError> {
-    if text.is_empty() {
+    let keywords = extract_weighted_keywords(text, DEFAULT_KEYWORD_COUNT, language::detect_language(text))?;
+    Ok(keywords.into_iter().map(|k| k.word).collect())
+}
+
+/// TF-IDF 방식의 가중치로 키워드 추출
+/// 
+/// 텍스트를 일정 단어 수의 구간으로 나누어 각 구간을 하나의 문서로 보고 IDF를 계산합니다.
+/// 문서 전체에 고르게 반복되는 단어보다 특정 부분에 집중된 단어가 높은 가중치를 받습니다.
+/// 단어 수가 `PARALLEL_WORD_THRESHOLD` 이상이면 구간별 집계를 병렬로 수행합니다.
+/// `language`에 맞는 불용어를 제외하고, 한국어 단어는 조사를 떼어 집계합니다.
+pub fn extract_weighted_keywords(
+    text: &str,
+    top_n: usize,
+    language: DocumentLanguage,
+) -> Result<Vec<WeightedKeyword>, NapiError> {
+    let words = tokenize_keywords(text, language);
+    if words.is_empty() || top_n == 0 {
         return Ok(Vec::new());
     }
     
-    // 간단한 키워드 추출 로직 구현
-    let words = text.split_whitespace()
-        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()))
-        .filter(|w| w.len() > 3)  // 4글자 이상 단어만 키워드로 간주
-        .map(|w| w.to_lowercase())
-        .collect::<Vec<String>>();
+    // 구간별 단어 빈도 집계
+    let segment_counts: Vec<HashMap<&str, usize>> = if words.len() >= PARALLEL_WORD_THRESHOLD {
+        words.par_chunks(SEGMENT_WORD_COUNT).map(count_segment_words).collect()
+    } else {
+        words.chunks(SEGMENT_WORD_COUNT).map(count_segment_words).collect()
+    };
     
-    // 빈도수 기반 상위 키워드 추출을 위한 준비
-    let mut word_counts = std::collections::HashMap::new();
-    for word in words {
-        *word_counts.entry(word).or_insert(0) += 1;
+    // 전체 빈도(TF)와 등장 구간 수(DF) 병합
+    let mut term_counts: HashMap<&str, (usize, usize)> = HashMap::new();
+    for counts in &segment_counts {
+        for (word, count) in counts {
+            let entry = term_counts.entry(word).or_insert((0, 0));
+            entry.0 += count;
+            entry.1 += 1;
+        }
     }
     
-    // 빈도수 기준 상위 10개 키워드 추출
-    let mut keywords: Vec<(String, usize)> = word_counts.into_iter().collect();
-    keywords.sort_by(|a, b| b.1.cmp(&a.1));
-    let top_keywords = keywords.into_iter()
-        .take(10)
-        .map(|(word, _)| word)
+    let total_words = words.len() as f64;
+    let segment_count = segment_counts.len() as f64;
+    
+    let mut keywords: Vec<WeightedKeyword> = term_counts.into_iter()
+        .map(|(word, (count, doc_freq))| {
+            let tf = count as f64 / total_words;
+            let idf = ((1.0 + segment_count) / (1.0 + doc_freq as f64)).ln() + 1.0;
+            WeightedKeyword {
+                word: word.to_string(),
+                weight: tf * idf,
+                count,
+            }
+        })
         .collect();
     
-    Ok(top_keywords)
+    keywords.sort_by(|a, b| {
+        b.weight.partial_cmp(&a.weight)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.word.cmp(&b.word))
+    });
+    keywords.truncate(top_n);
+    
+    // 최상위 키워드를 1.0으로 정규화
+    if let Some(max_weight) = keywords.first().map(|k| k.weight) {
+        if max_weight > 0.0 {
+            for keyword in &mut keywords {
+                keyword.weight /= max_weight;
+            }
+        }
+    }
+    
+    Ok(keywords)
+}
+
+/// 문서 키워드 추출 (요약용)
+/// 
+/// 대용량 문서도 메인 스레드를 막지 않도록 블로킹 스레드에서 처리하고
+/// `{ language, keywords: [{ word, weight, count }] }` 형태의 JSON을 반환합니다.
+/// `language`는 "ko", "en", "mixed" 중 하나이며, 생략하거나 "auto"이면 자동 감지합니다.
+#[napi]
+pub async fn extract_document_keywords(
+    text: String,
+    top_n: Option<u32>,
+    language: Option<String>,
+) -> Result<String, NapiError> {
+    let top_n = top_n.map(|n| n as usize).unwrap_or(DEFAULT_KEYWORD_COUNT);
+    
+    let result = tokio::task::spawn_blocking(move || {
+        let language = language.as_deref()
+            .and_then(DocumentLanguage::from_code)
+            .unwrap_or_else(|| language::detect_language(&text));
+        
+        extract_weighted_keywords(&text, top_n, language).map(|keywords| json!({
+            "language": language.code(),
+            "keywords": keywords,
+        }))
+    })
+        .await
+        .map_err(|e| NapiError::from_reason(format!("Keyword extraction task failed: {}", e)))??;
+    
+    Ok(result.to_string())
+}
+
+/// 단어 빈도 계산 (워드 클라우드용)
+/// 
+/// 하나 이상의 텍스트(문서 하나 또는 코퍼스)에서 단어별 빈도를 계산하고
+/// 최빈 단어를 1.0으로 하는 가중치와 함께 상위 `top_n`개를 반환합니다.
+/// 같은 입력에 대한 결과는 캐시됩니다.
+pub fn compute_term_frequencies(texts: &[String], top_n: usize, exclude_stopwords: bool) -> Value {
+    let mut hasher = DefaultHasher::new();
+    texts.hash(&mut hasher);
+    top_n.hash(&mut hasher);
+    exclude_stopwords.hash(&mut hasher);
+    let cache_key = hasher.finish();
+    
+    if let Some(cached) = TERM_FREQUENCY_CACHE.lock().get(cache_key) {
+        let mut result = cached.clone();
+        if let Value::Object(ref mut obj) = result {
+            obj.insert("cached".to_string(), json!(true));
+        }
+        return result;
+    }
+    
+    let document_counts: Vec<HashMap<String, usize>> = texts.par_iter()
+        .map(|text| {
+            let language = language::detect_language(text);
+            let mut counts = HashMap::new();
+            for term in tokenize_terms(text, language) {
+                if exclude_stopwords && language::is_stopword(&term, language) {
+                    continue;
+                }
+                *counts.entry(term).or_insert(0) += 1;
+            }
+            counts
+        })
+        .collect();
+    
+    let mut term_counts: HashMap<String, usize> = HashMap::new();
+    for counts in document_counts {
+        for (term, count) in counts {
+            *term_counts.entry(term).or_insert(0) += count;
+        }
+    }
+    
+    let total_terms: usize = term_counts.values().sum();
+    let unique_terms = term_counts.len();
+    
+    let mut terms: Vec<(String, usize)> = term_counts.into_iter().collect();
+    terms.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    terms.truncate(top_n);
+    
+    let max_count = terms.first().map(|(_, count)| *count).unwrap_or(0);
+    let terms_json: Vec<Value> = terms.into_iter()
+        .map(|(term, count)| json!({
+            "term": term,
+            "count": count,
+            "weight": if max_count > 0 { count as f64 / max_count as f64 } else { 0.0 },
+        }))
+        .collect();
+    
+    let result = json!({
+        "terms": terms_json,
+        "total_terms": total_terms,
+        "unique_terms": unique_terms,
+        "document_count": texts.len(),
+        "stopwords_excluded": exclude_stopwords,
+        "cached": false,
+    });
+    
+    TERM_FREQUENCY_CACHE.lock().insert(cache_key, result.clone());
+    
+    result
+}
+
+/// 단어 빈도 가져오기
+/// 
+/// `texts`에 문서 하나를 넘기면 문서별, 여러 문서를 넘기면 코퍼스 전체 빈도를 계산합니다.
+#[napi]
+pub async fn get_term_frequencies(
+    texts: Vec<String>,
+    top_n: Option<u32>,
+    exclude_stopwords: Option<bool>,
+) -> Result<String, NapiError> {
+    let top_n = top_n.map(|n| n as usize).unwrap_or(DEFAULT_TERM_COUNT);
+    let exclude_stopwords = exclude_stopwords.unwrap_or(true);
+    
+    let result = tokio::task::spawn_blocking(move || compute_term_frequencies(&texts, top_n, exclude_stopwords))
+        .await
+        .map_err(|e| NapiError::from_reason(format!("Term frequency task failed: {}", e)))?;
+    
+    Ok(result.to_string())
+}
+
+// 구간 내 단어 빈도 계산
+fn count_segment_words(segment: &[String]) -> HashMap<&str, usize> {
+    let mut counts = HashMap::new();
+    for word in segment {
+        *counts.entry(word.as_str()).or_insert(0) += 1;
+    }
+    counts
+}
+
+// 빈도 계산용 단어 추출 (2글자 이상, 숫자만으로 된 단어 제외)
+fn tokenize_terms(text: &str, language: DocumentLanguage) -> impl Iterator<Item = String> + '_ {
+    text.split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()))
+        .filter(|w| !w.chars().all(|c| c.is_numeric()))
+        .map(move |w| language::normalize_word(w, language))
+        .filter(|w| w.chars().count() > 1)
+}
+
+// 키워드 후보 단어 추출 (4바이트 이상, 불용어 제외)
+fn tokenize_keywords(text: &str, language: DocumentLanguage) -> Vec<String> {
+    text.split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()))
+        .map(|w| language::normalize_word(w, language))
+        .filter(|w| w.len() > 3)  // 4글자 이상 단어만 키워드로 간주
+        .filter(|w| !language::is_stopword(w, language))
+        .collect()
 }
 
 /// 텍스트의 감정 분석
@@ -1,7 +1,17 @@
+use std::collections::HashMap;
 use serde_json::{json, Value};
 use crate::gpu::Result;
 use crate::gpu::types::GpuCapabilities;
 
+// 반환할 느린 바이그램 수
+const SLOWEST_BIGRAM_LIMIT: usize = 20;
+
+// 바이그램 순위에 포함되기 위한 최소 관측 횟수
+const MIN_BIGRAM_SAMPLES: usize = 2;
+
+// 이 간격(ms)을 넘는 키 입력 사이는 타이핑 중단으로 보고 바이그램에서 제외
+const MAX_BIGRAM_LATENCY_MS: f64 = 2000.0;
+
 /// 타이핑 통계 수행
 pub fn perform_typing_statistics(data: &str, _capabilities: Option<&GpuCapabilities>) -> Result<Value> {
     // JSON 파싱
@@ -21,6 +31,7 @@ pub fn perform_typing_statistics(data: &str, _capabilities: Option<&GpuCapabilit
     let typing_time = data["typingTime"].as_u64().unwrap_or(0);
     let errors = data["errors"].as_u64().unwrap_or(0);
     let content = data["content"].as_str().unwrap_or("");
+    let bigrams = analyze_bigram_latency(&data["keystrokes"]);
     
     // 입력 유효성 검사
     if key_count == 0 || typing_time == 0 {
@@ -53,7 +64,101 @@ pub fn perform_typing_statistics(data: &str, _capabilities: Option<&GpuCapabilit
             "key_count": key_count,
             "errors": errors,
             "time_ms": typing_time,
-            "content_length": content.len()
+            "content_length": content.len(),
+            "bigrams": bigrams
         }
     }))
 }
+
+// 바이그램별 키 간 지연 시간 누적
+#[derive(Default)]
+struct BigramLatency {
+    total_ms: f64,
+    min_ms: f64,
+    max_ms: f64,
+    count: usize,
+}
+
+/// 바이그램(연속된 두 키) 지연 시간 분석
+///
+/// `keystrokes`는 `{ key, timestamp }` 객체 배열입니다. 문자/숫자 키 사이의 간격만 집계하며,
+/// 평균 지연 시간이 가장 긴 바이그램을 최대 20개까지 반환합니다.
+fn analyze_bigram_latency(keystrokes: &Value) -> Value {
+    // 문자가 아닌 키(백스페이스 등)는 None으로 남겨 그 앞뒤 키가 바이그램으로 묶이지 않게 함
+    let events: Vec<Option<(char, f64)>> = keystrokes.as_array()
+        .map(|arr| arr.iter().map(parse_keystroke).collect())
+        .unwrap_or_default();
+
+    let mut latencies: HashMap<String, BigramLatency> = HashMap::new();
+    let mut analyzed = 0;
+
+    for pair in events.windows(2) {
+        let ((first, started), (second, ended)) = match (pair[0], pair[1]) {
+            (Some(a), Some(b)) => (a, b),
+            _ => continue,
+        };
+        let latency = ended - started;
+        if !(0.0..=MAX_BIGRAM_LATENCY_MS).contains(&latency) {
+            continue;
+        }
+
+        let entry = latencies.entry(format!("{}{}", first, second)).or_default();
+        if entry.count == 0 {
+            entry.min_ms = latency;
+            entry.max_ms = latency;
+        } else {
+            entry.min_ms = entry.min_ms.min(latency);
+            entry.max_ms = entry.max_ms.max(latency);
+        }
+        entry.total_ms += latency;
+        entry.count += 1;
+        analyzed += 1;
+    }
+
+    let mut ranked: Vec<(&String, &BigramLatency)> = latencies.iter()
+        .filter(|(_, stats)| stats.count >= MIN_BIGRAM_SAMPLES)
+        .collect();
+    ranked.sort_by(|a, b| {
+        let mean_a = a.1.total_ms / a.1.count as f64;
+        let mean_b = b.1.total_ms / b.1.count as f64;
+        mean_b.partial_cmp(&mean_a).unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let overall_mean = if analyzed > 0 {
+        latencies.values().map(|s| s.total_ms).sum::<f64>() / analyzed as f64
+    } else {
+        0.0
+    };
+
+    let slowest: Vec<Value> = ranked.into_iter()
+        .take(SLOWEST_BIGRAM_LIMIT)
+        .map(|(bigram, stats)| json!({
+            "bigram": bigram,
+            "mean_ms": stats.total_ms / stats.count as f64,
+            "min_ms": stats.min_ms,
+            "max_ms": stats.max_ms,
+            "count": stats.count
+        }))
+        .collect();
+
+    json!({
+        "analyzed_pairs": analyzed,
+        "unique_bigrams": latencies.len(),
+        "mean_latency_ms": overall_mean,
+        "slowest": slowest
+    })
+}
+
+// 키 입력 이벤트에서 (소문자 키, 타임스탬프) 추출 (문자/숫자 한 글자 키만 사용)
+fn parse_keystroke(event: &Value) -> Option<(char, f64)> {
+    let key = event["key"].as_str()?;
+    let timestamp = event["timestamp"].as_f64()?;
+
+    let mut chars = key.chars();
+    let ch = chars.next()?;
+    if chars.next().is_some() || !ch.is_alphanumeric() {
+        return None;
+    }
+
+    Some((ch.to_lowercase().next().unwrap_or(ch), timestamp))
+}
@@ -1,6 +1,5 @@
 use napi::Error;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Once;
 use std::collections::HashMap;
 use log::{debug, warn, info};
 use serde_json::json;
@@ -9,11 +8,15 @@ use once_cell::sync::Lazy;
 use std::time::{SystemTime, UNIX_EPOCH};
 use crate::gpu::types::{GpuDeviceInfo, GpuCapabilities as TypesGpuCapabilities};
 use crate::gpu::Result;
+use crate::gpu::{adapter, device};
+use crate::gpu::shader_cache;
+use crate::gpu::settings as gpu_settings;
 use wgpu;
 
 // GPU 초기화 상태 추적
 static GPU_INITIALIZED: AtomicBool = AtomicBool::new(false);
-static INITIALIZATION_COMPLETE: Once = Once::new();
+// 동시 초기화 방지용 잠금 (정리 후 다시 초기화할 수 있도록 Once 대신 사용)
+static INITIALIZATION_LOCK: parking_lot::Mutex<()> = parking_lot::Mutex::new(());
 static GPU_AVAILABLE: AtomicBool = AtomicBool::new(false);
 static GPU_ACCELERATION_ENABLED: AtomicBool = AtomicBool::new(false);
 
@@ -42,6 +45,8 @@ pub struct GpuContext {
     pub timestamp: u64,
     pub profile_name: String,
     pub performance_class: u8, // 1-저성능, 2-중간, 3-고성능
+    pub adapter_key: String,   // 선택된 어댑터 식별자 (소프트웨어 폴백 시 빈 문자열)
+    pub adapter_fallback: bool, // 선호 장치를 찾지 못해 자동 선택된 경우
 }
 
 // 기본 GPU 컨텍스트 구현
@@ -73,6 +78,8 @@ impl Default for GpuContext {
                 .as_secs(),
             profile_name: "basic".to_string(),
             performance_class: 1,
+            adapter_key: String::new(),
+            adapter_fallback: false,
         }
     }
 }
@@ -125,7 +132,9 @@ impl GpuContext {
             "limits": limits_json,
             "timestamp": self.timestamp,
             "profile": self.profile_name,
-            "performance_class": self.performance_class
+            "performance_class": self.performance_class,
+            "adapter_key": self.adapter_key,
+            "adapter_fallback": self.adapter_fallback
         }).to_string()
     }
     
@@ -156,83 +165,114 @@ pub static GPU_CONTEXT: Lazy<RwLock<Option<GpuContext>>> = Lazy::new(|| RwLock::
 
 /// GPU 컨텍스트 초기화
 /// 
-/// 이 함수는 시스템의 GPU 하드웨어를 감지하고 초기화합니다.
-/// Chrome의 다중 백엔드 접근 방식과 유사하게, 가용한 최고의 GPU 백엔드를 선택합니다.
+/// 이 함수는 시스템의 GPU 어댑터를 열거하고 초기화합니다.
+/// 설정된 선호 장치가 있으면 해당 어댑터를 사용하고, 없거나 찾을 수 없으면
+/// 전력 설정에 맞는 최적의 어댑터를 자동으로 선택합니다.
 pub fn initialize_gpu_context() -> Result<bool> {
-    let mut success = false;
-    
     // 이미 초기화된 경우 즉시 반환
     if GPU_INITIALIZED.load(Ordering::SeqCst) {
         return Ok(true);
     }
     
-    // 한 번만 초기화 시도
-    INITIALIZATION_COMPLETE.call_once(|| {
-        info!("GPU 컨텍스트 초기화 시작");
-        
-        // 최적의 GPU 백엔드 탐색
-        match detect_best_gpu_backend() {
-            Ok(backend_info) => {
-                debug!("감지된 GPU 백엔드: {:?}", backend_info.backend_type);
-                
-                // 컨텍스트 설정
-                let mut context = GpuContext {
-                    backend_type: backend_info.backend_type,
-                    vendor_name: backend_info.vendor,
-                    device_name: backend_info.device,
-                    driver_version: backend_info.driver,
-                    compute_supported: backend_info.compute_supported,
-                    is_discrete: backend_info.backend_type != GpuBackendType::Software,
-                    ..GpuContext::default()
-                };
-                
-                // 특성과 한계 복사
-                for (key, value) in backend_info.features {
-                    context.features.insert(key, value);
-                }
-                
-                for (key, value) in backend_info.limits {
-                    context.limits.insert(key, value as u64);
-                }
-                
-                // 프로필 선택
-                context.select_profile();
-                
-                // 전역 상태 업데이트
-                if let Ok(mut ctx_guard) = GPU_CONTEXT.write() {
-                    *ctx_guard = Some(context);
-                }
-                
-                // 초기화 완료 표시
-                GPU_INITIALIZED.store(true, Ordering::SeqCst);
-                GPU_AVAILABLE.store(true, Ordering::SeqCst);
-                info!("GPU 컨텍스트 초기화 성공");
-                success = true;
-            },
-            Err(e) => {
-                warn!("GPU 백엔드 감지 실패: {}", e);
-                
-                // 소프트웨어 폄백 컨텍스트 생성
-                let fallback_context = GpuContext {
-                    backend_type: GpuBackendType::Software,
-                    device_name: "Software Renderer".to_string(),
-                    vendor_name: "Software".to_string(),
-                    ..GpuContext::default()
-                };
-                
-                // 전역 상태 업데이트
-                if let Ok(mut ctx_guard) = GPU_CONTEXT.write() {
-                    *ctx_guard = Some(fallback_context);
-                    GPU_INITIALIZED.store(true, Ordering::SeqCst);
-                    GPU_AVAILABLE.store(false, Ordering::SeqCst); // 하드웨어 가속 불가능
-                    debug!("소프트웨어 폄백 렌더러로 초기화됨");
-                    success = true; // 초기화는 성공했지만 하드웨어 가속은 불가능
-                }
+    let _guard = INITIALIZATION_LOCK.lock();
+    if GPU_INITIALIZED.load(Ordering::SeqCst) {
+        return Ok(true);
+    }
+    
+    // 설정에서 GPU를 끈 동안에는 자동 초기화하지 않음 (set_gpu_enabled)
+    if !gpu_settings::is_hardware_acceleration_enabled() {
+        return Err(Error::from_reason("GPU가 설정에서 꺼져 있음"));
+    }
+    
+    info!("GPU 컨텍스트 초기화 시작");
+    
+    // 최적의 GPU 백엔드 탐색
+    let (context, available) = match detect_best_gpu_backend() {
+        Ok(backend_info) => {
+            debug!("감지된 GPU 백엔드: {:?}", backend_info.backend_type);
+            
+            // 컨텍스트 설정
+            let mut context = GpuContext {
+                backend_type: backend_info.backend_type,
+                vendor_name: backend_info.vendor,
+                device_name: backend_info.device,
+                driver_version: backend_info.driver,
+                compute_supported: backend_info.compute_supported,
+                is_discrete: backend_info.is_discrete,
+                adapter_key: backend_info.adapter_key,
+                adapter_fallback: backend_info.fallback,
+                ..GpuContext::default()
+            };
+            
+            // 특성과 한계 복사
+            for (key, value) in backend_info.features {
+                context.features.insert(key, value);
             }
+            
+            for (key, value) in backend_info.limits {
+                context.limits.insert(key, value);
+            }
+            
+            // 프로필 선택
+            context.select_profile();
+            
+            info!("GPU 컨텍스트 초기화 성공: {}", context.device_name);
+            (context, backend_info.backend_type != GpuBackendType::Software)
+        },
+        Err(e) => {
+            warn!("GPU 백엔드 감지 실패: {}", e);
+            
+            // 소프트웨어 폴백 컨텍스트 생성
+            let fallback_context = GpuContext {
+                backend_type: GpuBackendType::Software,
+                device_name: "Software Renderer".to_string(),
+                vendor_name: "Software".to_string(),
+                ..GpuContext::default()
+            };
+            
+            debug!("소프트웨어 폴백 렌더러로 초기화됨");
+            (fallback_context, false)
         }
-    });
+    };
+    
+    // 전역 상태 업데이트
+    let mut ctx_guard = GPU_CONTEXT.write()
+        .map_err(|_| Error::from_reason("GPU 컨텍스트를 쓸 수 없음"))?;
+    *ctx_guard = Some(context);
+    drop(ctx_guard);
     
-    Ok(success)
+    GPU_INITIALIZED.store(true, Ordering::SeqCst);
+    GPU_AVAILABLE.store(available, Ordering::SeqCst); // 소프트웨어 폴백이면 하드웨어 가속 불가능
+    
+    // 장치가 바뀌었으면 이전 장치용 셰이더 캐시 정리
+    shader_cache::revalidate();
+    
+    Ok(true)
+}
+
+/// GPU 컨텍스트 재초기화
+/// 
+/// 선호 장치가 바뀌었을 때 기존 컨텍스트를 정리하고 다시 어댑터를 선택합니다.
+/// 가속 활성화 상태는 유지됩니다.
+pub fn reinitialize_gpu_context() -> Result<bool> {
+    let acceleration_enabled = is_acceleration_enabled();
+    
+    cleanup_gpu_context()?;
+    let result = initialize_gpu_context()?;
+    
+    if acceleration_enabled && check_gpu_availability() {
+        GPU_ACCELERATION_ENABLED.store(true, Ordering::SeqCst);
+    }
+    
+    Ok(result)
+}
+
+/// 현재 사용 중인 어댑터 키와 자동 대체 여부
+pub fn get_active_adapter() -> Option<(String, bool)> {
+    let ctx_guard = GPU_CONTEXT.read().ok()?;
+    ctx_guard.as_ref()
+        .filter(|ctx| !ctx.adapter_key.is_empty())
+        .map(|ctx| (ctx.adapter_key.clone(), ctx.adapter_fallback))
 }
 
 // 백엔드 정보 구조체
@@ -241,131 +281,57 @@ struct BackendInfo {
     vendor: String,
     device: String,
     driver: String,
+    is_discrete: bool,
     compute_supported: bool,
+    adapter_key: String,
+    fallback: bool,
     features: HashMap<String, bool>,
-    limits: HashMap<String, u32>,
+    limits: HashMap<String, u64>,
 }
 
 /// 최적의 GPU 백엔드 감지
-/// Chrome과 유사하게 여러 백엔드를 시도하고 최적의 것을 선택
+/// 
+/// wgpu로 실제 어댑터를 열거한 뒤 선호 장치 또는 전력 설정에 따라 하나를 선택합니다.
 fn detect_best_gpu_backend() -> Result<BackendInfo> {
-    #[cfg(target_os = "windows")]
-    {
-        // DirectX 백엔드 시도 (Windows)
-        if let Ok(backend) = try_initialize_directx() {
-            return Ok(backend);
-        }
-    }
-    
-    #[cfg(target_os = "macos")]
-    {
-        // Metal 백엔드 시도 (macOS)
-        if let Ok(backend) = try_initialize_metal() {
-            return Ok(backend);
-        }
-    }
-    
-    // Vulkan 백엔드 시도 (크로스 플랫폼)
-    if let Ok(backend) = try_initialize_vulkan() {
-        return Ok(backend);
-    }
-    
-    // OpenGL 백엔드 시도 (폴백)
-    if let Ok(backend) = try_initialize_opengl() {
-        return Ok(backend);
-    }
-    
-    // 모든 백엔드 시도 실패 시 오류 반환
-    Err(Error::from_reason("모든 GPU 백엔드 초기화 실패"))
-}
-
-// 더미 함수 (실제로는 백엔드별 구현 필요)
-fn try_initialize_vulkan() -> Result<BackendInfo> {
-    debug!("Vulkan 백엔드 초기화 시도");
-    
-    // wgpu를 통한 Vulkan 초기화 시도 (실제 구현에서는 확장)
-    // 이 예제에서는 단순히 정보 구성
-    let mut features = HashMap::new();
-    features.insert("compute_shader".to_string(), true);
-    features.insert("storage_buffer".to_string(), true);
-    features.insert("tessellation".to_string(), true);
-    
-    let mut limits = HashMap::new();
-    limits.insert("max_buffer_size".to_string(), 512 * 1024 * 1024); // 512MB
-    limits.insert("max_compute_workgroups".to_string(), 65535);
+    let adapters = adapter::enumerate_adapters(false);
+    let preferred_device = gpu_settings::get_preferred_device();
+    let power_preference = gpu_settings::get_power_preference();
     
-    Ok(BackendInfo {
-        backend_type: GpuBackendType::Vulkan,
-        vendor: "Generic Vulkan Device".to_string(),
-        device: "Vulkan Compatible GPU".to_string(),
-        driver: "Unknown".to_string(),
-        compute_supported: true,
-        features,
-        limits,
-    })
-}
-
-#[cfg(target_os = "windows")]
-fn try_initialize_directx() -> Result<BackendInfo> {
-    debug!("DirectX 백엔드 초기화 시도");
-    
-    // Windows 특화 DirectX 초기화
-    let mut features = HashMap::new();
-    features.insert("compute_shader".to_string(), true);
-    features.insert("tessellation".to_string(), true);
-    
-    let mut limits = HashMap::new();
-    limits.insert("max_buffer_size".to_string(), 256 * 1024 * 1024); // 256MB
+    let selection = adapter::select_adapter(&adapters, preferred_device.as_deref(), &power_preference)
+        .ok_or_else(|| Error::from_reason("사용 가능한 GPU 어댑터가 없음"))?;
+    let selected = selection.adapter;
     
-    Ok(BackendInfo {
-        backend_type: GpuBackendType::DirectX,
-        vendor: "DirectX Compatible Device".to_string(),
-        device: "Windows GPU".to_string(),
-        driver: "System Driver".to_string(),
-        compute_supported: true,
-        features,
-        limits,
-    })
-}
-
-#[cfg(target_os = "macos")]
-fn try_initialize_metal() -> Result<BackendInfo> {
-    debug!("Metal 백엔드 초기화 시도");
-    
-    // macOS 특화 Metal 초기화
-    let mut features = HashMap::new();
-    features.insert("compute_shader".to_string(), true);
-    
-    let mut limits = HashMap::new();
-    limits.insert("max_buffer_size".to_string(), 256 * 1024 * 1024); // 256MB
-    
-    Ok(BackendInfo {
-        backend_type: GpuBackendType::Metal,
-        vendor: "Apple".to_string(),
-        device: "Metal Compatible GPU".to_string(),
-        driver: "System Driver".to_string(),
-        compute_supported: true,
-        features,
-        limits,
-    })
-}
-
-fn try_initialize_opengl() -> Result<BackendInfo> {
-    debug!("OpenGL 백엔드 초기화 시도");
+    let backend_type = if selected.device_type == "Cpu" {
+        GpuBackendType::Software
+    } else {
+        match selected.backend.as_str() {
+            "Vulkan" => GpuBackendType::Vulkan,
+            "Metal" => GpuBackendType::Metal,
+            "Dx12" | "Dx11" => GpuBackendType::DirectX,
+            "Gl" => GpuBackendType::OpenGL,
+            "BrowserWebGpu" => GpuBackendType::WebGPU,
+            _ => GpuBackendType::Software,
+        }
+    };
     
-    // 폴백용 OpenGL 초기화
     let mut features = HashMap::new();
-    features.insert("compute_shader".to_string(), false);
+    features.insert("compute_shader".to_string(), selected.compute_supported);
+    features.insert("storage_buffer".to_string(), selected.max_storage_buffer_binding_size > 0);
     
     let mut limits = HashMap::new();
-    limits.insert("max_buffer_size".to_string(), 128 * 1024 * 1024); // 128MB
+    limits.insert("max_buffer_size".to_string(), selected.max_buffer_size);
+    limits.insert("max_compute_workgroups".to_string(), selected.max_compute_workgroups_per_dimension as u64);
+    limits.insert("max_storage_buffer_binding_size".to_string(), selected.max_storage_buffer_binding_size as u64);
     
     Ok(BackendInfo {
-        backend_type: GpuBackendType::OpenGL,
-        vendor: "OpenGL Compatible Device".to_string(),
-        device: "Generic GPU".to_string(),
-        driver: "OpenGL Driver".to_string(),
-        compute_supported: false,
+        backend_type,
+        vendor: selected.vendor_name,
+        device: selected.name,
+        driver: if selected.driver_info.is_empty() { selected.driver } else { selected.driver_info },
+        is_discrete: selected.is_discrete,
+        compute_supported: selected.compute_supported,
+        adapter_key: selected.key,
+        fallback: selection.fallback,
         features,
         limits,
     })
@@ -550,15 +516,15 @@ pub fn get_capabilities() -> Result<TypesGpuCapabilities> {
     // GPU 컨텍스트 읽기
     if let Ok(ctx_guard) = GPU_CONTEXT.read() {
         if let Some(ctx) = &*ctx_guard {
-            let max_buffer_size = ctx.limits.get("max_buffer_size").unwrap_or(&(128 * 1024 * 1024)).clone() as usize;
-            let max_compute_workgroups = ctx.limits.get("max_compute_workgroups").unwrap_or(&65535).clone() as u32;
+            let max_buffer_size = ctx.limits.get("max_buffer_size").copied().unwrap_or(128 * 1024 * 1024) as usize;
+            let max_compute_workgroups = ctx.limits.get("max_compute_workgroups").copied().unwrap_or(65535) as u32;
             
             Ok(TypesGpuCapabilities {
                 max_buffer_size,
                 max_compute_workgroups: [max_compute_workgroups, max_compute_workgroups, max_compute_workgroups],
                 max_invocations: 1024,
-                supports_timestamp_query: ctx.features.get("timestamp_query").unwrap_or(&false).clone(),
-                supports_pipeline_statistics_query: ctx.features.get("pipeline_statistics_query").unwrap_or(&false).clone(),
+                supports_timestamp_query: ctx.features.get("timestamp_query").copied().unwrap_or(false),
+                supports_pipeline_statistics_query: ctx.features.get("pipeline_statistics_query").copied().unwrap_or(false),
                 compute_supported: ctx.compute_supported,
                 shading_supported: true,
             })
@@ -595,6 +561,7 @@ pub fn cleanup_gpu_context() -> Result<()> {
         if let Ok(mut ctx_guard) = GPU_CONTEXT.write() {
             // 리소스 해제
             *ctx_guard = None;
+            device::release_compute_device();
             GPU_INITIALIZED.store(false, Ordering::SeqCst);
             GPU_AVAILABLE.store(false, Ordering::SeqCst);
             GPU_ACCELERATION_ENABLED.store(false, Ordering::SeqCst);
@@ -0,0 +1,139 @@
+//! GPU 계산 장치 모듈
+//!
+//! 현재 GPU 컨텍스트가 사용하는 어댑터로 wgpu 장치와 큐를 만들어 두고,
+//! 계산 셰이더를 실행하는 모듈들이 공유할 수 있게 합니다.
+//! 활성 어댑터가 바뀌면 다음 요청 시 새 장치를 만듭니다.
+
+use std::sync::Arc;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use log::{info, warn};
+use napi::Error;
+use crate::gpu::Result;
+use crate::gpu::{adapter, context};
+
+/// 계산 셰이더 실행용 장치
+pub struct ComputeDevice {
+    pub adapter_key: String,
+    pub device: wgpu::Device,
+    pub queue: wgpu::Queue,
+}
+
+impl ComputeDevice {
+    /// WGSL 소스로 계산 파이프라인 생성
+    ///
+    /// 셰이더 검증 오류는 패닉 대신 오류로 반환합니다.
+    pub fn create_pipeline(&self, label: &str, source: &str) -> Result<wgpu::ComputePipeline> {
+        self.device.push_error_scope(wgpu::ErrorFilter::Validation);
+
+        let module = self.device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some(label),
+            source: wgpu::ShaderSource::Wgsl(source.into()),
+        });
+        let pipeline = self.device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some(label),
+            layout: None,
+            module: &module,
+            entry_point: "main",
+        });
+
+        // 파이프라인이 유효하지 않으면 바인드 그룹 레이아웃 조회에서 패닉이 나므로 먼저 확인
+        if let Some(e) = pollster::block_on(self.device.pop_error_scope()) {
+            return Err(Error::from_reason(format!("셰이더 '{}' 컴파일 실패: {}", label, e)));
+        }
+
+        Ok(pipeline)
+    }
+
+    /// 인코더를 제출하고 결과 버퍼 내용을 `T` 배열로 읽어옴
+    ///
+    /// `buffer`는 `COPY_SRC` 용도로 만들어져 있어야 합니다.
+    pub fn submit_and_read<T: bytemuck::Pod>(
+        &self,
+        mut encoder: wgpu::CommandEncoder,
+        buffer: &wgpu::Buffer,
+        size: wgpu::BufferAddress,
+    ) -> Result<Vec<T>> {
+        let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("compute-readback"),
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        encoder.copy_buffer_to_buffer(buffer, 0, &readback, 0, size);
+        self.queue.submit(Some(encoder.finish()));
+
+        let slice = readback.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+
+        receiver.recv()
+            .map_err(|e| Error::from_reason(format!("GPU 결과 대기 실패: {}", e)))?
+            .map_err(|e| Error::from_reason(format!("GPU 결과 버퍼 매핑 실패: {}", e)))?;
+
+        // 매핑된 바이트는 정렬이 보장되지 않으므로 복사하면서 변환
+        let values = {
+            let mapped = slice.get_mapped_range();
+            let count = mapped.len() / std::mem::size_of::<T>();
+            let mut values = vec![T::zeroed(); count];
+            bytemuck::cast_slice_mut::<T, u8>(&mut values)
+                .copy_from_slice(&mapped[..count * std::mem::size_of::<T>()]);
+            values
+        };
+        readback.unmap();
+        Ok(values)
+    }
+}
+
+// 활성 어댑터의 계산 장치 캐시
+static COMPUTE_DEVICE: Lazy<Mutex<Option<Arc<ComputeDevice>>>> = Lazy::new(|| Mutex::new(None));
+
+/// 활성 어댑터의 계산 장치 가져오기
+///
+/// GPU 컨텍스트가 초기화되지 않았거나 계산 셰이더를 지원하지 않으면 오류를 반환합니다.
+pub fn acquire_compute_device() -> Result<Arc<ComputeDevice>> {
+    let (adapter_key, _) = context::get_active_adapter()
+        .ok_or_else(|| Error::from_reason("활성 GPU 어댑터가 없음"))?;
+
+    let mut cached = COMPUTE_DEVICE.lock();
+    if let Some(device) = cached.as_ref().filter(|d| d.adapter_key == adapter_key) {
+        return Ok(device.clone());
+    }
+
+    let instance = adapter::create_instance();
+    let wgpu_adapter = adapter::find_wgpu_adapter(&instance, &adapter_key)
+        .ok_or_else(|| Error::from_reason(format!("GPU 어댑터를 찾을 수 없음: {}", adapter_key)))?;
+
+    if !wgpu_adapter.get_downlevel_capabilities().flags.contains(wgpu::DownlevelFlags::COMPUTE_SHADERS) {
+        return Err(Error::from_reason("선택된 GPU 어댑터가 계산 셰이더를 지원하지 않음"));
+    }
+
+    let (device, queue) = pollster::block_on(wgpu_adapter.request_device(
+        &wgpu::DeviceDescriptor {
+            label: Some("loop-compute-device"),
+            features: wgpu::Features::empty(),
+            limits: wgpu::Limits::downlevel_defaults().using_resolution(wgpu_adapter.limits()),
+        },
+        None,
+    ))
+    .map_err(|e| Error::from_reason(format!("GPU 장치 생성 실패: {}", e)))?;
+
+    // 처리되지 않은 검증 오류로 프로세스가 중단되지 않도록 로그로만 남김
+    device.on_uncaptured_error(Box::new(|e| warn!("GPU 오류: {}", e)));
+
+    info!("GPU 계산 장치 생성됨: {}", adapter_key);
+
+    let compute_device = Arc::new(ComputeDevice { adapter_key, device, queue });
+    *cached = Some(compute_device.clone());
+    Ok(compute_device)
+}
+
+/// 캐시된 계산 장치 해제
+pub fn release_compute_device() {
+    if COMPUTE_DEVICE.lock().take().is_some() {
+        info!("GPU 계산 장치 해제됨");
+    }
+}
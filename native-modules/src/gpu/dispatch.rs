@@ -0,0 +1,265 @@
+//! GPU/CPU 적응형 분배 모듈
+//!
+//! GPU 경로가 있는 작업(패턴 감지, 키 히트맵)의 실행 시간을 작업 유형과 입력 크기 구간별로 기록하고,
+//! 컴퓨팅 모드가 auto이면 지금까지 더 빨랐던 경로로 실행합니다.
+//! 기록은 장치별로 셰이더 캐시 디렉터리의 작은 프로필 파일에 저장됩니다.
+
+use napi::Error;
+use napi_derive::napi;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::{Serialize, Deserialize};
+use serde_json::json;
+use std::collections::{BTreeMap, VecDeque};
+use std::fs;
+use log::debug;
+use crate::gpu::cache_file::{self, CacheFile, CacheFileStore};
+use crate::gpu::{benchmark, settings, shader_cache, GpuTaskType};
+
+// 저장 파일 형식 버전
+const PROFILE_FORMAT_VERSION: u32 = 1;
+
+// 셰이더 캐시 디렉터리 안의 프로필 파일 이름
+const PROFILE_FILE: &str = "gpu-dispatch-profile.json";
+
+// 경로별로 보관하는 최근 실행 시간 수
+const MAX_SAMPLES: usize = 15;
+
+// 비교에 필요한 경로별 최소 실행 수
+const MIN_SAMPLES: usize = 3;
+
+// 이 횟수마다 한 번은 느린 경로로 실행해 기록을 갱신
+const EXPLORE_INTERVAL: u64 = 50;
+
+// 이 수만큼 새 기록이 쌓이면 파일에 저장
+const SAVE_EVERY: u64 = 20;
+
+/// 실행 경로
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    Cpu,
+    Gpu,
+}
+
+impl Backend {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Backend::Cpu => "cpu",
+            Backend::Gpu => "gpu",
+        }
+    }
+
+    /// 재정의 값 파싱 ("auto"면 None)
+    pub fn parse_override(value: &str) -> Result<Option<Self>, Error> {
+        match value.to_lowercase().as_str() {
+            "auto" => Ok(None),
+            "cpu" => Ok(Some(Backend::Cpu)),
+            "gpu" => Ok(Some(Backend::Gpu)),
+            other => Err(Error::from_reason(format!("Invalid backend: {} (expected auto, gpu or cpu)", other))),
+        }
+    }
+}
+
+/// 경로 선택 결과
+#[derive(Debug, Clone, Copy)]
+pub struct Selection {
+    pub backend: Backend,
+    /// 선택 이유 (override, history, explore, benchmark)
+    pub reason: &'static str,
+}
+
+// 크기 구간별 실행 기록
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct BucketProfile {
+    cpu_ms: VecDeque<f64>,
+    gpu_ms: VecDeque<f64>,
+    #[serde(default)]
+    runs: u64,
+}
+
+impl BucketProfile {
+    fn samples_mut(&mut self, backend: Backend) -> &mut VecDeque<f64> {
+        match backend {
+            Backend::Cpu => &mut self.cpu_ms,
+            Backend::Gpu => &mut self.gpu_ms,
+        }
+    }
+}
+
+// 중앙값 (기록이 없으면 None)
+fn median(samples: &VecDeque<f64>) -> Option<f64> {
+    if samples.is_empty() {
+        return None;
+    }
+    let mut sorted: Vec<f64> = samples.iter().copied().collect();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    Some(sorted[sorted.len() / 2])
+}
+
+// 프로필 파일 (장치 지문|작업 유형|크기 구간 -> 기록)
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ProfileFile {
+    version: u32,
+    buckets: BTreeMap<String, BucketProfile>,
+}
+
+impl CacheFile for ProfileFile {
+    const FILE_NAME: &'static str = PROFILE_FILE;
+    const LABEL: &'static str = "GPU 분배 프로필";
+
+    fn empty() -> Self {
+        ProfileFile { version: PROFILE_FORMAT_VERSION, buckets: BTreeMap::new() }
+    }
+
+    fn is_current(&self) -> bool {
+        self.version == PROFILE_FORMAT_VERSION
+    }
+}
+
+static STORE: Lazy<Mutex<CacheFileStore<ProfileFile>>> = Lazy::new(|| Mutex::new(CacheFileStore::default()));
+
+// 불러온 프로필로 작업 실행 (셰이더 캐시 디렉터리가 없으면 메모리에만 보관)
+fn with_store<R>(f: impl FnOnce(&mut CacheFileStore<ProfileFile>) -> R) -> R {
+    cache_file::with_store(&STORE, f)
+}
+
+// GPU 경로가 있는 작업 유형인지 확인
+fn has_gpu_path(task_type: GpuTaskType) -> bool {
+    matches!(task_type, GpuTaskType::PatternDetection | GpuTaskType::KeyHeatmap)
+}
+
+// 입력 크기 구간 (4배 단위: 0은 빈 입력, 1은 1~3바이트, 2는 4~15바이트, ... - 구간 n은 4^n바이트 미만)
+fn size_bucket(input_len: usize) -> u32 {
+    (usize::BITS - input_len.leading_zeros()).div_ceil(2)
+}
+
+fn bucket_key(task_type: GpuTaskType, input_len: usize) -> String {
+    format!("{}|{:?}|{}", shader_cache::device_fingerprint(), task_type, size_bucket(input_len))
+}
+
+/// 작업을 실행할 경로 선택
+///
+/// 재정의 값이 있으면 그대로 사용합니다. 컴퓨팅 모드가 auto이고 GPU 경로가 있는 작업이면
+/// 같은 크기 구간의 실행 기록(경로별 중앙값)을 비교하고, 기록이 부족한 경로는 먼저 실행해 봅니다.
+/// 두 경로 모두 기록이 없으면 벤치마크 결과를 따르고, 그것도 없으면 None(기본 동작)을 반환합니다.
+pub fn select_backend(task_type: GpuTaskType, input_len: usize, requested: Option<Backend>) -> Option<Selection> {
+    if let Some(backend) = requested {
+        return Some(Selection { backend, reason: "override" });
+    }
+    if !has_gpu_path(task_type) || settings::get_compute_mode() != "auto" {
+        return None;
+    }
+
+    let key = bucket_key(task_type, input_len);
+    let history = with_store(|store| {
+        let bucket = store.file.buckets.get(&key)?;
+        Some((bucket.cpu_ms.len(), bucket.gpu_ms.len(), median(&bucket.cpu_ms), median(&bucket.gpu_ms), bucket.runs))
+    });
+
+    match history {
+        Some((cpu_count, gpu_count, Some(cpu), Some(gpu), runs)) if cpu_count >= MIN_SAMPLES && gpu_count >= MIN_SAMPLES => {
+            let (faster, slower) = if gpu <= cpu { (Backend::Gpu, Backend::Cpu) } else { (Backend::Cpu, Backend::Gpu) };
+            if runs > 0 && runs % EXPLORE_INTERVAL == 0 {
+                Some(Selection { backend: slower, reason: "explore" })
+            } else {
+                Some(Selection { backend: faster, reason: "history" })
+            }
+        },
+        Some((cpu_count, gpu_count, ..)) if cpu_count >= MIN_SAMPLES && gpu_count < MIN_SAMPLES => {
+            Some(Selection { backend: Backend::Gpu, reason: "explore" })
+        },
+        Some((cpu_count, gpu_count, ..)) if gpu_count >= MIN_SAMPLES && cpu_count < MIN_SAMPLES => {
+            Some(Selection { backend: Backend::Cpu, reason: "explore" })
+        },
+        _ => benchmark::prefers_cpu(task_type).then_some(Selection { backend: Backend::Cpu, reason: "benchmark" }),
+    }
+}
+
+/// 실행 시간 기록 (`backend`는 실제로 실행된 경로)
+pub fn record(task_type: GpuTaskType, input_len: usize, backend: Backend, duration_ms: f64) {
+    if !has_gpu_path(task_type) || !duration_ms.is_finite() {
+        return;
+    }
+
+    let key = bucket_key(task_type, input_len);
+    with_store(|store| {
+        let bucket = store.file.buckets.entry(key).or_default();
+        bucket.runs += 1;
+        let samples = bucket.samples_mut(backend);
+        if samples.len() >= MAX_SAMPLES {
+            samples.pop_front();
+        }
+        samples.push_back(duration_ms);
+
+        store.unsaved += 1;
+        if store.unsaved >= SAVE_EVERY {
+            store.save();
+        }
+    });
+}
+
+/// 저장하지 않은 기록을 파일에 쓰고 불러온 프로필 잊기
+pub fn reset() {
+    let mut store = STORE.lock();
+    if store.unsaved > 0 {
+        store.save();
+    }
+    *store = CacheFileStore::default();
+}
+
+/// GPU 분배 프로필 조회
+///
+/// 현재 장치의 작업 유형/크기 구간별 경로 실행 수와 중앙값(ms), 선호 경로를 JSON으로 반환합니다.
+#[napi]
+pub fn get_gpu_dispatch_profile() -> String {
+    let fingerprint = shader_cache::device_fingerprint();
+    let prefix = format!("{}|", fingerprint);
+
+    let buckets: Vec<serde_json::Value> = with_store(|store| {
+        store.file.buckets.iter()
+            .filter_map(|(key, bucket)| {
+                let mut parts = key.strip_prefix(&prefix)?.split('|');
+                let task_type = parts.next()?;
+                let size_bucket: u32 = parts.next()?.parse().ok()?;
+                let cpu = median(&bucket.cpu_ms);
+                let gpu = median(&bucket.gpu_ms);
+                let preferred = match (cpu, gpu) {
+                    (Some(cpu), Some(gpu)) if bucket.cpu_ms.len() >= MIN_SAMPLES && bucket.gpu_ms.len() >= MIN_SAMPLES => {
+                        Some(if gpu <= cpu { "gpu" } else { "cpu" })
+                    },
+                    _ => None,
+                };
+                Some(json!({
+                    "task_type": task_type,
+                    "input_bytes_below": 4u64.saturating_pow(size_bucket),
+                    "runs": bucket.runs,
+                    "cpu": { "samples": bucket.cpu_ms.len(), "median_ms": cpu },
+                    "gpu": { "samples": bucket.gpu_ms.len(), "median_ms": gpu },
+                    "preferred": preferred
+                }))
+            })
+            .collect()
+    });
+
+    json!({
+        "device_fingerprint": fingerprint,
+        "compute_mode": settings::get_compute_mode(),
+        "buckets": buckets
+    }).to_string()
+}
+
+/// GPU 분배 프로필 삭제
+///
+/// 모든 장치의 실행 기록을 지우고 프로필 파일을 삭제합니다.
+#[napi]
+pub fn reset_gpu_dispatch_profile() -> bool {
+    with_store(|store| {
+        store.file.buckets.clear();
+        store.unsaved = 0;
+        if let Some(path) = store.path() {
+            let _ = fs::remove_file(path);
+        }
+    });
+    debug!("GPU 분배 프로필 삭제");
+    true
+}
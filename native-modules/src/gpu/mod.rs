@@ -2,17 +2,90 @@ use napi::Error;
 use napi_derive::napi;
 use log::{info, debug, error, warn};
 use serde_json::json;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use crate::utils::power;
 
 // 모듈 선언
 pub mod shader;
+pub mod shader_cache;
 pub mod context;
 pub mod types;
 pub mod accelerator;
 pub mod computation;
+pub mod adapter;
+pub mod settings;
+pub mod thermal;
+pub mod device;
+pub mod result_cache;
+pub mod cache_file;
+pub mod benchmark;
+pub mod dispatch;
 
 // Result 타입 정의
 pub type Result<T> = std::result::Result<T, Error>;
 
+// 실행 중인 GPU 작업 수 (유휴 시간 GC 예약에서 사용)
+static ACTIVE_GPU_TASKS: AtomicUsize = AtomicUsize::new(0);
+
+// 실행 중인 GPU 작업 수를 세는 가드 (패닉 시에도 감소)
+struct ActiveTaskGuard;
+
+impl ActiveTaskGuard {
+    fn enter() -> Self {
+        ACTIVE_GPU_TASKS.fetch_add(1, Ordering::SeqCst);
+        Self
+    }
+}
+
+impl Drop for ActiveTaskGuard {
+    fn drop(&mut self) {
+        ACTIVE_GPU_TASKS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// 현재 실행 중인 GPU 작업 수
+pub fn active_gpu_task_count() -> usize {
+    ACTIVE_GPU_TASKS.load(Ordering::SeqCst)
+}
+
+/// 작업 유형별 누적 실행 통계 (메트릭 내보내기용)
+#[derive(Debug, Clone, Default)]
+pub struct GpuTaskMetrics {
+    pub count: u64,
+    pub failures: u64,
+    pub total_duration_ms: f64,
+}
+
+static TASK_METRICS: Lazy<Mutex<BTreeMap<String, GpuTaskMetrics>>> =
+    Lazy::new(|| Mutex::new(BTreeMap::new()));
+
+/// GPU 하위 시스템 상태 전체 초기화
+///
+/// GPU 컨텍스트와 계산 장치를 해제하고 셰이더/어댑터/계산 결과/작업 결과 캐시, 열 통계, 작업 통계, 설정을
+/// 처음 상태로 되돌립니다. 디스크 셰이더 캐시 파일은 삭제하지 않습니다.
+pub fn reset_gpu_state() -> Result<()> {
+    accelerator::shutdown_gpu()?;
+    shader::clear_shader_cache()?;
+    shader_cache::detach();
+    adapter::clear_adapter_cache();
+    thermal::reset();
+    computation::text::clear_term_frequency_cache();
+    result_cache::reset();
+    benchmark::reset();
+    dispatch::reset();
+    settings::reset_settings();
+    TASK_METRICS.lock().clear();
+    Ok(())
+}
+
+/// 작업 유형별 누적 실행 통계 가져오기
+pub fn gpu_task_metrics() -> BTreeMap<String, GpuTaskMetrics> {
+    TASK_METRICS.lock().clone()
+}
+
 // GPU 작업 유형 열거형
 #[napi]
 #[derive(Debug)]
@@ -22,6 +95,7 @@ pub enum GpuTaskType {
     ImageProcessing = 2,
     DataAggregation = 3,
     TypingStatistics = 4,
+    KeyHeatmap = 5,
 }
 
 // GPU 유형 열거형
@@ -36,60 +110,186 @@ pub enum GPUType {
 /// GPU 작업 실행 함수
 /// 
 /// 지정된 작업 유형에 따라 GPU 작업을 실행합니다.
+/// `backend`("auto", "gpu", "cpu")로 실행 경로를 지정할 수 있으며, 없거나 "auto"이면
+/// 컴퓨팅 모드가 auto일 때 실행 기록상 더 빠른 경로를 자동으로 선택합니다.
+#[napi]
+pub fn execute_gpu_task(task_type: GpuTaskType, data: String, backend: Option<String>) -> napi::Result<String> {
+    let backend = match backend {
+        Some(backend) => dispatch::Backend::parse_override(&backend)?,
+        None => None,
+    };
+    Ok(run_gpu_task(task_type, &data, backend).to_string())
+}
+
+/// GPU 배치 작업 실행 함수
+/// 
+/// 같은 유형의 작업 여러 개를 순서대로 실행합니다.
+/// 실행 중 열 스로틀링이 감지되면 다음 항목을 실행하기 전에 잠시 멈추며,
+/// 배치 전체에서 멈추는 시간은 `thermal::MAX_BATCH_PAUSE_MS`로 제한됩니다.
+#[napi]
+pub async fn execute_gpu_batch(task_type: GpuTaskType, items: Vec<String>) -> napi::Result<String> {
+    let result = tokio::task::spawn_blocking(move || {
+        let mut total_paused_ms = 0;
+        let results: Vec<serde_json::Value> = items.iter()
+            .map(|data| {
+                total_paused_ms += thermal::pause_if_throttled(thermal::MAX_BATCH_PAUSE_MS - total_paused_ms);
+                run_gpu_task(task_type, data, None)
+            })
+            .collect();
+        
+        json!({
+            "success": true,
+            "results": results,
+            "count": results.len(),
+            "task_type": task_type as i32,
+            "paused_ms": total_paused_ms,
+            "thermal_throttled": thermal::is_throttled(),
+            "timestamp": get_timestamp()
+        })
+    })
+    .await
+    .map_err(|e| Error::from_reason(format!("GPU 배치 작업 실행 실패: {}", e)))?;
+    
+    Ok(result.to_string())
+}
+
+/// 키 입력 히트맵 계산 함수
+/// 
+/// 키 입력 기록으로 표준 키보드 배치의 키별 입력 빈도와 평균 누름/이동 시간을 계산합니다.
+#[napi]
+pub fn compute_key_heatmap(data: String) -> napi::Result<String> {
+    Ok(run_gpu_task(GpuTaskType::KeyHeatmap, &data, None).to_string())
+}
+
+/// GPU 열 상태 가져오기
 #[napi]
-pub fn execute_gpu_task(task_type: GpuTaskType, data: String) -> napi::Result<String> {
+pub fn get_gpu_thermal_status() -> napi::Result<String> {
+    let mut status = thermal::get_thermal_status();
+    status["timestamp"] = json!(get_timestamp());
+    Ok(status.to_string())
+}
+
+/// GPU 열 보호(스로틀링 시 배치 작업 일시 정지) 활성화 설정
+#[napi]
+pub fn set_gpu_thermal_protection(enabled: bool) -> napi::Result<bool> {
+    thermal::set_thermal_protection_enabled(enabled);
+    Ok(true)
+}
+
+// 단일 GPU 작업 실행 및 결과 JSON 생성
+fn run_gpu_task(task_type: GpuTaskType, data: &str, backend: Option<dispatch::Backend>) -> serde_json::Value {
     debug!("GPU 작업 실행: {:?}", task_type);
+    let _active = ActiveTaskGuard::enter();
     
-    // GPU 기능 확인 - 에러 처리 추가
-    let capabilities = match context::get_capabilities() {
-        Ok(caps) => Some(caps),
-        Err(e) => {
-            warn!("GPU 기능 정보를 가져올 수 없음: {}", e);
-            None
+    // 실행 경로 선택 (재정의 값, auto 모드의 실행 기록/벤치마크)
+    let selection = dispatch::select_backend(task_type, data.len(), backend);
+    
+    // GPU 기능 확인 (설정에서 GPU를 끈 경우, 전원 절약 중인 경우, CPU 경로가 선택된 경우 CPU 경로로 실행)
+    let cpu_selected = selection.is_some_and(|selection| selection.backend == dispatch::Backend::Cpu);
+    let capabilities = if !settings::is_hardware_acceleration_enabled() || !power::allows_gpu() || cpu_selected {
+        None
+    } else {
+        match context::get_capabilities() {
+            Ok(caps) => Some(caps),
+            Err(e) => {
+                warn!("GPU 기능 정보를 가져올 수 없음: {}", e);
+                None
+            }
         }
     };
     
+    let started = std::time::Instant::now();
+    
+    // 같은 입력의 결과가 캐시되어 있으면 다시 계산하지 않음
+    let gpu_path = capabilities.is_some();
+    if let Some(result) = result_cache::lookup(task_type, gpu_path, data) {
+        return json!({
+            "success": true,
+            "result": result,
+            "cached": true,
+            "task_type": task_type as i32,
+            "duration_ms": started.elapsed().as_secs_f64() * 1000.0,
+            "thermal_throttled": thermal::is_throttled(),
+            "timestamp": get_timestamp()
+        });
+    }
+    
     // 작업 유형에 따른 처리 함수 선택
     let result = match task_type {
         GpuTaskType::TextAnalysis => {
-            computation::text::perform_text_analysis(&data, capabilities.as_ref())
+            computation::text::perform_text_analysis(data, capabilities.as_ref())
         },
         GpuTaskType::PatternDetection => {
-            computation::pattern::perform_pattern_detection(&data, capabilities.as_ref())
+            computation::pattern::perform_pattern_detection(data, capabilities.as_ref())
         },
         GpuTaskType::ImageProcessing => {
-            computation::image::perform_image_processing(&data, capabilities.as_ref())
+            computation::image::perform_image_processing(data, capabilities.as_ref())
         },
         GpuTaskType::DataAggregation => {
-            computation::data::perform_data_aggregation(&data, capabilities.as_ref())
+            computation::data::perform_data_aggregation(data, capabilities.as_ref())
         },
         GpuTaskType::TypingStatistics => {
-            computation::typing::perform_typing_statistics(&data, capabilities.as_ref())
+            computation::typing::perform_typing_statistics(data, capabilities.as_ref())
+        },
+        GpuTaskType::KeyHeatmap => {
+            computation::heatmap::perform_key_heatmap(data, capabilities.as_ref())
         },
     };
     
+    // 실행 시간 기록 (스로틀링 감지용)
+    let duration_ms = started.elapsed().as_secs_f64() * 1000.0;
+    let task_key = format!("{:?}", task_type);
+    thermal::record_task_duration(&task_key, data.len(), duration_ms);
+    
+    {
+        let mut metrics = TASK_METRICS.lock();
+        let entry = metrics.entry(task_key).or_default();
+        entry.count += 1;
+        entry.total_duration_ms += duration_ms;
+        if result.is_err() {
+            entry.failures += 1;
+        }
+    }
+    
     // 결과 처리
     match result {
         Ok(result) => {
-            let json_result = json!({
+            result_cache::store(task_type, gpu_path, data, &result);
+            
+            // 실제로 실행된 경로의 시간을 분배 프로필에 기록
+            let executed = match result.get("backend").and_then(|backend| backend.as_str()) {
+                Some("gpu") => Some(dispatch::Backend::Gpu),
+                Some("cpu") => Some(dispatch::Backend::Cpu),
+                _ => None,
+            };
+            if let Some(executed) = executed {
+                dispatch::record(task_type, data.len(), executed, duration_ms);
+            }
+            
+            json!({
                 "success": true,
                 "result": result,
+                "cached": false,
+                "dispatch": selection.map(|selection| json!({
+                    "backend": selection.backend.as_str(),
+                    "reason": selection.reason
+                })),
                 "task_type": task_type as i32,
+                "duration_ms": duration_ms,
+                "thermal_throttled": thermal::is_throttled(),
                 "timestamp": get_timestamp()
-            });
-            
-            Ok(json_result.to_string())
+            })
         },
         Err(e) => {
             error!("GPU 작업 실행 실패: {}", e);
-            let error_json = json!({
+            json!({
                 "success": false,
                 "error": e.to_string(),
                 "task_type": task_type as i32,
+                "duration_ms": duration_ms,
+                "thermal_throttled": thermal::is_throttled(),
                 "timestamp": get_timestamp()
-            });
-            
-            Ok(error_json.to_string())
+            })
         }
     }
 }
@@ -117,6 +317,81 @@ pub fn get_gpu_info() -> napi::Result<String> {
     Ok(info.to_string())
 }
 
+/// GPU 장치 목록 가져오기
+/// 
+/// 시스템의 모든 GPU 어댑터와 현재 선호/사용 중인 장치 키를 반환합니다.
+#[napi]
+pub fn list_gpu_devices(refresh: Option<bool>) -> napi::Result<String> {
+    let devices = adapter::enumerate_adapters(refresh.unwrap_or(false));
+    let active = context::get_active_adapter();
+    
+    let result = json!({
+        "devices": devices,
+        "preferred_device": settings::get_preferred_device(),
+        "active_device": active.as_ref().map(|(key, _)| key),
+        "fallback": active.map(|(_, fallback)| fallback).unwrap_or(false),
+        "timestamp": get_timestamp()
+    });
+    
+    Ok(result.to_string())
+}
+
+/// 선호 GPU 장치 설정
+/// 
+/// `device_key`는 `list_gpu_devices`가 반환한 장치의 `key`이며, 생략하면 자동 선택으로 돌아갑니다.
+/// GPU가 이미 초기화되어 있으면 선택한 장치로 컨텍스트를 다시 생성하고,
+/// 해당 장치를 찾을 수 없으면 자동 선택된 장치로 대체합니다.
+#[napi]
+pub fn set_preferred_gpu_device(device_key: Option<String>) -> napi::Result<String> {
+    info!("선호 GPU 장치 변경 요청: {:?}", device_key);
+    
+    settings::set_preferred_device(device_key.clone());
+    
+    if context::is_gpu_initialized() {
+        context::reinitialize_gpu_context()?;
+    }
+    
+    let active = context::get_active_adapter();
+    
+    let result = json!({
+        "success": true,
+        "preferred_device": device_key,
+        "active_device": active.as_ref().map(|(key, _)| key),
+        "fallback": active.map(|(_, fallback)| fallback).unwrap_or(false),
+        "timestamp": get_timestamp()
+    });
+    
+    Ok(result.to_string())
+}
+
+/// GPU 하위 시스템 켜기/끄기 (재시작 불필요)
+/// 
+/// 끄면 GPU 컨텍스트와 계산 장치를 해제하고, 이후 GPU 작업은 CPU 경로로 실행됩니다.
+/// 켜면 컨텍스트를 다시 만들고 어댑터를 선택합니다.
+/// 결과의 `gpu`는 초기화 보고서(`get_initialization_report`)의 gpu 항목과 같은 형식이며, 보고서도 함께 갱신됩니다.
+#[napi]
+pub fn set_gpu_enabled(enabled: bool) -> napi::Result<String> {
+    info!("GPU {} 요청", if enabled { "켜기" } else { "끄기" });
+    
+    // 새 작업이 GPU 경로를 타지 않도록 설정부터 바꾼 뒤 정리
+    settings::set_hardware_acceleration_enabled(enabled);
+    if !enabled {
+        accelerator::shutdown_gpu()?;
+    }
+    
+    let report = crate::utils::diagnostics::refresh_gpu();
+    
+    let result = json!({
+        "success": report.status != crate::utils::diagnostics::SubsystemStatus::Failed,
+        "enabled": enabled,
+        "gpu": report.to_json(),
+        "active_gpu_tasks": active_gpu_task_count(),
+        "timestamp": get_timestamp()
+    });
+    
+    Ok(result.to_string())
+}
+
 /// GPU 초기화
 #[napi]
 pub fn initialize_gpu_module() -> napi::Result<bool> {
@@ -142,8 +417,16 @@ pub fn initialize_gpu_module() -> napi::Result<bool> {
 }
 
 /// 셰이더 컴파일 함수
+/// 
+/// `language`("wgsl", "glsl", "hlsl", "spirv", 기본 wgsl)와 `entry_point`(기본 "main")를 지정할 수 있습니다.
+/// WGSL은 naga로 파싱/검증하며, 실패하면 위치(line, column)가 포함된 `diagnostics`를 반환하고 캐시하지 않습니다.
 #[napi]
-pub fn compile_shader_code(source: String, shader_type: String) -> napi::Result<String> {
+pub fn compile_shader_code(
+    source: String,
+    shader_type: String,
+    language: Option<String>,
+    entry_point: Option<String>,
+) -> napi::Result<String> {
     info!("셰이더 컴파일 요청: {}", shader_type);
     
     // GPU 모듈이 초기화되었는지 확인
@@ -160,11 +443,19 @@ pub fn compile_shader_code(source: String, shader_type: String) -> napi::Result<
         _ => return Err(Error::from_reason(format!("지원되지 않는 셰이더 타입: {}", shader_type)))
     };
     
+    let language = match language.as_deref().unwrap_or("wgsl").to_lowercase().as_str() {
+        "wgsl" => shader::ShaderLanguage::WGSL,
+        "glsl" => shader::ShaderLanguage::GLSL,
+        "hlsl" => shader::ShaderLanguage::HLSL,
+        "spirv" | "spir-v" => shader::ShaderLanguage::SpirV,
+        other => return Err(Error::from_reason(format!("지원되지 않는 셰이더 언어: {}", other)))
+    };
+    
     // ShaderSource 구조체 생성
     let shader_source = shader::ShaderSource {
-        code: source.clone(),
-        language: shader::ShaderLanguage::GLSL, // 기본값으로 GLSL 사용, 필요시 변경
-        entry_point: "main".to_string(), // 기본 진입점, 필요시 변경
+        code: source,
+        language,
+        entry_point: entry_point.unwrap_or_else(|| "main".to_string()),
     };
     
     // 셰이더 이름 생성 (타임스탬프 + 타입)
@@ -179,6 +470,7 @@ pub fn compile_shader_code(source: String, shader_type: String) -> napi::Result<
                 "shader_type": shader_type,
                 "shader_name": shader_name,
                 "compile_time_ms": compiled.compile_time_ms,
+                "from_disk_cache": compiled.from_disk_cache,
                 "timestamp": get_timestamp()
             });
             
@@ -186,9 +478,14 @@ pub fn compile_shader_code(source: String, shader_type: String) -> napi::Result<
         },
         Err(e) => {
             error!("셰이더 컴파일 실패: {}", e);
+            
+            // WGSL 검증 실패면 위치별 진단 정보 포함
+            let diagnostics: Vec<&shader::ShaderDiagnostic> = e.diagnostic().into_iter().collect();
+            
             let error_json = json!({
                 "success": false,
                 "error": e.to_string(),
+                "diagnostics": diagnostics,
                 "shader_type": shader_type,
                 "timestamp": get_timestamp()
             });
@@ -198,6 +495,81 @@ pub fn compile_shader_code(source: String, shader_type: String) -> napi::Result<
     }
 }
 
+/// 셰이더 디스크 캐시 디렉터리 설정
+/// 
+/// 앱 시작 시 앱 캐시 디렉터리 아래 경로를 전달하면 이전 실행에서 저장한 셰이더를 로드합니다.
+#[napi]
+pub fn set_shader_cache_dir(path: String) -> napi::Result<String> {
+    let loaded = shader_cache::set_cache_dir(std::path::Path::new(&path))?;
+    
+    let result = json!({
+        "success": true,
+        "directory": path,
+        "loaded_entries": loaded,
+        "timestamp": get_timestamp()
+    });
+    
+    Ok(result.to_string())
+}
+
+/// 셰이더 디스크 캐시 상태 가져오기
+#[napi]
+pub fn get_shader_cache_status() -> napi::Result<String> {
+    let mut status = shader_cache::get_status();
+    status["memory_entries"] = json!(shader::get_shader_cache_size());
+    status["timestamp"] = json!(get_timestamp());
+    Ok(status.to_string())
+}
+
+/// 캐시된 셰이더 목록 가져오기
+/// 
+/// 메모리 캐시(`memory`)와 디스크 캐시(`disk`)의 항목을 JSON으로 반환합니다.
+/// 디스크 항목은 소스 해시로 저장되므로 다음 실행에서 같은 소스를 컴파일하면 다시 사용됩니다.
+#[napi]
+pub fn list_cached_shaders() -> napi::Result<String> {
+    let memory: Vec<serde_json::Value> = shader::list_cached_shaders().into_iter()
+        .map(|compiled| json!({
+            "name": compiled.name,
+            "shader_type": format!("{:?}", compiled.shader_type),
+            "source_hash": compiled.source_hash,
+            "bytes": compiled.bytecode.len(),
+            "compile_time_ms": compiled.compile_time_ms,
+            "last_used": compiled.last_used,
+            "from_disk_cache": compiled.from_disk_cache
+        }))
+        .collect();
+    
+    let result = json!({
+        "memory": memory,
+        "disk": shader_cache::list_entries(),
+        "directory": shader_cache::cache_dir().map(|dir| dir.to_string_lossy().to_string()),
+        "timestamp": get_timestamp()
+    });
+    
+    Ok(result.to_string())
+}
+
+/// 셰이더 캐시 비우기 (메모리 및 디스크)
+/// 
+/// `name`을 지정하면 해당 이름의 셰이더만 제거합니다.
+#[napi]
+pub fn clear_shader_cache(name: Option<String>) -> napi::Result<String> {
+    let (memory_cleared, disk_cleared) = match &name {
+        Some(name) => (shader::remove_cached_shader(name)? as usize, shader_cache::remove_by_name(name)?),
+        None => (shader::clear_shader_cache()?, shader_cache::clear()?),
+    };
+    
+    let result = json!({
+        "success": true,
+        "name": name,
+        "memory_cleared": memory_cleared,
+        "disk_cleared": disk_cleared,
+        "timestamp": get_timestamp()
+    });
+    
+    Ok(result.to_string())
+}
+
 // 현재 타임스탬프 가져오기
 fn get_timestamp() -> u64 {
     match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
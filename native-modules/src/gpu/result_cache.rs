@@ -0,0 +1,286 @@
+//! GPU 작업 결과 캐시
+//!
+//! 같은 문서 텍스트를 반복해서 분석할 때 다시 계산하지 않도록, 결과가 입력에만 의존하는 작업
+//! (텍스트 분석, 패턴 감지)의 결과를 입력 해시로 보관합니다.
+//! 항목 수/크기를 넘으면 가장 오래 사용하지 않은 항목부터 제거하고, 유효 시간이 지난 항목은 버립니다.
+
+use napi_derive::napi;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+use log::debug;
+use crate::utils::clock;
+use super::GpuTaskType;
+
+/// 기본 최대 항목 수
+pub const DEFAULT_MAX_ENTRIES: usize = 256;
+
+/// 기본 최대 크기 (결과 JSON 바이트 합계)
+pub const DEFAULT_MAX_BYTES: usize = 16 * 1024 * 1024;
+
+/// 기본 유효 시간 (ms)
+pub const DEFAULT_TTL_MS: u64 = 10 * 60 * 1000;
+
+// 캐시 키 (작업 유형, GPU 경로 사용 여부, 입력 해시)
+type CacheKey = (i32, bool, u64);
+
+struct CacheEntry {
+    result: Value,
+    size: usize,
+    inserted_at: u64,
+    last_used: u64,
+}
+
+struct ResultCache {
+    entries: HashMap<CacheKey, CacheEntry>,
+    // 마지막 사용 순번 -> 키 (가장 작은 순번이 가장 오래 사용하지 않은 항목)
+    lru: BTreeMap<u64, CacheKey>,
+    tick: u64,
+    bytes: usize,
+    max_entries: usize,
+    max_bytes: usize,
+    ttl_ms: u64,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+    expirations: u64,
+}
+
+impl Default for ResultCache {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            lru: BTreeMap::new(),
+            tick: 0,
+            bytes: 0,
+            max_entries: DEFAULT_MAX_ENTRIES,
+            max_bytes: DEFAULT_MAX_BYTES,
+            ttl_ms: DEFAULT_TTL_MS,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+            expirations: 0,
+        }
+    }
+}
+
+impl ResultCache {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn remove(&mut self, key: &CacheKey) -> Option<CacheEntry> {
+        let entry = self.entries.remove(key)?;
+        self.lru.remove(&entry.last_used);
+        self.bytes -= entry.size;
+        Some(entry)
+    }
+
+    fn is_expired(&self, entry: &CacheEntry, now: u64) -> bool {
+        self.ttl_ms > 0 && now.saturating_sub(entry.inserted_at) >= self.ttl_ms
+    }
+
+    fn get(&mut self, key: &CacheKey) -> Option<Value> {
+        let now = clock::now_millis();
+        let expired = match self.entries.get(key) {
+            Some(entry) => self.is_expired(entry, now),
+            None => {
+                self.misses += 1;
+                return None;
+            }
+        };
+        if expired {
+            self.remove(key);
+            self.expirations += 1;
+            self.misses += 1;
+            return None;
+        }
+
+        let tick = self.next_tick();
+        let entry = self.entries.get_mut(key)?;
+        self.lru.remove(&entry.last_used);
+        entry.last_used = tick;
+        self.lru.insert(tick, *key);
+        self.hits += 1;
+        Some(entry.result.clone())
+    }
+
+    fn insert(&mut self, key: CacheKey, result: Value) {
+        let size = result.to_string().len();
+        if self.max_entries == 0 || size > self.max_bytes {
+            return;
+        }
+
+        self.remove(&key);
+        let tick = self.next_tick();
+        self.entries.insert(key, CacheEntry { result, size, inserted_at: clock::now_millis(), last_used: tick });
+        self.lru.insert(tick, key);
+        self.bytes += size;
+        self.enforce_limits();
+    }
+
+    // 제한을 넘으면 가장 오래 사용하지 않은 항목부터 제거
+    fn enforce_limits(&mut self) {
+        while self.entries.len() > self.max_entries || self.bytes > self.max_bytes {
+            let oldest = match self.lru.first_key_value() {
+                Some((_, key)) => *key,
+                None => break,
+            };
+            self.remove(&oldest);
+            self.evictions += 1;
+        }
+    }
+
+    fn stats(&self) -> Value {
+        let lookups = self.hits + self.misses;
+        json!({
+            "entries": self.entries.len(),
+            "bytes": self.bytes,
+            "max_entries": self.max_entries,
+            "max_bytes": self.max_bytes,
+            "ttl_ms": self.ttl_ms,
+            "hits": self.hits,
+            "misses": self.misses,
+            "hit_rate": if lookups > 0 { self.hits as f64 / lookups as f64 } else { 0.0 },
+            "evictions": self.evictions,
+            "expirations": self.expirations
+        })
+    }
+}
+
+static RESULT_CACHE: Lazy<Mutex<ResultCache>> = Lazy::new(|| Mutex::new(ResultCache::default()));
+
+// 결과를 캐시할 수 있는 작업 입력인지 확인
+// (벤치마크 요청은 실행 시간을 측정해야 하므로 제외)
+fn is_cacheable(task_type: GpuTaskType, data: &str) -> bool {
+    match task_type {
+        GpuTaskType::TextAnalysis => true,
+        GpuTaskType::PatternDetection => !data.contains("\"benchmark\""),
+        _ => false,
+    }
+}
+
+fn cache_key(task_type: GpuTaskType, gpu_path: bool, data: &str) -> CacheKey {
+    let mut hasher = DefaultHasher::new();
+    data.hash(&mut hasher);
+    (task_type as i32, gpu_path, hasher.finish())
+}
+
+/// 캐시된 작업 결과 조회 (캐시 대상이 아니거나 없으면 None)
+///
+/// `gpu_path`는 GPU 경로 사용 여부로, 결과의 backend 값이 달라지므로 키에 포함합니다.
+pub fn lookup(task_type: GpuTaskType, gpu_path: bool, data: &str) -> Option<Value> {
+    if !is_cacheable(task_type, data) {
+        return None;
+    }
+    RESULT_CACHE.lock().get(&cache_key(task_type, gpu_path, data))
+}
+
+/// 작업 결과 저장 (캐시 대상이 아니거나 실패 결과면 무시)
+pub fn store(task_type: GpuTaskType, gpu_path: bool, data: &str, result: &Value) {
+    if !is_cacheable(task_type, data) || result.get("success") == Some(&json!(false)) {
+        return;
+    }
+    RESULT_CACHE.lock().insert(cache_key(task_type, gpu_path, data), result.clone());
+}
+
+/// 캐시 항목 제거 - 제거한 항목 수 반환 (`task_type`이 없으면 전체)
+pub fn invalidate(task_type: Option<GpuTaskType>) -> usize {
+    let mut cache = RESULT_CACHE.lock();
+    let keys: Vec<CacheKey> = cache.entries.keys()
+        .filter(|key| task_type.is_none_or(|task_type| key.0 == task_type as i32))
+        .copied()
+        .collect();
+    for key in &keys {
+        cache.remove(key);
+    }
+    keys.len()
+}
+
+/// 캐시와 통계, 제한 설정을 처음 상태로 초기화
+pub fn reset() {
+    *RESULT_CACHE.lock() = ResultCache::default();
+}
+
+/// GPU 작업 결과 캐시 통계 조회
+#[napi]
+pub fn get_gpu_result_cache_stats() -> String {
+    RESULT_CACHE.lock().stats().to_string()
+}
+
+/// GPU 작업 결과 캐시 제한 설정
+///
+/// 지정한 값만 바꾸며, 줄어든 제한을 넘는 항목은 바로 제거합니다.
+/// `max_entries`가 0이면 캐시를 사용하지 않고, `ttl_ms`가 0이면 시간이 지나도 만료되지 않습니다.
+/// 변경 후 통계를 JSON으로 반환합니다.
+#[napi]
+pub fn configure_gpu_result_cache(
+    max_entries: Option<u32>,
+    max_bytes: Option<u32>,
+    ttl_ms: Option<u32>,
+) -> String {
+    let mut cache = RESULT_CACHE.lock();
+    if let Some(max_entries) = max_entries {
+        cache.max_entries = max_entries as usize;
+    }
+    if let Some(max_bytes) = max_bytes {
+        cache.max_bytes = max_bytes as usize;
+    }
+    if let Some(ttl_ms) = ttl_ms {
+        cache.ttl_ms = ttl_ms as u64;
+    }
+    cache.enforce_limits();
+    debug!("GPU 결과 캐시 설정: {}개, {}바이트, {}ms", cache.max_entries, cache.max_bytes, cache.ttl_ms);
+    cache.stats().to_string()
+}
+
+/// GPU 작업 결과 캐시 비우기
+///
+/// `task_type`을 지정하면 해당 작업 유형의 결과만 제거합니다. 제거한 항목 수를 반환합니다.
+#[napi]
+pub fn invalidate_gpu_result_cache(task_type: Option<GpuTaskType>) -> u32 {
+    let removed = invalidate(task_type);
+    debug!("GPU 결과 캐시 {}개 항목 제거", removed);
+    removed as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_expire_after_ttl() {
+        clock::with_mock_clock(1_000_000, |mock| {
+            let mut cache = ResultCache { ttl_ms: 1000, ..ResultCache::default() };
+            let key = (GpuTaskType::TextAnalysis as i32, false, 1);
+            cache.insert(key, json!({ "success": true }));
+
+            mock.advance(999);
+            assert_eq!(cache.get(&key), Some(json!({ "success": true })));
+
+            // 조회해도 유효 시간은 저장 시각 기준
+            mock.advance(1);
+            assert_eq!(cache.get(&key), None);
+            assert_eq!(cache.expirations, 1);
+            assert!(cache.entries.is_empty());
+            assert_eq!(cache.bytes, 0);
+        });
+    }
+
+    #[test]
+    fn zero_ttl_never_expires() {
+        clock::with_mock_clock(1_000_000, |mock| {
+            let mut cache = ResultCache { ttl_ms: 0, ..ResultCache::default() };
+            let key = (GpuTaskType::PatternDetection as i32, true, 2);
+            cache.insert(key, json!({ "success": true }));
+
+            mock.advance(DEFAULT_TTL_MS * 10);
+            assert!(cache.get(&key).is_some());
+            assert_eq!(cache.expirations, 0);
+        });
+    }
+}
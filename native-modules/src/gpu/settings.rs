@@ -47,6 +47,10 @@ pub struct GpuSettings {
     
     /// 성능 프로필 이름
     pub profile_name: String,
+    
+    /// 선호 GPU 장치 키 (없으면 자동 선택)
+    #[serde(default)]
+    pub preferred_device: Option<String>,
 }
 
 impl Default for GpuSettings {
@@ -59,6 +63,7 @@ impl Default for GpuSettings {
             max_resource_size: DEFAULT_MAX_RESOURCE_SIZE,
             debug_mode: false,
             profile_name: "standard".to_string(),
+            preferred_device: None,
         }
     }
 }
@@ -134,6 +139,19 @@ pub fn set_power_preference(preference: &str) {
     }
 }
 
+/// 선호 GPU 장치 키 가져오기
+pub fn get_preferred_device() -> Option<String> {
+    SETTINGS.read().expect("설정 읽기 실패").preferred_device.clone()
+}
+
+/// 선호 GPU 장치 키 설정 (None이면 자동 선택)
+pub fn set_preferred_device(device_key: Option<String>) {
+    if let Ok(mut settings) = SETTINGS.write() {
+        debug!("선호 GPU 장치가 {:?}로 설정되었습니다", device_key);
+        settings.preferred_device = device_key;
+    }
+}
+
 /// 최대 리소스 크기 가져오기
 pub fn get_max_resource_size() -> usize {
     SETTINGS.read().expect("설정 읽기 실패").max_resource_size
@@ -165,6 +183,11 @@ pub fn update_settings(new_settings: GpuSettings) {
     }
 }
 
+/// 모든 설정을 기본값으로 되돌림
+pub fn reset_settings() {
+    update_settings(GpuSettings::default());
+}
+
 /// 설정을 JSON 형식으로 가져오기
 pub fn get_settings_json() -> Result<String, String> {
     let settings = get_settings();
@@ -5,9 +5,11 @@
 use std::collections::HashMap;
 use std::sync::RwLock;
 use once_cell::sync::Lazy;
-use log::debug;
+use log::{debug, warn};
 use napi::Error;
+use serde::Serialize;
 use crate::gpu::Result;
+use crate::gpu::shader_cache;
 
 // 셰이더 캐시
 static SHADER_CACHE: Lazy<RwLock<HashMap<String, CompiledShader>>> = Lazy::new(|| {
@@ -31,6 +33,12 @@ pub struct CompiledShader {
     
     /// 컴파일 시간 (밀리초)
     pub compile_time_ms: u64,
+    
+    /// 소스 해시 (같은 이름의 셰이더 소스가 바뀌었는지 확인용)
+    pub source_hash: String,
+    
+    /// 디스크 캐시에서 로드되었는지 여부
+    pub from_disk_cache: bool,
 }
 
 /// 셰이더 타입
@@ -42,6 +50,109 @@ pub enum ShaderType {
     Geometry,
 }
 
+/// 셰이더 진단 정보
+///
+/// 위치(`line`, `column`, 1부터 시작)를 알 수 없으면 None입니다.
+#[derive(Debug, Clone, Serialize)]
+pub struct ShaderDiagnostic {
+    /// 실패 단계 (parse, validation, entry_point)
+    pub stage: &'static str,
+    pub message: String,
+    pub line: Option<u32>,
+    pub column: Option<u32>,
+    /// 관련 위치별 설명
+    pub labels: Vec<ShaderDiagnosticLabel>,
+}
+
+/// 진단 정보의 위치별 설명
+#[derive(Debug, Clone, Serialize)]
+pub struct ShaderDiagnosticLabel {
+    pub line: u32,
+    pub column: u32,
+    pub message: String,
+}
+
+impl std::fmt::Display for ShaderDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "{}:{}: {}", line, column, self.message),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl ShaderDiagnostic {
+    fn new(stage: &'static str, message: String, location: Option<naga::SourceLocation>) -> Self {
+        Self {
+            stage,
+            message,
+            line: location.map(|location| location.line_number),
+            column: location.map(|location| location.line_position),
+            labels: Vec::new(),
+        }
+    }
+
+    fn with_labels<'a>(mut self, source: &str, labels: impl Iterator<Item = (naga::Span, &'a str)>) -> Self {
+        self.labels = labels
+            .filter(|(span, _)| span.is_defined())
+            .map(|(span, message)| {
+                let location = span.location(source);
+                ShaderDiagnosticLabel {
+                    line: location.line_number,
+                    column: location.line_position,
+                    message: message.to_string(),
+                }
+            })
+            .collect();
+        self
+    }
+}
+
+/// 셰이더 컴파일 오류
+///
+/// WGSL 검증 실패는 진단 정보를 그대로 담아, 호출자가 다시 검증하지 않고 위치별 오류를 보고할 수 있게 합니다.
+#[derive(Debug)]
+pub enum ShaderCompileError {
+    /// WGSL 검증 실패
+    Validation(ShaderDiagnostic),
+    /// 그 외 오류
+    Other(Error),
+}
+
+impl ShaderCompileError {
+    /// 검증 실패인 경우 진단 정보
+    pub fn diagnostic(&self) -> Option<&ShaderDiagnostic> {
+        match self {
+            ShaderCompileError::Validation(diagnostic) => Some(diagnostic),
+            ShaderCompileError::Other(_) => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ShaderCompileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShaderCompileError::Validation(diagnostic) => write!(f, "WGSL 검증 실패: {}", diagnostic),
+            ShaderCompileError::Other(e) => write!(f, "{}", e.reason),
+        }
+    }
+}
+
+impl From<Error> for ShaderCompileError {
+    fn from(e: Error) -> Self {
+        ShaderCompileError::Other(e)
+    }
+}
+
+impl From<ShaderCompileError> for Error {
+    fn from(e: ShaderCompileError) -> Self {
+        match e {
+            ShaderCompileError::Other(e) => e,
+            validation => Error::from_reason(validation.to_string()),
+        }
+    }
+}
+
 /// 셰이더 소스코드
 #[derive(Debug, Clone)]
 pub struct ShaderSource {
@@ -65,12 +176,14 @@ pub enum ShaderLanguage {
 }
 
 /// 셰이더 컴파일
-pub fn compile_shader(name: &str, source: &ShaderSource, shader_type: ShaderType) -> Result<CompiledShader> {
+pub fn compile_shader(name: &str, source: &ShaderSource, shader_type: ShaderType) -> std::result::Result<CompiledShader, ShaderCompileError> {
     debug!("셰이더 '{}' 컴파일 중...", name);
     
-    // 캐시에서 이미 컴파일된 셰이더 확인
+    let source_hash = shader_cache::source_hash(source);
+    
+    // 캐시에서 이미 컴파일된 셰이더 확인 (소스가 바뀌었으면 다시 컴파일)
     if let Ok(cache) = SHADER_CACHE.read() {
-        if let Some(shader) = cache.get(name) {
+        if let Some(shader) = cache.get(name).filter(|shader| shader.source_hash == source_hash) {
             debug!("'{}' 셰이더를 캐시에서 찾음", name);
             return Ok(shader.clone());
         }
@@ -83,27 +196,49 @@ pub fn compile_shader(name: &str, source: &ShaderSource, shader_type: ShaderType
         .unwrap_or_default()
         .as_secs();
     
-    // 실제 구현에서는 여기서 셰이더를 컴파일
-    // 더미 구현
-    let bytecode = match source.language {
-        ShaderLanguage::GLSL => compile_glsl(&source.code, shader_type)?,
-        ShaderLanguage::HLSL => compile_hlsl(&source.code, shader_type)?,
-        ShaderLanguage::WGSL => compile_wgsl(&source.code, shader_type)?,
-        ShaderLanguage::SpirV => {
-            // SPIR-V는 이미 바이너리 형식이므로 직접 파싱
-            parse_spirv(&source.code)?
+    // 디스크 캐시 확인 (장치/드라이버/소스가 같으면 재사용)
+    let disk_key = shader_cache::cache_key(source, shader_type);
+    let compiled = if let Some((bytecode, compile_time_ms)) = shader_cache::load(&disk_key) {
+        CompiledShader {
+            name: name.to_string(),
+            shader_type,
+            bytecode,
+            last_used: now,
+            compile_time_ms,
+            source_hash,
+            from_disk_cache: true,
+        }
+    } else {
+        // 실제 구현에서는 여기서 셰이더를 컴파일
+        // 더미 구현
+        let bytecode = match source.language {
+            ShaderLanguage::GLSL => compile_glsl(&source.code, shader_type)?,
+            ShaderLanguage::HLSL => compile_hlsl(&source.code, shader_type)?,
+            ShaderLanguage::WGSL => compile_wgsl(&source.code, &source.entry_point, shader_type)?,
+            ShaderLanguage::SpirV => {
+                // SPIR-V는 이미 바이너리 형식이므로 직접 파싱
+                parse_spirv(&source.code)?
+            }
+        };
+        
+        let compile_time_ms = start_time.elapsed().as_millis() as u64;
+        
+        // 디스크 캐시 저장 실패는 컴파일 결과에 영향을 주지 않음
+        if let Err(e) = shader_cache::store(&disk_key, name, source, shader_type, &bytecode, compile_time_ms) {
+            warn!("셰이더 '{}' 디스크 캐시 저장 실패: {}", name, e);
+        }
+        
+        debug!("'{}' 셰이더 컴파일 완료 ({}ms)", name, compile_time_ms);
+        
+        CompiledShader {
+            name: name.to_string(),
+            shader_type,
+            bytecode,
+            last_used: now,
+            compile_time_ms,
+            source_hash,
+            from_disk_cache: false,
         }
-    };
-    
-    let compile_time_ms = start_time.elapsed().as_millis() as u64;
-    
-    // 컴파일된 셰이더 생성
-    let compiled = CompiledShader {
-        name: name.to_string(),
-        shader_type,
-        bytecode,
-        last_used: now,
-        compile_time_ms,
     };
     
     // 캐시에 저장
@@ -111,8 +246,6 @@ pub fn compile_shader(name: &str, source: &ShaderSource, shader_type: ShaderType
         cache.insert(name.to_string(), compiled.clone());
     }
     
-    debug!("'{}' 셰이더 컴파일 완료 ({}ms)", name, compile_time_ms);
-    
     Ok(compiled)
 }
 
@@ -138,15 +271,61 @@ fn compile_hlsl(source: &str, _shader_type: ShaderType) -> Result<Vec<u8>> {
     Ok(vec![0x11, 0x12, 0x13, 0x14])
 }
 
-// WGSL 컴파일 (더미 구현)
-fn compile_wgsl(source: &str, _shader_type: ShaderType) -> Result<Vec<u8>> {
-    // 실제 구현에서는 Naga 또는 tint 라이브러리를 사용하여 컴파일
+/// WGSL 파싱 및 검증
+///
+/// naga로 소스를 파싱하고 모듈 전체를 검증한 뒤, `entry_point` 진입점이 셰이더 타입과 같은 단계로
+/// 선언되어 있는지 확인합니다. 실패하면 위치가 포함된 진단 정보를 반환합니다.
+pub fn validate_wgsl(code: &str, entry_point: &str, shader_type: ShaderType) -> std::result::Result<naga::Module, ShaderDiagnostic> {
+    let module = naga::front::wgsl::parse_str(code).map_err(|e| {
+        ShaderDiagnostic::new("parse", e.message().to_string(), e.location(code))
+            .with_labels(code, e.labels())
+    })?;
+
+    naga::valid::Validator::new(naga::valid::ValidationFlags::all(), naga::valid::Capabilities::all())
+        .validate(&module)
+        .map_err(|e| {
+            // 중첩된 오류 원인까지 이어서 메시지 구성 (예: 함수 오류 -> 식 오류)
+            let mut message = e.as_inner().to_string();
+            let mut cause = std::error::Error::source(e.as_inner());
+            while let Some(inner) = cause {
+                message.push_str(": ");
+                message.push_str(&inner.to_string());
+                cause = inner.source();
+            }
+            ShaderDiagnostic::new("validation", message, e.location(code))
+                .with_labels(code, e.spans().map(|(span, label)| (*span, label.as_str())))
+        })?;
+
+    let stage = match shader_type {
+        ShaderType::Compute => naga::ShaderStage::Compute,
+        ShaderType::Vertex => naga::ShaderStage::Vertex,
+        ShaderType::Fragment => naga::ShaderStage::Fragment,
+        ShaderType::Geometry => {
+            return Err(ShaderDiagnostic::new("entry_point", "WGSL은 geometry 셰이더를 지원하지 않습니다".to_string(), None));
+        }
+    };
+    if !module.entry_points.iter().any(|ep| ep.name == entry_point && ep.stage == stage) {
+        return Err(ShaderDiagnostic::new(
+            "entry_point",
+            format!("{:?} 단계의 진입점 '{}'이(가) 없습니다", stage, entry_point),
+            None,
+        ));
+    }
+
+    Ok(module)
+}
+
+// WGSL 컴파일
+//
+// wgpu는 WGSL 소스를 그대로 받아 백엔드별로 변환하므로, 검증을 통과한 소스를 바이트코드로 보관합니다.
+fn compile_wgsl(source: &str, entry_point: &str, shader_type: ShaderType) -> std::result::Result<Vec<u8>, ShaderCompileError> {
     if source.is_empty() {
-        return Err(Error::from_reason("빈 WGSL 소스 코드"));
+        return Err(Error::from_reason("빈 WGSL 소스 코드").into());
     }
-    
-    // 더미 바이트코드 반환
-    Ok(vec![0x21, 0x22, 0x23, 0x24])
+
+    validate_wgsl(source, entry_point, shader_type).map_err(ShaderCompileError::Validation)?;
+
+    Ok(source.as_bytes().to_vec())
 }
 
 // SPIR-V 파싱 (더미 구현)
@@ -228,6 +407,23 @@ pub fn get_shader_cache_info() -> Result<HashMap<String, (ShaderType, u64)>> {
     Ok(info)
 }
 
+/// 메모리 캐시의 셰이더 목록 (이름순)
+pub fn list_cached_shaders() -> Vec<CompiledShader> {
+    let mut shaders: Vec<CompiledShader> = SHADER_CACHE.read()
+        .map(|cache| cache.values().cloned().collect())
+        .unwrap_or_default();
+    shaders.sort_by(|a, b| a.name.cmp(&b.name));
+    shaders
+}
+
+/// 메모리 캐시에서 셰이더 제거 (없으면 false)
+pub fn remove_cached_shader(name: &str) -> Result<bool> {
+    match SHADER_CACHE.write() {
+        Ok(mut cache) => Ok(cache.remove(name).is_some()),
+        Err(_) => Err(Error::from_reason("셰이더 캐시 잠금 획득 실패")),
+    }
+}
+
 /// 셰이더 캐시 크기 가져오기
 pub fn get_shader_cache_size() -> usize {
     if let Ok(cache) = SHADER_CACHE.read() {
@@ -237,13 +433,32 @@ pub fn get_shader_cache_size() -> usize {
     }
 }
 
-/// 셰이더 모듈 - 타이핑 통계 분석을 위한 컴퓨트 셰이더 정의
+// 셰이더 모듈 - 타이핑 통계 분석을 위한 컴퓨트 셰이더 정의
 
-/// 간단한 GPU 셰이더 초기화 함수
-pub fn initialize_shaders() -> Result<()> {
-    // 이 파일은 컴파일 오류를 해결하기 위해 생성됨
-    // 실제 셰이더 초기화 로직은 추후 구현 필요
-    Ok(())
+/// 기본 계산 셰이더 이름 (`get_shader_source`의 유형 이름)
+pub const CORE_SHADERS: [&str; 4] = ["matrix", "pattern", "typing", "heatmap"];
+
+/// 기본 계산 셰이더 미리 컴파일
+///
+/// 첫 GPU 작업에서 컴파일 시간이 걸리지 않도록 메모리/디스크 캐시에 올려 둡니다.
+/// 디스크 캐시에서 로드된 셰이더 수를 반환합니다.
+pub fn initialize_shaders() -> Result<usize> {
+    let mut from_disk = 0;
+    
+    for shader_type in CORE_SHADERS {
+        let source = ShaderSource {
+            code: get_shader_source(shader_type)?.to_string(),
+            language: ShaderLanguage::WGSL,
+            entry_point: "main".to_string(),
+        };
+        let compiled = compile_shader(&format!("core_{}", shader_type), &source, ShaderType::Compute)?;
+        if compiled.from_disk_cache {
+            from_disk += 1;
+        }
+    }
+    
+    debug!("기본 셰이더 {}개 준비됨 (디스크 캐시 {}개)", CORE_SHADERS.len(), from_disk);
+    Ok(from_disk)
 }
 
 /// 행렬 곱셈 컴퓨트 셰이더
@@ -338,8 +553,8 @@ pub fn get_pattern_detection_shader() -> &'static str {
             }
         }
         
-        results[patternIdx * 2] = bestMatch;
-        results[patternIdx * 2 + 1] = f32(bestPos);
+        results[patternIdx * 2u] = bestMatch;
+        results[patternIdx * 2u + 1u] = f32(bestPos);
     }
     "#
 }
@@ -416,6 +631,66 @@ pub fn get_typing_analysis_shader() -> &'static str {
     "#
 }
 
+/// 키 입력 히트맵 집계 컴퓨트 셰이더
+pub fn get_key_heatmap_shader() -> &'static str {
+    r#"
+    @group(0) @binding(0)
+    var<storage, read> keyIndices: array<u32>;
+    
+    @group(0) @binding(1)
+    var<storage, read> dwellTimes: array<u32>;
+    
+    @group(0) @binding(2)
+    var<storage, read> flightTimes: array<u32>;
+    
+    // 키마다 [입력 수, 누름 시간 합계, 누름 시간 개수, 이동 시간 합계, 이동 시간 개수]
+    @group(0) @binding(3)
+    var<storage, read_write> keyStats: array<atomic<u32>>;
+    
+    struct Uniforms {
+        eventCount: u32,
+        keyCount: u32,
+        padding0: u32,
+        padding1: u32,
+    }
+    
+    @group(0) @binding(4)
+    var<uniform> uniforms: Uniforms;
+    
+    // 값이 없는 항목 표시
+    const MISSING: u32 = 0xffffffffu;
+    
+    @compute @workgroup_size(256)
+    fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+        let idx = global_id.x;
+        
+        if (idx >= uniforms.eventCount) {
+            return;
+        }
+        
+        let key = keyIndices[idx];
+        if (key >= uniforms.keyCount) {
+            return;
+        }
+        
+        let base = key * 5u;
+        atomicAdd(&keyStats[base], 1u);
+        
+        let dwell = dwellTimes[idx];
+        if (dwell != MISSING) {
+            atomicAdd(&keyStats[base + 1u], dwell);
+            atomicAdd(&keyStats[base + 2u], 1u);
+        }
+        
+        let flight = flightTimes[idx];
+        if (flight != MISSING) {
+            atomicAdd(&keyStats[base + 3u], flight);
+            atomicAdd(&keyStats[base + 4u], 1u);
+        }
+    }
+    "#
+}
+
 /// 셰이더 모듈 생성 (가상 구현)
 pub fn create_shader_module(_device: &wgpu::Device, _source: &str) -> Result<wgpu::ShaderModule> {
     debug!("셰이더 모듈 생성 시뮬레이션...");
@@ -456,6 +731,7 @@ pub fn get_shader_source(shader_type: &str) -> Result<&'static str> {
         "matrix" => Ok(get_matrix_multiplication_shader()),
         "pattern" => Ok(get_pattern_detection_shader()),
         "typing" => Ok(get_typing_analysis_shader()),
+        "heatmap" => Ok(get_key_heatmap_shader()),
         _ => Err(Error::from_reason(format!("Unsupported shader type: {}", shader_type))),
     }
 }
@@ -0,0 +1,510 @@
+//! 셰이더 디스크 캐시 모듈
+//!
+//! 컴파일된 셰이더 바이트코드를 앱 캐시 디렉터리에 저장해 두고 다음 실행 때 다시 사용합니다.
+//! 캐시 키는 장치/드라이버 정보와 셰이더 소스 해시로 만들어지므로,
+//! 소스가 바뀌면 새 키로 다시 컴파일되고, 드라이버나 장치가 바뀌면 GPU 컨텍스트 초기화 시 기존 항목이 삭제됩니다.
+//! 바이트코드 파일 크기의 합이 `MAX_CACHE_BYTES`를 넘으면 가장 오래 쓰이지 않은 항목부터 삭제합니다.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use serde::{Serialize, Deserialize};
+use serde_json::{json, Value};
+use log::{debug, info, warn};
+use napi::Error;
+use crate::gpu::Result;
+use crate::gpu::context;
+use crate::gpu::settings as gpu_settings;
+use crate::gpu::shader::{ShaderLanguage, ShaderSource, ShaderType};
+
+// 캐시 형식 버전 (형식이 바뀌면 기존 캐시 전체 무효화)
+// 2: WGSL을 naga로 검증한 뒤 저장 (검증 없이 저장된 이전 항목 제거)
+const CACHE_FORMAT_VERSION: u32 = 2;
+
+// 매니페스트 파일 이름
+const MANIFEST_FILE: &str = "manifest.json";
+
+// 장치 정보를 알 수 없을 때 사용하는 지문
+const SOFTWARE_FINGERPRINT: &str = "software";
+
+// 디스크 캐시 최대 크기 (바이트코드 파일 크기 합계)
+const MAX_CACHE_BYTES: u64 = 32 * 1024 * 1024;
+
+/// 디스크 캐시 항목
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShaderCacheEntry {
+    /// 셰이더 이름 (마지막으로 이 항목을 만든 셰이더)
+    pub name: String,
+    /// 셰이더 타입 이름
+    pub shader_type: String,
+    /// 소스 해시 (16진수)
+    pub source_hash: String,
+    /// 장치/드라이버 지문
+    pub device_fingerprint: String,
+    /// 바이트코드 파일 이름
+    pub file: String,
+    /// 원래 컴파일 시간 (밀리초)
+    pub compile_time_ms: u64,
+    /// 저장 시간 (Unix 타임스탬프, 초)
+    pub created_at: u64,
+    /// 바이트코드 파일 크기 (바이트)
+    #[serde(default)]
+    pub bytes: u64,
+    /// 마지막 사용 시간 (Unix 타임스탬프, 초 - 저장 또는 로드 시 갱신)
+    #[serde(default)]
+    pub last_used_at: u64,
+}
+
+// 매니페스트 (캐시 키 -> 항목)
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheManifest {
+    version: u32,
+    entries: HashMap<String, ShaderCacheEntry>,
+}
+
+// 디스크 캐시 상태
+struct DiskCache {
+    dir: PathBuf,
+    manifest: CacheManifest,
+    hits: u64,
+    misses: u64,
+}
+
+static DISK_CACHE: Lazy<RwLock<Option<DiskCache>>> = Lazy::new(|| RwLock::new(None));
+
+/// 캐시 디렉터리 설정 및 기존 항목 로드
+///
+/// GPU 컨텍스트가 이미 초기화되어 있으면 현재 장치/드라이버와 맞지 않는 항목을 바로 정리하고,
+/// 아니면 컨텍스트 초기화 시점에 정리합니다. 로드된 항목 수를 반환합니다.
+pub fn set_cache_dir(dir: &Path) -> Result<usize> {
+    fs::create_dir_all(dir)
+        .map_err(|e| Error::from_reason(format!("셰이더 캐시 디렉터리 생성 실패: {}", e)))?;
+
+    let mut manifest = read_manifest(dir);
+
+    if manifest.version != CACHE_FORMAT_VERSION {
+        if !manifest.entries.is_empty() {
+            info!("셰이더 캐시 형식이 변경되어 기존 캐시를 삭제합니다");
+        }
+        for entry in manifest.entries.values() {
+            let _ = fs::remove_file(dir.join(&entry.file));
+        }
+        manifest = CacheManifest { version: CACHE_FORMAT_VERSION, entries: HashMap::new() };
+    }
+
+    // 크기/사용 시간 없이 저장된 이전 항목은 파일 크기와 저장 시간으로 채움
+    for entry in manifest.entries.values_mut() {
+        if entry.bytes == 0 {
+            entry.bytes = fs::metadata(dir.join(&entry.file)).map(|m| m.len()).unwrap_or(0);
+        }
+        if entry.last_used_at == 0 {
+            entry.last_used_at = entry.created_at;
+        }
+    }
+
+    let cache = DiskCache { dir: dir.to_path_buf(), manifest, hits: 0, misses: 0 };
+    write_manifest(&cache)?;
+    *DISK_CACHE.write() = Some(cache);
+
+    if context::is_gpu_initialized() {
+        revalidate();
+    }
+
+    let loaded = DISK_CACHE.read().as_ref().map(|c| c.manifest.entries.len()).unwrap_or(0);
+    info!("셰이더 디스크 캐시 로드됨: {} ({}개 항목)", dir.display(), loaded);
+    Ok(loaded)
+}
+
+/// 현재 장치/드라이버와 맞지 않거나 바이트코드 파일이 없는 항목 삭제
+///
+/// GPU 컨텍스트가 (재)초기화될 때 호출됩니다.
+pub fn revalidate() {
+    let fingerprint = device_fingerprint();
+    let mut guard = DISK_CACHE.write();
+    let cache = match guard.as_mut() {
+        Some(cache) => cache,
+        None => return,
+    };
+
+    let stale: Vec<String> = cache.manifest.entries.iter()
+        .filter(|(_, entry)| entry.device_fingerprint != fingerprint || !cache.dir.join(&entry.file).exists())
+        .map(|(key, _)| key.clone())
+        .collect();
+
+    if stale.is_empty() {
+        return;
+    }
+
+    for key in &stale {
+        if let Some(entry) = cache.manifest.entries.remove(key) {
+            let _ = fs::remove_file(cache.dir.join(&entry.file));
+        }
+    }
+
+    info!("장치 또는 드라이버 변경으로 셰이더 캐시 {}개 무효화", stale.len());
+    if let Err(e) = write_manifest(cache) {
+        warn!("{}", e);
+    }
+}
+
+/// 캐시 키 생성 (장치 지문 + 소스 해시 + 셰이더 설정)
+pub fn cache_key(source: &ShaderSource, shader_type: ShaderType) -> String {
+    format!("{:016x}", fnv1a(&[
+        device_fingerprint().as_bytes(),
+        source_hash(source).as_bytes(),
+        format!("{:?}", shader_type).as_bytes(),
+    ]))
+}
+
+/// 소스 해시 생성 (코드, 언어, 진입점)
+pub fn source_hash(source: &ShaderSource) -> String {
+    format!("{:016x}", fnv1a(&[
+        source.code.as_bytes(),
+        language_name(source.language).as_bytes(),
+        source.entry_point.as_bytes(),
+    ]))
+}
+
+/// 디스크 캐시에서 바이트코드 가져오기
+pub fn load(key: &str) -> Option<(Vec<u8>, u64)> {
+    if !gpu_settings::is_shader_cache_enabled() {
+        return None;
+    }
+
+    let mut guard = DISK_CACHE.write();
+    let cache = guard.as_mut()?;
+
+    let entry = match cache.manifest.entries.get_mut(key) {
+        Some(entry) => {
+            entry.last_used_at = unix_now();
+            entry.clone()
+        },
+        None => {
+            cache.misses += 1;
+            return None;
+        }
+    };
+
+    match fs::read(cache.dir.join(&entry.file)) {
+        Ok(bytecode) => {
+            cache.hits += 1;
+            debug!("셰이더 '{}' 디스크 캐시에서 로드됨", entry.name);
+            // 사용 시간 기록 (용량 초과 시 삭제 순서에 사용)
+            if let Err(e) = write_manifest(cache) {
+                warn!("{}", e);
+            }
+            Some((bytecode, entry.compile_time_ms))
+        },
+        Err(e) => {
+            warn!("셰이더 캐시 파일 읽기 실패: {}", e);
+            cache.manifest.entries.remove(key);
+            cache.misses += 1;
+            let _ = write_manifest(cache);
+            None
+        }
+    }
+}
+
+/// 컴파일된 바이트코드를 디스크 캐시에 저장
+///
+/// 같은 이름이지만 소스가 다른 이전 항목은 더 이상 쓰이지 않으므로 함께 삭제하고,
+/// 저장 후 전체 크기가 `MAX_CACHE_BYTES`를 넘으면 가장 오래 쓰이지 않은 항목부터 삭제합니다.
+pub fn store(
+    key: &str,
+    name: &str,
+    source: &ShaderSource,
+    shader_type: ShaderType,
+    bytecode: &[u8],
+    compile_time_ms: u64,
+) -> Result<()> {
+    if !gpu_settings::is_shader_cache_enabled() {
+        return Ok(());
+    }
+
+    let mut guard = DISK_CACHE.write();
+    let cache = match guard.as_mut() {
+        Some(cache) => cache,
+        None => return Ok(()),
+    };
+
+    let hash = source_hash(source);
+    let outdated: Vec<String> = cache.manifest.entries.iter()
+        .filter(|(k, entry)| entry.name == name && entry.source_hash != hash && k.as_str() != key)
+        .map(|(k, _)| k.clone())
+        .collect();
+
+    for outdated_key in outdated {
+        if let Some(entry) = cache.manifest.entries.remove(&outdated_key) {
+            debug!("셰이더 '{}' 소스 변경으로 이전 캐시 삭제", name);
+            let _ = fs::remove_file(cache.dir.join(&entry.file));
+        }
+    }
+
+    let file = format!("{}.bin", key);
+    fs::write(cache.dir.join(&file), bytecode)
+        .map_err(|e| Error::from_reason(format!("셰이더 캐시 저장 실패: {}", e)))?;
+
+    let now = unix_now();
+    cache.manifest.entries.insert(key.to_string(), ShaderCacheEntry {
+        name: name.to_string(),
+        shader_type: format!("{:?}", shader_type),
+        source_hash: hash,
+        device_fingerprint: device_fingerprint(),
+        file,
+        compile_time_ms,
+        created_at: now,
+        bytes: bytecode.len() as u64,
+        last_used_at: now,
+    });
+
+    let evicted = evict_lru(cache, key, MAX_CACHE_BYTES);
+    if evicted > 0 {
+        debug!("셰이더 디스크 캐시 용량 초과로 {}개 항목 삭제", evicted);
+    }
+
+    write_manifest(cache)
+}
+
+// 전체 크기가 `max_bytes` 이하가 될 때까지 가장 오래 쓰이지 않은 항목 삭제 (`keep` 항목 제외)
+// 삭제한 항목 수 반환
+fn evict_lru(cache: &mut DiskCache, keep: &str, max_bytes: u64) -> usize {
+    let mut total: u64 = cache.manifest.entries.values().map(|entry| entry.bytes).sum();
+    if total <= max_bytes {
+        return 0;
+    }
+
+    let mut candidates: Vec<(String, u64)> = cache.manifest.entries.iter()
+        .filter(|(k, _)| k.as_str() != keep)
+        .map(|(k, entry)| (k.clone(), entry.last_used_at))
+        .collect();
+    candidates.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+
+    let mut evicted = 0;
+    for (k, _) in candidates {
+        if total <= max_bytes {
+            break;
+        }
+        if let Some(entry) = cache.manifest.entries.remove(&k) {
+            let _ = fs::remove_file(cache.dir.join(&entry.file));
+            total = total.saturating_sub(entry.bytes);
+            evicted += 1;
+        }
+    }
+    evicted
+}
+
+/// 디스크 캐시 전체 삭제
+pub fn clear() -> Result<usize> {
+    let mut guard = DISK_CACHE.write();
+    let cache = match guard.as_mut() {
+        Some(cache) => cache,
+        None => return Ok(0),
+    };
+
+    let count = cache.manifest.entries.len();
+    for entry in cache.manifest.entries.values() {
+        let _ = fs::remove_file(cache.dir.join(&entry.file));
+    }
+    cache.manifest.entries.clear();
+    write_manifest(cache)?;
+
+    debug!("셰이더 디스크 캐시 {}개 항목 삭제됨", count);
+    Ok(count)
+}
+
+/// 이름이 같은 디스크 캐시 항목 삭제 - 삭제한 항목 수 반환
+pub fn remove_by_name(name: &str) -> Result<usize> {
+    let mut guard = DISK_CACHE.write();
+    let cache = match guard.as_mut() {
+        Some(cache) => cache,
+        None => return Ok(0),
+    };
+
+    let keys: Vec<String> = cache.manifest.entries.iter()
+        .filter(|(_, entry)| entry.name == name)
+        .map(|(key, _)| key.clone())
+        .collect();
+    if keys.is_empty() {
+        return Ok(0);
+    }
+
+    for key in &keys {
+        if let Some(entry) = cache.manifest.entries.remove(key) {
+            let _ = fs::remove_file(cache.dir.join(&entry.file));
+        }
+    }
+    write_manifest(cache)?;
+
+    debug!("셰이더 '{}' 디스크 캐시 {}개 항목 삭제됨", name, keys.len());
+    Ok(keys.len())
+}
+
+/// 디스크 캐시 항목 목록 (이름순)
+///
+/// 항목마다 캐시 키, 바이트코드 파일 크기, 현재 장치/드라이버용인지 여부를 함께 반환합니다.
+pub fn list_entries() -> Vec<Value> {
+    let guard = DISK_CACHE.read();
+    let cache = match guard.as_ref() {
+        Some(cache) => cache,
+        None => return Vec::new(),
+    };
+
+    let fingerprint = device_fingerprint();
+    let mut entries: Vec<(&String, &ShaderCacheEntry)> = cache.manifest.entries.iter().collect();
+    entries.sort_by(|a, b| a.1.name.cmp(&b.1.name).then_with(|| a.0.cmp(b.0)));
+
+    entries.into_iter()
+        .map(|(key, entry)| json!({
+            "key": key,
+            "name": entry.name,
+            "shader_type": entry.shader_type,
+            "source_hash": entry.source_hash,
+            "bytes": fs::metadata(cache.dir.join(&entry.file)).map(|m| m.len()).unwrap_or(0),
+            "compile_time_ms": entry.compile_time_ms,
+            "created_at": entry.created_at,
+            "current_device": entry.device_fingerprint == fingerprint
+        }))
+        .collect()
+}
+
+/// 현재 캐시 디렉터리 (설정되지 않았으면 None)
+pub fn cache_dir() -> Option<PathBuf> {
+    DISK_CACHE.read().as_ref().map(|cache| cache.dir.clone())
+}
+
+/// 디스크 캐시 연결 해제 (파일은 남겨 두고 다음 `set_cache_dir` 호출 때 다시 로드)
+pub fn detach() {
+    if DISK_CACHE.write().take().is_some() {
+        debug!("셰이더 디스크 캐시 연결 해제됨");
+    }
+}
+
+/// 디스크 캐시 상태 정보
+pub fn get_status() -> Value {
+    let guard = DISK_CACHE.read();
+
+    match guard.as_ref() {
+        Some(cache) => json!({
+            "enabled": gpu_settings::is_shader_cache_enabled(),
+            "directory": cache.dir.to_string_lossy(),
+            "entries": cache.manifest.entries.len(),
+            "bytes": cache.manifest.entries.values().map(|entry| entry.bytes).sum::<u64>(),
+            "max_bytes": MAX_CACHE_BYTES,
+            "hits": cache.hits,
+            "misses": cache.misses,
+            "device_fingerprint": device_fingerprint()
+        }),
+        None => json!({
+            "enabled": false,
+            "directory": null,
+            "entries": 0,
+            "bytes": 0,
+            "max_bytes": MAX_CACHE_BYTES,
+            "hits": 0,
+            "misses": 0,
+            "device_fingerprint": device_fingerprint()
+        }),
+    }
+}
+
+/// 현재 장치/드라이버 지문 (어댑터 키 + 드라이버 버전)
+pub fn device_fingerprint() -> String {
+    context::GPU_CONTEXT.read().ok()
+        .and_then(|guard| guard.as_ref()
+            .filter(|ctx| !ctx.adapter_key.is_empty())
+            .map(|ctx| format!("{}|{}", ctx.adapter_key, ctx.driver_version)))
+        .unwrap_or_else(|| SOFTWARE_FINGERPRINT.to_string())
+}
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn language_name(language: ShaderLanguage) -> &'static str {
+    match language {
+        ShaderLanguage::GLSL => "glsl",
+        ShaderLanguage::HLSL => "hlsl",
+        ShaderLanguage::WGSL => "wgsl",
+        ShaderLanguage::SpirV => "spirv",
+    }
+}
+
+// FNV-1a 64비트 해시 (실행/빌드마다 값이 바뀌지 않아야 하므로 std 해셔 대신 사용)
+fn fnv1a(parts: &[&[u8]]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for part in parts {
+        for byte in part.iter() {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+        // 구분자 (["ab", "c"]와 ["a", "bc"]가 같은 해시가 되지 않도록)
+        hash ^= 0xff;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+fn read_manifest(dir: &Path) -> CacheManifest {
+    fs::read_to_string(dir.join(MANIFEST_FILE)).ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or(CacheManifest { version: CACHE_FORMAT_VERSION, entries: HashMap::new() })
+}
+
+fn write_manifest(cache: &DiskCache) -> Result<()> {
+    let content = serde_json::to_string_pretty(&cache.manifest)
+        .map_err(|e| Error::from_reason(format!("셰이더 캐시 매니페스트 직렬화 실패: {}", e)))?;
+    fs::write(cache.dir.join(MANIFEST_FILE), content)
+        .map_err(|e| Error::from_reason(format!("셰이더 캐시 매니페스트 저장 실패: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(file: &str, bytes: u64, last_used_at: u64) -> ShaderCacheEntry {
+        ShaderCacheEntry {
+            name: file.to_string(),
+            shader_type: "Compute".to_string(),
+            source_hash: String::new(),
+            device_fingerprint: SOFTWARE_FINGERPRINT.to_string(),
+            file: file.to_string(),
+            compile_time_ms: 0,
+            created_at: last_used_at,
+            bytes,
+            last_used_at,
+        }
+    }
+
+    #[test]
+    fn evicts_least_recently_used_until_under_limit() {
+        let dir = std::env::temp_dir().join(format!("shader-cache-lru-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut entries = HashMap::new();
+        for (key, last_used_at) in [("old", 10), ("mid", 20), ("new", 30), ("stored", 5)] {
+            fs::write(dir.join(key), [0u8; 4]).unwrap();
+            entries.insert(key.to_string(), entry(key, 40, last_used_at));
+        }
+        let mut cache = DiskCache {
+            dir: dir.clone(),
+            manifest: CacheManifest { version: CACHE_FORMAT_VERSION, entries },
+            hits: 0,
+            misses: 0,
+        };
+
+        // 방금 저장한 항목은 사용 시간이 오래되어 보여도 남김
+        assert_eq!(evict_lru(&mut cache, "stored", 100), 2);
+        let mut remaining: Vec<&String> = cache.manifest.entries.keys().collect();
+        remaining.sort();
+        assert_eq!(remaining, ["new", "stored"]);
+        assert!(!dir.join("old").exists());
+        assert!(dir.join("new").exists());
+
+        assert_eq!(evict_lru(&mut cache, "stored", 100), 0);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,272 @@
+//! GPU 열 스로틀링 감지 모듈
+//!
+//! 같은 유형의 작업 실행 시간이 기준보다 크게 늘어나고 편차가 커지면
+//! 하드웨어가 스로틀링 중인 것으로 보고, 가능한 경우 GPU 온도 센서 값도 함께 확인합니다.
+//! 스로틀링이 감지되면 배치 작업은 다음 항목을 실행하기 전에 잠시 쉬어 갑니다
+//! (배치 하나에서 쉬는 시간의 합은 `MAX_BATCH_PAUSE_MS`를 넘지 않음).
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use log::{debug, info, warn};
+
+// 작업 유형별로 보관할 최근 실행 시간 샘플 수
+const DURATION_WINDOW: usize = 24;
+
+// 판단에 필요한 최소 샘플 수
+const MIN_SAMPLES: usize = 8;
+
+// 최근 평균을 계산할 샘플 수
+const RECENT_SAMPLES: usize = 4;
+
+// 최근 평균이 기준의 이 배수를 넘으면 스로틀링 의심
+const SLOWDOWN_RATIO: f64 = 1.6;
+
+// 스로틀링 판단에 필요한 최소 변동 계수 (표준편차 / 평균)
+const MIN_VARIATION: f64 = 0.25;
+
+// 이 온도(섭씨) 이상이면 GPU 센서 기준 스로틀링으로 판단
+const THERMAL_LIMIT_CELSIUS: f64 = 85.0;
+
+// 스로틀링 시 배치 작업 일시 정지 시간 및 최대 반복 횟수
+const COOLDOWN_PAUSE_MS: u64 = 1000;
+const MAX_COOLDOWN_PAUSES: u32 = 5;
+
+/// 배치 작업 하나에서 쉬는 시간의 최대 합 (ms)
+///
+/// 센서 온도가 계속 높게 읽혀도 항목마다 최대 정지 시간을 채우며 배치가 끝없이 늘어지지 않게 합니다.
+pub const MAX_BATCH_PAUSE_MS: u64 = 10_000;
+
+// GPU 온도를 보고하는 hwmon 드라이버 이름 (Linux)
+#[cfg(target_os = "linux")]
+const GPU_HWMON_DRIVERS: &[&str] = &["amdgpu", "radeon", "nouveau"];
+
+// 열 보호 활성화 여부
+static THERMAL_PROTECTION_ENABLED: AtomicBool = AtomicBool::new(true);
+
+// 실행 시간 변동 기반 스로틀링 감지 상태
+static VARIANCE_THROTTLED: AtomicBool = AtomicBool::new(false);
+
+// 통계
+static THROTTLE_EVENTS: AtomicU64 = AtomicU64::new(0);
+static TOTAL_PAUSED_MS: AtomicU64 = AtomicU64::new(0);
+
+// 작업 유형별 실행 시간 기록
+static DURATIONS: Lazy<Mutex<HashMap<String, DurationHistory>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+// 작업 유형별 실행 시간 기록 (입력 1KB당 ms로 정규화)
+struct DurationHistory {
+    samples: VecDeque<f64>,
+    baseline: Option<f64>,
+}
+
+impl DurationHistory {
+    fn new() -> Self {
+        Self {
+            samples: VecDeque::with_capacity(DURATION_WINDOW),
+            baseline: None,
+        }
+    }
+
+    fn push(&mut self, value: f64) {
+        if self.samples.len() == DURATION_WINDOW {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(value);
+
+        // 기준값은 지금까지 관측된 빠른 실행(하위 25%)을 따라가며, clear 전까지는 내려가기만 함
+        if self.samples.len() >= MIN_SAMPLES {
+            let mut sorted: Vec<f64> = self.samples.iter().copied().collect();
+            sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            let quartile = sorted[sorted.len() / 4];
+            self.baseline = Some(self.baseline.map_or(quartile, |b| b.min(quartile)));
+        }
+    }
+
+    // 샘플과 기준값을 함께 비움 (기준값만 남으면 예전의 빠른 실행과 계속 비교하게 됨)
+    fn clear(&mut self) {
+        self.samples.clear();
+        self.baseline = None;
+    }
+
+    // 최근 실행이 기준보다 느려지고 편차가 커졌는지 확인
+    fn is_slowing_down(&self) -> bool {
+        let baseline = match self.baseline {
+            Some(b) if b > 0.0 => b,
+            _ => return false,
+        };
+        if self.samples.len() < MIN_SAMPLES {
+            return false;
+        }
+
+        let recent_mean = self.samples.iter().rev().take(RECENT_SAMPLES).sum::<f64>()
+            / RECENT_SAMPLES as f64;
+
+        let mean = self.samples.iter().sum::<f64>() / self.samples.len() as f64;
+        let variance = self.samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>()
+            / self.samples.len() as f64;
+        let variation = if mean > 0.0 { variance.sqrt() / mean } else { 0.0 };
+
+        recent_mean > baseline * SLOWDOWN_RATIO && variation >= MIN_VARIATION
+    }
+}
+
+/// 작업 실행 시간 기록
+///
+/// 입력 크기가 다른 작업끼리 비교할 수 있도록 입력 1KB당 실행 시간으로 정규화합니다.
+pub fn record_task_duration(task_key: &str, input_bytes: usize, duration_ms: f64) {
+    let units = (input_bytes as f64 / 1024.0).max(1.0);
+
+    let slowing_down = {
+        let mut durations = DURATIONS.lock();
+        let history = durations.entry(task_key.to_string()).or_insert_with(DurationHistory::new);
+        history.push(duration_ms / units);
+        durations.values().any(|h| h.is_slowing_down())
+    };
+
+    let was_throttled = VARIANCE_THROTTLED.swap(slowing_down, Ordering::SeqCst);
+    if slowing_down && !was_throttled {
+        THROTTLE_EVENTS.fetch_add(1, Ordering::Relaxed);
+        warn!("GPU 작업 실행 시간 증가 감지: {} (스로틀링 의심)", task_key);
+    } else if !slowing_down && was_throttled {
+        info!("GPU 작업 실행 시간이 정상으로 돌아옴");
+    }
+}
+
+/// 현재 스로틀링 상태 확인 (실행 시간 변동 또는 GPU 온도 센서 기준)
+pub fn is_throttled() -> bool {
+    VARIANCE_THROTTLED.load(Ordering::SeqCst)
+        || read_gpu_temperature().is_some_and(|t| t >= THERMAL_LIMIT_CELSIUS)
+}
+
+/// 스로틀링 중이면 배치 작업을 잠시 멈춤
+///
+/// `budget_ms`는 배치에 남은 정지 가능 시간으로, 이를 넘겨 멈추지 않습니다.
+/// 정지 후에는 최근 실행 기록과 기준값을 비워 새 샘플로 다시 판단합니다.
+/// 실제로 멈춘 시간(ms)을 반환합니다.
+pub fn pause_if_throttled(budget_ms: u64) -> u64 {
+    if !THERMAL_PROTECTION_ENABLED.load(Ordering::Relaxed) {
+        return 0;
+    }
+
+    let mut paused_ms = 0;
+    for _ in 0..MAX_COOLDOWN_PAUSES {
+        if paused_ms + COOLDOWN_PAUSE_MS > budget_ms || !is_throttled() {
+            break;
+        }
+        debug!("GPU 스로틀링 감지, 배치 작업 {}ms 일시 정지", COOLDOWN_PAUSE_MS);
+        std::thread::sleep(Duration::from_millis(COOLDOWN_PAUSE_MS));
+        paused_ms += COOLDOWN_PAUSE_MS;
+
+        for history in DURATIONS.lock().values_mut() {
+            history.clear();
+        }
+        VARIANCE_THROTTLED.store(false, Ordering::SeqCst);
+    }
+
+    TOTAL_PAUSED_MS.fetch_add(paused_ms, Ordering::Relaxed);
+    paused_ms
+}
+
+/// 열 보호 활성화 설정
+pub fn set_thermal_protection_enabled(enabled: bool) {
+    THERMAL_PROTECTION_ENABLED.store(enabled, Ordering::Relaxed);
+    info!("GPU 열 보호가 {}되었습니다", if enabled { "활성화" } else { "비활성화" });
+}
+
+/// 실행 시간 기록과 스로틀링 통계 초기화 (열 보호 설정은 기본값으로 되돌림)
+pub fn reset() {
+    DURATIONS.lock().clear();
+    VARIANCE_THROTTLED.store(false, Ordering::SeqCst);
+    THROTTLE_EVENTS.store(0, Ordering::Relaxed);
+    TOTAL_PAUSED_MS.store(0, Ordering::Relaxed);
+    THERMAL_PROTECTION_ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// 열 상태 정보 가져오기
+pub fn get_thermal_status() -> Value {
+    let temperature = read_gpu_temperature();
+
+    json!({
+        "protection_enabled": THERMAL_PROTECTION_ENABLED.load(Ordering::Relaxed),
+        "throttled": is_throttled(),
+        "duration_throttled": VARIANCE_THROTTLED.load(Ordering::SeqCst),
+        "temperature_celsius": temperature,
+        "temperature_limit_celsius": THERMAL_LIMIT_CELSIUS,
+        "temperature_available": temperature.is_some(),
+        "throttle_events": THROTTLE_EVENTS.load(Ordering::Relaxed),
+        "total_paused_ms": TOTAL_PAUSED_MS.load(Ordering::Relaxed)
+    })
+}
+
+// GPU 온도 센서 중 가장 높은 값 (섭씨)
+//
+// CPU나 배터리 등 다른 온도 영역은 GPU 작업 속도와 무관하므로 제외하고,
+// 유형 이름에 gpu가 들어간 thermal zone과 GPU 드라이버의 hwmon 센서만 읽습니다.
+#[cfg(target_os = "linux")]
+fn read_gpu_temperature() -> Option<f64> {
+    let read_millidegrees = |path: std::path::PathBuf| {
+        std::fs::read_to_string(path).ok()?.trim().parse::<f64>().ok().map(|m| m / 1000.0)
+    };
+
+    let zones = std::fs::read_dir("/sys/class/thermal")
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("thermal_zone"))
+        .filter(|entry| {
+            std::fs::read_to_string(entry.path().join("type"))
+                .is_ok_and(|zone_type| zone_type.to_ascii_lowercase().contains("gpu"))
+        })
+        .filter_map(|entry| read_millidegrees(entry.path().join("temp")));
+
+    let hwmon = std::fs::read_dir("/sys/class/hwmon")
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| {
+            std::fs::read_to_string(entry.path().join("name"))
+                .is_ok_and(|name| GPU_HWMON_DRIVERS.contains(&name.trim()))
+        })
+        .flat_map(|entry| std::fs::read_dir(entry.path()).into_iter().flatten().flatten())
+        .filter(|sensor| {
+            let name = sensor.file_name().to_string_lossy().into_owned();
+            name.starts_with("temp") && name.ends_with("_input")
+        })
+        .filter_map(|sensor| read_millidegrees(sensor.path()));
+
+    zones.chain(hwmon).fold(None, |max: Option<f64>, t| Some(max.map_or(t, |m| m.max(t))))
+}
+
+// 다른 플랫폼은 별도 권한 없이 읽을 수 있는 GPU 온도 API가 없어 실행 시간 변동만 사용
+#[cfg(not(target_os = "linux"))]
+fn read_gpu_temperature() -> Option<f64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clear_resets_baseline_with_samples() {
+        let mut history = DurationHistory::new();
+        for _ in 0..MIN_SAMPLES {
+            history.push(1.0);
+        }
+        assert_eq!(history.baseline, Some(1.0));
+
+        // 정지 후 새 샘플만으로 기준값을 다시 잡으므로 예전 기준과 비교해 계속 느리다고 보지 않음
+        history.clear();
+        assert_eq!(history.baseline, None);
+        for i in 0..MIN_SAMPLES {
+            history.push(if i % 2 == 0 { 3.0 } else { 5.0 });
+        }
+        assert_eq!(history.baseline, Some(3.0));
+        assert!(!history.is_slowing_down());
+    }
+}
@@ -91,6 +91,7 @@ impl WorkloadSize {
         }
     }
     
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Self {
         match s.to_lowercase().as_str() {
             "small" => WorkloadSize::Small,
@@ -157,7 +158,7 @@ pub struct TypingStatistics {
 }
 
 /// GPU 기능 구조체
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GpuFeatures {
     pub compute_shader: bool,
     pub float32_filterable: bool,
@@ -168,7 +169,7 @@ pub struct GpuFeatures {
 }
 
 /// GPU 성능 한계 구조체
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GpuLimits {
     pub max_compute_workgroups_per_dimension: u32,
     pub max_buffer_size: u64,
@@ -251,28 +252,3 @@ impl Serialize for GpuDeviceInfo {
         s.end()
     }
 }
-
-impl Default for GpuFeatures {
-    fn default() -> Self {
-        Self {
-            compute_shader: false,
-            float32_filterable: false,
-            texture_compression: false,
-            multiview: false,
-            tensor_cores: false,
-            ray_tracing: false,
-        }
-    }
-}
-
-impl Default for GpuLimits {
-    fn default() -> Self {
-        Self {
-            max_compute_workgroups_per_dimension: 0,
-            max_buffer_size: 0,
-            max_texture_dimension_2d: 0,
-            max_workgroup_size: None,
-            max_bindings: None,
-        }
-    }
-}
@@ -6,10 +6,9 @@ pub mod gpu;
 pub mod worker;
 pub mod utils;
 
-use std::sync::atomic::AtomicBool;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-// 초기화 상태 추적 - 미사용 경고 제거를 위한 속성 추가
-#[allow(dead_code)]
+// 초기화 상태 추적
 static INITIALIZED: AtomicBool = AtomicBool::new(false);
 
 // u64를 직접 반환하지 않고 String으로 변환하여 반환
@@ -30,18 +29,28 @@ pub fn get_native_module_version() -> String {
 }
 
 /// 네이티브 모듈 초기화
+///
+/// 메모리 풀, 워커 풀, GPU(하드웨어 가속 설정이 켜진 경우)를 초기화하고
+/// 하위 시스템별 상태(ok, disabled, unavailable, failed)와 오류, 사용 가능한 기능을 담은
+/// 보고서를 JSON으로 반환합니다. 같은 보고서는 `get_initialization_report`로 다시 조회할 수 있습니다.
+/// 첫 작업의 초기화 지연을 없애려면 이어서 `warm_up`을 호출합니다.
 #[napi]
-pub fn initialize_native_modules() -> bool {
-    // 기본적인 초기화 작업
-    // 필요한 경우 메모리, GPU, 워커 풀 초기화
-    true
+pub fn initialize_native_modules() -> String {
+    let report = utils::diagnostics::initialize_all();
+    INITIALIZED.store(true, Ordering::SeqCst);
+    report.to_json().to_string()
 }
 
 /// 네이티브 모듈 정리
+///
+/// 워커 풀과 백그라운드 모니터를 멈추고 GPU 컨텍스트, 셰이더 캐시, 최적화 기록 등 전역 상태를 비웁니다.
+/// 정리 후 `initialize_native_modules`로 다시 초기화할 수 있습니다 (Electron 개발 중 다시 로드).
+/// 모든 단계가 성공하면 `true`를 반환합니다.
 #[napi]
 pub fn cleanup_native_modules() -> bool {
-    // 정리 작업 수행
-    true
+    let errors = utils::diagnostics::shutdown_all();
+    INITIALIZED.store(false, Ordering::SeqCst);
+    errors.is_empty()
 }
 
 /// 네이티브 모듈 정보 반환
@@ -80,10 +80,42 @@ fn get_windows_memory_info() -> Result<MemoryInfo, Error> {
 }
 
 /// Linux 메모리 정보 가져오기
+/// 
+/// /proc/self/statm과 /proc/meminfo를 기반으로 계산하며,
+/// 사용률은 시스템 전체 메모리 대비 RSS 비율입니다.
 #[cfg(target_os = "linux")]
 fn get_linux_memory_info() -> Result<MemoryInfo, Error> {
-    // 실제 구현 코드 추가 필요
-    get_fallback_memory_info()
+    let statm = match procfs::process::Process::myself().and_then(|process| process.statm()) {
+        Ok(statm) => statm,
+        Err(_) => return get_fallback_memory_info(),
+    };
+    let mem_total = match procfs::Meminfo::new() {
+        Ok(meminfo) => meminfo.mem_total,
+        Err(_) => return get_fallback_memory_info(),
+    };
+    
+    let page_size = procfs::page_size();
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+    
+    let heap_used = statm.data * page_size;
+    let rss = statm.resident * page_size;
+    let shared = statm.shared * page_size;
+    
+    Ok(MemoryInfo {
+        heap_used,
+        heap_total: mem_total,
+        heap_limit: Some(mem_total),
+        rss: Some(rss),
+        external: Some(shared),
+        array_buffers: Some(0), // 기본값 설정
+        heap_used_mb: heap_used as f64 / (1024.0 * 1024.0),
+        rss_mb: Some(rss as f64 / (1024.0 * 1024.0)),
+        percent_used: if mem_total > 0 { (rss as f64 / mem_total as f64) * 100.0 } else { 0.0 },
+        timestamp: now,
+    })
 }
 
 /// macOS 메모리 정보 가져오기
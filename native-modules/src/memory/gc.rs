@@ -1,10 +1,14 @@
 use napi::Error;
+use std::collections::VecDeque;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::time::{SystemTime, UNIX_EPOCH};
-use log::{debug, warn, error}; // info 제거함
-use serde_json::json;
-use crate::memory::analyzer;
+use std::time::Duration;
+use log::{debug, info, warn, error};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use crate::memory::{analyzer, pool};
 use crate::memory::settings;
+use crate::utils::{clock, supervisor};
 
 // 메트릭 수집용 카운터
 static GC_INVOCATIONS: AtomicU64 = AtomicU64::new(0);
@@ -14,22 +18,81 @@ static TOTAL_MEMORY_FREED: AtomicU64 = AtomicU64::new(0);
 // 최소 GC 간격 (ms)
 const MIN_GC_INTERVAL: u64 = 5000;
 
+// 예약된 GC의 유휴 상태 확인 주기 (ms)
+const IDLE_POLL_INTERVAL_MS: u64 = 100;
+
+// 유휴 상태가 이 시간 이상 유지되어야 예약된 GC 실행 (작업 사이의 짧은 공백 제외, ms)
+const IDLE_SETTLE_MS: u64 = 500;
+
+// 유휴 상태가 오지 않아도 예약된 GC를 실행하는 기본 최대 대기 시간 (ms)
+const DEFAULT_MAX_GC_DELAY_MS: u64 = 60_000;
+
+/// 예약 GC 모드
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GcMode {
+    /// 가벼운 GC
+    Basic,
+    /// 적극적인 GC와 유휴 풀 객체 회수
+    Aggressive,
+    /// 메모리 풀 압축
+    Compact,
+}
+
+impl GcMode {
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        match value.to_lowercase().as_str() {
+            "basic" | "normal" => Ok(GcMode::Basic),
+            "aggressive" => Ok(GcMode::Aggressive),
+            "compact" | "compaction" => Ok(GcMode::Compact),
+            _ => Err(Error::from_reason(format!("알 수 없는 GC 모드: {}", value))),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GcMode::Basic => "basic",
+            GcMode::Aggressive => "aggressive",
+            GcMode::Compact => "compact",
+        }
+    }
+}
+
+// 예약된 GC 작업
+#[derive(Debug, Clone)]
+struct ScheduledGc {
+    id: String,
+    mode: GcMode,
+    requested_at: u64,
+    deadline: u64,
+}
+
+// GC 예약 상태
+#[derive(Default)]
+struct GcScheduler {
+    jobs: VecDeque<ScheduledGc>,
+    worker_running: bool,
+    next_id: u64,
+    completed: u64,
+    cancelled: u64,
+    forced_by_deadline: u64,
+    last_run: Option<Value>,
+}
+
+static GC_SCHEDULER: Lazy<Mutex<GcScheduler>> = Lazy::new(|| Mutex::new(GcScheduler::default()));
+
 /// 전체 가비지 컬렉션 강제 실행
 /// 
 /// 이 함수는 가비지 컬렉션을 강제로 실행하고 메모리 해제를 시도합니다.
 pub fn force_garbage_collection() -> Result<String, Error> {
     // 호출 간 최소 간격 확인 (너무 자주 호출되지 않도록)
-    let now = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis() as u64;
+    let now = clock::now_millis();
     
     let last_gc = LAST_GC_TIME.load(Ordering::SeqCst);
     
     // 마지막 GC 이후 최소 간격을 유지 (과도한 GC 방지)
-    if now - last_gc < MIN_GC_INTERVAL {
+    if now.saturating_sub(last_gc) < MIN_GC_INTERVAL {
         debug!("GC 요청 무시: 마지막 GC 이후 충분한 시간이 경과하지 않음 ({}ms < {}ms)", 
-               now - last_gc, MIN_GC_INTERVAL);
+               now.saturating_sub(last_gc), MIN_GC_INTERVAL);
         
         // 최소 간격을 유지하지 못한 경우에도 실패로 처리하지 않고, 성공으로 처리하되 freed_memory를 0으로 설정
         let result = json!({
@@ -80,11 +143,7 @@ pub fn force_garbage_collection() -> Result<String, Error> {
     };
     
     // 해제된 메모리 계산 
-    let freed_memory = if memory_before.heap_used > memory_after.heap_used {
-        memory_before.heap_used - memory_after.heap_used 
-    } else {
-        0
-    };
+    let freed_memory = memory_before.heap_used.saturating_sub(memory_after.heap_used);
     
     // 총 해제된 메모리 누적
     TOTAL_MEMORY_FREED.fetch_add(freed_memory, Ordering::SeqCst);
@@ -209,10 +268,7 @@ pub fn get_gc_statistics() -> Result<String, Error> {
         "last_gc_time": last_gc,
         "total_memory_freed": total_freed,
         "total_memory_freed_mb": (total_freed as f64) / (1024.0 * 1024.0),
-        "timestamp": SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis()
+        "timestamp": clock::now_millis()
     });
     
     Ok(result.to_string())
@@ -220,17 +276,213 @@ pub fn get_gc_statistics() -> Result<String, Error> {
 
 /// 가비지 컬렉션 간 최소 간격 확인
 pub fn can_perform_gc() -> bool {
-    let now = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis() as u64;
+    let now = clock::now_millis();
     
     let last_gc = LAST_GC_TIME.load(Ordering::SeqCst);
     
-    now - last_gc >= MIN_GC_INTERVAL
+    now.saturating_sub(last_gc) >= MIN_GC_INTERVAL
 }
 
 /// 마지막 GC 시간 가져오기
 pub fn get_last_gc_time() -> u64 {
     LAST_GC_TIME.load(Ordering::SeqCst)
 }
+
+/// 누적 GC 횟수와 해제된 메모리 (바이트)
+pub fn get_gc_totals() -> (u64, u64) {
+    (GC_INVOCATIONS.load(Ordering::SeqCst), TOTAL_MEMORY_FREED.load(Ordering::SeqCst))
+}
+
+// 워커 풀과 GPU 작업이 모두 쉬고 있는지 확인
+fn is_system_idle() -> bool {
+    crate::worker::pool::is_worker_pool_idle() && crate::gpu::active_gpu_task_count() == 0
+}
+
+/// 유휴 시간 GC 예약
+///
+/// 호출자를 막지 않고 바로 반환하며, 워커 풀과 GPU 작업이 모두 쉬고 있을 때 백그라운드에서 실행합니다.
+/// `max_delay_ms`가 지나도 유휴 상태가 오지 않으면 그대로 실행합니다.
+/// 같은 모드의 GC가 이미 대기 중이면 새로 예약하지 않고 기존 예약 ID를 반환합니다.
+pub fn schedule_gc(mode: GcMode, max_delay_ms: Option<u64>) -> Result<String, Error> {
+    let now = clock::now_millis();
+    let mut scheduler = GC_SCHEDULER.lock();
+
+    if let Some(existing) = scheduler.jobs.iter().find(|job| job.mode == mode) {
+        debug!("같은 모드의 GC가 이미 예약됨: {}", existing.id);
+        return Ok(existing.id.clone());
+    }
+
+    scheduler.next_id += 1;
+    let id = format!("gc-{}", scheduler.next_id);
+    scheduler.jobs.push_back(ScheduledGc {
+        id: id.clone(),
+        mode,
+        requested_at: now,
+        deadline: now.saturating_add(max_delay_ms.unwrap_or(DEFAULT_MAX_GC_DELAY_MS)),
+    });
+
+    if !scheduler.worker_running {
+        std::thread::Builder::new()
+            .name("typing-stats-gc-scheduler".to_string())
+            .spawn(|| supervisor::run_supervised("gc-scheduler", scheduler_loop, || false))
+            .map_err(|e| Error::from_reason(format!("GC 예약 스레드 생성 실패: {}", e)))?;
+        scheduler.worker_running = true;
+    }
+
+    debug!("GC 예약됨: {} ({})", id, mode.as_str());
+    Ok(id)
+}
+
+/// 예약된 GC 취소 (아직 실행되지 않은 경우에만 성공)
+pub fn cancel_scheduled_gc(id: &str) -> bool {
+    let mut scheduler = GC_SCHEDULER.lock();
+    match scheduler.jobs.iter().position(|job| job.id == id) {
+        Some(position) => {
+            scheduler.jobs.remove(position);
+            scheduler.cancelled += 1;
+            debug!("예약된 GC 취소: {}", id);
+            true
+        },
+        None => false,
+    }
+}
+
+/// 대기 중인 GC 예약을 모두 취소하고 통계 초기화
+///
+/// 예약 스레드는 대기열이 비어 있는 것을 확인하면 스스로 종료합니다.
+pub fn reset() {
+    {
+        let mut scheduler = GC_SCHEDULER.lock();
+        let worker_running = scheduler.worker_running;
+        *scheduler = GcScheduler { worker_running, ..GcScheduler::default() };
+    }
+    GC_INVOCATIONS.store(0, Ordering::SeqCst);
+    LAST_GC_TIME.store(0, Ordering::SeqCst);
+    TOTAL_MEMORY_FREED.store(0, Ordering::SeqCst);
+}
+
+/// GC 예약 상태 가져오기
+pub fn get_scheduled_gc_status() -> Value {
+    let now = clock::now_millis();
+    let scheduler = GC_SCHEDULER.lock();
+
+    let pending: Vec<Value> = scheduler.jobs.iter()
+        .map(|job| json!({
+            "id": job.id,
+            "mode": job.mode.as_str(),
+            "requested_at": job.requested_at,
+            "waiting_ms": now.saturating_sub(job.requested_at),
+            "deadline": job.deadline
+        }))
+        .collect();
+
+    json!({
+        "pending": pending,
+        "system_idle": is_system_idle(),
+        "completed": scheduler.completed,
+        "cancelled": scheduler.cancelled,
+        "forced_by_deadline": scheduler.forced_by_deadline,
+        "last_run": scheduler.last_run,
+        "timestamp": now
+    })
+}
+
+// 예약된 GC를 유휴 상태에서 하나씩 실행 (대기열이 비면 종료)
+fn scheduler_loop() {
+    let mut idle_since: Option<u64> = None;
+
+    loop {
+        let job = {
+            let mut scheduler = GC_SCHEDULER.lock();
+            if scheduler.jobs.is_empty() {
+                scheduler.worker_running = false;
+                return;
+            }
+
+            let now = clock::now_millis();
+            idle_since = if is_system_idle() { idle_since.or(Some(now)) } else { None };
+            let settled = idle_since.is_some_and(|since| now.saturating_sub(since) >= IDLE_SETTLE_MS);
+
+            let position = if settled {
+                Some(0)
+            } else {
+                scheduler.jobs.iter().position(|job| now >= job.deadline)
+            };
+
+            position.and_then(|position| scheduler.jobs.remove(position))
+                .map(|job| (job, !settled))
+        };
+
+        match job {
+            Some((job, forced)) => run_scheduled_gc(job, forced),
+            None => std::thread::sleep(Duration::from_millis(IDLE_POLL_INTERVAL_MS)),
+        }
+    }
+}
+
+// 예약된 GC 실행 및 결과 기록
+fn run_scheduled_gc(job: ScheduledGc, forced: bool) {
+    let started = std::time::Instant::now();
+    let memory_before = analyzer::get_process_memory_info().ok();
+
+    let result = match job.mode {
+        GcMode::Basic => perform_basic_gc(),
+        GcMode::Aggressive => perform_aggressive_gc().and_then(|_| pool::reclaim_idle_objects()),
+        GcMode::Compact => pool::compact_memory_pools().and_then(|_| pool::reclaim_idle_objects()),
+    };
+
+    let now = clock::now_millis();
+    GC_INVOCATIONS.fetch_add(1, Ordering::SeqCst);
+    LAST_GC_TIME.store(now, Ordering::SeqCst);
+
+    let freed_memory = match (memory_before, analyzer::get_process_memory_info().ok()) {
+        (Some(before), Some(after)) => before.heap_used.saturating_sub(after.heap_used),
+        _ => 0,
+    };
+    TOTAL_MEMORY_FREED.fetch_add(freed_memory, Ordering::SeqCst);
+
+    if let Err(e) = &result {
+        error!("예약된 GC 실패 ({}): {}", job.id, e);
+    } else {
+        info!("예약된 GC 완료: {} ({}), 대기 {}ms", job.id, job.mode.as_str(), now.saturating_sub(job.requested_at));
+    }
+
+    let mut scheduler = GC_SCHEDULER.lock();
+    scheduler.completed += 1;
+    if forced {
+        scheduler.forced_by_deadline += 1;
+    }
+    scheduler.last_run = Some(json!({
+        "id": job.id,
+        "mode": job.mode.as_str(),
+        "success": result.is_ok(),
+        "error": result.err().map(|e| e.to_string()),
+        "forced_by_deadline": forced,
+        "waited_ms": now.saturating_sub(job.requested_at),
+        "duration_ms": started.elapsed().as_millis() as u64,
+        "freed_memory": freed_memory,
+        "timestamp": now
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gc_is_throttled_for_min_interval() {
+        clock::with_mock_clock(1_000_000, |mock| {
+            // 방금 GC를 실행한 상태
+            LAST_GC_TIME.store(clock::now_millis(), Ordering::SeqCst);
+            assert!(!can_perform_gc());
+
+            mock.advance(MIN_GC_INTERVAL - 1);
+            assert!(!can_perform_gc());
+
+            mock.advance(1);
+            assert!(can_perform_gc());
+
+            LAST_GC_TIME.store(0, Ordering::SeqCst);
+        });
+    }
+}
@@ -5,20 +5,54 @@ pub mod pool;
 pub mod types;
 pub mod settings;
 pub mod info;
+pub mod monitor;
+use napi::{Env, JsBuffer, JsFunction};
+use napi::threadsafe_function::ThreadSafeCallContext;
 use napi_derive::napi;
 use serde_json::json;
 use log::{info, error}; 
 use once_cell::sync::Lazy;
+use std::collections::VecDeque;
 use std::sync::RwLock;
-use std::sync::atomic::AtomicU64;
+use std::sync::atomic::{AtomicU64, Ordering};
 
-#[allow(dead_code)]
-static OPTIMIZATION_HISTORY: Lazy<RwLock<Vec<optimizer::OptimizationResult>>> = 
-    Lazy::new(|| RwLock::new(Vec::with_capacity(10)));
+// 보관할 최적화 기록 수 (가장 오래된 기록부터 삭제)
+const OPTIMIZATION_HISTORY_CAPACITY: usize = 100;
+
+static OPTIMIZATION_HISTORY: Lazy<RwLock<VecDeque<optimizer::OptimizationResult>>> = 
+    Lazy::new(|| RwLock::new(VecDeque::with_capacity(OPTIMIZATION_HISTORY_CAPACITY)));
 
-#[allow(dead_code)]
 static LAST_MEMORY_OPTIMIZATION: AtomicU64 = AtomicU64::new(0);
 
+// 최적화 결과를 기록에 추가
+fn record_optimization(result: optimizer::OptimizationResult) {
+    LAST_MEMORY_OPTIMIZATION.store(result.timestamp, Ordering::SeqCst);
+
+    if let Ok(mut history) = OPTIMIZATION_HISTORY.write() {
+        if history.len() >= OPTIMIZATION_HISTORY_CAPACITY {
+            history.pop_front();
+        }
+        history.push_back(result);
+    }
+}
+
+/// 메모리 하위 시스템 상태 전체 초기화
+///
+/// 메모리 모니터와 GC 예약을 멈추고 최적화 기록, 메모리 풀, 설정을 처음 상태로 되돌립니다.
+pub fn reset_memory_state() -> napi::Result<()> {
+    monitor::reset()?;
+    gc::reset();
+    optimizer::reset_stats();
+    pool::clear_memory_pools();
+    settings::reset_memory_settings();
+
+    if let Ok(mut history) = OPTIMIZATION_HISTORY.write() {
+        history.clear();
+    }
+    LAST_MEMORY_OPTIMIZATION.store(0, Ordering::SeqCst);
+    Ok(())
+}
+
 /// 메모리 정보 가져오기
 #[napi]
 pub fn get_memory_info() -> napi::Result<String> {
@@ -95,6 +129,59 @@ pub fn force_garbage_collection() -> napi::Result<String> {
     }
 }
 
+/// 유휴 시간 GC 예약
+///
+/// 워커 풀과 GPU 작업이 쉬고 있을 때 실행되도록 예약하고 예약 ID를 바로 반환합니다.
+/// mode: basic, aggressive, compact
+#[napi]
+pub fn schedule_gc(mode: String, max_delay_ms: Option<u32>) -> napi::Result<String> {
+    let mode = gc::GcMode::parse(&mode)?;
+    gc::schedule_gc(mode, max_delay_ms.map(u64::from))
+}
+
+/// 메모리 설정 초기화 (이미 초기화되었으면 무시)
+#[napi]
+pub fn initialize_memory_settings(settings_json: String) -> napi::Result<bool> {
+    settings::initialize_memory_settings(&settings_json)
+}
+
+/// 메모리 설정 업데이트
+///
+/// 지정한 항목만 바꾸며(예: `{"power_saving_policy": "off"}`), 변경 후 전체 설정을 JSON으로 반환합니다.
+#[napi]
+pub fn update_memory_settings(settings_json: String) -> napi::Result<String> {
+    let patch: serde_json::Value = serde_json::from_str(&settings_json)
+        .map_err(|e| napi::Error::from_reason(format!("메모리 설정 파싱 실패: {}", e)))?;
+    let mut merged = serde_json::to_value(settings::get_memory_settings())
+        .map_err(|e| napi::Error::from_reason(format!("설정 직렬화 실패: {}", e)))?;
+
+    match (merged.as_object_mut(), patch.as_object()) {
+        (Some(merged), Some(patch)) => merged.extend(patch.clone()),
+        _ => return Err(napi::Error::from_reason("Expected a JSON object")),
+    }
+
+    settings::update_memory_settings(&merged.to_string())?;
+    settings::get_settings_json()
+}
+
+/// 현재 메모리 설정 가져오기
+#[napi]
+pub fn get_memory_settings() -> napi::Result<String> {
+    settings::get_settings_json()
+}
+
+/// 예약된 GC 취소
+#[napi]
+pub fn cancel_scheduled_gc(id: String) -> napi::Result<bool> {
+    Ok(gc::cancel_scheduled_gc(&id))
+}
+
+/// GC 예약 상태 가져오기
+#[napi]
+pub fn get_scheduled_gc_status() -> napi::Result<String> {
+    Ok(gc::get_scheduled_gc_status().to_string())
+}
+
 /// 메모리 최적화 수행
 #[napi]
 pub fn optimize_memory(level_str: String, emergency: bool) -> napi::Result<String> {
@@ -115,7 +202,7 @@ pub fn optimize_memory(level_str: String, emergency: bool) -> napi::Result<Strin
                     1 => optimizer::OptimizationLevel::Low,
                     2 => optimizer::OptimizationLevel::Medium,
                     3 => optimizer::OptimizationLevel::High,
-                    4 | _ => optimizer::OptimizationLevel::Critical,
+                    _ => optimizer::OptimizationLevel::Critical,
                 }
             } else {
                 // 기본값: 중간 수준
@@ -126,6 +213,7 @@ pub fn optimize_memory(level_str: String, emergency: bool) -> napi::Result<Strin
     
     let result = optimizer::optimize_memory(level, emergency);
     let json = optimizer::optimization_result_to_json(&result);
+    record_optimization(result);
     
     Ok(json.to_string())
 }
@@ -150,7 +238,7 @@ pub async fn optimize_memory_async(level_str: String, emergency: bool) -> napi::
                     1 => optimizer::OptimizationLevel::Low,
                     2 => optimizer::OptimizationLevel::Medium,
                     3 => optimizer::OptimizationLevel::High,
-                    4 | _ => optimizer::OptimizationLevel::Critical,
+                    _ => optimizer::OptimizationLevel::Critical,
                 }
             } else {
                 // 기본값: 중간 수준
@@ -162,6 +250,7 @@ pub async fn optimize_memory_async(level_str: String, emergency: bool) -> napi::
     match optimizer::perform_memory_optimization(level, emergency).await {
         Ok(result) => {
             let json = optimizer::optimization_result_to_json(&result);
+            record_optimization(result);
             Ok(json.to_string())
         },
         Err(e) => {
@@ -197,3 +286,91 @@ pub fn get_memory_optimization_stats() -> napi::Result<String> {
     
     Ok(json.to_string())
 }
+
+/// 메모리 최적화 기록 가져오기
+///
+/// 최근 `limit`개(기본값: 보관 중인 전체)의 최적화 결과를 오래된 순으로 반환합니다.
+/// 각 항목에는 최적화 전후 메모리 스냅샷이 포함됩니다.
+#[napi]
+pub fn get_optimization_history(limit: Option<u32>) -> napi::Result<String> {
+    let history = OPTIMIZATION_HISTORY.read()
+        .map_err(|e| napi::Error::from_reason(format!("최적화 기록 잠금 실패: {}", e)))?;
+
+    let limit = limit.map(|l| l as usize).unwrap_or(history.len()).min(history.len());
+    let entries: Vec<serde_json::Value> = history.iter()
+        .skip(history.len() - limit)
+        .map(optimizer::optimization_result_to_json)
+        .collect();
+
+    let json = json!({
+        "history": entries,
+        "count": entries.len(),
+        "capacity": OPTIMIZATION_HISTORY_CAPACITY,
+        "last_optimization": LAST_MEMORY_OPTIMIZATION.load(Ordering::SeqCst),
+        "timestamp": std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64
+    });
+
+    Ok(json.to_string())
+}
+
+/// 메모리 모니터 시작
+///
+/// 메모리 사용량이 임계값(MB)을 넘거나 다시 내려올 때마다 이벤트 JSON 문자열로 콜백을 호출합니다.
+#[napi]
+pub fn start_memory_monitor(
+    env: Env,
+    interval_ms: u32,
+    thresholds_mb: Vec<f64>,
+    callback: JsFunction,
+) -> napi::Result<bool> {
+    let mut tsfn: monitor::MemoryEventCallback = callback.create_threadsafe_function(
+        0,
+        |ctx: ThreadSafeCallContext<String>| Ok(vec![ctx.value]),
+    )?;
+
+    // 모니터가 실행 중이어도 Node 프로세스 종료를 막지 않도록 설정
+    tsfn.unref(&env)?;
+
+    monitor::start_memory_monitor(interval_ms as u64, thresholds_mb, tsfn)
+}
+
+/// 메모리 모니터 중지
+#[napi]
+pub fn stop_memory_monitor() -> napi::Result<bool> {
+    monitor::stop_memory_monitor()
+}
+
+/// 메모리 모니터 상태 가져오기
+#[napi]
+pub fn get_memory_monitor_status() -> napi::Result<String> {
+    Ok(monitor::get_memory_monitor_status().to_string())
+}
+
+/// 메모리 풀 버퍼를 JavaScript Buffer로 빌려주기
+///
+/// 복사 없이 풀 버퍼를 외부 Buffer로 노출하며, Buffer가 GC되면 자동으로 풀에 반환됩니다.
+/// 외부 Buffer를 허용하지 않는 환경(Electron)에서는 내용을 복사하고 버퍼를 즉시 반환합니다.
+#[napi]
+pub fn lend_pool_buffer(env: Env, size: u32) -> napi::Result<JsBuffer> {
+    let mut buffer = pool::lend_buffer(size as usize)?;
+    let data = buffer.as_mut_ptr();
+    let length = buffer.len();
+
+    // 힙 데이터는 Vec을 종료자 힌트로 옮겨도 그대로이므로 Buffer가 살아 있는 동안 유효함
+    let js_buffer = unsafe {
+        env.create_buffer_with_borrowed_data(data, length, buffer, |buffer, _env| {
+            pool::return_lent_buffer(buffer);
+        })?
+    };
+
+    Ok(js_buffer.into_raw())
+}
+
+/// JavaScript로 빌려준 풀 버퍼 통계 가져오기
+#[napi]
+pub fn get_pool_lending_stats() -> napi::Result<String> {
+    Ok(pool::get_lending_stats().to_string())
+}
@@ -0,0 +1,199 @@
+use napi::Error;
+use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::Duration;
+use parking_lot::{Mutex, Condvar};
+use once_cell::sync::Lazy;
+use serde_json::json;
+use log::{debug, info, warn};
+use crate::memory::analyzer;
+use crate::utils::{clock, supervisor};
+
+/// 메모리 임계값 이벤트 콜백 (JSON 문자열 전달)
+pub type MemoryEventCallback = ThreadsafeFunction<String, ErrorStrategy::Fatal>;
+
+// 최소 샘플링 간격 (ms)
+const MIN_SAMPLE_INTERVAL: u64 = 100;
+
+// 임계값 아래로 내려갈 때 적용하는 여유 비율 (경계값 부근에서 이벤트가 반복되지 않도록)
+const HYSTERESIS_RATIO: f64 = 0.05;
+
+// 실행 중인 모니터 정보
+struct MonitorHandle {
+    thread: thread::JoinHandle<()>,
+    stop: Arc<(Mutex<bool>, Condvar)>,
+    interval_ms: u64,
+    thresholds_mb: Vec<f64>,
+}
+
+static MONITOR: Lazy<Mutex<Option<MonitorHandle>>> = Lazy::new(|| Mutex::new(None));
+
+// 모니터 통계
+static SAMPLE_COUNT: AtomicU64 = AtomicU64::new(0);
+static EVENT_COUNT: AtomicU64 = AtomicU64::new(0);
+static LAST_USAGE_KB: AtomicU64 = AtomicU64::new(0);
+
+/// 메모리 모니터 시작
+///
+/// `interval_ms`마다 프로세스 메모리를 샘플링하고, 사용량(RSS, 없으면 힙 사용량)이
+/// `thresholds_mb` 중 하나를 넘거나 다시 내려올 때 콜백을 호출합니다.
+/// 이미 실행 중이면 기존 모니터를 중지하고 새 설정으로 다시 시작합니다.
+pub fn start_memory_monitor(
+    interval_ms: u64,
+    thresholds_mb: Vec<f64>,
+    callback: MemoryEventCallback,
+) -> Result<bool, Error> {
+    let mut thresholds: Vec<f64> = thresholds_mb.into_iter()
+        .filter(|t| t.is_finite() && *t > 0.0)
+        .collect();
+    if thresholds.is_empty() {
+        return Err(Error::from_reason("At least one positive memory threshold is required"));
+    }
+    thresholds.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    thresholds.dedup();
+
+    let interval_ms = interval_ms.max(MIN_SAMPLE_INTERVAL);
+
+    stop_memory_monitor()?;
+
+    let stop = Arc::new((Mutex::new(false), Condvar::new()));
+    let thread_stop = stop.clone();
+    let thread_thresholds = thresholds.clone();
+
+    let thread = thread::Builder::new()
+        .name("memory-monitor".to_string())
+        .spawn(move || {
+            let stop_check = thread_stop.clone();
+            supervisor::run_supervised(
+                "memory-monitor",
+                || monitor_loop(interval_ms, thread_thresholds.clone(), callback.clone(), thread_stop.clone()),
+                || *stop_check.0.lock(),
+            );
+        })
+        .map_err(|e| Error::from_reason(format!("Failed to spawn memory monitor thread: {}", e)))?;
+
+    *MONITOR.lock() = Some(MonitorHandle {
+        thread,
+        stop,
+        interval_ms,
+        thresholds_mb: thresholds,
+    });
+
+    info!("메모리 모니터 시작: {}ms 간격", interval_ms);
+    Ok(true)
+}
+
+/// 메모리 모니터 중지
+///
+/// 실행 중인 모니터가 없었으면 `false`를 반환합니다.
+pub fn stop_memory_monitor() -> Result<bool, Error> {
+    let handle = match MONITOR.lock().take() {
+        Some(handle) => handle,
+        None => return Ok(false),
+    };
+
+    {
+        let (lock, cvar) = &*handle.stop;
+        *lock.lock() = true;
+        cvar.notify_all();
+    }
+
+    if handle.thread.join().is_err() {
+        warn!("메모리 모니터 스레드가 비정상 종료되었습니다");
+    }
+
+    info!("메모리 모니터 중지");
+    Ok(true)
+}
+
+/// 모니터를 중지하고 통계 초기화
+pub fn reset() -> Result<(), Error> {
+    stop_memory_monitor()?;
+    SAMPLE_COUNT.store(0, Ordering::Relaxed);
+    EVENT_COUNT.store(0, Ordering::Relaxed);
+    LAST_USAGE_KB.store(0, Ordering::Relaxed);
+    Ok(())
+}
+
+/// 메모리 모니터 상태 가져오기
+pub fn get_memory_monitor_status() -> serde_json::Value {
+    let monitor = MONITOR.lock();
+
+    json!({
+        "running": monitor.is_some(),
+        "interval_ms": monitor.as_ref().map(|m| m.interval_ms),
+        "thresholds_mb": monitor.as_ref().map(|m| m.thresholds_mb.clone()).unwrap_or_default(),
+        "samples": SAMPLE_COUNT.load(Ordering::SeqCst),
+        "events": EVENT_COUNT.load(Ordering::SeqCst),
+        "last_usage_mb": LAST_USAGE_KB.load(Ordering::SeqCst) as f64 / 1024.0,
+        "timestamp": clock::now_millis()
+    })
+}
+
+// 모니터 스레드 메인 루프
+fn monitor_loop(
+    interval_ms: u64,
+    thresholds: Vec<f64>,
+    callback: MemoryEventCallback,
+    stop: Arc<(Mutex<bool>, Condvar)>,
+) {
+    let mut level = 0usize;
+
+    loop {
+        match analyzer::get_process_memory_info() {
+            Ok(info) => {
+                SAMPLE_COUNT.fetch_add(1, Ordering::SeqCst);
+
+                let usage_mb = info.rss_mb.unwrap_or(info.heap_used_mb);
+                LAST_USAGE_KB.store((usage_mb * 1024.0) as u64, Ordering::SeqCst);
+
+                let crossed = thresholds.iter().filter(|t| usage_mb >= **t).count();
+
+                // 내려가는 경우에는 여유 비율 이상 떨어졌을 때만 단계 변경
+                let new_level = if crossed < level
+                    && usage_mb >= thresholds[level - 1] * (1.0 - HYSTERESIS_RATIO) {
+                    level
+                } else {
+                    crossed
+                };
+
+                if new_level != level {
+                    let (direction, threshold) = if new_level > level {
+                        ("up", thresholds[new_level - 1])
+                    } else {
+                        ("down", thresholds[new_level])
+                    };
+
+                    debug!("메모리 임계값 {} 통과: {:.1}MB ({:.1}MB)", direction, threshold, usage_mb);
+
+                    let event = json!({
+                        "type": "memory_threshold",
+                        "direction": direction,
+                        "threshold_mb": threshold,
+                        "usage_mb": usage_mb,
+                        "level": new_level,
+                        "percent_used": info.percent_used,
+                        "timestamp": info.timestamp
+                    });
+
+                    callback.call(event.to_string(), ThreadsafeFunctionCallMode::NonBlocking);
+                    EVENT_COUNT.fetch_add(1, Ordering::SeqCst);
+                    level = new_level;
+                }
+            },
+            Err(e) => warn!("메모리 모니터 샘플링 실패: {}", e),
+        }
+
+        // 다음 샘플까지 대기 (중지 요청 시 즉시 깨어남)
+        let (lock, cvar) = &*stop;
+        let mut stopped = lock.lock();
+        if !*stopped {
+            cvar.wait_for(&mut stopped, Duration::from_millis(interval_ms));
+        }
+        if *stopped {
+            break;
+        }
+    }
+}
@@ -12,6 +12,7 @@ use crate::memory::pool;
 use crate::memory::settings;
 // GPU 모듈 올바르게 import - self 제거
 use crate::gpu::context;
+use crate::utils::power;
 // memory_info_to_json 함수 import
 use crate::memory::info::memory_info_to_json;
 
@@ -80,8 +81,10 @@ impl Default for OptimizationResult {
 // Optimize memory with specified level
 pub fn optimize_memory(level: OptimizationLevel, emergency: bool) -> OptimizationResult {
     let start_time = Instant::now();
-    let mut result = OptimizationResult::default();
-    result.optimization_level = level;
+    let mut result = OptimizationResult {
+        optimization_level: level,
+        ..OptimizationResult::default()
+    };
     
     // Get memory info before optimization
     match analyzer::get_process_memory_info() {
@@ -220,6 +223,18 @@ static LAST_OPTIMIZATION_TIME: AtomicU64 = AtomicU64::new(0);
 static OPTIMIZATION_COUNT: AtomicU64 = AtomicU64::new(0);
 static TOTAL_FREED_MEMORY: AtomicU64 = AtomicU64::new(0);
 
+/// 최적화 통계 초기화
+pub fn reset_stats() {
+    if let Ok(mut state) = OPTIMIZATION_STATE.lock() {
+        state.last_optimization = None;
+        state.optimization_count = 0;
+        state.total_freed_memory = 0;
+    }
+    LAST_OPTIMIZATION_TIME.store(0, Ordering::SeqCst);
+    OPTIMIZATION_COUNT.store(0, Ordering::SeqCst);
+    TOTAL_FREED_MEMORY.store(0, Ordering::SeqCst);
+}
+
 pub fn get_last_optimization_time() -> u64 {
     LAST_OPTIMIZATION_TIME.load(Ordering::SeqCst)
 }
@@ -269,6 +284,13 @@ pub fn adjust_gpu_acceleration_based_on_memory(memory_info: &MemoryInfo) -> Resu
         return Ok(());
     }
 
+    // 전원 절약 중에는 GPU 작업이 CPU로 실행되므로 GPU 리소스만 정리
+    if !power::allows_gpu() {
+        debug!("전원 절약 모드: GPU 가속화 비활성화");
+        optimize_gpu_resources()?;
+        return Ok(());
+    }
+
     // 처리 모드에 따라 GPU 사용 여부 결정
     let processing_mode = settings::get_processing_mode();
     let _mode_specific_setting = match processing_mode.as_str() {
@@ -307,9 +329,7 @@ pub fn adjust_gpu_acceleration_based_on_memory(memory_info: &MemoryInfo) -> Resu
                memory_info.percent_used);
         
         // GPU 리소스 최적화 - bool 결과 무시하고 Error만 전파
-        if let Err(e) = optimize_gpu_resources() {
-            return Err(e);
-        }
+        optimize_gpu_resources()?;
         
         // GPU 가속화 상태를 변경하는 다른 함수 호출
         if context::is_gpu_initialized() {
@@ -457,11 +477,7 @@ pub async fn perform_memory_optimization(
         }
     };
     
-    let freed_memory = if memory_before.heap_used > memory_after.heap_used {
-        memory_before.heap_used - memory_after.heap_used
-    } else {
-        0
-    };
+    let freed_memory = memory_before.heap_used.saturating_sub(memory_after.heap_used);
     
     // u64를 usize로 변환하는 대신, usize를 u64로 변환 (이 방향이 항상 안전함)
     TOTAL_FREED_MEMORY.fetch_add(freed_memory as u64, Ordering::SeqCst);
@@ -583,18 +599,12 @@ pub fn perform_memory_optimization_sync(
         }
     };
     
-    if emergency && memory_after.percent_used < 75.0 {
-        if !is_gpu_acceleration_enabled() {
-            debug!("메모리 회복 후 GPU 가속화 재활성화 시도");
-            // GPU 활성화 구현 필요
-        }
+    if emergency && memory_after.percent_used < 75.0 && !is_gpu_acceleration_enabled() {
+        debug!("메모리 회복 후 GPU 가속화 재활성화 시도");
+        // GPU 활성화 구현 필요
     }
     
-    let freed_memory = if memory_before.heap_used > memory_after.heap_used {
-        memory_before.heap_used - memory_after.heap_used
-    } else {
-        0
-    };
+    let freed_memory = memory_before.heap_used.saturating_sub(memory_after.heap_used);
     
     // u64를 u64로 변환 (이미 u64임)
     TOTAL_FREED_MEMORY.fetch_add(freed_memory, Ordering::SeqCst);
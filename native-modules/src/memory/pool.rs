@@ -3,9 +3,9 @@ use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 use parking_lot::RwLock;
 use once_cell::sync::Lazy;
-use std::time::{SystemTime, UNIX_EPOCH};
 use log::{info, debug, warn};
 use crate::memory::types::{MemoryPoolStats, PoolDetail};
+use crate::utils::clock;
 
 // 메모리 풀 크기 상수 (바이트) - 더 세분화된 버퍼 크기
 const TINY_BUFFER_SIZE: usize = 128;       // 128 바이트
@@ -61,35 +61,30 @@ impl MemoryPool {
         }
     }
     
-    // 버퍼 획득
-    fn acquire_buffer(&mut self) -> Vec<u8> {
-        let _now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as u64;
-        
+    // 버퍼 획득 (재사용 여부 함께 반환)
+    fn acquire_buffer(&mut self) -> (Vec<u8>, bool) {
         // 사용 가능한 아이템이 있으면 재사용
         if let Some(item) = self.available_items.pop() {
             self.reuse_count.fetch_add(1, Ordering::Relaxed);
             self.active_count.fetch_add(1, Ordering::Relaxed);
-            return item.buffer;
+            return (item.buffer, true);
         }
         
         // 새 버퍼 생성
         let buffer = Vec::with_capacity(self.item_size);
         self.total_allocated.fetch_add(self.item_size as u64, Ordering::Relaxed);
         self.active_count.fetch_add(1, Ordering::Relaxed);
-        buffer
+        (buffer, false)
     }
     
     // 버퍼 반환
     fn release_buffer(&mut self, mut buffer: Vec<u8>) {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as u64;
+        let now = clock::now_millis();
         
-        self.active_count.fetch_sub(1, Ordering::Relaxed);
+        // 풀이 초기화된 뒤 이전 버퍼가 돌아와도 음수가 되지 않도록 처리
+        let _ = self.active_count.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |count| {
+            Some(count.saturating_sub(1))
+        });
         
         // 버퍼 크기가 맞지 않으면 무시
         if buffer.capacity() != self.item_size {
@@ -112,10 +107,7 @@ impl MemoryPool {
     
     // 오래된 버퍼 정리
     fn cleanup_old_buffers(&mut self, max_age_ms: u64) -> usize {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as u64;
+        let now = clock::now_millis();
         
         let initial_len = self.available_items.len();
         
@@ -154,6 +146,13 @@ static POOL_ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
 static POOL_REUSES: AtomicU64 = AtomicU64::new(0);
 static LAST_CLEANUP_TIME: AtomicU64 = AtomicU64::new(0);
 
+// JavaScript로 빌려준 버퍼 카운터
+static LENT_BUFFERS: AtomicU64 = AtomicU64::new(0);
+static LENT_REUSES: AtomicU64 = AtomicU64::new(0);
+static LENT_RETURNS: AtomicU64 = AtomicU64::new(0);
+static LENT_BYTES: AtomicU64 = AtomicU64::new(0);
+static LENT_OUTSTANDING_BYTES: AtomicU64 = AtomicU64::new(0);
+
 /// 메모리 풀 초기화
 pub fn initialize_memory_pools() -> Result<(), Error> {
     let mut pools = MEMORY_POOLS.write();
@@ -183,10 +182,7 @@ pub fn initialize_memory_pools() -> Result<(), Error> {
     
     // 초기화 시간 저장
     LAST_CLEANUP_TIME.store(
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as u64,
+        clock::now_millis(),
         Ordering::SeqCst
     );
     
@@ -194,8 +190,45 @@ pub fn initialize_memory_pools() -> Result<(), Error> {
     Ok(())
 }
 
+/// 메모리 풀에 재사용 버퍼 미리 채우기
+///
+/// 각 풀에 최대 `per_pool`개(풀 최대 크기 이내)의 버퍼를 미리 할당해 두어
+/// 첫 작업에서 할당 비용이 들지 않도록 합니다. 큰 버퍼는 상주 메모리를 늘리므로
+/// `max_item_size`보다 큰 풀은 건너뜁니다. 새로 할당한 버퍼 수를 반환합니다.
+pub fn prime_memory_pools(per_pool: usize, max_item_size: usize) -> Result<usize, Error> {
+    if MEMORY_POOLS.read().is_empty() {
+        initialize_memory_pools()?;
+    }
+    
+    let now = clock::now_millis();
+    let mut primed = 0;
+    
+    for pool in MEMORY_POOLS.read().values() {
+        let mut pool = pool.write();
+        if pool.item_size > max_item_size {
+            continue;
+        }
+        
+        let target = per_pool.min(pool.max_items);
+        while pool.available_items.len() < target {
+            let buffer = Vec::with_capacity(pool.item_size);
+            pool.total_allocated.fetch_add(pool.item_size as u64, Ordering::Relaxed);
+            pool.available_items.push(PoolItem { buffer, last_used: now });
+            primed += 1;
+        }
+    }
+    
+    debug!("메모리 풀 버퍼 {}개 미리 할당됨", primed);
+    Ok(primed)
+}
+
 /// 메모리 풀에서 버퍼 획득
 pub fn acquire_buffer(size: usize) -> Result<Vec<u8>, Error> {
+    acquire_pooled_buffer(size).map(|(buffer, _)| buffer)
+}
+
+// 메모리 풀에서 버퍼 획득 (재사용 여부 함께 반환)
+fn acquire_pooled_buffer(size: usize) -> Result<(Vec<u8>, bool), Error> {
     // 초기화 필요한 경우 초기화
     if MEMORY_POOLS.read().is_empty() {
         initialize_memory_pools()?;
@@ -220,7 +253,11 @@ pub fn acquire_buffer(size: usize) -> Result<Vec<u8>, Error> {
     if let Some(pool) = pools.get(pool_name) {
         let mut pool_guard = pool.write();
         POOL_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
-        return Ok(pool_guard.acquire_buffer());
+        let (buffer, reused) = pool_guard.acquire_buffer();
+        if reused {
+            POOL_REUSES.fetch_add(1, Ordering::Relaxed);
+        }
+        return Ok((buffer, reused));
     }
     
     // 풀을 찾을 수 없는 경우 직접 생성
@@ -260,6 +297,60 @@ pub fn release_buffer(buffer: Vec<u8>) -> Result<(), Error> {
     Ok(())
 }
 
+/// JavaScript에 빌려줄 버퍼 획득
+///
+/// 풀에서 버퍼를 꺼내 요청한 길이만큼 0으로 채워 반환합니다.
+/// 빌려준 버퍼는 JavaScript 쪽 Buffer가 GC될 때 `return_lent_buffer`로 풀에 돌아옵니다.
+pub fn lend_buffer(size: usize) -> Result<Vec<u8>, Error> {
+    if size == 0 {
+        return Err(Error::from_reason("빌려줄 버퍼 크기는 0보다 커야 합니다".to_string()));
+    }
+    
+    let (mut buffer, reused) = acquire_pooled_buffer(size)?;
+    // 풀 버퍼의 용량이 요청 크기 이상이므로 재할당 없이 길이만 설정됨
+    buffer.resize(size, 0);
+    
+    LENT_BUFFERS.fetch_add(1, Ordering::Relaxed);
+    LENT_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    LENT_OUTSTANDING_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    if reused {
+        LENT_REUSES.fetch_add(1, Ordering::Relaxed);
+    }
+    
+    Ok(buffer)
+}
+
+/// JavaScript에 빌려준 버퍼를 풀에 반환 (Buffer 종료자에서 호출)
+pub fn return_lent_buffer(buffer: Vec<u8>) {
+    let size = buffer.len() as u64;
+    LENT_RETURNS.fetch_add(1, Ordering::Relaxed);
+    let _ = LENT_OUTSTANDING_BYTES.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bytes| {
+        Some(bytes.saturating_sub(size))
+    });
+    
+    if let Err(e) = release_buffer(buffer) {
+        warn!("빌려준 버퍼 반환 실패: {}", e);
+    }
+}
+
+/// JavaScript로 빌려준 버퍼 통계
+pub fn get_lending_stats() -> serde_json::Value {
+    let lent = LENT_BUFFERS.load(Ordering::Relaxed);
+    let reused = LENT_REUSES.load(Ordering::Relaxed);
+    let returned = LENT_RETURNS.load(Ordering::Relaxed);
+    
+    serde_json::json!({
+        "lent": lent,
+        "returned": returned,
+        "outstanding": lent.saturating_sub(returned),
+        "reused": reused,
+        "reuse_rate": if lent > 0 { reused as f64 / lent as f64 } else { 0.0 },
+        "lent_bytes": LENT_BYTES.load(Ordering::Relaxed),
+        "outstanding_bytes": LENT_OUTSTANDING_BYTES.load(Ordering::Relaxed),
+        "timestamp": clock::now_millis()
+    })
+}
+
 /// 특정 크기의 버퍼 풀 가져오기
 pub fn get_pool_for_size(size: usize) -> Result<String, Error> {
     let pool_name = match size {
@@ -280,14 +371,11 @@ pub fn get_pool_for_size(size: usize) -> Result<String, Error> {
 
 /// 비활성 풀 정리
 pub fn cleanup_inactive_pools() -> Result<(), Error> {
-    let now = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis() as u64;
+    let now = clock::now_millis();
     
     // 마지막 정리 후 일정 시간 이상 지났는지 확인
     let last_cleanup = LAST_CLEANUP_TIME.load(Ordering::SeqCst);
-    if now - last_cleanup < 60000 {  // 1분마다 정리
+    if now.saturating_sub(last_cleanup) < 60000 {  // 1분마다 정리
         return Ok(());
     }
     
@@ -444,10 +532,7 @@ pub fn get_pool_stats() -> Result<MemoryPoolStats, Error> {
     }
     
     // 통계 결과 생성
-    let now = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis() as u64;
+    let now = clock::now_millis();
     
     let stats = MemoryPoolStats {
         timestamp: now,
@@ -476,10 +561,7 @@ pub fn reset_memory_pools() -> Result<(), Error> {
     POOL_ALLOCATIONS.store(0, Ordering::Relaxed);
     POOL_REUSES.store(0, Ordering::Relaxed);
     LAST_CLEANUP_TIME.store(
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as u64,
+        clock::now_millis(),
         Ordering::SeqCst
     );
     
@@ -488,6 +570,24 @@ pub fn reset_memory_pools() -> Result<(), Error> {
     Ok(())
 }
 
+/// 모든 메모리 풀과 통계 삭제 (다음 사용 시 다시 초기화)
+///
+/// `reset_memory_pools`와 달리 풀을 다시 만들지 않으므로 종료 시 사용합니다.
+/// JavaScript에 빌려준 버퍼는 돌려받을 때 크기에 맞는 새 풀로 들어갑니다.
+pub fn clear_memory_pools() {
+    MEMORY_POOLS.write().clear();
+    
+    POOL_ALLOCATIONS.store(0, Ordering::Relaxed);
+    POOL_REUSES.store(0, Ordering::Relaxed);
+    LAST_CLEANUP_TIME.store(0, Ordering::SeqCst);
+    LENT_BUFFERS.store(0, Ordering::Relaxed);
+    LENT_REUSES.store(0, Ordering::Relaxed);
+    LENT_RETURNS.store(0, Ordering::Relaxed);
+    LENT_BYTES.store(0, Ordering::Relaxed);
+    
+    debug!("메모리 풀 삭제됨");
+}
+
 /// 메모리 풀 이름 목록 가져오기
 pub fn get_pool_names() -> Result<Vec<String>, Error> {
     let pools = MEMORY_POOLS.read();
@@ -557,3 +657,35 @@ pub fn recommend_pool(expected_size: usize, _usage_hint: &str) -> (String, usize
     
     (pool_name.to_string(), size)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cleanup_removes_only_expired_buffers() {
+        clock::with_mock_clock(1_000_000, |mock| {
+            let mut pool = MemoryPool::new("test", TINY_BUFFER_SIZE, 4);
+
+            let (old, _) = pool.acquire_buffer();
+            let (recent, _) = pool.acquire_buffer();
+            pool.release_buffer(old);
+            mock.advance(500);
+            pool.release_buffer(recent);
+            assert_eq!(pool.available_items.len(), 2);
+
+            // 첫 버퍼 반환 후 1000ms: 경계값은 아직 유지
+            mock.advance(500);
+            assert_eq!(pool.cleanup_old_buffers(1000), 0);
+
+            mock.advance(1);
+            assert_eq!(pool.cleanup_old_buffers(1000), 1);
+            assert_eq!(pool.available_items.len(), 1);
+            assert_eq!(pool.total_freed.load(Ordering::Relaxed), TINY_BUFFER_SIZE as u64);
+
+            mock.advance(500);
+            assert_eq!(pool.cleanup_old_buffers(1000), 1);
+            assert!(pool.available_items.is_empty());
+        });
+    }
+}
@@ -25,6 +25,26 @@ pub struct MemorySettings {
     // 메모리 풀 설정
     pub use_memory_pool: bool,
     pub pool_cleanup_interval: u64, // ms 단위
+    
+    // 전원 절약 설정 (이전 설정 JSON과 호환되도록 기본값 사용)
+    #[serde(default = "default_power_saving_policy")]
+    pub power_saving_policy: String, // "battery"(배터리 또는 저전력 모드), "low-power"(저전력 모드만), "off"
+    #[serde(default = "default_power_saving_thread_ratio")]
+    pub power_saving_thread_ratio: f64, // 전원 절약 시 사용할 워커 스레드 비율 (0.0 ~ 1.0)
+    #[serde(default = "default_power_saving_disable_gpu")]
+    pub power_saving_disable_gpu: bool,
+}
+
+fn default_power_saving_policy() -> String {
+    "battery".to_string()
+}
+
+fn default_power_saving_thread_ratio() -> f64 {
+    0.5
+}
+
+fn default_power_saving_disable_gpu() -> bool {
+    true
 }
 
 impl Default for MemorySettings {
@@ -43,6 +63,10 @@ impl Default for MemorySettings {
             
             use_memory_pool: true,
             pool_cleanup_interval: 300000, // 5분
+            
+            power_saving_policy: default_power_saving_policy(),
+            power_saving_thread_ratio: default_power_saving_thread_ratio(),
+            power_saving_disable_gpu: default_power_saving_disable_gpu(),
         }
     }
 }
@@ -114,6 +138,12 @@ pub fn update_memory_settings(settings_json: &str) -> Result<bool, Error> {
     }
 }
 
+/// 설정을 기본값으로 되돌리고 다시 초기화할 수 있도록 표시
+pub fn reset_memory_settings() {
+    *MEMORY_SETTINGS.write() = MemorySettings::default();
+    SETTINGS_INITIALIZED.store(false, Ordering::SeqCst);
+}
+
 /// 자동 최적화가 활성화되어 있는지 확인
 pub fn is_automatic_optimization_enabled() -> bool {
     MEMORY_SETTINGS.read().enable_automatic_optimization
@@ -156,3 +186,18 @@ pub fn is_performance_metrics_enabled() -> bool {
 pub fn is_aggressive_gc_enabled() -> bool {
     MEMORY_SETTINGS.read().aggressive_gc
 }
+
+/// 전원 절약 정책 가져오기 ("battery", "low-power", "off")
+pub fn get_power_saving_policy() -> String {
+    MEMORY_SETTINGS.read().power_saving_policy.clone()
+}
+
+/// 전원 절약 시 워커 스레드 비율 가져오기
+pub fn get_power_saving_thread_ratio() -> f64 {
+    MEMORY_SETTINGS.read().power_saving_thread_ratio
+}
+
+/// 전원 절약 시 GPU 가속 비활성화 여부 확인
+pub fn is_power_saving_gpu_disabled() -> bool {
+    MEMORY_SETTINGS.read().power_saving_disable_gpu
+}
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+
+/// 현재 시각 제공자
+///
+/// GC 간격 제한, 메모리 풀 정리, 워커 대기 시간처럼 시간에 의존하는 로직이
+/// 시스템 시계를 직접 호출하지 않도록 추상화합니다.
+pub trait Clock: Send + Sync {
+    /// UNIX epoch 기준 현재 시각 (밀리초)
+    fn now_millis(&self) -> u64;
+}
+
+/// 시스템 시계
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_millis(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64
+    }
+}
+
+/// 수동으로 조작하는 시계
+///
+/// 만료/간격 제한의 경계 조건을 재현할 때 `set_clock`으로 주입해 사용합니다.
+#[derive(Debug, Default)]
+pub struct MockClock {
+    now: AtomicU64,
+}
+
+impl MockClock {
+    pub fn new(start_millis: u64) -> Self {
+        Self { now: AtomicU64::new(start_millis) }
+    }
+
+    /// 현재 시각 설정
+    pub fn set(&self, millis: u64) {
+        self.now.store(millis, Ordering::SeqCst);
+    }
+
+    /// 현재 시각을 주어진 만큼 앞당김
+    pub fn advance(&self, millis: u64) {
+        self.now.fetch_add(millis, Ordering::SeqCst);
+    }
+}
+
+impl Clock for MockClock {
+    fn now_millis(&self) -> u64 {
+        self.now.load(Ordering::SeqCst)
+    }
+}
+
+// 전역 시계 (기본값: 시스템 시계)
+static CLOCK: Lazy<RwLock<Arc<dyn Clock>>> = Lazy::new(|| RwLock::new(Arc::new(SystemClock)));
+
+/// 전역 시계 교체
+pub fn set_clock(clock: Arc<dyn Clock>) {
+    *CLOCK.write() = clock;
+}
+
+/// 전역 시계를 시스템 시계로 복원
+pub fn reset_clock() {
+    set_clock(Arc::new(SystemClock));
+}
+
+/// 현재 전역 시계 가져오기
+pub fn clock() -> Arc<dyn Clock> {
+    CLOCK.read().clone()
+}
+
+/// 전역 시계 기준 현재 시각 (밀리초)
+pub fn now_millis() -> u64 {
+    CLOCK.read().now_millis()
+}
+
+// 테스트 간 전역 시계/전역 상태 공유를 막기 위한 잠금
+#[cfg(test)]
+pub(crate) static TEST_LOCK: Lazy<parking_lot::Mutex<()>> = Lazy::new(|| parking_lot::Mutex::new(()));
+
+/// 전역 시계를 `MockClock`으로 바꿔 실행하고, 끝나면 (패닉이 나도) 시스템 시계로 복원
+#[cfg(test)]
+pub(crate) fn with_mock_clock<R>(start_millis: u64, f: impl FnOnce(&MockClock) -> R) -> R {
+    struct Restore;
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            reset_clock();
+        }
+    }
+
+    let _lock = TEST_LOCK.lock();
+    let mock = Arc::new(MockClock::new(start_millis));
+    set_clock(mock.clone());
+    let _restore = Restore;
+    f(&mock)
+}
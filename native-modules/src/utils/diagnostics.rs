@@ -0,0 +1,320 @@
+use napi_derive::napi;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use log::{info, warn};
+use std::time::Instant;
+use crate::gpu::{accelerator, context, settings as gpu_settings};
+use crate::memory::{analyzer, pool};
+use crate::utils::{clock, fatigue, power, supervisor, warmup};
+use crate::{gpu, memory};
+use crate::worker::{js_handlers as worker_js_handlers, pool as worker_pool};
+
+/// 하위 시스템 초기화 상태 코드
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubsystemStatus {
+    /// 정상 초기화됨
+    Ok,
+    /// 설정으로 꺼져 있어 초기화하지 않음
+    Disabled,
+    /// 시스템에서 지원하지 않음 (예: 사용 가능한 GPU 어댑터 없음)
+    Unavailable,
+    /// 초기화 중 오류
+    Failed,
+    /// 아직 초기화를 시도하지 않음
+    NotInitialized,
+}
+
+impl SubsystemStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubsystemStatus::Ok => "ok",
+            SubsystemStatus::Disabled => "disabled",
+            SubsystemStatus::Unavailable => "unavailable",
+            SubsystemStatus::Failed => "failed",
+            SubsystemStatus::NotInitialized => "not_initialized",
+        }
+    }
+}
+
+/// 하위 시스템 하나의 초기화 결과
+#[derive(Debug, Clone)]
+pub struct SubsystemReport {
+    pub status: SubsystemStatus,
+    pub error: Option<String>,
+    pub capabilities: Value,
+    pub duration_ms: f64,
+}
+
+impl SubsystemReport {
+    fn not_initialized() -> Self {
+        Self {
+            status: SubsystemStatus::NotInitialized,
+            error: None,
+            capabilities: json!({}),
+            duration_ms: 0.0,
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "status": self.status.as_str(),
+            "error": self.error,
+            "capabilities": self.capabilities,
+            "duration_ms": self.duration_ms
+        })
+    }
+}
+
+/// 전체 초기화 보고서
+#[derive(Debug, Clone)]
+pub struct InitializationReport {
+    pub gpu: SubsystemReport,
+    pub memory: SubsystemReport,
+    pub worker: SubsystemReport,
+    pub initialized_at: Option<u64>,
+}
+
+impl InitializationReport {
+    fn empty() -> Self {
+        Self {
+            gpu: SubsystemReport::not_initialized(),
+            memory: SubsystemReport::not_initialized(),
+            worker: SubsystemReport::not_initialized(),
+            initialized_at: None,
+        }
+    }
+
+    /// 실패한 하위 시스템이 없는지 여부 (꺼져 있거나 지원하지 않는 경우는 실패로 보지 않음)
+    pub fn is_ok(&self) -> bool {
+        self.initialized_at.is_some()
+            && [&self.gpu, &self.memory, &self.worker]
+                .iter()
+                .all(|subsystem| subsystem.status != SubsystemStatus::Failed)
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!({
+            "initialized": self.initialized_at.is_some(),
+            "ok": self.is_ok(),
+            "initialized_at": self.initialized_at,
+            "subsystems": {
+                "gpu": self.gpu.to_json(),
+                "memory": self.memory.to_json(),
+                "worker": self.worker.to_json()
+            },
+            "timestamp": clock::now_millis()
+        })
+    }
+}
+
+// 마지막 초기화 보고서
+static LAST_REPORT: Lazy<Mutex<InitializationReport>> = Lazy::new(|| Mutex::new(InitializationReport::empty()));
+
+// 단계 실행 시간 측정
+fn timed(run: impl FnOnce() -> (SubsystemStatus, Option<String>, Value)) -> SubsystemReport {
+    let started = Instant::now();
+    let (status, error, capabilities) = run();
+    SubsystemReport {
+        status,
+        error,
+        capabilities,
+        duration_ms: started.elapsed().as_secs_f64() * 1000.0,
+    }
+}
+
+// 메모리 풀 초기화와 프로세스 메모리 조회 확인
+fn initialize_memory() -> SubsystemReport {
+    timed(|| {
+        if let Err(e) = pool::initialize_memory_pools() {
+            return (SubsystemStatus::Failed, Some(format!("메모리 풀 초기화 실패: {}", e)), json!({}));
+        }
+
+        // 프로세스 메모리 정보를 읽지 못해도 풀과 GC 예약은 사용할 수 있음
+        let memory_info = analyzer::get_process_memory_info();
+        let capabilities = json!({
+            "memory_pools": true,
+            "buffer_lending": true,
+            "gc_scheduling": true,
+            "process_memory_info": memory_info.is_ok()
+        });
+        (SubsystemStatus::Ok, memory_info.err().map(|e| format!("프로세스 메모리 정보 조회 실패: {}", e)), capabilities)
+    })
+}
+
+// 하드웨어 가속 설정이 켜져 있으면 GPU 컨텍스트 초기화
+fn initialize_gpu() -> SubsystemReport {
+    timed(|| {
+        if !gpu_settings::is_hardware_acceleration_enabled() {
+            return (SubsystemStatus::Disabled, None, json!({ "compute": false }));
+        }
+
+        if let Err(e) = accelerator::initialize_gpu() {
+            return (SubsystemStatus::Failed, Some(e.reason.clone()), json!({ "compute": false }));
+        }
+
+        // 하드웨어 어댑터가 없으면(가상 머신, 원격 세션 등) 소프트웨어 렌더러로 초기화됨
+        if !context::check_gpu_availability() {
+            return (
+                SubsystemStatus::Unavailable,
+                Some("사용 가능한 GPU 어댑터가 없어 소프트웨어 렌더러를 사용합니다".to_string()),
+                json!({ "compute": false, "device_name": accelerator::get_device_name() }),
+            );
+        }
+
+        match context::get_capabilities() {
+            Ok(caps) => (SubsystemStatus::Ok, None, json!({
+                "device_name": accelerator::get_device_name(),
+                "vendor_name": accelerator::get_vendor_name(),
+                "compute": caps.compute_supported,
+                "max_buffer_size": caps.max_buffer_size,
+                "timestamp_query": caps.supports_timestamp_query,
+                "pipeline_statistics_query": caps.supports_pipeline_statistics_query,
+                "acceleration_enabled": accelerator::is_acceleration_enabled()
+            })),
+            Err(e) => (SubsystemStatus::Failed, Some(format!("GPU 기능 조회 실패: {}", e.reason)), json!({ "compute": false })),
+        }
+    })
+}
+
+// 워커 풀 시작 (이미 실행 중이면 그대로 사용)
+fn initialize_worker() -> SubsystemReport {
+    timed(|| {
+        if let Err(e) = worker_pool::initialize_worker_pool(0, None) {
+            return (SubsystemStatus::Failed, Some(e.reason.clone()), json!({}));
+        }
+
+        let thread_count = worker_pool::get_worker_pool_stats()
+            .map(|stats| stats.thread_count)
+            .unwrap_or(0);
+        let mut task_types = worker_pool::get_available_task_types().unwrap_or_default();
+        task_types.sort();
+
+        (SubsystemStatus::Ok, None, json!({
+            "thread_count": thread_count,
+            "task_types": task_types
+        }))
+    })
+}
+
+/// 모든 하위 시스템 초기화 후 보고서 저장
+///
+/// 한 하위 시스템이 실패해도 나머지는 계속 초기화합니다.
+pub fn initialize_all() -> InitializationReport {
+    let report = InitializationReport {
+        memory: initialize_memory(),
+        worker: initialize_worker(),
+        gpu: initialize_gpu(),
+        initialized_at: Some(clock::now_millis()),
+    };
+
+    for (name, subsystem) in [("gpu", &report.gpu), ("memory", &report.memory), ("worker", &report.worker)] {
+        match subsystem.status {
+            SubsystemStatus::Ok | SubsystemStatus::Disabled => {
+                info!("{} 초기화 상태: {}", name, subsystem.status.as_str());
+            },
+            _ => {
+                warn!("{} 초기화 상태: {} ({})", name, subsystem.status.as_str(), subsystem.error.as_deref().unwrap_or("-"));
+            }
+        }
+    }
+
+    *LAST_REPORT.lock() = report.clone();
+    report
+}
+
+/// GPU만 다시 초기화하고 보고서의 gpu 항목 갱신
+///
+/// 설정에서 GPU를 끈 상태면 `disabled`로 기록합니다.
+pub fn refresh_gpu() -> SubsystemReport {
+    let gpu = initialize_gpu();
+    LAST_REPORT.lock().gpu = gpu.clone();
+    gpu
+}
+
+/// 모든 하위 시스템 종료 후 처음 상태로 초기화
+///
+/// 워커 풀과 메모리 모니터 스레드를 멈추고, GPU 컨텍스트와 캐시, 기록, 설정을 비웁니다.
+/// 한 단계가 실패해도 나머지는 계속 진행하며, 실패한 단계의 오류 목록을 반환합니다.
+/// 이후 `initialize_all`로 다시 초기화할 수 있습니다.
+pub fn shutdown_all() -> Vec<String> {
+    let mut errors = Vec::new();
+
+    if let Err(e) = worker_pool::shutdown_worker_pool() {
+        errors.push(format!("워커 풀 종료 실패: {}", e.reason));
+    }
+    if let Err(e) = memory::reset_memory_state() {
+        errors.push(format!("메모리 상태 초기화 실패: {}", e.reason));
+    }
+    if let Err(e) = gpu::reset_gpu_state() {
+        errors.push(format!("GPU 상태 초기화 실패: {}", e.reason));
+    }
+    worker_js_handlers::clear();
+    fatigue::reset();
+    warmup::reset();
+    power::reset();
+    supervisor::reset();
+    *LAST_REPORT.lock() = InitializationReport::empty();
+
+    if errors.is_empty() {
+        info!("네이티브 모듈 정리 완료");
+    } else {
+        warn!("네이티브 모듈 정리 중 오류: {}", errors.join(", "));
+    }
+    errors
+}
+
+/// 마지막 초기화 보고서 (초기화 전이면 모든 하위 시스템이 not_initialized)
+pub fn last_report() -> InitializationReport {
+    LAST_REPORT.lock().clone()
+}
+
+/// 네이티브 모듈 초기화 보고서 조회
+///
+/// `initialize_native_modules`가 반환한 것과 같은 형식의 JSON입니다.
+#[napi]
+pub fn get_initialization_report() -> String {
+    last_report().to_json().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gpu::{result_cache, shader, GpuTaskType};
+    use crate::memory::{gc, monitor};
+
+    // 종료 후 남아 있으면 안 되는 상태 확인
+    fn assert_cleared() {
+        assert_eq!(monitor::get_memory_monitor_status()["running"], json!(false));
+        assert_eq!(gc::get_scheduled_gc_status()["pending"], json!([]));
+        assert_eq!(serde_json::from_str::<Value>(&result_cache::get_gpu_result_cache_stats()).unwrap()["entries"], json!(0));
+        assert!(shader::list_cached_shaders().is_empty());
+        let history: Value = serde_json::from_str(&memory::get_optimization_history(None).unwrap()).unwrap();
+        assert_eq!(history["count"], json!(0));
+        assert!(last_report().initialized_at.is_none());
+    }
+
+    #[test]
+    fn reinitializes_cleanly_after_shutdown() {
+        let _lock = clock::TEST_LOCK.lock();
+
+        for _ in 0..2 {
+            let report = initialize_all();
+            assert!(report.is_ok(), "초기화 실패: {}", report.to_json());
+            assert_eq!(report.worker.status, SubsystemStatus::Ok);
+
+            // 종료 시 비워야 할 상태 채우기
+            gc::schedule_gc(gc::GcMode::Basic, Some(60_000)).unwrap();
+            result_cache::store(GpuTaskType::TextAnalysis, false, "{\"text\":\"abc\"}", &json!({ "success": true }));
+            shader::initialize_shaders().unwrap();
+            memory::optimize_memory("low".to_string(), false).unwrap();
+            assert!(!shader::list_cached_shaders().is_empty());
+
+            assert_eq!(shutdown_all(), Vec::<String>::new());
+            assert_cleared();
+        }
+
+        assert!(initialize_all().is_ok());
+        assert!(shutdown_all().is_empty());
+    }
+}
@@ -0,0 +1,227 @@
+//! 타이핑 피로도 추적 모듈
+//!
+//! 최근 타이핑 활동을 시간 구간으로 모아 두고 피로도 점수(0~100)를 계산합니다.
+//! 타이핑하는 동안에는 입력 강도에 비례해 점수가 쌓이고, 쉬는 동안에는 반감기에 따라 회복됩니다.
+//! 점수가 설정한 임계값을 넘으면 휴식 권장 여부를 한 번 알려 주고,
+//! 충분히 회복되어 임계값 아래로 내려가면 다시 알릴 수 있는 상태가 됩니다.
+
+use std::collections::VecDeque;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use log::{debug, info};
+use napi_derive::napi;
+use crate::utils::clock;
+
+// 활동 기록을 유지하는 시간 (밀리초, 2시간)
+const ACTIVITY_WINDOW_MS: u64 = 2 * 60 * 60 * 1000;
+
+// 연속 타이핑 1분당 기본 피로도 증가량
+const FATIGUE_PER_ACTIVE_MINUTE: f64 = 1.5;
+
+// 입력 강도 기준 (분당 타수) 및 강도 배율 범위
+const BASELINE_KEYS_PER_MINUTE: f64 = 200.0;
+const MIN_INTENSITY: f64 = 0.5;
+const MAX_INTENSITY: f64 = 2.0;
+
+// 휴식 시 피로도가 절반으로 줄어드는 시간 (분)
+const RECOVERY_HALF_LIFE_MINUTES: f64 = 10.0;
+
+// 이 시간 이상 입력이 없으면 휴식으로 보고 연속 타이핑 시간을 초기화 (밀리초)
+const BREAK_RESET_MS: u64 = 5 * 60 * 1000;
+
+// 알림 후 다시 알리려면 임계값보다 이만큼 낮아져야 함
+const THRESHOLD_HYSTERESIS: f64 = 10.0;
+
+// 기본 휴식 권장 임계값
+const DEFAULT_THRESHOLD: f64 = 70.0;
+
+// 타이핑 활동 구간
+#[derive(Debug, Clone, Copy)]
+struct ActivitySample {
+    ended_at: u64,
+    key_count: u32,
+    active_ms: u32,
+}
+
+// 피로도 추적 상태
+struct FatigueTracker {
+    samples: VecDeque<ActivitySample>,
+    score: f64,
+    last_update: Option<u64>,
+    continuous_since: Option<u64>,
+    threshold: f64,
+    notified: bool,
+    recommendations: u64,
+}
+
+impl FatigueTracker {
+    fn new() -> Self {
+        Self {
+            samples: VecDeque::new(),
+            score: 0.0,
+            last_update: None,
+            continuous_since: None,
+            threshold: DEFAULT_THRESHOLD,
+            notified: false,
+            recommendations: 0,
+        }
+    }
+
+    // 마지막 갱신 이후 쉬었던 시간만큼 회복
+    fn recover(&mut self, now: u64, active_ms: u64) {
+        if let Some(last) = self.last_update {
+            let idle_ms = now.saturating_sub(last).saturating_sub(active_ms);
+            let idle_minutes = idle_ms as f64 / 60_000.0;
+            self.score *= 0.5f64.powf(idle_minutes / RECOVERY_HALF_LIFE_MINUTES);
+
+            if idle_ms >= BREAK_RESET_MS {
+                self.continuous_since = None;
+            }
+        }
+        self.last_update = Some(now);
+    }
+
+    // 기록 구간 밖의 오래된 활동 제거
+    fn trim(&mut self, now: u64) {
+        while self.samples.front().is_some_and(|s| now.saturating_sub(s.ended_at) > ACTIVITY_WINDOW_MS) {
+            self.samples.pop_front();
+        }
+    }
+
+    fn record(&mut self, now: u64, key_count: u32, active_ms: u32) -> bool {
+        self.recover(now, active_ms as u64);
+
+        if active_ms > 0 {
+            let active_minutes = active_ms as f64 / 60_000.0;
+            let keys_per_minute = key_count as f64 / active_minutes;
+            let intensity = (keys_per_minute / BASELINE_KEYS_PER_MINUTE).clamp(MIN_INTENSITY, MAX_INTENSITY);
+            self.score = (self.score + active_minutes * FATIGUE_PER_ACTIVE_MINUTE * intensity).min(100.0);
+
+            if self.continuous_since.is_none() {
+                self.continuous_since = Some(now.saturating_sub(active_ms as u64));
+            }
+            self.samples.push_back(ActivitySample { ended_at: now, key_count, active_ms });
+        }
+        self.trim(now);
+
+        self.check_threshold()
+    }
+
+    // 충분히 회복되면 다시 알릴 수 있도록 재설정
+    fn rearm(&mut self) {
+        if self.notified && self.score < self.threshold - THRESHOLD_HYSTERESIS {
+            self.notified = false;
+        }
+    }
+
+    // 임계값을 넘는 순간 한 번만 true
+    fn check_threshold(&mut self) -> bool {
+        self.rearm();
+        if !self.notified && self.score >= self.threshold {
+            self.notified = true;
+            self.recommendations += 1;
+            return true;
+        }
+        false
+    }
+
+    fn status(&self, now: u64, break_recommended: bool) -> Value {
+        let window_keys: u64 = self.samples.iter().map(|s| s.key_count as u64).sum();
+        let window_active_ms: u64 = self.samples.iter().map(|s| s.active_ms as u64).sum();
+
+        json!({
+            "score": self.score,
+            "level": fatigue_level(self.score),
+            "threshold": self.threshold,
+            "break_recommended": break_recommended,
+            "above_threshold": self.score >= self.threshold,
+            "continuous_typing_ms": self.continuous_since.map(|since| now.saturating_sub(since)).unwrap_or(0),
+            "window_key_count": window_keys,
+            "window_active_ms": window_active_ms,
+            "recommendations": self.recommendations,
+            "timestamp": now
+        })
+    }
+}
+
+static TRACKER: Lazy<Mutex<FatigueTracker>> = Lazy::new(|| Mutex::new(FatigueTracker::new()));
+
+fn fatigue_level(score: f64) -> &'static str {
+    match score {
+        s if s < 30.0 => "low",
+        s if s < 60.0 => "moderate",
+        s if s < 85.0 => "high",
+        _ => "severe",
+    }
+}
+
+/// 타이핑 활동 기록
+///
+/// 마지막 기록 이후의 입력 수와 실제 입력한 시간(ms)을 전달합니다.
+/// 기록 사이에 입력하지 않은 시간은 휴식으로 보고 피로도를 회복시킵니다.
+pub fn record_activity(key_count: u32, active_ms: u32) -> Value {
+    let now = clock::now_millis();
+    let mut tracker = TRACKER.lock();
+    let break_recommended = tracker.record(now, key_count, active_ms);
+
+    if break_recommended {
+        info!("피로도 {:.1}점으로 휴식 권장 임계값({:.0}) 초과", tracker.score, tracker.threshold);
+    }
+
+    tracker.status(now, break_recommended)
+}
+
+/// 현재 피로도 상태 (쉬고 있던 시간만큼 회복을 반영)
+pub fn get_status() -> Value {
+    let now = clock::now_millis();
+    let mut tracker = TRACKER.lock();
+    tracker.recover(now, 0);
+    tracker.trim(now);
+    tracker.rearm();
+    tracker.status(now, false)
+}
+
+/// 휴식 권장 임계값 설정 (1~100)
+pub fn set_threshold(threshold: f64) -> f64 {
+    let threshold = threshold.clamp(1.0, 100.0);
+    let mut tracker = TRACKER.lock();
+    tracker.threshold = threshold;
+    // 이미 임계값을 넘은 상태에서 설정을 바꾼 경우 바로 알리지 않고 회복 후부터 다시 판단
+    tracker.notified = tracker.score >= threshold;
+    debug!("휴식 권장 임계값 변경: {}", threshold);
+    threshold
+}
+
+/// 피로도 상태 초기화 (임계값은 유지)
+pub fn reset() {
+    let mut tracker = TRACKER.lock();
+    let threshold = tracker.threshold;
+    *tracker = FatigueTracker::new();
+    tracker.threshold = threshold;
+}
+
+/// 타이핑 활동 기록 후 피로도 상태 반환 (JSON)
+#[napi]
+pub fn record_typing_activity(key_count: u32, active_ms: u32) -> String {
+    record_activity(key_count, active_ms).to_string()
+}
+
+/// 현재 피로도 상태 가져오기 (JSON)
+#[napi]
+pub fn get_fatigue_status() -> String {
+    get_status().to_string()
+}
+
+/// 휴식 권장 임계값 설정
+#[napi]
+pub fn set_fatigue_threshold(threshold: f64) -> f64 {
+    set_threshold(threshold)
+}
+
+/// 피로도 상태 초기화
+#[napi]
+pub fn reset_fatigue() -> bool {
+    reset();
+    true
+}
@@ -0,0 +1,192 @@
+//! 키보드 레이아웃 감지 모듈
+//!
+//! 현재 활성 키보드 레이아웃을 플랫폼별 방법으로 조회합니다.
+//! 레이아웃에 따라 WPM 정규화 기준이 달라지므로 타이핑 세션 메타데이터에 포함됩니다.
+
+use napi_derive::napi;
+use serde_json::json;
+use crate::utils::clock;
+
+/// 키보드 레이아웃 정보
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyboardLayout {
+    /// 플랫폼 고유 레이아웃 식별자 (예: "00000412", "us", "com.apple.keylayout.ABC")
+    pub id: String,
+    /// 언어 코드 (예: "ko", "en"), 알 수 없으면 None
+    pub language: Option<String>,
+    /// 조회 방법 ("winapi", "xkb", "hitoolbox", "env")
+    pub source: &'static str,
+}
+
+/// 현재 키보드 레이아웃 감지
+pub fn detect_keyboard_layout() -> Option<KeyboardLayout> {
+    detect_platform_layout().or_else(detect_layout_from_env)
+}
+
+/// 현재 키보드 레이아웃 가져오기 (JSON)
+#[napi]
+pub fn get_keyboard_layout() -> String {
+    let result = match detect_keyboard_layout() {
+        Some(layout) => json!({
+            "available": true,
+            "layout": layout.id,
+            "language": layout.language,
+            "source": layout.source,
+            "timestamp": clock::now_millis()
+        }),
+        None => json!({
+            "available": false,
+            "layout": null,
+            "language": null,
+            "source": null,
+            "timestamp": clock::now_millis()
+        }),
+    };
+
+    result.to_string()
+}
+
+// Windows: 포그라운드 창 스레드의 키보드 레이아웃 (HKL 하위 16비트가 언어 ID)
+#[cfg(all(target_os = "windows", feature = "use-winapi"))]
+fn detect_platform_layout() -> Option<KeyboardLayout> {
+    use winapi::um::winuser::{GetForegroundWindow, GetKeyboardLayout, GetWindowThreadProcessId};
+
+    let hkl = unsafe {
+        let window = GetForegroundWindow();
+        let thread_id = if window.is_null() {
+            0
+        } else {
+            GetWindowThreadProcessId(window, std::ptr::null_mut())
+        };
+        GetKeyboardLayout(thread_id)
+    };
+
+    if hkl.is_null() {
+        return None;
+    }
+
+    let lang_id = (hkl as usize & 0xFFFF) as u16;
+    Some(KeyboardLayout {
+        id: format!("{:08X}", lang_id as u32),
+        language: windows_language(lang_id).map(str::to_string),
+        source: "winapi",
+    })
+}
+
+// Windows 언어 ID의 주 언어 부분을 언어 코드로 변환
+#[cfg(all(target_os = "windows", feature = "use-winapi"))]
+fn windows_language(lang_id: u16) -> Option<&'static str> {
+    match lang_id & 0x3FF {
+        0x12 => Some("ko"),
+        0x09 => Some("en"),
+        0x11 => Some("ja"),
+        0x04 => Some("zh"),
+        0x07 => Some("de"),
+        0x0C => Some("fr"),
+        0x0A => Some("es"),
+        0x19 => Some("ru"),
+        _ => None,
+    }
+}
+
+// Linux: X 키보드 확장(XKB) 설정에서 레이아웃 조회
+#[cfg(target_os = "linux")]
+fn detect_platform_layout() -> Option<KeyboardLayout> {
+    let output = std::process::Command::new("setxkbmap").arg("-query").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let layout = stdout.lines()
+        .find_map(|line| line.strip_prefix("layout:"))
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())?;
+
+    // 여러 레이아웃이 설정된 경우 첫 번째가 기본 그룹
+    let primary = layout.split(',').next().unwrap_or(&layout).to_string();
+    Some(KeyboardLayout {
+        language: xkb_language(&primary).map(str::to_string),
+        id: layout,
+        source: "xkb",
+    })
+}
+
+// XKB 레이아웃 이름을 언어 코드로 변환
+#[cfg(target_os = "linux")]
+fn xkb_language(layout: &str) -> Option<&'static str> {
+    match layout {
+        "kr" => Some("ko"),
+        "us" | "gb" => Some("en"),
+        "jp" => Some("ja"),
+        "cn" => Some("zh"),
+        "de" => Some("de"),
+        "fr" => Some("fr"),
+        "es" => Some("es"),
+        "ru" => Some("ru"),
+        _ => None,
+    }
+}
+
+// macOS: HIToolbox 환경설정의 현재 입력 소스
+#[cfg(target_os = "macos")]
+fn detect_platform_layout() -> Option<KeyboardLayout> {
+    let output = std::process::Command::new("defaults")
+        .args(["read", "com.apple.HIToolbox", "AppleCurrentKeyboardLayoutInputSourceID"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if id.is_empty() {
+        return None;
+    }
+
+    let lower = id.to_lowercase();
+    let language = if lower.contains("korean") || lower.contains("2setkorean") {
+        Some("ko")
+    } else if lower.contains("abc") || lower.ends_with(".us") || lower.contains("british") {
+        Some("en")
+    } else {
+        None
+    };
+
+    Some(KeyboardLayout {
+        id,
+        language: language.map(str::to_string),
+        source: "hitoolbox",
+    })
+}
+
+// 그 외 플랫폼이거나 Windows API 사용이 비활성화된 경우
+#[cfg(not(any(
+    all(target_os = "windows", feature = "use-winapi"),
+    target_os = "linux",
+    target_os = "macos"
+)))]
+fn detect_platform_layout() -> Option<KeyboardLayout> {
+    None
+}
+
+// 환경 변수 기반 추정 (XKB_DEFAULT_LAYOUT, LANG)
+fn detect_layout_from_env() -> Option<KeyboardLayout> {
+    if let Ok(layout) = std::env::var("XKB_DEFAULT_LAYOUT") {
+        if !layout.is_empty() {
+            return Some(KeyboardLayout { id: layout, language: None, source: "env" });
+        }
+    }
+
+    let locale = std::env::var("LC_ALL").ok()
+        .filter(|v| !v.is_empty())
+        .or_else(|| std::env::var("LANG").ok())
+        .filter(|v| !v.is_empty() && v != "C" && v != "POSIX")?;
+
+    let language = locale.split(['_', '.']).next().unwrap_or("").to_lowercase();
+    Some(KeyboardLayout {
+        id: locale,
+        language: if language.is_empty() { None } else { Some(language) },
+        source: "env",
+    })
+}
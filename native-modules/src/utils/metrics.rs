@@ -0,0 +1,154 @@
+use napi_derive::napi;
+use std::fmt::Write;
+use crate::memory::{analyzer, gc, pool};
+use crate::gpu::{self, thermal};
+use crate::utils::supervisor;
+use crate::worker::pool as worker_pool;
+
+// 메트릭 이름 접두사
+const METRIC_PREFIX: &str = "typing_stats";
+
+// Prometheus 텍스트 형식 작성기
+struct MetricsWriter {
+    out: String,
+}
+
+impl MetricsWriter {
+    fn new() -> Self {
+        Self { out: String::new() }
+    }
+
+    // HELP/TYPE 헤더와 함께 레이블 없는 단일 값 기록
+    fn single(&mut self, name: &str, kind: &str, help: &str, value: f64) {
+        self.header(name, kind, help);
+        self.sample(name, &[], value);
+    }
+
+    fn header(&mut self, name: &str, kind: &str, help: &str) {
+        let _ = writeln!(self.out, "# HELP {}_{} {}", METRIC_PREFIX, name, help);
+        let _ = writeln!(self.out, "# TYPE {}_{} {}", METRIC_PREFIX, name, kind);
+    }
+
+    fn sample(&mut self, name: &str, labels: &[(&str, &str)], value: f64) {
+        let _ = write!(self.out, "{}_{}", METRIC_PREFIX, name);
+        if !labels.is_empty() {
+            let rendered: Vec<String> = labels.iter()
+                .map(|(key, value)| format!("{}=\"{}\"", key, escape_label(value)))
+                .collect();
+            let _ = write!(self.out, "{{{}}}", rendered.join(","));
+        }
+        let _ = writeln!(self.out, " {}", format_value(value));
+    }
+}
+
+// 레이블 값 이스케이프 (역슬래시, 따옴표, 줄바꿈)
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+// 숫자 값 표기 (Prometheus 형식의 무한대는 +Inf/-Inf, NaN은 Rust 표기와 같음)
+fn format_value(value: f64) -> String {
+    if value.is_infinite() {
+        return if value > 0.0 { "+Inf" } else { "-Inf" }.to_string();
+    }
+    if value.is_finite() && value.fract() == 0.0 && value.abs() < 1e15 {
+        format!("{}", value as i64)
+    } else {
+        format!("{}", value)
+    }
+}
+
+/// 네이티브 모듈 메트릭을 Prometheus 텍스트 형식으로 렌더링
+///
+/// 메모리 풀, GC, 워커 풀 큐, GPU 작업 시간, 서브시스템 재시작 횟수를 포함합니다.
+/// 초기화되지 않은 모듈의 메트릭은 건너뜁니다.
+pub fn render_metrics() -> String {
+    let mut w = MetricsWriter::new();
+
+    // 프로세스 메모리
+    if let Ok(info) = analyzer::get_process_memory_info() {
+        if let Some(rss) = info.rss {
+            w.single("process_resident_memory_bytes", "gauge", "Resident set size of the process.", rss as f64);
+        }
+        w.single("process_heap_used_bytes", "gauge", "Heap memory in use.", info.heap_used as f64);
+    }
+
+    // 메모리 풀
+    if let Ok(stats) = pool::get_pool_stats() {
+        w.single("memory_pool_allocations_total", "counter", "Buffers allocated by the memory pools.", stats.total_allocations as f64);
+        w.single("memory_pool_reuses_total", "counter", "Buffers served from the memory pools without allocating.", stats.total_reuses as f64);
+        w.single("memory_pool_bytes_in_use", "gauge", "Bytes currently held by the memory pools.", stats.current_memory_usage as f64);
+
+        w.header("memory_pool_objects", "gauge", "Pooled buffers by pool and state.");
+        for detail in &stats.pools {
+            w.sample("memory_pool_objects", &[("pool", &detail.name), ("state", "active")], detail.active_objects as f64);
+            w.sample("memory_pool_objects", &[("pool", &detail.name), ("state", "available")], detail.available_objects as f64);
+        }
+    }
+
+    let lending = pool::get_lending_stats();
+    w.single("memory_pool_lent_buffers", "gauge", "Pool buffers currently lent to JavaScript.",
+        lending["outstanding"].as_u64().unwrap_or(0) as f64);
+
+    // GC
+    let (gc_count, gc_freed) = gc::get_gc_totals();
+    w.single("gc_runs_total", "counter", "Garbage collection runs.", gc_count as f64);
+    w.single("gc_freed_bytes_total", "counter", "Bytes freed by garbage collection.", gc_freed as f64);
+    let scheduled = gc::get_scheduled_gc_status();
+    w.single("gc_scheduled_jobs", "gauge", "Idle-time GC jobs waiting to run.",
+        scheduled["pending"].as_array().map(|jobs| jobs.len()).unwrap_or(0) as f64);
+
+    // 워커 풀
+    if let Ok(stats) = worker_pool::get_worker_pool_stats() {
+        w.single("worker_pool_queue_depth", "gauge", "Tasks waiting in the worker pool queue.", stats.pending_tasks as f64);
+        w.single("worker_pool_active_workers", "gauge", "Workers currently running a task.", stats.active_workers as f64);
+        w.header("worker_pool_tasks_total", "counter", "Worker pool tasks by outcome.");
+        for (outcome, value) in [
+            ("completed", stats.completed_tasks),
+            ("failed", stats.failed_tasks),
+            ("cancelled", stats.cancelled_tasks),
+            ("rejected", stats.rejected_tasks),
+        ] {
+            w.sample("worker_pool_tasks_total", &[("outcome", outcome)], value as f64);
+        }
+    }
+
+    // GPU 작업
+    w.single("gpu_active_tasks", "gauge", "GPU tasks currently running.", gpu::active_gpu_task_count() as f64);
+    let task_metrics = gpu::gpu_task_metrics();
+    if !task_metrics.is_empty() {
+        w.header("gpu_task_duration_milliseconds", "summary", "GPU task durations by task type.");
+        for (task, m) in &task_metrics {
+            w.sample("gpu_task_duration_milliseconds_sum", &[("task", task)], m.total_duration_ms);
+            w.sample("gpu_task_duration_milliseconds_count", &[("task", task)], m.count as f64);
+        }
+        w.header("gpu_task_failures_total", "counter", "Failed GPU tasks by task type.");
+        for (task, m) in &task_metrics {
+            w.sample("gpu_task_failures_total", &[("task", task)], m.failures as f64);
+        }
+    }
+
+    let thermal_status = thermal::get_thermal_status();
+    w.single("gpu_thermal_throttled", "gauge", "Whether GPU work is currently throttled (1) or not (0).",
+        if thermal_status["throttled"].as_bool().unwrap_or(false) { 1.0 } else { 0.0 });
+    w.single("gpu_throttle_events_total", "counter", "Times GPU work was paused for thermal throttling.",
+        thermal_status["throttle_events"].as_u64().unwrap_or(0) as f64);
+
+    // 백그라운드 서브시스템
+    let health = supervisor::get_health();
+    if let Some(subsystems) = health["subsystems"].as_array() {
+        w.header("subsystem_restarts_total", "counter", "Restarts of background threads after a panic.");
+        for subsystem in subsystems {
+            let name = subsystem["name"].as_str().unwrap_or("unknown");
+            w.sample("subsystem_restarts_total", &[("subsystem", name)], subsystem["restarts"].as_u64().unwrap_or(0) as f64);
+        }
+    }
+
+    w.out
+}
+
+/// 네이티브 모듈 메트릭 가져오기 (Prometheus 텍스트 형식)
+#[napi]
+pub fn get_prometheus_metrics() -> String {
+    render_metrics()
+}
@@ -1,3 +1,12 @@
+pub mod clock;
+pub mod keyboard;
+pub mod fatigue;
+pub mod supervisor;
+pub mod metrics;
+pub mod diagnostics;
+pub mod warmup;
+pub mod power;
+
 use napi_derive::napi;
 use napi::Error;
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -16,12 +25,10 @@ pub fn get_timestamp_string() -> String {
 /// 현재 타임스탬프를 수치형으로 반환 (Number 타입 사용)
 #[napi]
 pub fn get_timestamp() -> f64 {
-    let now = SystemTime::now()
+    SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap_or_default()
-        .as_millis() as f64;
-    
-    now
+        .as_millis() as f64
 }
 
 /// CPU 코어 수를 반환
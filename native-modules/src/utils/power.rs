@@ -0,0 +1,235 @@
+//! 전원 상태 감지 모듈
+//!
+//! 배터리 사용 여부와 OS 저전력 모드를 확인하고, 메모리 설정의 전원 절약 정책에 따라
+//! 워커 풀이 사용할 스레드 수와 GPU 가속 허용 여부를 결정합니다.
+//! 확인 결과는 잠시 보관해 작업마다 OS에 다시 묻지 않도록 합니다.
+
+use napi_derive::napi;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde_json::json;
+use log::info;
+use crate::memory::settings;
+use crate::utils::clock;
+
+// 전원 상태를 다시 확인하는 간격 (ms)
+const PROBE_INTERVAL_MS: u64 = 10_000;
+
+/// 전원 상태
+#[derive(Debug, Clone)]
+pub struct PowerState {
+    /// 배터리로 동작 중인지 여부
+    pub on_battery: bool,
+    /// OS 저전력(배터리 절약) 모드 여부
+    pub low_power_mode: bool,
+    /// 배터리 잔량 (%) - 배터리가 없거나 알 수 없으면 None
+    pub battery_percent: Option<f64>,
+    /// 상태를 읽은 방법 (sysfs, pmset, winapi, unavailable)
+    pub source: &'static str,
+}
+
+impl PowerState {
+    // 상태를 알 수 없으면 AC 전원으로 간주
+    fn unavailable() -> Self {
+        Self {
+            on_battery: false,
+            low_power_mode: false,
+            battery_percent: None,
+            source: "unavailable",
+        }
+    }
+}
+
+// 마지막 확인 시각과 결과
+static LAST_STATE: Lazy<Mutex<Option<(u64, PowerState)>>> = Lazy::new(|| Mutex::new(None));
+
+// OS에서 전원 상태를 읽어 보관 (상태가 바뀌면 로그 기록)
+fn refresh() -> PowerState {
+    let state = probe_power_state();
+    let mut last = LAST_STATE.lock();
+    if last.as_ref().is_some_and(|(_, previous)| previous.on_battery != state.on_battery || previous.low_power_mode != state.low_power_mode) {
+        info!("전원 상태 변경: 배터리 {}, 저전력 모드 {}", state.on_battery, state.low_power_mode);
+    }
+    *last = Some((clock::now_millis(), state.clone()));
+    state
+}
+
+/// 현재 전원 상태 (마지막 확인 후 일정 시간이 지났으면 다시 확인)
+pub fn current_state() -> PowerState {
+    let cached = LAST_STATE.lock().as_ref()
+        .filter(|(checked_at, _)| clock::now_millis().saturating_sub(*checked_at) < PROBE_INTERVAL_MS)
+        .map(|(_, state)| state.clone());
+    cached.unwrap_or_else(refresh)
+}
+
+/// 마지막으로 확인한 전원 상태 (OS에 묻지 않으므로 다른 잠금을 잡은 상태에서도 사용 가능)
+pub fn last_known_state() -> PowerState {
+    LAST_STATE.lock().as_ref()
+        .map(|(_, state)| state.clone())
+        .unwrap_or_else(PowerState::unavailable)
+}
+
+// 전원 절약 정책 적용 여부
+//
+// "battery"(기본): 배터리 사용 중이거나 저전력 모드, "low-power": 저전력 모드일 때만, "off": 적용 안 함
+fn is_power_saving_state(state: &PowerState) -> bool {
+    match settings::get_power_saving_policy().as_str() {
+        "off" => false,
+        "low-power" => state.low_power_mode,
+        _ => state.on_battery || state.low_power_mode,
+    }
+}
+
+/// 전원 절약 정책이 적용 중인지 확인
+pub fn is_power_saving() -> bool {
+    is_power_saving_state(&current_state())
+}
+
+/// 전원 상태에 따라 사용할 워커 스레드 수 (최소 1)
+///
+/// 마지막으로 확인한 상태를 사용하므로, 워커 풀 잠금 밖에서 `current_state`로 먼저 갱신해야 합니다.
+pub fn worker_thread_limit(thread_count: usize) -> usize {
+    if !is_power_saving_state(&last_known_state()) {
+        return thread_count;
+    }
+    let ratio = settings::get_power_saving_thread_ratio().clamp(0.0, 1.0);
+    ((thread_count as f64 * ratio).ceil() as usize).clamp(1, thread_count.max(1))
+}
+
+/// 전원 상태상 GPU 가속을 사용해도 되는지 확인
+pub fn allows_gpu() -> bool {
+    !(settings::is_power_saving_gpu_disabled() && is_power_saving())
+}
+
+/// 보관한 전원 상태 삭제
+pub fn reset() {
+    *LAST_STATE.lock() = None;
+}
+
+// Linux: /sys/class/power_supply의 AC 어댑터/배터리 상태와 ACPI 플랫폼 프로필
+#[cfg(target_os = "linux")]
+fn probe_power_state() -> PowerState {
+    let read = |path: std::path::PathBuf| std::fs::read_to_string(path).ok().map(|value| value.trim().to_string());
+
+    let supplies: Vec<std::path::PathBuf> = match std::fs::read_dir("/sys/class/power_supply") {
+        Ok(entries) => entries.flatten().map(|entry| entry.path()).collect(),
+        Err(_) => return PowerState::unavailable(),
+    };
+
+    let mut ac_online = false;
+    let mut discharging = false;
+    let mut battery_percent = None;
+    for supply in &supplies {
+        // 마우스, 키보드 같은 주변기기 배터리는 제외
+        if read(supply.join("scope")).as_deref() == Some("Device") {
+            continue;
+        }
+        match read(supply.join("type")).as_deref() {
+            Some("Mains") | Some("USB") => {
+                ac_online |= read(supply.join("online")).as_deref() == Some("1");
+            },
+            Some("Battery") => {
+                discharging |= read(supply.join("status")).as_deref() == Some("Discharging");
+                if battery_percent.is_none() {
+                    battery_percent = read(supply.join("capacity")).and_then(|value| value.parse::<f64>().ok());
+                }
+            },
+            _ => {}
+        }
+    }
+
+    let low_power_mode = read("/sys/firmware/acpi/platform_profile".into()).as_deref() == Some("low-power");
+
+    PowerState {
+        on_battery: discharging && !ac_online,
+        low_power_mode,
+        battery_percent,
+        source: "sysfs",
+    }
+}
+
+// macOS: pmset 출력 (전원 공급원, 잔량, 저전력 모드)
+#[cfg(target_os = "macos")]
+fn probe_power_state() -> PowerState {
+    let pmset = |args: &[&str]| {
+        std::process::Command::new("pmset")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).to_string())
+    };
+
+    let battery = match pmset(&["-g", "batt"]) {
+        Some(output) => output,
+        None => return PowerState::unavailable(),
+    };
+
+    let battery_percent = battery
+        .split_whitespace()
+        .find(|word| word.contains('%'))
+        .and_then(|word| word.trim_end_matches([';', '%']).parse::<f64>().ok());
+    let low_power_mode = pmset(&["-g"]).is_some_and(|output| {
+        output.lines().any(|line| {
+            let mut parts = line.split_whitespace();
+            parts.next() == Some("lowpowermode") && parts.next() == Some("1")
+        })
+    });
+
+    PowerState {
+        on_battery: battery.contains("'Battery Power'"),
+        low_power_mode,
+        battery_percent,
+        source: "pmset",
+    }
+}
+
+// Windows: GetSystemPowerStatus (Reserved1은 배터리 절약 모드 플래그)
+#[cfg(all(target_os = "windows", feature = "use-winapi"))]
+fn probe_power_state() -> PowerState {
+    use winapi::um::winbase::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+    let mut status: SYSTEM_POWER_STATUS = unsafe { std::mem::zeroed() };
+    if unsafe { GetSystemPowerStatus(&mut status) } == 0 {
+        return PowerState::unavailable();
+    }
+
+    PowerState {
+        on_battery: status.ACLineStatus == 0,
+        low_power_mode: status.Reserved1 == 1,
+        battery_percent: (status.BatteryLifePercent <= 100).then_some(status.BatteryLifePercent as f64),
+        source: "winapi",
+    }
+}
+
+// 그 외 플랫폼이거나 Windows API 사용이 비활성화된 경우
+#[cfg(not(any(
+    all(target_os = "windows", feature = "use-winapi"),
+    target_os = "linux",
+    target_os = "macos"
+)))]
+fn probe_power_state() -> PowerState {
+    PowerState::unavailable()
+}
+
+/// 전원 상태 조회
+///
+/// OS에서 전원 상태를 다시 읽고, 현재 전원 절약 정책과 그에 따른 워커 스레드 비율,
+/// GPU 가속 허용 여부를 함께 JSON으로 반환합니다.
+/// Electron `powerMonitor`의 on-ac/on-battery 이벤트에서 호출하면 바로 반영됩니다.
+#[napi]
+pub fn get_power_state() -> String {
+    let state = refresh();
+    let power_saving = is_power_saving_state(&state);
+
+    json!({
+        "on_battery": state.on_battery,
+        "low_power_mode": state.low_power_mode,
+        "battery_percent": state.battery_percent,
+        "source": state.source,
+        "policy": settings::get_power_saving_policy(),
+        "power_saving": power_saving,
+        "worker_thread_ratio": if power_saving { settings::get_power_saving_thread_ratio().clamp(0.0, 1.0) } else { 1.0 },
+        "gpu_allowed": !(power_saving && settings::is_power_saving_gpu_disabled())
+    }).to_string()
+}
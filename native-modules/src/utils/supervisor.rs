@@ -0,0 +1,180 @@
+//! 백그라운드 서브시스템 감시 모듈
+//!
+//! 메모리 모니터, 워커 스레드, GC 예약 스레드처럼 오래 실행되는 루프를 감싸서
+//! 패닉으로 멈추면 지수 백오프 후 다시 시작하고, 재시작 횟수와 마지막 오류를 기록합니다.
+//! 루프가 정상적으로 반환하면 서브시스템이 종료된 것으로 보고 다시 시작하지 않습니다.
+
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use log::{error, info};
+use napi_derive::napi;
+use crate::utils::clock;
+
+// 재시작 대기 시간 (첫 재시작, 최대)
+const INITIAL_BACKOFF_MS: u64 = 250;
+const MAX_BACKOFF_MS: u64 = 30_000;
+
+// 이 시간 이상 정상 실행된 뒤의 패닉은 연속 실패로 보지 않고 백오프 초기화
+const STABLE_RUN_MS: u64 = 60_000;
+
+// 재시작 대기 중 중지 요청 확인 주기
+const STOP_POLL_MS: u64 = 100;
+
+// 서브시스템 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SubsystemState {
+    Running,
+    Restarting,
+    Stopped,
+}
+
+impl SubsystemState {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SubsystemState::Running => "running",
+            SubsystemState::Restarting => "restarting",
+            SubsystemState::Stopped => "stopped",
+        }
+    }
+}
+
+// 서브시스템별 상태 기록
+#[derive(Debug, Clone)]
+struct SubsystemHealth {
+    state: SubsystemState,
+    started_at: u64,
+    restarts: u64,
+    last_panic: Option<String>,
+    last_panic_at: Option<u64>,
+    next_backoff_ms: u64,
+}
+
+static SUBSYSTEMS: Lazy<Mutex<BTreeMap<String, SubsystemHealth>>> =
+    Lazy::new(|| Mutex::new(BTreeMap::new()));
+
+/// 패닉 페이로드에서 메시지 추출
+pub fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    payload.downcast_ref::<&str>().map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "알 수 없는 패닉".to_string())
+}
+
+fn update<F: FnOnce(&mut SubsystemHealth)>(name: &str, f: F) {
+    if let Some(health) = SUBSYSTEMS.lock().get_mut(name) {
+        f(health);
+    }
+}
+
+/// 감시 하에 루프 실행 (현재 스레드에서 실행)
+///
+/// `body`가 패닉하면 백오프 후 다시 호출하고, 정상 반환하면 종료합니다.
+/// 재시작을 기다리는 동안 `should_stop`이 true가 되면 다시 시작하지 않고 종료합니다.
+pub fn run_supervised<F, S>(name: &str, body: F, should_stop: S)
+where
+    F: Fn(),
+    S: Fn() -> bool,
+{
+    let restarts = SUBSYSTEMS.lock().get(name).map(|h| h.restarts).unwrap_or(0);
+    SUBSYSTEMS.lock().insert(name.to_string(), SubsystemHealth {
+        state: SubsystemState::Running,
+        started_at: clock::now_millis(),
+        restarts,
+        last_panic: None,
+        last_panic_at: None,
+        next_backoff_ms: INITIAL_BACKOFF_MS,
+    });
+
+    let mut backoff_ms = INITIAL_BACKOFF_MS;
+
+    loop {
+        let started = Instant::now();
+        update(name, |h| {
+            h.state = SubsystemState::Running;
+            h.started_at = clock::now_millis();
+        });
+
+        let payload = match panic::catch_unwind(AssertUnwindSafe(&body)) {
+            Ok(()) => {
+                update(name, |h| h.state = SubsystemState::Stopped);
+                return;
+            },
+            Err(payload) => payload,
+        };
+
+        if started.elapsed() >= Duration::from_millis(STABLE_RUN_MS) {
+            backoff_ms = INITIAL_BACKOFF_MS;
+        }
+
+        let message = panic_message(payload.as_ref());
+        error!("서브시스템 {} 패닉: {} ({}ms 후 재시작)", name, message, backoff_ms);
+        update(name, |h| {
+            h.state = SubsystemState::Restarting;
+            h.restarts += 1;
+            h.last_panic = Some(message);
+            h.last_panic_at = Some(clock::now_millis());
+            h.next_backoff_ms = backoff_ms;
+        });
+
+        // 백오프 대기 (중지 요청 시 즉시 종료)
+        let deadline = Instant::now() + Duration::from_millis(backoff_ms);
+        while Instant::now() < deadline {
+            if should_stop() {
+                update(name, |h| h.state = SubsystemState::Stopped);
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(STOP_POLL_MS).min(deadline.saturating_duration_since(Instant::now())));
+        }
+        if should_stop() {
+            update(name, |h| h.state = SubsystemState::Stopped);
+            return;
+        }
+
+        backoff_ms = (backoff_ms * 2).min(MAX_BACKOFF_MS);
+        info!("서브시스템 {} 재시작", name);
+    }
+}
+
+/// 종료된 서브시스템 기록 삭제 (아직 실행 중인 루프의 기록은 유지)
+pub fn reset() {
+    SUBSYSTEMS.lock().retain(|_, health| health.state != SubsystemState::Stopped);
+}
+
+/// 서브시스템 상태 목록
+pub fn get_health() -> Value {
+    let now = clock::now_millis();
+    let subsystems = SUBSYSTEMS.lock();
+
+    let entries: Vec<Value> = subsystems.iter()
+        .map(|(name, health)| json!({
+            "name": name,
+            "state": health.state.as_str(),
+            "uptime_ms": if health.state == SubsystemState::Running {
+                now.saturating_sub(health.started_at)
+            } else {
+                0
+            },
+            "restarts": health.restarts,
+            "last_panic": health.last_panic,
+            "last_panic_at": health.last_panic_at,
+            "next_backoff_ms": health.next_backoff_ms
+        }))
+        .collect();
+
+    json!({
+        "healthy": subsystems.values().all(|h| h.state != SubsystemState::Restarting),
+        "total_restarts": subsystems.values().map(|h| h.restarts).sum::<u64>(),
+        "subsystems": entries,
+        "timestamp": now
+    })
+}
+
+/// 백그라운드 서브시스템 상태 가져오기 (JSON)
+#[napi]
+pub fn get_subsystem_health() -> String {
+    get_health().to_string()
+}
+
@@ -0,0 +1,166 @@
+//! 초기화 후 예열 모듈
+//!
+//! 첫 GPU/분석 작업이 GPU 컨텍스트 생성, 셰이더 컴파일, 버퍼 할당 비용을 한꺼번에 치르지 않도록
+//! `initialize_native_modules` 직후 백그라운드 스레드에서 미리 준비합니다.
+
+use napi::Error;
+use napi_derive::napi;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde_json::{json, Value};
+use log::{info, warn};
+use std::sync::Arc;
+use std::time::Instant;
+use crate::gpu::{accelerator, shader, settings as gpu_settings};
+use crate::memory::pool;
+use crate::utils::{clock, power};
+
+// 풀마다 미리 할당할 버퍼 수
+const PRIME_BUFFERS_PER_POOL: usize = 4;
+
+// 미리 할당할 최대 버퍼 크기 (이보다 큰 풀은 상주 메모리를 늘리므로 제외)
+const PRIME_MAX_BUFFER_SIZE: usize = 256 * 1024;
+
+/// 예열 진행 상태
+#[derive(Debug, Clone)]
+enum WarmUpState {
+    NotStarted,
+    Running { started_at: u64 },
+    Completed(Value),
+}
+
+static WARM_UP_STATE: Lazy<Mutex<WarmUpState>> = Lazy::new(|| Mutex::new(WarmUpState::NotStarted));
+
+// 예열 완료를 기다리는 호출 (여러 번 호출해도 예열은 한 번만 실행, 정리 후에는 새 셀로 교체)
+static WARM_UP: Lazy<Mutex<Arc<tokio::sync::OnceCell<Value>>>> =
+    Lazy::new(|| Mutex::new(Arc::new(tokio::sync::OnceCell::new())));
+
+// 단계 실행 결과 (status: ok, skipped, failed)
+fn step(run: impl FnOnce() -> Result<(&'static str, Value), String>) -> Value {
+    let started = Instant::now();
+    let (status, detail, error) = match run() {
+        Ok((status, detail)) => (status, detail, None),
+        Err(e) => ("failed", json!({}), Some(e)),
+    };
+    json!({
+        "status": status,
+        "error": error,
+        "detail": detail,
+        "duration_ms": started.elapsed().as_secs_f64() * 1000.0
+    })
+}
+
+// GPU 컨텍스트 준비 (설정에서 꺼져 있으면 건너뜀)
+fn warm_up_gpu() -> Value {
+    step(|| {
+        if !gpu_settings::is_hardware_acceleration_enabled() {
+            return Ok(("skipped", json!({ "reason": "disabled" })));
+        }
+        if !power::allows_gpu() {
+            return Ok(("skipped", json!({ "reason": "power_saving" })));
+        }
+        accelerator::initialize_gpu().map_err(|e| e.reason.clone())?;
+        Ok(("ok", json!({ "device_name": accelerator::get_device_name() })))
+    })
+}
+
+// 기본 계산 셰이더 컴파일 (GPU 컨텍스트가 없으면 건너뜀)
+fn warm_up_shaders() -> Value {
+    step(|| {
+        if !accelerator::is_gpu_initialized() {
+            return Ok(("skipped", json!({ "reason": "gpu_not_initialized" })));
+        }
+        let from_disk_cache = shader::initialize_shaders().map_err(|e| e.reason.clone())?;
+        Ok(("ok", json!({
+            "compiled": shader::CORE_SHADERS.len(),
+            "from_disk_cache": from_disk_cache
+        })))
+    })
+}
+
+// 메모리 풀 버퍼 미리 할당
+fn warm_up_memory() -> Value {
+    step(|| {
+        let primed = pool::prime_memory_pools(PRIME_BUFFERS_PER_POOL, PRIME_MAX_BUFFER_SIZE)
+            .map_err(|e| e.reason.clone())?;
+        Ok(("ok", json!({ "primed_buffers": primed })))
+    })
+}
+
+// 모든 예열 단계 실행
+fn run_warm_up(started_at: u64) -> Value {
+    let started = Instant::now();
+    let gpu = warm_up_gpu();
+    let shaders = warm_up_shaders();
+    let memory = warm_up_memory();
+
+    let ok = [&gpu, &shaders, &memory].iter().all(|step| step["status"] != "failed");
+    let report = json!({
+        "ok": ok,
+        "started_at": started_at,
+        "completed_at": clock::now_millis(),
+        "duration_ms": started.elapsed().as_secs_f64() * 1000.0,
+        "steps": { "gpu": gpu, "shaders": shaders, "memory": memory }
+    });
+
+    if ok {
+        info!("예열 완료 ({:.1}ms)", report["duration_ms"].as_f64().unwrap_or(0.0));
+    } else {
+        warn!("예열 중 일부 단계 실패: {}", report["steps"]);
+    }
+    report
+}
+
+/// 네이티브 모듈 예열
+///
+/// GPU 컨텍스트 초기화, 기본 셰이더 컴파일, 메모리 풀 버퍼 할당을 백그라운드 스레드에서 실행하고
+/// 끝나면 단계별 결과(ok, skipped, failed)를 JSON으로 반환합니다.
+/// 한 번만 실행되며, 이미 시작했거나 끝난 경우 같은 결과를 기다려 반환합니다.
+#[napi]
+pub async fn warm_up() -> napi::Result<String> {
+    let cell = WARM_UP.lock().clone();
+    // 실행 중 `reset`되면 상태를 덮어쓰지 않음
+    let set_state = |state: WarmUpState| {
+        if Arc::ptr_eq(&WARM_UP.lock(), &cell) {
+            *WARM_UP_STATE.lock() = state;
+        }
+    };
+
+    let report = cell.get_or_try_init(|| async {
+        let started_at = clock::now_millis();
+        set_state(WarmUpState::Running { started_at });
+
+        let report = tokio::task::spawn_blocking(move || run_warm_up(started_at))
+            .await
+            .map_err(|e| {
+                set_state(WarmUpState::NotStarted);
+                Error::from_reason(format!("예열 실행 실패: {}", e))
+            })?;
+
+        set_state(WarmUpState::Completed(report.clone()));
+        Ok::<Value, Error>(report)
+    }).await?;
+
+    Ok(report.to_string())
+}
+
+/// 예열 진행 상태 조회
+///
+/// `state`는 not_started, running, completed 중 하나이며 완료된 경우 `report`에 `warm_up` 결과가 들어갑니다.
+#[napi]
+pub fn get_warm_up_status() -> String {
+    let status = match &*WARM_UP_STATE.lock() {
+        WarmUpState::NotStarted => json!({ "state": "not_started", "report": null }),
+        WarmUpState::Running { started_at } => json!({ "state": "running", "started_at": started_at, "report": null }),
+        WarmUpState::Completed(report) => json!({ "state": "completed", "report": report }),
+    };
+    status.to_string()
+}
+
+/// 예열 상태 초기화 (다음 `warm_up` 호출 때 다시 실행)
+///
+/// 이미 진행 중인 예열은 끝까지 실행되지만 그 결과는 상태에 반영되지 않습니다.
+pub fn reset() {
+    *WARM_UP.lock() = Arc::new(tokio::sync::OnceCell::new());
+    *WARM_UP_STATE.lock() = WarmUpState::NotStarted;
+}
@@ -0,0 +1,138 @@
+//! JavaScript 작업 핸들러 등록 모듈
+//!
+//! Electron 쪽에서 네이티브 모듈을 다시 빌드하지 않고 워커 풀 작업 유형을 추가할 수 있도록
+//! JS 함수를 작업 유형별로 등록합니다. 워커 스레드는 등록된 함수를 JS 메인 스레드에서 호출하고
+//! 결과(문자열 또는 Promise)가 나올 때까지 기다립니다.
+
+use napi::bindgen_prelude::{block_on, Either, Promise};
+use napi::threadsafe_function::{ErrorStrategy, ThreadSafeCallContext, ThreadsafeFunction};
+use napi::{Env, Error, JsFunction};
+use napi_derive::napi;
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use log::debug;
+use super::pool;
+
+/// JS 작업 핸들러 (작업 데이터 JSON 문자열 전달, 결과 JSON 문자열 또는 Promise 반환)
+///
+/// 핸들러가 예외를 던지거나 잘못된 값을 반환해도 프로세스가 종료되지 않고 작업 오류가 되도록
+/// `CalleeHandled`를 사용하므로, 핸들러는 Node 콜백 형식 `(err, data)`로 호출됩니다 (`err`는 항상 null).
+pub type JsTaskHandler = ThreadsafeFunction<String, ErrorStrategy::CalleeHandled>;
+
+// 결과를 기다리는 동안 작업 취소/시간 초과 여부를 확인하는 간격
+const ABANDON_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+// 작업 유형 -> JS 핸들러
+static JS_TASK_HANDLERS: Lazy<RwLock<HashMap<String, Arc<JsTaskHandler>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// 등록된 JS 핸들러 가져오기
+pub fn get(task_type: &str) -> Option<Arc<JsTaskHandler>> {
+    JS_TASK_HANDLERS.read().get(task_type).cloned()
+}
+
+/// JS 핸들러가 등록된 작업 유형인지 확인
+pub fn is_registered(task_type: &str) -> bool {
+    JS_TASK_HANDLERS.read().contains_key(task_type)
+}
+
+/// 등록된 JS 작업 유형 목록 (이름순)
+pub fn registered_task_types() -> Vec<String> {
+    let mut task_types: Vec<String> = JS_TASK_HANDLERS.read().keys().cloned().collect();
+    task_types.sort();
+    task_types
+}
+
+/// 모든 JS 핸들러 해제
+pub fn clear() {
+    JS_TASK_HANDLERS.write().clear();
+}
+
+/// 워커 스레드에서 JS 핸들러 실행
+///
+/// 핸들러가 결과를 내기 전에 `is_abandoned`가 true가 되면(취소, 시간 초과) 기다리지 않고 오류를 반환해
+/// 워커가 다른 작업을 처리할 수 있게 합니다. JS 메인 스레드가 막혀 있으면 핸들러가 실행되지 않으므로
+/// JS 핸들러 작업은 비동기 `submit_task`로 제출해야 합니다.
+pub fn call_handler(
+    handler: &JsTaskHandler,
+    data: &str,
+    is_abandoned: impl Fn() -> bool,
+) -> Result<String, Error> {
+    block_on(async {
+        let result = async {
+            match handler.call_async::<Either<String, Promise<String>>>(Ok(data.to_string())).await? {
+                Either::A(result) => Ok(result),
+                Either::B(promise) => promise.await,
+            }
+        };
+        tokio::pin!(result);
+
+        let mut ticker = tokio::time::interval(ABANDON_CHECK_INTERVAL);
+        loop {
+            tokio::select! {
+                result = &mut result => return result,
+                _ = ticker.tick() => {
+                    if is_abandoned() {
+                        return Err(Error::from_reason("Task was abandoned before the JavaScript handler finished"));
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// JS 작업 핸들러 등록
+///
+/// `handler(err, data)`는 작업 데이터 문자열을 받아 결과 JSON 문자열(또는 그 Promise)을 반환해야 합니다.
+/// 같은 유형의 JS 핸들러가 있으면 교체하며, 네이티브 기본 작업 유형은 덮어쓸 수 없습니다.
+#[napi(js_name = "register_task_handler")]
+pub fn register_task_handler(env: Env, task_type: String, handler: JsFunction) -> napi::Result<bool> {
+    if task_type.trim().is_empty() {
+        return Err(Error::from_reason("Task type must not be empty"));
+    }
+    if pool::is_native_task_type(&task_type) {
+        return Err(Error::from_reason(format!("Task type is reserved by a native handler: {}", task_type)));
+    }
+
+    let mut tsfn: JsTaskHandler = handler.create_threadsafe_function(
+        0,
+        |ctx: ThreadSafeCallContext<String>| Ok(vec![ctx.value]),
+    )?;
+
+    // 핸들러가 등록되어 있어도 Node 프로세스 종료를 막지 않도록 설정
+    tsfn.unref(&env)?;
+
+    let replaced = JS_TASK_HANDLERS.write().insert(task_type.clone(), Arc::new(tsfn)).is_some();
+    debug!("JS 작업 핸들러 {}: {}", if replaced { "교체" } else { "등록" }, task_type);
+    Ok(true)
+}
+
+/// JS 작업 핸들러 해제
+///
+/// 이미 대기 중인 해당 유형의 작업은 실행될 때 알 수 없는 작업 유형 오류로 끝납니다.
+/// 등록되지 않은 유형이면 `false`를 반환합니다.
+#[napi(js_name = "unregister_task_handler")]
+pub fn unregister_task_handler(task_type: String) -> bool {
+    let removed = JS_TASK_HANDLERS.write().remove(&task_type).is_some();
+    if removed {
+        debug!("JS 작업 핸들러 해제: {}", task_type);
+    }
+    removed
+}
+
+/// 등록된 작업 유형 목록 조회
+///
+/// 네이티브 기본 작업 유형(`native`)과 JS에서 등록한 작업 유형(`javascript`)을 JSON으로 반환합니다.
+#[napi(js_name = "get_registered_task_types")]
+pub fn get_registered_task_types() -> napi::Result<String> {
+    let mut native = pool::get_available_task_types()?;
+    native.sort();
+
+    Ok(serde_json::json!({
+        "native": native,
+        "javascript": registered_task_types()
+    }).to_string())
+}
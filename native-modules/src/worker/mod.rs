@@ -1,3 +1,4 @@
+pub mod js_handlers;
 pub mod pool;
 pub mod task;
 
@@ -6,15 +7,21 @@ use pool::{
     initialize_worker_pool as pool_initialize_worker_pool,
     shutdown_worker_pool as pool_shutdown_worker_pool,
     get_worker_pool_stats as pool_get_worker_pool_stats,
-    submit_task as pool_submit_task
+    submit_task as pool_submit_task,
+    enqueue_task as pool_enqueue_task,
+    cancel_task as pool_cancel_task,
+    set_max_pending_tasks as pool_set_max_pending_tasks,
+    task_timeout,
+    wait_for_result,
+    TaskPriority
 };
 
 use napi_derive::napi;
 
 // napi 인터페이스 함수
 #[napi]
-pub fn initialize_worker_pool(thread_count: u32) -> napi::Result<bool> {
-    pool_initialize_worker_pool(thread_count)
+pub fn initialize_worker_pool(thread_count: u32, max_pending_tasks: Option<u32>) -> napi::Result<bool> {
+    pool_initialize_worker_pool(thread_count, max_pending_tasks)
         .map_err(|e| napi::Error::from_reason(format!("Failed to initialize worker pool: {}", e)))
 }
 
@@ -24,12 +31,55 @@ pub fn shutdown_worker_pool() -> napi::Result<bool> {
         .map_err(|e| napi::Error::from_reason(format!("Failed to shutdown worker pool: {}", e)))
 }
 
-#[napi(js_name = "submit_task")]
-pub fn submit_task_sync(task_type: String, data: String) -> napi::Result<String> {
-    pool_submit_task(task_type, data)
+/// 작업을 제출하고 결과가 나올 때까지 대기
+/// 
+/// JS 핸들러로 등록된 작업 유형은 JS 스레드를 막으면 실행될 수 없으므로 바로 오류를 반환합니다.
+#[napi(js_name = "submit_task_sync")]
+pub fn submit_task_sync(task_type: String, data: String, timeout_ms: Option<u32>) -> napi::Result<String> {
+    pool_submit_task(task_type, data, timeout_ms)
         .map_err(|e| napi::Error::from_reason(format!("Failed to submit task: {}", e)))
 }
 
+/// 작업을 워커 풀 스레드에서 실행하고 결과를 Promise로 반환
+/// 
+/// `task_id`를 지정하면 `cancel_task`로 취소할 수 있습니다.
+/// `priority`는 "high", "normal"(기본값), "low" 중 하나입니다.
+/// 대기열이 가득 차면 `QueueFull` 코드의 오류로 거부됩니다.
+/// `timeout_ms`(기본 30초) 안에 끝나지 않으면 `timed_out: true`가 포함된 오류 JSON을 반환합니다.
+#[napi(js_name = "submit_task")]
+pub async fn submit_task(
+    task_type: String,
+    data: String,
+    task_id: Option<String>,
+    priority: Option<String>,
+    timeout_ms: Option<u32>,
+) -> napi::Result<String> {
+    let priority = match priority {
+        Some(value) => TaskPriority::parse(&value)?,
+        None => TaskPriority::default(),
+    };
+    
+    // 상태 코드를 유지해 JS 측에서 백프레셔(QueueFull)를 구분할 수 있도록 함
+    let (task_id, receiver) = pool_enqueue_task(task_type.clone(), data, task_id, priority)
+        .map_err(|e| napi::Error::new(e.status, format!("Failed to submit task: {}", e.reason)))?;
+    
+    wait_for_result(task_id, task_type, receiver, task_timeout(timeout_ms)).await
+        .map_err(|e| napi::Error::from_reason(format!("Failed to receive task result: {}", e)))
+}
+
+#[napi(js_name = "cancel_task")]
+pub fn cancel_task(task_id: String) -> napi::Result<bool> {
+    pool_cancel_task(&task_id)
+        .map_err(|e| napi::Error::from_reason(format!("Failed to cancel task: {}", e)))
+}
+
+/// 워커 풀 최대 대기 작업 수 설정
+#[napi(js_name = "set_max_pending_tasks")]
+pub fn set_max_pending_tasks(max_pending_tasks: u32) -> napi::Result<bool> {
+    pool_set_max_pending_tasks(max_pending_tasks)
+        .map_err(|e| napi::Error::from_reason(format!("Failed to set max pending tasks: {}", e)))
+}
+
 #[napi]
 pub fn get_worker_pool_stats() -> napi::Result<String> {
     let stats = pool_get_worker_pool_stats()
@@ -1,10 +1,32 @@
-use napi::Error;
+use napi::{Error, Status};
 use serde::{Serialize, Deserialize};
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
-use parking_lot::{RwLock, Mutex};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::thread;
+use parking_lot::{RwLock, Mutex, MutexGuard, Condvar};
 use once_cell::sync::{Lazy, OnceCell};
 use std::collections::{HashMap, VecDeque};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+use log::{debug, error, warn};
+use crate::utils::{clock, power, supervisor};
+use super::js_handlers;
+
+/// 작업 핸들러 함수 타입
+pub type TaskHandler = fn(&str) -> Result<String, Error>;
+
+/// 기본 최대 대기 작업 수
+pub const DEFAULT_MAX_PENDING_TASKS: usize = 256;
+
+/// 기본 작업 제한 시간 (제출부터 결과까지, ms)
+pub const DEFAULT_TASK_TIMEOUT_MS: u64 = 30_000;
+
+// 전원 절약으로 쉬는 워커가 전원 상태를 다시 확인하는 간격
+const POWER_RECHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 네이티브 기본 작업 유형 (JS 핸들러로 덮어쓸 수 없음)
+pub const DEFAULT_TASK_TYPES: [&str; 3] = ["optimize_memory", "gpu_computation", "echo"];
 
 // 워커 풀 상태 구조체
 struct WorkerPoolState {
@@ -28,11 +50,26 @@ static WORKER_POOL: Lazy<RwLock<WorkerPoolState>> = Lazy::new(|| {
 static ACTIVE_TASKS: AtomicU64 = AtomicU64::new(0);
 static COMPLETED_TASKS: AtomicU64 = AtomicU64::new(0);
 static POOL_RUNNING: AtomicBool = AtomicBool::new(false);
+static POOL_STARTED_AT: AtomicU64 = AtomicU64::new(0);
+static TASK_ID_COUNTER: AtomicU64 = AtomicU64::new(0);
+static TASK_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+static MAX_PENDING_TASKS: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_PENDING_TASKS);
 
 // 작업 핸들러 맵 (작업 유형 -> 핸들러 함수)
-static TASK_HANDLERS: Lazy<RwLock<HashMap<String, fn(&str) -> Result<String, Error>>>> = 
+static TASK_HANDLERS: Lazy<RwLock<HashMap<String, TaskHandler>>> = 
     Lazy::new(|| RwLock::new(HashMap::new()));
 
+// 대기 중인 워커 스레드를 깨우기 위한 조건 변수
+static TASK_AVAILABLE: Condvar = Condvar::new();
+
+// 워커 스레드 핸들 (종료 시 join)
+static WORKER_THREADS: Lazy<Mutex<Vec<thread::JoinHandle<()>>>> =
+    Lazy::new(|| Mutex::new(Vec::new()));
+
+// 실행 중인 작업의 취소/시간 초과 플래그 (작업 ID -> 플래그)
+static RUNNING_TASKS: Lazy<Mutex<HashMap<String, Arc<TaskFlags>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
 /// 워커 풀 통계 구조체
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct WorkerPoolStats {
@@ -43,6 +80,12 @@ pub struct WorkerPoolStats {
     pub idle_workers: u32,
     pub pending_tasks: u64,
     pub failed_tasks: u64,
+    pub cancelled_tasks: u64,
+    pub rejected_tasks: u64,
+    pub timed_out_tasks: u64,
+    pub panicked_tasks: u64,
+    pub stolen_tasks: u64,
+    pub max_pending_tasks: u64,
     pub total_tasks: u64,
     pub uptime_ms: u64,
     pub timestamp: u64,
@@ -54,12 +97,179 @@ pub struct WorkerPoolStats {
 pub struct WorkerPool {
     workers: Vec<Worker>,
     max_workers: usize,
-    task_queue: VecDeque<Task>,
+    max_pending_tasks: usize,
+    // 워커별 작업 큐 (쉬는 워커는 다른 워커의 큐에서 작업을 가져감)
+    queues: Vec<TaskQueue>,
     active: bool,
-    task_handlers: HashMap<String, fn(&str) -> Result<String, Error>>,
+    task_handlers: HashMap<String, TaskHandler>,
     pub stats: WorkerPoolStats,
 }
 
+impl WorkerPool {
+    // 워커/큐 상태로부터 통계 갱신
+    fn refresh_stats(&mut self) {
+        let active_workers = self.workers.iter().filter(|w| w.active).count() as u32;
+        self.stats.active_workers = active_workers;
+        self.stats.idle_workers = (self.workers.len() as u32).saturating_sub(active_workers);
+        self.stats.active_tasks = active_workers as u64;
+        self.stats.pending_tasks = self.pending_len() as u64;
+        self.stats.max_pending_tasks = self.max_pending_tasks as u64;
+    }
+    
+    fn pending_len(&self) -> usize {
+        self.queues.iter().map(TaskQueue::len).sum()
+    }
+    
+    fn contains_task(&self, task_id: &str) -> bool {
+        self.queues.iter().any(|queue| queue.iter().any(|task| task.id == task_id))
+    }
+    
+    // 쉬고 있는 워커 중 큐가 가장 짧은 워커에 배정 (모두 바쁘면 큐가 가장 짧은 워커)
+    // 전원 절약 중에는 작업을 가져가는 워커에만 배정
+    fn push_task(&mut self, task: Task) {
+        let limit = power::worker_thread_limit(self.queues.len());
+        let target = (0..limit.min(self.queues.len()))
+            .min_by_key(|&id| (self.workers.get(id).is_some_and(|w| w.active), self.queues[id].len()))
+            .unwrap_or(0);
+        if let Some(queue) = self.queues.get_mut(target) {
+            queue.push(task);
+        }
+    }
+    
+    // 워커가 실행할 다음 작업 선택
+    //
+    // 자기 큐를 먼저 보고, 다른 워커의 큐에 더 높은 우선순위 작업이 있거나 자기 큐가 비어 있으면
+    // 그 큐에서 가져옵니다 (우선순위가 같으면 대기 작업이 가장 많은 큐). 가져온 작업인지 함께 반환합니다.
+    fn take_task(&mut self, worker_id: usize) -> Option<(Task, bool)> {
+        let own = self.queues.get(worker_id).and_then(TaskQueue::best_priority);
+        let victim = (0..self.queues.len())
+            .filter(|&id| id != worker_id)
+            .filter_map(|id| self.queues[id].best_priority().map(|priority| (priority, self.queues[id].len(), id)))
+            .max()
+            .filter(|&(priority, _, _)| own.is_none_or(|own| priority > own));
+        
+        match victim {
+            Some((_, _, id)) => self.queues[id].pop().map(|task| (task, true)),
+            None => self.queues.get_mut(worker_id).and_then(TaskQueue::pop).map(|task| (task, false)),
+        }
+    }
+    
+    fn remove_task(&mut self, task_id: &str) -> Option<Task> {
+        self.queues.iter_mut().find_map(|queue| queue.remove(task_id))
+    }
+    
+    // 주어진 우선순위보다 낮은 작업 중 가장 나중에 들어온 작업을 제거
+    fn evict_lower_than(&mut self, priority: TaskPriority) -> Option<Task> {
+        for lane in [TaskPriority::Low, TaskPriority::Normal] {
+            if lane >= priority {
+                break;
+            }
+            let newest = (0..self.queues.len())
+                .filter_map(|id| self.queues[id].lane(lane).back().map(|task| (task.sequence, id)))
+                .max();
+            if let Some((_, id)) = newest {
+                return self.queues[id].lane_mut(lane).pop_back();
+            }
+        }
+        None
+    }
+    
+    fn drain_all(&mut self) -> Vec<Task> {
+        self.queues.iter_mut().flat_map(TaskQueue::drain).collect()
+    }
+}
+
+/// 작업 우선순위
+/// 
+/// 대화형 타이핑 분석 작업은 `High`로 제출해 무거운 GPU/메모리 작업에 밀리지 않도록 합니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TaskPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl TaskPriority {
+    /// 문자열에서 우선순위 파싱 ("high", "normal", "low")
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        match value.to_lowercase().as_str() {
+            "high" => Ok(TaskPriority::High),
+            "normal" => Ok(TaskPriority::Normal),
+            "low" => Ok(TaskPriority::Low),
+            _ => Err(Error::new(Status::InvalidArg, format!("Unknown task priority: {}", value))),
+        }
+    }
+}
+
+// 우선순위별 작업 큐 - 높은 우선순위부터 FIFO 순서로 꺼냄
+#[derive(Debug, Default)]
+struct TaskQueue {
+    high: VecDeque<Task>,
+    normal: VecDeque<Task>,
+    low: VecDeque<Task>,
+}
+
+impl TaskQueue {
+    fn lane(&self, priority: TaskPriority) -> &VecDeque<Task> {
+        match priority {
+            TaskPriority::High => &self.high,
+            TaskPriority::Normal => &self.normal,
+            TaskPriority::Low => &self.low,
+        }
+    }
+    
+    fn lane_mut(&mut self, priority: TaskPriority) -> &mut VecDeque<Task> {
+        match priority {
+            TaskPriority::High => &mut self.high,
+            TaskPriority::Normal => &mut self.normal,
+            TaskPriority::Low => &mut self.low,
+        }
+    }
+    
+    fn push(&mut self, task: Task) {
+        self.lane_mut(task.priority).push_back(task);
+    }
+    
+    fn pop(&mut self) -> Option<Task> {
+        self.high.pop_front()
+            .or_else(|| self.normal.pop_front())
+            .or_else(|| self.low.pop_front())
+    }
+    
+    fn len(&self) -> usize {
+        self.high.len() + self.normal.len() + self.low.len()
+    }
+    
+    // 대기 중인 작업의 가장 높은 우선순위
+    fn best_priority(&self) -> Option<TaskPriority> {
+        [TaskPriority::High, TaskPriority::Normal, TaskPriority::Low]
+            .into_iter()
+            .find(|&priority| !self.lane(priority).is_empty())
+    }
+    
+    fn iter(&self) -> impl Iterator<Item = &Task> {
+        self.high.iter().chain(self.normal.iter()).chain(self.low.iter())
+    }
+    
+    fn remove(&mut self, task_id: &str) -> Option<Task> {
+        for lane in [&mut self.high, &mut self.normal, &mut self.low] {
+            if let Some(position) = lane.iter().position(|task| task.id == task_id) {
+                return lane.remove(position);
+            }
+        }
+        None
+    }
+    
+    fn drain(&mut self) -> Vec<Task> {
+        let mut tasks: Vec<Task> = self.high.drain(..).collect();
+        tasks.extend(self.normal.drain(..));
+        tasks.extend(self.low.drain(..));
+        tasks
+    }
+}
+
 // 워커 구조체 정의 - 미사용 필드 경고 제거를 위한 속성 추가
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -69,22 +279,54 @@ struct Worker {
     task_count: u64,
 }
 
-// 작업 구조체 정의 - 미사용 필드 경고 제거를 위한 속성 추가
+// 작업 상태 플래그 (워커와 제출자가 공유)
+#[derive(Debug, Default)]
+struct TaskFlags {
+    cancelled: AtomicBool,
+    timed_out: AtomicBool,
+}
+
+// 작업 구조체 정의
 #[derive(Debug)]
-#[allow(dead_code)]
 struct Task {
     id: String,
     task_type: String,
     data: String,
+    priority: TaskPriority,
     timestamp: u64,
+    // 제출 순서 (큐가 가득 찼을 때 가장 최근 작업을 고르기 위해 사용)
+    sequence: u64,
+    flags: Arc<TaskFlags>,
+    responder: Option<oneshot::Sender<String>>,
+}
+
+// 작업 실행 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TaskOutcome {
+    Completed,
+    Failed,
+    Panicked,
+    Cancelled,
+    TimedOut,
+}
+
+impl Task {
+    // 결과 JSON을 제출자에게 전달 (수신 측이 이미 사라졌으면 무시)
+    fn respond(mut self, response: serde_json::Value) {
+        if let Some(responder) = self.responder.take() {
+            let _ = responder.send(serde_json::to_string(&response).unwrap_or_default());
+        }
+    }
 }
 
 // WorkerPool 싱글톤 인스턴스
 static WORKER_POOL_INSTANCE: OnceCell<Mutex<WorkerPool>> = OnceCell::new();
 
 /// 워커 풀 초기화
+/// 
+/// `max_pending_tasks`를 지정하지 않으면 현재 설정값(기본 256)을 사용합니다.
 #[napi]
-pub fn initialize_worker_pool(thread_count: u32) -> Result<bool, Error> {
+pub fn initialize_worker_pool(thread_count: u32, max_pending_tasks: Option<u32>) -> Result<bool, Error> {
     // 이미 초기화되었는지 확인
     {
         let pool = WORKER_POOL.read();
@@ -101,6 +343,11 @@ pub fn initialize_worker_pool(thread_count: u32) -> Result<bool, Error> {
         thread_count
     };
     
+    if let Some(max_pending) = max_pending_tasks {
+        set_max_pending_tasks(max_pending)?;
+    }
+    let max_pending = MAX_PENDING_TASKS.load(Ordering::SeqCst);
+    
     // 워커 풀 상태 업데이트
     {
         let mut pool = WORKER_POOL.write();
@@ -111,33 +358,70 @@ pub fn initialize_worker_pool(thread_count: u32) -> Result<bool, Error> {
     }
     
     POOL_RUNNING.store(true, Ordering::SeqCst);
+    POOL_STARTED_AT.store(current_timestamp(), Ordering::SeqCst);
     
     // 워커 풀 인스턴스 초기화
-    let worker_pool = WorkerPool {
+    let stats = WorkerPoolStats {
+        thread_count: threads,
+        active_tasks: 0,
+        completed_tasks: 0,
+        active_workers: 0,
+        idle_workers: threads,
+        pending_tasks: 0,
+        failed_tasks: 0,
+        cancelled_tasks: 0,
+        rejected_tasks: 0,
+        timed_out_tasks: 0,
+        panicked_tasks: 0,
+        stolen_tasks: 0,
+        max_pending_tasks: max_pending as u64,
+        total_tasks: 0,
+        uptime_ms: 0,
+        timestamp: current_timestamp(),
+    };
+    
+    let pool_mutex = WORKER_POOL_INSTANCE.get_or_init(|| Mutex::new(WorkerPool {
         workers: Vec::new(),
         max_workers: threads as usize,
-        task_queue: VecDeque::new(),
+        max_pending_tasks: max_pending,
+        queues: Vec::new(),
         active: true,
         task_handlers: HashMap::new(),
-        stats: WorkerPoolStats {
-            thread_count: threads,
-            active_tasks: 0,
-            completed_tasks: 0,
-            active_workers: 0,
-            idle_workers: threads,
-            pending_tasks: 0,
-            failed_tasks: 0,
-            total_tasks: 0,
-            uptime_ms: 0,
-            timestamp: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_millis() as u64,
-        },
-    };
+        stats: stats.clone(),
+    }));
     
-    // 싱글톤 인스턴스 설정
-    let _ = WORKER_POOL_INSTANCE.set(Mutex::new(worker_pool));
+    // 종료 후 재초기화되는 경우 기존 인스턴스 재설정
+    {
+        let mut pool = pool_mutex.lock();
+        pool.max_workers = threads as usize;
+        pool.max_pending_tasks = max_pending;
+        pool.active = true;
+        pool.queues = (0..threads as usize).map(|_| TaskQueue::default()).collect();
+        pool.workers = (0..threads as usize)
+            .map(|id| Worker { id, active: false, task_count: 0 })
+            .collect();
+        pool.stats = stats;
+    }
+    
+    // 워커 스레드 생성
+    {
+        let mut handles = WORKER_THREADS.lock();
+        for worker_id in 0..threads as usize {
+            let handle = thread::Builder::new()
+                .name(format!("typing-stats-worker-{}", worker_id))
+                .spawn(move || {
+                    supervisor::run_supervised(
+                        &format!("worker-{}", worker_id),
+                        || worker_loop(worker_id),
+                        || WORKER_POOL_INSTANCE.get().is_none_or(|pool| !pool.lock().active),
+                    );
+                })
+                .map_err(|e| Error::from_reason(format!("Failed to spawn worker thread: {}", e)))?;
+            handles.push(handle);
+        }
+    }
+    
+    debug!("워커 풀 초기화 완료: {} 스레드", threads);
     
     // 기본 작업 핸들러 등록
     register_default_task_handlers();
@@ -167,12 +451,40 @@ pub fn shutdown_worker_pool() -> Result<bool, Error> {
     let mut handlers = TASK_HANDLERS.write();
     handlers.clear();
     
-    // 워커 풀 인스턴스에도 변경 적용
+    // 워커 풀 인스턴스에도 변경 적용 - 대기 중인 작업은 종료 오류로 응답
+    if let Some(pool_mutex) = WORKER_POOL_INSTANCE.get() {
+        let pending: Vec<Task> = {
+            let mut pool = pool_mutex.lock();
+            pool.active = false;
+            // 실행 중인 작업 취소 - JS 핸들러 작업은 이 스레드(JS 메인 스레드)가 아래에서 join하는 동안
+            // 핸들러가 실행될 수 없으므로, 취소 플래그로 다음 취소 확인(ABANDON_CHECK_INTERVAL) 때 기다림을 끝내게 함
+            for flags in RUNNING_TASKS.lock().values() {
+                flags.cancelled.store(true, Ordering::SeqCst);
+            }
+            let pending = pool.drain_all();
+            pool.refresh_stats();
+            pending
+        };
+        
+        for task in pending {
+            let response = task_error_json(&task.task_type, 0, "Worker pool was shut down".to_string());
+            task.respond(response);
+        }
+    }
+    
+    // 모든 워커 스레드를 깨우고 종료 대기
+    TASK_AVAILABLE.notify_all();
+    let handles = std::mem::take(&mut *WORKER_THREADS.lock());
+    for handle in handles {
+        if handle.join().is_err() {
+            warn!("워커 스레드가 비정상 종료되었습니다");
+        }
+    }
+    
     if let Some(pool_mutex) = WORKER_POOL_INSTANCE.get() {
         let mut pool = pool_mutex.lock();
-        pool.active = false;
-        pool.task_queue.clear();
         pool.workers.clear();
+        pool.refresh_stats();
     }
     
     Ok(true)
@@ -180,16 +492,16 @@ pub fn shutdown_worker_pool() -> Result<bool, Error> {
 
 /// 워커 풀 통계 가져오기
 pub fn get_worker_pool_stats() -> Result<WorkerPoolStats, Error> {
-    let now = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .as_millis() as u64;
+    let now = current_timestamp();
     
     // 워커 풀 인스턴스가 있으면 해당 통계 반환
     if let Some(pool_mutex) = WORKER_POOL_INSTANCE.get() {
         let pool = pool_mutex.lock();
         let mut stats = pool.stats.clone();
         stats.timestamp = now;
+        if pool.active {
+            stats.uptime_ms = now.saturating_sub(POOL_STARTED_AT.load(Ordering::SeqCst));
+        }
         return Ok(stats);
     }
     
@@ -204,50 +516,399 @@ pub fn get_worker_pool_stats() -> Result<WorkerPoolStats, Error> {
         idle_workers: pool.thread_count,
         pending_tasks: 0,
         failed_tasks: 0,
+        cancelled_tasks: 0,
+        rejected_tasks: 0,
+        timed_out_tasks: 0,
+        panicked_tasks: 0,
+        stolen_tasks: 0,
+        max_pending_tasks: MAX_PENDING_TASKS.load(Ordering::SeqCst) as u64,
         total_tasks: COMPLETED_TASKS.load(Ordering::SeqCst),
         uptime_ms: 0, // 실제 구현에서는 시작 시간부터 계산
         timestamp: now,
     })
 }
 
+/// 워커 풀 유휴 여부 (실행 중이거나 대기 중인 작업이 없으면 true)
+pub fn is_worker_pool_idle() -> bool {
+    match WORKER_POOL_INSTANCE.get() {
+        Some(pool_mutex) => {
+            let pool = pool_mutex.lock();
+            !pool.active || (pool.pending_len() == 0 && pool.workers.iter().all(|w| !w.active))
+        },
+        None => true,
+    }
+}
+
 /// 워커 풀 가져오기
 pub fn get_worker_pool() -> Option<&'static Mutex<WorkerPool>> {
     WORKER_POOL_INSTANCE.get()
 }
 
-/// 작업 제출
+/// 작업 제출 (결과가 나올 때까지 대기)
+/// 
+/// 작업은 호출 스레드가 아니라 워커 스레드에서 실행되며, 호출 스레드는 결과를 기다립니다.
+/// `timeout_ms`(기본 30초) 안에 끝나지 않으면 시간 초과 오류 JSON을 반환합니다.
+/// JS 핸들러 작업은 JS 스레드에서 실행되므로, JS 스레드를 막는 이 함수로는 제출할 수 없습니다.
 #[napi]
-pub fn submit_task(task_type: String, data: String) -> Result<String, Error> {
-    // 워커 풀 초기화 확인
+pub fn submit_task(task_type: String, data: String, timeout_ms: Option<u32>) -> Result<String, Error> {
+    // 호출한 JS 스레드가 결과를 기다리는 동안 핸들러가 실행되지 못해 시간 초과까지 멈추는 것을 방지
+    if js_handlers::is_registered(&task_type) {
+        return Err(Error::from_reason(format!(
+            "JS 핸들러 작업은 동기로 제출할 수 없습니다 (비동기 submit_task 사용): {}", task_type
+        )));
+    }
+    let (id, rx) = enqueue_task(task_type.clone(), data, None, TaskPriority::Normal)?;
+    napi::bindgen_prelude::block_on(wait_for_result(id, task_type, rx, task_timeout(timeout_ms)))
+}
+
+/// 작업 제한 시간 (지정하지 않거나 0이면 기본값)
+pub fn task_timeout(timeout_ms: Option<u32>) -> Duration {
+    match timeout_ms {
+        Some(ms) if ms > 0 => Duration::from_millis(ms as u64),
+        _ => Duration::from_millis(DEFAULT_TASK_TIMEOUT_MS),
+    }
+}
+
+/// 제출한 작업의 결과 대기
+/// 
+/// 제한 시간이 지나면 대기 중인 작업은 큐에서 제거하고, 실행 중인 작업은 끝난 뒤 결과를 폐기합니다.
+/// (실행 중인 핸들러를 강제로 멈출 수는 없으므로 워커는 핸들러가 끝날 때까지 사용 중으로 남습니다)
+pub async fn wait_for_result(
+    task_id: String,
+    task_type: String,
+    rx: oneshot::Receiver<String>,
+    timeout: Duration,
+) -> Result<String, Error> {
+    match tokio::time::timeout(timeout, rx).await {
+        Ok(result) => result.map_err(|_| Error::from_reason("Worker pool was shut down before the task completed")),
+        Err(_) => {
+            let elapsed = timeout.as_millis() as u64;
+            expire_task(&task_id);
+            warn!("작업 시간 초과: {} ({}ms)", task_id, elapsed);
+            let response = timed_out_task_json(&task_id, &task_type, elapsed);
+            Ok(serde_json::to_string(&response).unwrap_or_default())
+        }
+    }
+}
+
+// 시간 초과된 작업 정리
+fn expire_task(task_id: &str) {
+    let pool_mutex = match WORKER_POOL_INSTANCE.get() {
+        Some(pool_mutex) => pool_mutex,
+        None => return,
+    };
+    let mut pool = pool_mutex.lock();
+    
+    if pool.remove_task(task_id).is_some() {
+        pool.stats.timed_out_tasks += 1;
+        pool.refresh_stats();
+        return;
+    }
+    
+    // 실행 중인 작업은 플래그만 설정 (워커가 끝난 뒤 시간 초과로 집계)
+    if let Some(flags) = RUNNING_TASKS.lock().get(task_id) {
+        flags.timed_out.store(true, Ordering::SeqCst);
+    }
+}
+
+/// 최대 대기 작업 수 설정
+/// 
+/// 이미 대기 중인 작업은 유지되며, 새 제한은 이후 제출부터 적용됩니다.
+pub fn set_max_pending_tasks(max_pending_tasks: u32) -> Result<bool, Error> {
+    if max_pending_tasks == 0 {
+        return Err(Error::new(Status::InvalidArg, "max_pending_tasks must be greater than 0".to_string()));
+    }
+    
+    MAX_PENDING_TASKS.store(max_pending_tasks as usize, Ordering::SeqCst);
+    
+    if let Some(pool_mutex) = WORKER_POOL_INSTANCE.get() {
+        let mut pool = pool_mutex.lock();
+        pool.max_pending_tasks = max_pending_tasks as usize;
+        pool.refresh_stats();
+    }
+    
+    Ok(true)
+}
+
+/// 작업을 워커 풀 큐에 추가
+/// 
+/// 작업 ID와 결과 JSON을 받을 수 있는 채널을 반환합니다.
+/// `task_id`를 지정하지 않으면 자동으로 생성됩니다.
+/// 
+/// 큐가 가득 차면 더 낮은 우선순위의 가장 최근 작업을 밀어내고,
+/// 밀어낼 작업이 없으면 `Status::QueueFull` 오류를 반환합니다.
+pub fn enqueue_task(
+    task_type: String,
+    data: String,
+    task_id: Option<String>,
+    priority: TaskPriority,
+) -> Result<(String, oneshot::Receiver<String>), Error> {
     if !POOL_RUNNING.load(Ordering::SeqCst) {
         return Err(Error::from_reason("Worker pool is not initialized"));
     }
     
-    // 활성 작업 카운터 증가
-    ACTIVE_TASKS.fetch_add(1, Ordering::SeqCst);
+    if !TASK_HANDLERS.read().contains_key(&task_type) && !js_handlers::is_registered(&task_type) {
+        return Err(Error::from_reason(format!("Unknown task type: {}", task_type)));
+    }
     
-    // 작업 처리 시작 시간
-    let start = std::time::Instant::now();
+    let pool_mutex = WORKER_POOL_INSTANCE.get()
+        .ok_or_else(|| Error::from_reason("Worker pool is not initialized"))?;
     
-    // 작업 핸들러 찾기 및 실행
-    let handlers = TASK_HANDLERS.read();
-    let handler = handlers.get(&task_type).ok_or_else(|| {
-        // 활성 작업 카운터 감소 (오류 발생 시)
-        ACTIVE_TASKS.fetch_sub(1, Ordering::SeqCst);
-        Error::from_reason(format!("Unknown task type: {}", task_type))
-    })?;
+    let id = task_id.unwrap_or_else(generate_task_id);
+    let (tx, rx) = oneshot::channel();
+    
+    // 배정 전에 전원 상태 갱신 (전원 절약 중이면 일부 워커만 작업을 가져감)
+    power::current_state();
+    
+    let (evicted, throttled) = {
+        let mut pool = pool_mutex.lock();
+        
+        if !pool.active {
+            return Err(Error::from_reason("Worker pool is not initialized"));
+        }
+        
+        // 같은 ID의 작업이 대기 중이거나 실행 중이면 거부
+        if pool.contains_task(&id) || RUNNING_TASKS.lock().contains_key(&id) {
+            return Err(Error::from_reason(format!("Task id already in use: {}", id)));
+        }
+        
+        // 큐가 가득 찬 경우 백프레셔 적용
+        let mut evicted = None;
+        if pool.pending_len() >= pool.max_pending_tasks {
+            pool.stats.rejected_tasks += 1;
+            
+            match pool.evict_lower_than(priority) {
+                Some(task) => evicted = Some(task),
+                None => {
+                    pool.refresh_stats();
+                    return Err(Error::new(
+                        Status::QueueFull,
+                        format!("Worker pool queue is full ({} pending tasks)", pool.max_pending_tasks),
+                    ));
+                }
+            }
+        }
+        
+        pool.push_task(Task {
+            id: id.clone(),
+            task_type,
+            data,
+            priority,
+            timestamp: current_timestamp(),
+            sequence: TASK_SEQUENCE.fetch_add(1, Ordering::SeqCst),
+            flags: Arc::new(TaskFlags::default()),
+            responder: Some(tx),
+        });
+        pool.stats.total_tasks += 1;
+        pool.refresh_stats();
+        
+        let throttled = power::worker_thread_limit(pool.workers.len()) < pool.workers.len();
+        (evicted, throttled)
+    };
+    
+    // 쉬는 워커가 알림을 받으면 작업이 늦어지므로 전원 절약 중에는 모든 워커를 깨움
+    if throttled {
+        TASK_AVAILABLE.notify_all();
+    } else {
+        TASK_AVAILABLE.notify_one();
+    }
+    
+    if let Some(task) = evicted {
+        debug!("우선순위가 낮은 대기 작업 밀어냄: {} ({:?})", task.id, task.priority);
+        let response = rejected_task_json(&task);
+        task.respond(response);
+    }
+    
+    Ok((id, rx))
+}
+
+/// 작업 취소
+/// 
+/// 대기 중인 작업은 큐에서 제거되고, 실행 중인 작업은 완료 후 결과가 폐기됩니다.
+/// 해당 ID의 작업을 찾지 못하면 `false`를 반환합니다.
+pub fn cancel_task(task_id: &str) -> Result<bool, Error> {
+    let pool_mutex = match WORKER_POOL_INSTANCE.get() {
+        Some(pool_mutex) => pool_mutex,
+        None => return Ok(false),
+    };
+    
+    let queued = {
+        let mut pool = pool_mutex.lock();
+        
+        match pool.remove_task(task_id) {
+            Some(task) => {
+                pool.stats.cancelled_tasks += 1;
+                pool.refresh_stats();
+                Some(task)
+            },
+            None => {
+                // 실행 중인 작업이면 취소 플래그만 설정
+                if let Some(flags) = RUNNING_TASKS.lock().get(task_id) {
+                    flags.cancelled.store(true, Ordering::SeqCst);
+                    debug!("실행 중인 작업 취소 요청: {}", task_id);
+                    return Ok(true);
+                }
+                return Ok(false);
+            }
+        }
+    };
+    
+    if let Some(task) = queued {
+        debug!("대기 중인 작업 취소: {}", task_id);
+        let response = cancelled_task_json(&task, 0);
+        task.respond(response);
+    }
+    
+    Ok(true)
+}
+
+// 워커 스레드 메인 루프
+fn worker_loop(worker_id: usize) {
+    let pool_mutex = match WORKER_POOL_INSTANCE.get() {
+        Some(pool_mutex) => pool_mutex,
+        None => return,
+    };
     
-    // 작업 실행
-    let result = handler(&data);
+    // 패닉 후 재시작된 경우 이전 작업 중 상태 초기화
+    {
+        let mut pool = pool_mutex.lock();
+        if let Some(worker) = pool.workers.get_mut(worker_id) {
+            worker.active = false;
+        }
+        pool.refresh_stats();
+    }
+    
+    loop {
+        // 작업이 들어올 때까지 대기
+        let task = {
+            let mut pool = pool_mutex.lock();
+            let task = loop {
+                if !pool.active {
+                    return;
+                }
+                
+                // 전원 절약 중에는 허용된 수를 넘는 워커는 작업을 가져가지 않고 전원 상태만 다시 확인
+                let limit = power::worker_thread_limit(pool.workers.len());
+                if worker_id >= limit {
+                    // 자기 큐에 남은 작업은 쉬고 있는 허용된 워커가 가져가도록 깨움
+                    let has_queued = pool.queues.get(worker_id).is_some_and(|queue| queue.len() > 0);
+                    if has_queued && pool.workers.iter().take(limit).any(|w| !w.active) {
+                        TASK_AVAILABLE.notify_all();
+                    }
+                    TASK_AVAILABLE.wait_for(&mut pool, POWER_RECHECK_INTERVAL);
+                    MutexGuard::unlocked(&mut pool, || {
+                        power::current_state();
+                    });
+                    continue;
+                }
+                
+                if let Some((task, stolen)) = pool.take_task(worker_id) {
+                    if stolen {
+                        pool.stats.stolen_tasks += 1;
+                    }
+                    break task;
+                }
+                
+                TASK_AVAILABLE.wait(&mut pool);
+            };
+            
+            if let Some(worker) = pool.workers.get_mut(worker_id) {
+                worker.active = true;
+            }
+            pool.refresh_stats();
+            RUNNING_TASKS.lock().insert(task.id.clone(), task.flags.clone());
+            
+            task
+        };
+        
+        let outcome = run_queued_task(task);
+        
+        let mut pool = pool_mutex.lock();
+        if let Some(worker) = pool.workers.get_mut(worker_id) {
+            worker.active = false;
+            worker.task_count += 1;
+        }
+        match outcome {
+            TaskOutcome::Completed => pool.stats.completed_tasks += 1,
+            TaskOutcome::Failed => pool.stats.failed_tasks += 1,
+            TaskOutcome::Panicked => {
+                pool.stats.failed_tasks += 1;
+                pool.stats.panicked_tasks += 1;
+            },
+            TaskOutcome::Cancelled => pool.stats.cancelled_tasks += 1,
+            TaskOutcome::TimedOut => pool.stats.timed_out_tasks += 1,
+        }
+        pool.refresh_stats();
+    }
+}
+
+// 큐에서 꺼낸 작업 실행
+//
+// 핸들러 패닉은 여기서 잡아 작업 오류로 응답하므로 워커 스레드는 계속 실행됩니다.
+fn run_queued_task(task: Task) -> TaskOutcome {
+    ACTIVE_TASKS.fetch_add(1, Ordering::SeqCst);
+    
+    let queue_time = current_timestamp().saturating_sub(task.timestamp);
+    let start = Instant::now();
+    
+    let handler = TASK_HANDLERS.read().get(&task.task_type).copied();
+    let js_handler = handler.is_none().then(|| js_handlers::get(&task.task_type)).flatten();
+    let mut panicked = false;
+    let result = match (handler, js_handler) {
+        (Some(handler), _) => panic::catch_unwind(AssertUnwindSafe(|| handler(&task.data))).unwrap_or_else(|payload| {
+            panicked = true;
+            let message = supervisor::panic_message(payload.as_ref());
+            error!("작업 핸들러 패닉 ({}, {}): {}", task.task_type, task.id, message);
+            Err(Error::from_reason(format!("Task handler panicked: {}", message)))
+        }),
+        (None, Some(js_handler)) => js_handlers::call_handler(&js_handler, &task.data, || {
+            task.flags.cancelled.load(Ordering::SeqCst) || task.flags.timed_out.load(Ordering::SeqCst)
+        }),
+        (None, None) => Err(Error::from_reason(format!("Unknown task type: {}", task.task_type))),
+    };
     
-    // 처리 시간 계산
     let execution_time = start.elapsed().as_millis() as u64;
     
-    // 활성 작업 카운터 감소, 완료 작업 카운터 증가
     ACTIVE_TASKS.fetch_sub(1, Ordering::SeqCst);
     COMPLETED_TASKS.fetch_add(1, Ordering::SeqCst);
+    RUNNING_TASKS.lock().remove(&task.id);
+    
+    // 제출자가 이미 시간 초과 응답을 받았으므로 결과 폐기
+    if task.flags.timed_out.load(Ordering::SeqCst) {
+        debug!("시간 초과된 작업 결과 폐기: {} ({}ms)", task.id, execution_time);
+        return TaskOutcome::TimedOut;
+    }
+    
+    if task.flags.cancelled.load(Ordering::SeqCst) {
+        let response = cancelled_task_json(&task, execution_time);
+        task.respond(response);
+        return TaskOutcome::Cancelled;
+    }
+    
+    let outcome = match (&result, panicked) {
+        (_, true) => TaskOutcome::Panicked,
+        (Ok(_), _) => TaskOutcome::Completed,
+        (Err(_), _) => TaskOutcome::Failed,
+    };
+    let mut response = build_task_response(&task.task_type, result, execution_time)
+        .unwrap_or_else(|e| task_error_json(&task.task_type, execution_time, e.to_string()));
+    
+    if let serde_json::Value::Object(ref mut obj) = response {
+        obj.insert("task_id".to_string(), serde_json::json!(task.id));
+        obj.insert("queue_time_ms".to_string(), serde_json::json!(queue_time));
+        obj.insert("priority".to_string(), serde_json::json!(task.priority));
+    }
     
-    // 결과 반환
+    task.respond(response);
+    outcome
+}
+
+// 핸들러 결과를 응답 JSON으로 변환
+fn build_task_response(
+    task_type: &str,
+    result: Result<String, Error>,
+    execution_time: u64,
+) -> Result<serde_json::Value, Error> {
     match result {
         Ok(result_json) => {
             // 결과 JSON에 실행 시간 추가
@@ -256,32 +917,73 @@ pub fn submit_task(task_type: String, data: String) -> Result<String, Error> {
             
             if let serde_json::Value::Object(ref mut obj) = parsed {
                 obj.insert("execution_time_ms".to_string(), serde_json::json!(execution_time));
-                obj.insert("timestamp".to_string(), serde_json::json!(
-                    std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_millis() as u64
-                ));
+                obj.insert("timestamp".to_string(), serde_json::json!(current_timestamp()));
             }
             
-            Ok(serde_json::to_string(&parsed).unwrap_or_default())
+            Ok(parsed)
         },
-        Err(e) => {
-            // 오류 발생 시 오류 정보를 담은 JSON 반환
-            let error_json = serde_json::json!({
-                "success": false,
-                "task_type": task_type,
-                "execution_time_ms": execution_time,
-                "timestamp": std::time::SystemTime::now()
-                    .duration_since(std::time::UNIX_EPOCH)
-                    .unwrap_or_default()
-                    .as_millis() as u64,
-                "error": e.to_string()
-            });
-            
-            Ok(serde_json::to_string(&error_json).unwrap_or_default())
-        }
+        // 오류 발생 시 오류 정보를 담은 JSON 반환
+        Err(e) => Ok(task_error_json(task_type, execution_time, e.to_string())),
+    }
+}
+
+// 작업 오류 JSON 생성
+fn task_error_json(task_type: &str, execution_time: u64, error: String) -> serde_json::Value {
+    serde_json::json!({
+        "success": false,
+        "task_type": task_type,
+        "execution_time_ms": execution_time,
+        "timestamp": current_timestamp(),
+        "error": error
+    })
+}
+
+// 취소된 작업 JSON 생성
+fn cancelled_task_json(task: &Task, execution_time: u64) -> serde_json::Value {
+    let mut response = task_error_json(&task.task_type, execution_time, "Task was cancelled".to_string());
+    
+    if let serde_json::Value::Object(ref mut obj) = response {
+        obj.insert("task_id".to_string(), serde_json::json!(task.id));
+        obj.insert("cancelled".to_string(), serde_json::json!(true));
+    }
+    
+    response
+}
+
+// 시간 초과된 작업 JSON 생성
+fn timed_out_task_json(task_id: &str, task_type: &str, elapsed: u64) -> serde_json::Value {
+    let mut response = task_error_json(task_type, elapsed, format!("Task timed out after {}ms", elapsed));
+    
+    if let serde_json::Value::Object(ref mut obj) = response {
+        obj.insert("task_id".to_string(), serde_json::json!(task_id));
+        obj.insert("timed_out".to_string(), serde_json::json!(true));
+    }
+    
+    response
+}
+
+// 큐에서 밀려난 작업 JSON 생성
+fn rejected_task_json(task: &Task) -> serde_json::Value {
+    let mut response = task_error_json(&task.task_type, 0, "Task was rejected because the worker pool queue is full".to_string());
+    
+    if let serde_json::Value::Object(ref mut obj) = response {
+        obj.insert("task_id".to_string(), serde_json::json!(task.id));
+        obj.insert("priority".to_string(), serde_json::json!(task.priority));
+        obj.insert("rejected".to_string(), serde_json::json!(true));
     }
+    
+    response
+}
+
+// 작업 ID 생성
+fn generate_task_id() -> String {
+    let sequence = TASK_ID_COUNTER.fetch_add(1, Ordering::SeqCst);
+    format!("task_{}_{}", current_timestamp(), sequence)
+}
+
+// 현재 타임스탬프 (밀리초)
+fn current_timestamp() -> u64 {
+    clock::now_millis()
 }
 
 /// 기본 작업 핸들러 등록
@@ -329,6 +1031,11 @@ fn register_default_task_handlers() {
     });
 }
 
+/// 네이티브 핸들러 작업 유형인지 확인 (워커 풀 초기화 전에도 기본 작업 유형 포함)
+pub fn is_native_task_type(task_type: &str) -> bool {
+    DEFAULT_TASK_TYPES.contains(&task_type) || TASK_HANDLERS.read().contains_key(task_type)
+}
+
 /// 작업 유형 목록 가져오기
 pub fn get_available_task_types() -> Result<Vec<String>, Error> {
     let handlers = TASK_HANDLERS.read();
@@ -266,6 +266,490 @@ contextBridge.exposeInMainWorld('electronAPI', {
    */
   toggleMiniView: () => {
     ipcRenderer.send('toggle-mini-view');
+  },
+
+  /**
+   * 샌드박스 모드 상태 조회 (별도 데이터 디렉토리 사용 여부)
+   * @returns {Promise<Object>} { sandbox, userDataPath }
+   */
+  getSandboxStatus: () => {
+    return ipcRenderer.invoke('get-sandbox-status');
+  },
+
+  /**
+   * 메트릭 내보내기 상태 조회
+   * @returns {Promise<Object>} { enabled, running, path, lastWrite, lastError }
+   */
+  getMetricsStatus: () => {
+    return ipcRenderer.invoke('get-metrics-status');
+  },
+
+  /**
+   * 로그인 시 자동 실행 등록/해제
+   * @param {boolean} enabled - 등록 여부
+   * @param {Object} [options] - { captureOnly: 창 없이 기록만 하는 모드로 시작 (기본값 true) }
+   * @returns {Promise<Object>} { success, enabled, captureOnly, platform, method, location } 또는 { success: false, error }
+   */
+  setAutostart: (enabled, options) => {
+    return ipcRenderer.invoke('set-autostart', enabled, options);
+  },
+
+  /**
+   * 네이티브 GPU 연산 켜기/끄기 (재시작 없이 적용되며 설정에 저장됨)
+   * 끄면 GPU 작업이 CPU로 계산됩니다. 드라이버가 불안정할 때 사용합니다.
+   * @param {boolean} enabled - 사용 여부
+   * @returns {Promise<Object>} { success, enabled, gpu: { status, error, capabilities }, error? }
+   */
+  setGpuEnabled: (enabled) => {
+    return ipcRenderer.invoke('set-gpu-enabled', enabled);
+  },
+
+  /**
+   * 자동 실행 등록 상태 조회
+   * @returns {Promise<Object>} { enabled, captureOnly, platform, method, location }
+   */
+  getAutostartStatus: () => {
+    return ipcRenderer.invoke('get-autostart-status');
+  },
+
+  /**
+   * 게스트(발표) 모드 켜기/끄기
+   * 켜져 있는 동안 창 제목·입력 내용·이메일이 가려지고 기록 변경/내보내기가 막힙니다.
+   * @param {boolean} enabled - 켤지 여부
+   * @returns {Promise<Object>} { active, startedAt }
+   */
+  setGuestMode: (enabled) => {
+    return ipcRenderer.invoke('set-guest-mode', enabled);
+  },
+
+  /**
+   * 게스트 모드 상태 조회
+   * @returns {Promise<Object>} { active, startedAt }
+   */
+  getGuestModeStatus: () => {
+    return ipcRenderer.invoke('get-guest-mode-status');
+  },
+
+  /**
+   * 게스트 모드 변경 이벤트 수신
+   * @param {Function} callback - { active, timestamp } 인자 콜백
+   * @returns {Function} - 이벤트 리스너 제거 함수
+   */
+  onGuestModeChanged: (callback) => {
+    if (!callback || typeof callback !== 'function') {
+      console.error('유효한 콜백 함수가 필요합니다');
+      return () => {};
+    }
+
+    const handler = (_event, data) => {
+      callback(data);
+    };
+
+    ipcRenderer.on('guest-mode-changed', handler);
+
+    return () => {
+      ipcRenderer.removeListener('guest-mode-changed', handler);
+    };
+  },
+
+  /**
+   * 작성 목표 목록과 오늘 진행 상태 조회
+   * @returns {Promise<Object>} { success, goals: [{ goalId, document, metric, target, progress, percent, achieved }] }
+   */
+  getGoals: () => {
+    return ipcRenderer.invoke('get-goals');
+  },
+
+  /**
+   * 일일 작성 목표 추가
+   * @param {Object} options - { document?, metric: 'words' | 'minutes', target }
+   * document(창 제목)를 비우면 모든 입력을 합산하는 전체 목표가 됩니다.
+   * @returns {Promise<Object>} { success, goal?, error? }
+   */
+  createGoal: (options) => {
+    return ipcRenderer.invoke('create-goal', options);
+  },
+
+  /**
+   * 작성 목표 삭제
+   * @param {number} id - 목표 ID
+   * @returns {Promise<Object>} { success }
+   */
+  deleteGoal: (id) => {
+    return ipcRenderer.invoke('delete-goal', id);
+  },
+
+  /**
+   * 작성 목표 일별 진행 기록 조회
+   * @param {Object} options - { goalId, days? }
+   * @returns {Promise<Object>} { success, history: [{ date, progress, target, achievedAt }] }
+   */
+  getGoalHistory: (options) => {
+    return ipcRenderer.invoke('get-goal-history', options);
+  },
+
+  /**
+   * 작성 목표 진행 상태 변경 이벤트 수신
+   * @param {Function} callback - { date, goals, timestamp } 인자 콜백
+   * @returns {Function} - 이벤트 리스너 제거 함수
+   */
+  onGoalProgress: (callback) => {
+    if (!callback || typeof callback !== 'function') {
+      console.error('유효한 콜백 함수가 필요합니다');
+      return () => {};
+    }
+
+    const handler = (_event, data) => {
+      callback(data);
+    };
+
+    ipcRenderer.on('goal-progress', handler);
+
+    return () => {
+      ipcRenderer.removeListener('goal-progress', handler);
+    };
+  },
+
+  /**
+   * 작성 목표 달성 이벤트 수신 (목표별로 하루 한 번)
+   * @param {Function} callback - 달성한 목표 상태 인자 콜백
+   * @returns {Function} - 이벤트 리스너 제거 함수
+   */
+  onGoalAchieved: (callback) => {
+    if (!callback || typeof callback !== 'function') {
+      console.error('유효한 콜백 함수가 필요합니다');
+      return () => {};
+    }
+
+    const handler = (_event, data) => {
+      callback(data);
+    };
+
+    ipcRenderer.on('goal-achieved', handler);
+
+    return () => {
+      ipcRenderer.removeListener('goal-achieved', handler);
+    };
+  },
+
+  /**
+   * 주간 작성 보고서 생성
+   * 보고서 폴더에 저장하고, 메일 발송이 켜져 있으면 SMTP로도 보냅니다.
+   * @param {Object} [options] - { week?: 주에 포함된 날짜(YYYY-MM-DD, 생략 시 지난주), format?: 'markdown' | 'html', email? }
+   * @returns {Promise<Object>} { success, filePath, week, totals, emailed, emailError?, error? }
+   */
+  generateWeeklyReport: (options) => {
+    return ipcRenderer.invoke('generate-weekly-report', options);
+  },
+
+  /**
+   * 보고서 메일용 SMTP 비밀번호 저장 (빈 문자열이면 삭제)
+   * @param {string} password - 비밀번호
+   * @returns {Promise<Object>} { success }
+   */
+  setReportSmtpPassword: (password) => {
+    return ipcRenderer.invoke('set-report-smtp-password', password);
+  },
+
+  /**
+   * 외부 분석 내보내기 상태 조회
+   * @returns {Promise<Object>} { enabled, running, endpoint, hasToken, pending, failed, lastSuccess, lastError }
+   */
+  getAnalyticsExportStatus: () => {
+    return ipcRenderer.invoke('get-analytics-export-status');
+  },
+
+  /**
+   * 분석 내보내기 인증 토큰 저장 (빈 문자열이면 삭제)
+   * @param {string} token - Bearer 토큰
+   * @returns {Promise<Object>} { success }
+   */
+  setAnalyticsToken: (token) => {
+    return ipcRenderer.invoke('set-analytics-token', token);
+  },
+
+  /**
+   * 분석 내보내기 대기열 즉시 전송
+   * @param {Object} [options] - { retryFailed?: 재시도를 멈춘 항목도 다시 전송 }
+   * @returns {Promise<Object>} { success, sent, enqueued, requeued, error? }
+   */
+  flushAnalyticsExport: (options) => {
+    return ipcRenderer.invoke('flush-analytics-export', options);
+  },
+
+  /**
+   * 앱 잠금 상태 조회
+   * @returns {Promise<Object>} { enabled, locked, hasPin, idleMinutes, systemAuthAvailable, retryAfterMs }
+   */
+  getAppLockStatus: () => {
+    return ipcRenderer.invoke('get-app-lock-status');
+  },
+
+  /**
+   * PIN으로 앱 잠금 해제
+   * @param {string} pin - PIN
+   * @returns {Promise<Object>} { success, locked, error?, remainingAttempts?, retryAfterMs? }
+   */
+  unlockApp: (pin) => {
+    return ipcRenderer.invoke('unlock-app', pin);
+  },
+
+  /**
+   * 시스템 인증(Touch ID)으로 앱 잠금 해제
+   * @returns {Promise<Object>} { success, locked, error? }
+   */
+  unlockAppWithSystemAuth: () => {
+    return ipcRenderer.invoke('unlock-app-with-system-auth');
+  },
+
+  /**
+   * 앱 바로 잠그기
+   * @returns {Promise<Object>} { success, locked }
+   */
+  lockApp: () => {
+    return ipcRenderer.invoke('lock-app');
+  },
+
+  /**
+   * 앱 잠금 PIN 설정/변경
+   * @param {Object} options - { currentPin, newPin }
+   * @returns {Promise<Object>} { success, error? }
+   */
+  setAppLockPin: (options) => {
+    return ipcRenderer.invoke('set-app-lock-pin', options);
+  },
+
+  /**
+   * 앱 잠금 PIN 삭제
+   * @param {string} currentPin - 현재 PIN
+   * @returns {Promise<Object>} { success, error? }
+   */
+  clearAppLockPin: (currentPin) => {
+    return ipcRenderer.invoke('clear-app-lock-pin', currentPin);
+  },
+
+  /**
+   * 앱 잠금 상태 변경 이벤트 수신
+   * @param {Function} callback - { locked, timestamp } 인자 콜백
+   * @returns {Function} - 이벤트 리스너 제거 함수
+   */
+  onAppLockChanged: (callback) => {
+    if (!callback || typeof callback !== 'function') {
+      console.error('유효한 콜백 함수가 필요합니다');
+      return () => {};
+    }
+
+    const handler = (_event, data) => {
+      callback(data);
+    };
+
+    ipcRenderer.on('app-lock-changed', handler);
+
+    return () => {
+      ipcRenderer.removeListener('app-lock-changed', handler);
+    };
+  },
+
+  /**
+   * 기간별 타이핑 추세 조회 (기간에 따라 일/주/월 단위 자동 선택)
+   * @param {string} from - 시작 시각 (ISO 문자열)
+   * @param {string} [to] - 종료 시각 (ISO 문자열, 기본값: 현재)
+   * @param {string} [granularity] - day, week, month 중 하나로 단위 고정
+   * @returns {Promise<Object>} { granularity, points }
+   */
+  getTypingTrend: (from, to, granularity) => {
+    return ipcRenderer.invoke('get-typing-trend', { from, to, granularity });
+  },
+
+  /**
+   * 일 단위 통계 조회 (일 롤업과 시간대별 입력 시간)
+   * @param {string} from - 시작 시각 (ISO 문자열)
+   * @param {string} [to] - 종료 시각 (ISO 문자열, 기본값: 현재)
+   * @returns {Promise<Object>} { granularity, points, activeHours }
+   */
+  getDailyStats: (from, to) => {
+    return ipcRenderer.invoke('get-daily-stats', { from, to });
+  },
+
+  /**
+   * 주 단위 통계 조회 (주 시작 요일 설정 기준 주 롤업과 시간대별 입력 시간)
+   * @param {string} from - 시작 시각 (ISO 문자열)
+   * @param {string} [to] - 종료 시각 (ISO 문자열, 기본값: 현재)
+   * @returns {Promise<Object>} { granularity, points, activeHours }
+   */
+  getWeeklyStats: (from, to) => {
+    return ipcRenderer.invoke('get-weekly-stats', { from, to });
+  },
+
+  /**
+   * 현재 기간의 앱(브라우저)별 타이핑 통계 조회
+   * @param {string} [period] - day, week, month, all 중 하나 (기본값: week)
+   * @returns {Promise<Object>} { period, from, applications }
+   */
+  getTypingStatsByApp: (period) => {
+    return ipcRenderer.invoke('get-typing-stats-by-app', period);
+  },
+
+  /**
+   * 일별 작성 단어 수/타이핑 속도 이상치 조회
+   * @param {Object} [options] - { days, threshold }
+   * @returns {Promise<Array>} { date, metric, value, expected, zScore, direction } 배열
+   */
+  getAnomalies: (options) => {
+    return ipcRenderer.invoke('get-anomalies', options);
+  },
+
+  /**
+   * 로컬 데이터베이스에 읽기 전용 조회 쿼리 실행 (SELECT/WITH 단일 문장만 허용)
+   * @param {string} sql - 실행할 쿼리
+   * @param {Object} [options] - { maxRows, timeoutMs }
+   * @returns {Promise<Object>} { success, columns, rows, rowCount, truncated, timedOut, durationMs } 또는 { success: false, error }
+   */
+  executeReadonlyQuery: (sql, options) => {
+    return ipcRenderer.invoke('execute-readonly-query', sql, options);
+  },
+
+  /**
+   * 로컬 데이터베이스 상태 점검 (연결 설정, 파일 크기, 무결성 빠른 검사)
+   * @returns {Promise<Object>} { healthy, journalMode, busyTimeoutMs, foreignKeys, sizeBytes, quickCheck, ... } 또는 { healthy: false, error }
+   */
+  getDatabaseHealth: () => {
+    return ipcRenderer.invoke('get-database-health');
+  },
+
+  /**
+   * 타이핑 세션을 JSON 파일로 내보내기 (저장 위치 선택 창 표시)
+   * @param {Object} [options] - { includeContent: 입력 내용 포함 여부 }
+   * @returns {Promise<Object>} { success, exported, filePath } 또는 { success: false, canceled|error }
+   */
+  exportTypingSessions: (options) => {
+    return ipcRenderer.invoke('export-typing-sessions', options);
+  },
+
+  /**
+   * JSON 파일에서 타이핑 세션 가져오기 (파일 선택 창 표시)
+   * @returns {Promise<Object>} { success, imported, skipped, invalid } 또는 { success: false, canceled|error }
+   */
+  importTypingSessions: () => {
+    return ipcRenderer.invoke('import-typing-sessions');
+  },
+
+  /**
+   * 기간별 집계 통계를 JSON 파일로 내보내기 (저장 위치 선택 창 표시)
+   * @param {Object} [options] - { granularity, from, to, differentialPrivacy, epsilon }
+   * @returns {Promise<Object>} { success, exported, filePath, privacy } 또는 { success: false, canceled|error }
+   */
+  exportAggregateStats: (options) => {
+    return ipcRenderer.invoke('export-aggregate-stats', options);
+  },
+
+  /**
+   * 전체 분석 데이터 내보내기 (저장 위치 선택 창 표시)
+   * @param {Object} [options] - { format: 'json' | 'csv', from, to (YYYY-MM-DD), includeContent }
+   * @returns {Promise<Object>} { success, exported, files } 또는 { success: false, canceled|error }
+   */
+  exportAnalytics: (options) => {
+    return ipcRenderer.invoke('export-analytics', options);
+  },
+
+  /**
+   * 다른 기기에서 내보낸 분석 데이터 가져오기 (파일 선택 창 표시)
+   * @returns {Promise<Object>} { success, imported, skipped, invalid, dailyImported, dailySkipped } 또는 { success: false, canceled|error }
+   */
+  importAnalytics: () => {
+    return ipcRenderer.invoke('import-analytics');
+  },
+
+  /**
+   * 모든 사용자 데이터를 ZIP 파일로 내보내기 (저장 위치 선택 창 표시)
+   * @returns {Promise<Object>} { success, filePath, files, bytes } 또는 { success: false, canceled|error }
+   */
+  exportAllUserData: () => {
+    return ipcRenderer.invoke('export-all-user-data');
+  },
+
+  /**
+   * 데이터 삭제 확인 토큰 발급 (5분간 한 번 사용 가능)
+   * @returns {Promise<Object>} { success, token, expiresAt, locations }
+   */
+  requestDataWipe: () => {
+    return ipcRenderer.invoke('request-data-wipe');
+  },
+
+  /**
+   * 이 기기의 모든 앱 데이터 삭제 후 앱 재시작
+   * @param {string} token - requestDataWipe로 받은 확인 토큰
+   * @param {Object} [options] - { exportBeforeWipe: 삭제 전에 ZIP으로 내보내기 }
+   * @returns {Promise<Object>} { success, deletedFiles, exported } 또는 { success: false, canceled|error }
+   */
+  wipeAllLocalData: (token, options) => {
+    return ipcRenderer.invoke('wipe-all-local-data', token, options);
+  },
+
+  /**
+   * 시스템 절전 해제 이벤트 수신 (절전 구간은 타이핑 시간에서 제외됨)
+   * @param {Function} callback - { suspendedAt, resumedAt, sleptMs } 인자 콜백
+   * @returns {Function} - 이벤트 리스너 제거 함수
+   */
+  onSystemResumed: (callback) => {
+    if (!callback || typeof callback !== 'function') {
+      console.error('유효한 콜백 함수가 필요합니다');
+      return () => {};
+    }
+
+    const handler = (_event, data) => {
+      callback(data);
+    };
+
+    ipcRenderer.on('system-resumed', handler);
+
+    return () => {
+      ipcRenderer.removeListener('system-resumed', handler);
+    };
+  },
+
+  /**
+   * 유휴 세션 일시 정지 이벤트 수신 (입력이 없어 세션이 나뉘어 저장됨)
+   * @param {Function} callback - { idleSeconds, lastActiveTime, savedSession, trackingStopped, timestamp } 인자 콜백
+   * @returns {Function} - 이벤트 리스너 제거 함수
+   */
+  onTypingSessionIdle: (callback) => {
+    if (!callback || typeof callback !== 'function') {
+      console.error('유효한 콜백 함수가 필요합니다');
+      return () => {};
+    }
+
+    const handler = (_event, data) => {
+      callback(data);
+    };
+
+    ipcRenderer.on('typing-session-idle', handler);
+
+    return () => {
+      ipcRenderer.removeListener('typing-session-idle', handler);
+    };
+  },
+
+  /**
+   * 휴식 권장 이벤트 수신 (피로도가 설정한 임계값을 넘을 때)
+   * @param {Function} callback - { score, level, threshold, continuousTypingMs, timestamp } 인자 콜백
+   * @returns {Function} - 이벤트 리스너 제거 함수
+   */
+  onBreakRecommended: (callback) => {
+    if (!callback || typeof callback !== 'function') {
+      console.error('유효한 콜백 함수가 필요합니다');
+      return () => {};
+    }
+
+    const handler = (_event, data) => {
+      callback(data);
+    };
+
+    ipcRenderer.on('break-recommended', handler);
+
+    return () => {
+      ipcRenderer.removeListener('break-recommended', handler);
+    };
   }
 });
 
@@ -0,0 +1,47 @@
+/**
+ * 데이터베이스 스키마 덤프 스크립트
+ * 스키마 DDL과 (선택 시) 익명화한 샘플 기록을 JSON 픽스처로 저장합니다.
+ * 통합 테스트용 픽스처를 만들거나, 사용자가 DB 관련 문제를 보고할 때 첨부하도록 안내할 때 사용합니다.
+ *
+ * 사용법:
+ *   node scripts/dump-schema.js <데이터베이스 경로> [출력 경로] [--samples] [--limit=N]
+ *   출력 경로를 생략하면 표준 출력으로 씁니다.
+ */
+const fs = require('fs');
+const path = require('path');
+const BetterSqlite3 = require('better-sqlite3');
+const { dumpSchema, serializeFixture, DEFAULT_SAMPLE_LIMIT } = require('../src/main/schema-dump');
+
+const args = process.argv.slice(2);
+const positional = args.filter(arg => !arg.startsWith('--'));
+const [dbPath, outputPath] = positional;
+const includeSamples = args.includes('--samples');
+const limitArg = args.find(arg => arg.startsWith('--limit='));
+const sampleLimit = limitArg ? parseInt(limitArg.split('=')[1], 10) : DEFAULT_SAMPLE_LIMIT;
+
+if (!dbPath) {
+  console.error('사용법: node scripts/dump-schema.js <데이터베이스 경로> [출력 경로] [--samples] [--limit=N]');
+  process.exit(1);
+}
+
+if (!fs.existsSync(dbPath)) {
+  console.error(`데이터베이스 파일을 찾을 수 없습니다: ${dbPath}`);
+  process.exit(1);
+}
+
+// 원본을 수정하지 않도록 읽기 전용으로 열기
+const db = new BetterSqlite3(dbPath, { readonly: true, fileMustExist: true });
+
+try {
+  const output = serializeFixture(dumpSchema(db, { includeSamples, sampleLimit }));
+
+  if (outputPath) {
+    fs.mkdirSync(path.dirname(path.resolve(outputPath)), { recursive: true });
+    fs.writeFileSync(outputPath, output, 'utf8');
+    console.error(`스키마 픽스처 저장 완료: ${outputPath}`);
+  } else {
+    process.stdout.write(output);
+  }
+} finally {
+  db.close();
+}
@@ -40,6 +40,23 @@ interface TypingChartProps {
   logs: LogType[];
 }
 
+// 메인 프로세스에서 감지한 일별 이상치
+interface AnomalyType {
+  date: string;
+  metric: 'words' | 'wpm';
+  value: number;
+  expected: number;
+  zScore: number;
+  direction: 'high' | 'low';
+}
+
+// 현지 시간 기준 날짜 키 (YYYY-MM-DD, 롤업 테이블의 일 단위와 같은 형식)
+const toLocalDateKey = (date: Date) => {
+  const month = String(date.getMonth() + 1).padStart(2, '0');
+  const day = String(date.getDate()).padStart(2, '0');
+  return `${date.getFullYear()}-${month}-${day}`;
+};
+
 // 로그 데이터 필터링 함수 - 컴포넌트 외부로 이동
 const filterLogsForChart = (logs: LogType[]) => {
   // 최근 7일 데이터만 표시 (30일에서 7일로 단축)
@@ -117,6 +134,23 @@ export const TypingChart = React.memo(function TypingChart({ logs }: TypingChart
     };
   }, []);
 
+  // 이상치 목록 (차트 표시용)
+  const [anomalies, setAnomalies] = useState<AnomalyType[]>([]);
+
+  // 이상치 조회 (로그가 바뀔 때마다 다시 조회)
+  useEffect(() => {
+    const api = window.electronAPI as { getAnomalies?: (options?: { days?: number }) => Promise<AnomalyType[]> } | undefined;
+    if (!api?.getAnomalies) return;
+
+    api.getAnomalies({ days: 30 })
+      .then(result => {
+        if (isMountedRef.current && Array.isArray(result)) {
+          setAnomalies(result);
+        }
+      })
+      .catch(error => console.error('이상치 조회 중 오류:', error));
+  }, [logs]);
+
   // 필터링된 로그 데이터 메모이제이션 (최소 데이터만 사용)
   const filteredLogs = useMemo(() => {
     try {
@@ -229,6 +263,7 @@ export const TypingChart = React.memo(function TypingChart({ logs }: TypingChart
       if (!Array.isArray(filteredLogs) || filteredLogs.length === 0) {
         return {
           labels: [],
+          dateKeys: [],
           keyCountData: [],
           timeData: [],
           speedData: [],
@@ -247,7 +282,7 @@ export const TypingChart = React.memo(function TypingChart({ logs }: TypingChart
         // timestamp가 없거나 유효하지 않은 경우 건너뛰기
         if (!log.timestamp || !Date.parse(log.timestamp)) continue;
         
-        const date = new Date(log.timestamp).toLocaleDateString();
+        const date = toLocalDateKey(new Date(log.timestamp));
         
         if (!dataMap.has(date)) {
           dataMap.set(date, {
@@ -268,17 +303,16 @@ export const TypingChart = React.memo(function TypingChart({ logs }: TypingChart
         data.totalWords += Math.ceil(contentLength / 5);
       }
 
-      // 배열로 변환 (Object.entries 사용)
-      const sortedDates = Array.from(dataMap.keys()).sort((a, b) => 
-        new Date(a).getTime() - new Date(b).getTime()
-      );
+      // 배열로 변환 (YYYY-MM-DD 키는 문자열 정렬이 날짜순)
+      const sortedDates = Array.from(dataMap.keys()).sort();
       
       return {
         // 날짜 레이블 간략화 (월/일만 표시)
         labels: sortedDates.map(date => {
-          const parts = date.split('/');
-          return parts.length >= 2 ? `${parts[0]}/${parts[1]}` : date;
+          const [, month, day] = date.split('-');
+          return `${Number(month)}/${Number(day)}`;
         }),
+        dateKeys: sortedDates,
         keyCountData: sortedDates.map(date => dataMap.get(date).totalKeyCount),
         // 분 단위로 변환하고 정수로 반올림
         timeData: sortedDates.map(date => Math.round(dataMap.get(date).totalTime / 60)),
@@ -295,6 +329,7 @@ export const TypingChart = React.memo(function TypingChart({ logs }: TypingChart
       // 오류 발생 시 빈 데이터 반환
       return {
         labels: [],
+        dateKeys: [],
         keyCountData: [],
         timeData: [],
         speedData: [],
@@ -329,16 +364,41 @@ export const TypingChart = React.memo(function TypingChart({ logs }: TypingChart
     };
   }, []);
 
-  // 메모이제이션된 차트 데이터 (함수로 생성)
-  const speedChartData = useMemo(() => 
-    createChartData(
+  // 지표별 이상치 날짜 집합
+  const anomalyDates = useMemo(() => ({
+    wpm: new Set(anomalies.filter(a => a.metric === 'wpm').map(a => a.date)),
+    words: new Set(anomalies.filter(a => a.metric === 'words').map(a => a.date)),
+  }), [anomalies]);
+
+  const anomalyColor = isDarkMode ? 'rgb(255, 183, 77)' : 'rgb(255, 152, 0)';
+
+  // 메모이제이션된 차트 데이터 (함수로 생성, 속도 이상치 날짜는 강조 표시)
+  const speedChartData = useMemo(() => {
+    const data = createChartData(
       chartData.labels,
       chartData.speedData,
       '평균 타이핑 속도 (타/분)',
       isDarkMode ? 'rgb(3, 218, 198)' : 'rgb(75, 192, 192)',
       isDarkMode ? 'rgba(3, 218, 198, 0.5)' : 'rgba(75, 192, 192, 0.5)'
-    ),
-  [chartData.labels, chartData.speedData, isDarkMode, createChartData]);
+    );
+    const dataset = data.datasets[0];
+    const isAnomaly = chartData.dateKeys.map(date => anomalyDates.wpm.has(date));
+
+    return {
+      ...data,
+      datasets: [{
+        ...dataset,
+        pointBackgroundColor: isAnomaly.map(flagged => flagged ? anomalyColor : dataset.borderColor),
+        pointRadius: isAnomaly.map(flagged => flagged ? 5 : 2),
+      }],
+    };
+  }, [chartData.labels, chartData.speedData, chartData.dateKeys, anomalyDates, anomalyColor, isDarkMode, createChartData]);
+
+  // 차트 구간의 이상치 설명 목록
+  const visibleAnomalies = useMemo(() => {
+    const visibleDates = new Set(chartData.dateKeys);
+    return anomalies.filter(a => visibleDates.has(a.date));
+  }, [anomalies, chartData.dateKeys]);
 
   // 더 효율적인 렌더링을 위한 지연 로딩 상태
   const [shouldRenderCharts, setShouldRenderCharts] = useState(false);
@@ -397,7 +457,10 @@ export const TypingChart = React.memo(function TypingChart({ logs }: TypingChart
                       datasets: [{
                         label: '총 타자 수',
                         data: chartData.keyCountData,
-                        backgroundColor: isDarkMode ? 'rgba(30, 136, 229, 0.7)' : 'rgba(54, 162, 235, 0.5)',
+                        // 작성량 이상치 날짜는 강조 색으로 표시
+                        backgroundColor: chartData.dateKeys.map(date => anomalyDates.words.has(date)
+                          ? anomalyColor
+                          : (isDarkMode ? 'rgba(30, 136, 229, 0.7)' : 'rgba(54, 162, 235, 0.5)')),
                       }]
                     }}
                     options={getChartOptions('일별 총 타자 수')}
@@ -427,6 +490,21 @@ export const TypingChart = React.memo(function TypingChart({ logs }: TypingChart
               </div>
             </>
           )}
+          
+          {visibleAnomalies.length > 0 && (
+            <div className={styles.anomalies}>
+              <h3>평소와 다른 날</h3>
+              <ul>
+                {visibleAnomalies.map(anomaly => (
+                  <li key={`${anomaly.date}-${anomaly.metric}`}>
+                    {anomaly.date}: {anomaly.metric === 'wpm' ? '타이핑 속도' : '작성 단어 수'}
+                    {anomaly.direction === 'high' ? ' 평소보다 높음' : ' 평소보다 낮음'}
+                    {` (${Math.round(anomaly.value)}, 평소 ${Math.round(anomaly.expected)})`}
+                  </li>
+                ))}
+              </ul>
+            </div>
+          )}
         </div>
       ) : (
         <p className={styles.noData}>저장된 타이핑 데이터가 없습니다.</p>
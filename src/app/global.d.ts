@@ -53,6 +53,16 @@ type WindowModeType = 'windowed' | 'fullscreen' | 'fullscreen-auto-hide';
 
 type ProcessingModeType = 'auto' | 'normal' | 'cpu-intensive' | 'gpu-intensive';
 
+interface WritingGoalStatus {
+  goalId: number;
+  document: string | null;
+  metric: 'words' | 'minutes';
+  target: number;
+  progress: number;
+  percent: number;
+  achieved: boolean;
+}
+
 interface TraySettings {
   minimizeToTray: boolean;
   showTrayNotifications: boolean;
@@ -82,6 +92,13 @@ interface SettingsState {
   maxMemoryThreshold: number;
 }
 
+// 일/주 단위 통계 (activeHours는 0~23시 시간대별 입력 시간, 초)
+interface PeriodStats {
+  granularity: 'day' | 'week' | null;
+  points: Array<Record<string, number | string>>;
+  activeHours: number[];
+}
+
 interface ElectronAPI {
   onTypingStatsUpdate: (callback: (data: TypingStatsUpdate) => void) => () => void;
   onStatsSaved: (callback: (data: StatsSaved) => void) => () => void;
@@ -121,6 +138,268 @@ interface ElectronAPI {
   onMiniViewStatsUpdate?: (callback: (data: any) => void) => () => void;
   toggleMiniView?: () => void;
   
+  getSandboxStatus?: () => Promise<{ sandbox: boolean; userDataPath: string }>;
+  setAutostart?: (enabled: boolean, options?: { captureOnly?: boolean }) => Promise<{
+    success: boolean;
+    error?: string;
+    enabled?: boolean;
+    captureOnly?: boolean;
+    platform?: string;
+    method?: 'registry-run-key' | 'launch-agent' | 'xdg-autostart' | null;
+    location?: string | null;
+  }>;
+  setGpuEnabled?: (enabled: boolean) => Promise<{
+    success: boolean;
+    enabled?: boolean;
+    error?: string;
+    gpu?: {
+      status: 'ok' | 'disabled' | 'unavailable' | 'failed' | 'not_initialized';
+      error: string | null;
+      capabilities: Record<string, unknown>;
+      duration_ms: number;
+    };
+    active_gpu_tasks?: number;
+  }>;
+  getAutostartStatus?: () => Promise<{
+    enabled: boolean;
+    captureOnly: boolean;
+    platform: string;
+    method: 'registry-run-key' | 'launch-agent' | 'xdg-autostart' | null;
+    location: string | null;
+  }>;
+  setGuestMode?: (enabled: boolean) => Promise<{ active: boolean; startedAt: number | null }>;
+  getGuestModeStatus?: () => Promise<{ active: boolean; startedAt: number | null }>;
+  onGuestModeChanged?: (callback: (data: { active: boolean; timestamp: number; description?: string }) => void) => () => void;
+  getGoals?: () => Promise<{ success: boolean; goals?: WritingGoalStatus[]; error?: string }>;
+  createGoal?: (options: { document?: string | null; metric: 'words' | 'minutes'; target: number }) => Promise<{
+    success: boolean;
+    goal?: { id: number; document: string | null; metric: 'words' | 'minutes'; target: number; created_at: string };
+    error?: string;
+  }>;
+  deleteGoal?: (id: number) => Promise<{ success: boolean; error?: string }>;
+  getGoalHistory?: (options: { goalId: number; days?: number }) => Promise<{
+    success: boolean;
+    history?: Array<{ date: string; progress: number; target: number; achievedAt: string | null }>;
+    error?: string;
+  }>;
+  onGoalProgress?: (callback: (data: { date: string; goals: WritingGoalStatus[]; timestamp: number }) => void) => () => void;
+  onGoalAchieved?: (callback: (data: WritingGoalStatus & { timestamp: number; description?: string }) => void) => () => void;
+  generateWeeklyReport?: (options?: { week?: string; format?: 'markdown' | 'html'; email?: boolean }) => Promise<{
+    success: boolean;
+    filePath?: string;
+    week?: { start: string; end: string };
+    totals?: {
+      sessions: number;
+      words: number;
+      keyCount: number;
+      typingMinutes: number;
+      averageWpm: number;
+      averageAccuracy: number;
+    };
+    emailed?: boolean;
+    emailError?: string;
+    error?: string;
+  }>;
+  setReportSmtpPassword?: (password: string) => Promise<{ success: boolean }>;
+  getAnalyticsExportStatus?: () => Promise<{
+    enabled: boolean;
+    running: boolean;
+    endpoint: string;
+    hasToken: boolean;
+    pending: number;
+    failed: number;
+    lastSuccess: number | null;
+    lastError: string | null;
+  }>;
+  setAnalyticsToken?: (token: string) => Promise<{ success: boolean }>;
+  flushAnalyticsExport?: (options?: { retryFailed?: boolean }) => Promise<{
+    success: boolean;
+    sent?: number;
+    enqueued?: number;
+    requeued?: number;
+    error?: string;
+  }>;
+  getAppLockStatus?: () => Promise<{
+    enabled: boolean;
+    locked: boolean;
+    hasPin: boolean;
+    idleMinutes: number;
+    systemAuthAvailable: boolean;
+    retryAfterMs: number;
+  }>;
+  unlockApp?: (pin: string) => Promise<{
+    success: boolean;
+    locked: boolean;
+    error?: string;
+    remainingAttempts?: number;
+    retryAfterMs?: number;
+  }>;
+  unlockAppWithSystemAuth?: () => Promise<{ success: boolean; locked: boolean; error?: string }>;
+  lockApp?: () => Promise<{ success: boolean; locked: boolean }>;
+  setAppLockPin?: (options: { currentPin?: string | null; newPin: string }) => Promise<{ success: boolean; error?: string }>;
+  clearAppLockPin?: (currentPin: string) => Promise<{ success: boolean; error?: string }>;
+  onAppLockChanged?: (callback: (data: { locked: boolean; timestamp: number; description?: string }) => void) => () => void;
+  getMetricsStatus?: () => Promise<{
+    enabled: boolean;
+    running: boolean;
+    path: string;
+    lastWrite: number | null;
+    lastError: string | null;
+  }>;
+  
+  // 통계 조회 관련 메서드
+  getTypingTrend?: (from: string, to?: string, granularity?: 'day' | 'week' | 'month') => Promise<{
+    granularity: 'day' | 'week' | 'month' | null;
+    points: Array<Record<string, number | string>>;
+  }>;
+  getDailyStats?: (from: string, to?: string) => Promise<PeriodStats>;
+  getWeeklyStats?: (from: string, to?: string) => Promise<PeriodStats>;
+  getTypingStatsByApp?: (period?: 'day' | 'week' | 'month' | 'all') => Promise<{
+    period: 'day' | 'week' | 'month' | 'all' | null;
+    from: string | null;
+    applications: Array<{
+      application: string | null;
+      sessionCount: number;
+      keyCount: number;
+      typingTime: number;
+      totalChars: number;
+      totalWords: number;
+      wpm: number;
+      avgAccuracy: number;
+    }>;
+  }>;
+  getAnomalies?: (options?: { days?: number; threshold?: number }) => Promise<Array<{
+    date: string;
+    metric: 'words' | 'wpm';
+    value: number;
+    expected: number;
+    zScore: number;
+    direction: 'high' | 'low';
+  }>>;
+  executeReadonlyQuery?: (sql: string, options?: { maxRows?: number; timeoutMs?: number }) => Promise<{
+    success: boolean;
+    error?: string;
+    columns?: string[];
+    rows?: Array<Record<string, unknown>>;
+    rowCount?: number;
+    truncated?: boolean;
+    timedOut?: boolean;
+    durationMs?: number;
+  }>;
+  getDatabaseHealth?: () => Promise<{
+    healthy: boolean;
+    path: string;
+    error?: string;
+    journalMode?: string;
+    busyTimeoutMs?: number;
+    foreignKeys?: boolean;
+    pageCount?: number;
+    pageSize?: number;
+    sizeBytes?: number;
+    walSizeBytes?: number;
+    quickCheck?: string;
+    activeQueryWorkers?: number;
+    durationMs: number;
+  }>;
+  exportTypingSessions?: (options?: { includeContent?: boolean }) => Promise<{
+    success: boolean;
+    canceled?: boolean;
+    error?: string;
+    exported?: number;
+    filePath?: string;
+  }>;
+  exportAggregateStats?: (options?: {
+    granularity?: 'day' | 'week' | 'month';
+    from?: string;
+    to?: string;
+    differentialPrivacy?: boolean;
+    epsilon?: number | 'strong' | 'balanced' | 'light';
+  }) => Promise<{
+    success: boolean;
+    canceled?: boolean;
+    error?: string;
+    exported?: number;
+    filePath?: string;
+    privacy?: {
+      mechanism: 'laplace';
+      unit: 'session';
+      epsilonPerPeriod: number;
+      sessionBounds: Record<string, number>;
+    } | null;
+  }>;
+  importTypingSessions?: () => Promise<{
+    success: boolean;
+    canceled?: boolean;
+    error?: string;
+    imported?: number;
+    skipped?: number;
+    invalid?: number;
+  }>;
+  exportAnalytics?: (options?: {
+    format?: 'json' | 'csv';
+    from?: string;
+    to?: string;
+    includeContent?: boolean;
+  }) => Promise<{
+    success: boolean;
+    canceled?: boolean;
+    error?: string;
+    exported?: { sessions: number; daily: number; applications: number };
+    files?: string[];
+  }>;
+  importAnalytics?: () => Promise<{
+    success: boolean;
+    canceled?: boolean;
+    error?: string;
+    imported?: number;
+    skipped?: number;
+    invalid?: number;
+    dailyImported?: number;
+    dailySkipped?: number;
+  }>;
+  exportAllUserData?: () => Promise<{
+    success: boolean;
+    canceled?: boolean;
+    error?: string;
+    filePath?: string;
+    files?: number;
+    bytes?: number;
+  }>;
+  requestDataWipe?: () => Promise<{
+    success: boolean;
+    error?: string;
+    token?: string;
+    expiresAt?: number;
+    locations?: Array<{ name: string; path: string }>;
+  }>;
+  wipeAllLocalData?: (token: string, options?: { exportBeforeWipe?: boolean }) => Promise<{
+    success: boolean;
+    canceled?: boolean;
+    error?: string;
+    deletedFiles?: number;
+    exported?: { filePath: string; files: number; bytes: number } | null;
+  }>;
+  onSystemResumed?: (callback: (data: {
+    suspendedAt: number | null;
+    resumedAt: number;
+    sleptMs: number;
+  }) => void) => () => void;
+  onTypingSessionIdle?: (callback: (data: {
+    idleSeconds: number;
+    lastActiveTime: number;
+    savedSession: Record<string, unknown> | null;
+    trackingStopped: boolean;
+    timestamp: number;
+    description?: string;
+  }) => void) => () => void;
+  onBreakRecommended?: (callback: (data: {
+    score: number;
+    level: 'low' | 'moderate' | 'high' | 'severe';
+    threshold: number;
+    continuousTypingMs: number;
+    timestamp: number;
+  }) => void) => () => void;
+  
   // 트레이 관련 메서드
   updateTraySettings?: (settings: TraySettings) => Promise<any>;
   quitApp?: () => void;
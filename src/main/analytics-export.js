@@ -0,0 +1,327 @@
+/**
+ * 외부 분석 서비스 내보내기 모듈
+ *
+ * 저장된 타이핑 세션의 집계값(입력 내용 제외)을 로컬 대기열(analytics_outbox)에 쌓아 두고,
+ * 설정한 주소로 주기적으로 묶어서 전송합니다. 전송에 실패한 항목은 점점 긴 간격으로 다시 시도하며,
+ * 앱을 다시 시작해도 대기열이 유지되므로 오프라인 동안의 기록도 나중에 전송됩니다.
+ */
+const crypto = require('crypto');
+const { app } = require('electron');
+const { appState } = require('./constants');
+const { debugLog, calculateWpm } = require('./utils');
+const {
+  getMaxStatId,
+  getStatsAfter,
+  enqueueAnalyticsEvents,
+  getDueAnalyticsEvents,
+  deleteAnalyticsEvents,
+  markAnalyticsEventsFailed,
+  getAnalyticsOutboxStats,
+  retryFailedAnalyticsEvents,
+  saveSetting,
+  loadSetting
+} = require('./database');
+const { saveSecureSetting, loadSecureSetting, hasSecureSetting } = require('./secure-setting');
+
+// 설정 테이블 키
+const TOKEN_KEY = 'analytics_export_token';
+const CURSOR_KEY = 'analytics_export_cursor';
+const INSTALL_ID_KEY = 'analytics_install_id';
+
+// 전송 간격 (ms)
+const DEFAULT_INTERVAL = 5 * 60 * 1000;
+const MIN_INTERVAL = 60 * 1000;
+
+// 한 번에 보내는 세션 수
+const DEFAULT_BATCH_SIZE = 50;
+const MAX_BATCH_SIZE = 500;
+
+// 한 번에 대기열로 옮기는 기록 수
+const ENQUEUE_CHUNK = 500;
+
+// 재시도 간격 (1분부터 두 배씩, 최대 6시간)
+const RETRY_BASE_MS = 60 * 1000;
+const RETRY_MAX_MS = 6 * 60 * 60 * 1000;
+
+// 이 횟수만큼 실패하면 재시도를 멈춤 (retryFailedAnalyticsExports로 다시 시도)
+const MAX_ATTEMPTS = 10;
+
+// 요청 제한 시간
+const REQUEST_TIMEOUT_MS = 30000;
+
+// 암호화하지 않은 http를 허용하는 호스트
+const LOCAL_HOSTS = new Set(['localhost', '127.0.0.1', '[::1]']);
+
+let exportTimer = null;
+let flushing = null;
+let lastSuccess = null;
+let lastError = null;
+
+/**
+ * 전송 주소 검사 (https 또는 로컬 http만 허용)
+ * @param {string} endpoint - 주소
+ * @returns {URL} 파싱된 주소
+ */
+function parseEndpoint(endpoint) {
+  let url;
+  try {
+    url = new URL(endpoint);
+  } catch (error) {
+    throw new Error(`잘못된 분석 내보내기 주소입니다: ${endpoint}`);
+  }
+  if (url.protocol !== 'https:' && !(url.protocol === 'http:' && LOCAL_HOSTS.has(url.hostname))) {
+    throw new Error('분석 내보내기 주소는 https여야 합니다 (로컬 주소는 http 허용)');
+  }
+  return url;
+}
+
+/**
+ * 이 설치본의 식별자 (수신 측에서 세션 ID 중복 제거에 사용)
+ * @returns {string}
+ */
+function getInstallId() {
+  let installId = loadSetting(INSTALL_ID_KEY, null);
+  if (!installId) {
+    installId = crypto.randomUUID();
+    saveSetting(INSTALL_ID_KEY, installId);
+  }
+  return installId;
+}
+
+/**
+ * 통계 기록을 전송용 집계로 변환
+ * @param {Object} row - typing_stats 기록
+ * @param {string} installId - 설치 식별자
+ * @returns {Object}
+ */
+function toEvent(row, installId) {
+  const event = {
+    id: `${installId}:${row.id}`,
+    time: row.timestamp,
+    durationSeconds: row.typing_time || 0,
+    keystrokes: row.key_count || 0,
+    words: row.total_words || 0,
+    characters: row.total_chars || 0,
+    accuracy: row.accuracy,
+    wpm: Math.round(calculateWpm(row.key_count, row.typing_time) * 10) / 10,
+    browser: row.browser_name || null,
+    endReason: row.end_reason || null
+  };
+  // 창 제목은 설정에서 허용한 경우에만 포함
+  if (appState.settings?.analyticsIncludeTitles) {
+    event.title = row.window_title || null;
+  }
+  return event;
+}
+
+/**
+ * 마지막으로 대기열에 넣은 이후의 세션을 대기열에 추가
+ * 처음 켤 때는 이전 기록을 올리지 않도록 현재 위치부터 시작합니다.
+ * @returns {number} 추가된 수
+ */
+function enqueueNewSessions() {
+  let cursor = loadSetting(CURSOR_KEY, null);
+  if (cursor === null) {
+    cursor = getMaxStatId();
+    saveSetting(CURSOR_KEY, cursor);
+    return 0;
+  }
+
+  const installId = getInstallId();
+  let added = 0;
+  for (;;) {
+    const rows = getStatsAfter(Number(cursor), ENQUEUE_CHUNK);
+    if (rows.length === 0) break;
+
+    added += enqueueAnalyticsEvents(rows.map(row => ({ sessionId: row.id, payload: toEvent(row, installId) })));
+    cursor = rows[rows.length - 1].id;
+    saveSetting(CURSOR_KEY, cursor);
+    if (rows.length < ENQUEUE_CHUNK) break;
+  }
+  return added;
+}
+
+// 실패 횟수에 따른 다음 시도까지의 대기 시간
+function retryDelay(attempts) {
+  return Math.min(RETRY_BASE_MS * 2 ** attempts, RETRY_MAX_MS);
+}
+
+/**
+ * 묶음 하나 전송
+ * @param {URL} url - 전송 주소
+ * @param {string} token - 인증 토큰
+ * @param {Array} events - 대기열 항목
+ */
+async function sendBatch(url, token, events) {
+  const controller = new AbortController();
+  const timeout = setTimeout(() => controller.abort(), REQUEST_TIMEOUT_MS);
+
+  try {
+    const headers = { 'Content-Type': 'application/json' };
+    if (token) {
+      headers.Authorization = `Bearer ${token}`;
+    }
+
+    const response = await fetch(url, {
+      method: 'POST',
+      headers,
+      body: JSON.stringify({
+        source: 'loop',
+        version: app.getVersion(),
+        installId: getInstallId(),
+        sessions: events.map(event => event.payload)
+      }),
+      signal: controller.signal
+    });
+
+    if (!response.ok) {
+      throw new Error(`HTTP ${response.status} ${response.statusText}`.trim());
+    }
+  } catch (error) {
+    throw error.name === 'AbortError' ? new Error('요청 시간 초과') : error;
+  } finally {
+    clearTimeout(timeout);
+  }
+}
+
+// 대기열 전송 (flushAnalyticsExport 참고)
+async function runFlush() {
+  const settings = appState.settings || {};
+  if (!settings.analyticsEndpoint) {
+    return { success: false, sent: 0, enqueued: 0, error: '분석 내보내기 주소가 설정되지 않았습니다' };
+  }
+
+  let url;
+  try {
+    url = parseEndpoint(settings.analyticsEndpoint);
+  } catch (error) {
+    lastError = error.message;
+    return { success: false, sent: 0, enqueued: 0, error: error.message };
+  }
+
+  const enqueued = enqueueNewSessions();
+  const token = loadSecureSetting(TOKEN_KEY);
+  const batchSize = Math.min(MAX_BATCH_SIZE, Math.max(1, Number(settings.analyticsBatchSize) || DEFAULT_BATCH_SIZE));
+  let sent = 0;
+
+  for (;;) {
+    const events = getDueAnalyticsEvents(Date.now(), batchSize, MAX_ATTEMPTS);
+    if (events.length === 0) break;
+
+    try {
+      await sendBatch(url, token, events);
+    } catch (error) {
+      const attempts = Math.min(...events.map(event => event.attempts));
+      markAnalyticsEventsFailed(events.map(event => event.id), error.message, Date.now() + retryDelay(attempts));
+      lastError = error.message;
+      debugLog(`분석 내보내기 전송 실패 (${events.length}개):`, error.message);
+      return { success: false, sent, enqueued, error: error.message };
+    }
+
+    deleteAnalyticsEvents(events.map(event => event.id));
+    sent += events.length;
+    lastSuccess = Date.now();
+    lastError = null;
+    if (events.length < batchSize) break;
+  }
+
+  if (sent > 0) {
+    debugLog(`분석 내보내기 전송 완료: ${sent}개 세션`);
+  }
+  return { success: true, sent, enqueued };
+}
+
+/**
+ * 대기열 전송
+ * 새 세션을 대기열에 넣은 뒤, 보낼 차례가 된 항목을 묶음으로 전송합니다.
+ * 한 묶음이 실패하면 그 묶음의 항목만 재시도 대기로 돌리고 이번 전송을 멈춥니다.
+ * 이미 전송 중이면 진행 중인 전송 결과를 함께 기다립니다.
+ * @returns {Promise<Object>} { success, sent, enqueued, error? }
+ */
+function flushAnalyticsExport() {
+  if (!flushing) {
+    flushing = runFlush()
+      .catch(error => {
+        lastError = error.message;
+        console.error('분석 내보내기 오류:', error);
+        return { success: false, sent: 0, enqueued: 0, error: error.message };
+      })
+      .finally(() => {
+        flushing = null;
+      });
+  }
+  return flushing;
+}
+
+/**
+ * 분석 내보내기 중지
+ */
+function stopAnalyticsExport() {
+  if (exportTimer) {
+    clearInterval(exportTimer);
+    exportTimer = null;
+    debugLog('분석 내보내기 중지');
+  }
+}
+
+/**
+ * 현재 설정에 맞춰 분석 내보내기 시작/중지
+ * 설정이 바뀔 때마다 호출해도 되며, 주소나 간격 변경도 반영합니다.
+ */
+function applyAnalyticsSettings() {
+  stopAnalyticsExport();
+
+  const settings = appState.settings || {};
+  if (!settings.analyticsExportEnabled || !settings.analyticsEndpoint) {
+    return;
+  }
+
+  const interval = Math.max(MIN_INTERVAL, Number(settings.analyticsInterval) || DEFAULT_INTERVAL);
+  flushAnalyticsExport();
+  exportTimer = setInterval(flushAnalyticsExport, interval);
+  debugLog(`분석 내보내기 시작 (${interval}ms 간격)`);
+}
+
+/**
+ * 인증 토큰 저장 (빈 값이면 삭제)
+ * @param {string} token - 토큰
+ * @returns {boolean} 저장 성공 여부
+ */
+function setAnalyticsToken(token) {
+  return saveSecureSetting(TOKEN_KEY, token);
+}
+
+/**
+ * 재시도를 멈춘 항목을 다시 보내도록 초기화
+ * @returns {number} 초기화된 수
+ */
+function retryFailedAnalyticsExports() {
+  return retryFailedAnalyticsEvents(MAX_ATTEMPTS);
+}
+
+/**
+ * 분석 내보내기 상태
+ * @returns {Object} 상태 정보
+ */
+function getAnalyticsExportStatus() {
+  const outbox = getAnalyticsOutboxStats(MAX_ATTEMPTS);
+  return {
+    enabled: Boolean(appState.settings?.analyticsExportEnabled),
+    running: exportTimer !== null,
+    endpoint: appState.settings?.analyticsEndpoint || '',
+    hasToken: hasSecureSetting(TOKEN_KEY),
+    pending: outbox.pending,
+    failed: outbox.failed,
+    lastSuccess,
+    lastError: lastError || outbox.lastError
+  };
+}
+
+module.exports = {
+  applyAnalyticsSettings,
+  stopAnalyticsExport,
+  flushAnalyticsExport,
+  setAnalyticsToken,
+  retryFailedAnalyticsExports,
+  getAnalyticsExportStatus
+};
@@ -1,4 +1,4 @@
-const { app } = require('electron');
+const { app, powerMonitor } = require('electron');
 const { appState, MEMORY_CHECK_INTERVAL, HIGH_MEMORY_THRESHOLD } = require('./constants.js');
 const { createWindow } = require('./window');
 const { setupKeyboardListener } = require('./keyboard.js');
@@ -7,7 +7,14 @@ const { loadSettings } = require('./settings.js');
 const { debugLog } = require('./utils.js');
 const { setupTray, destroyTray } = require('./tray.js');
 const memoryManager = require('./memory-manager.js');
-const { switchToLowMemoryMode } = require('./stats.js');
+const { switchToLowMemoryMode, startTracking } = require('./stats.js');
+const { applyMetricsSettings, stopMetricsExport } = require('./metrics-exporter');
+const { applyAnalyticsSettings, stopAnalyticsExport } = require('./analytics-export');
+const { initializeAppLock, cleanupAppLock } = require('./app-lock');
+const { isCaptureOnlyLaunch } = require('./autostart');
+const { setupGuestMode } = require('./guest-mode');
+const { initializeGoals, cleanupGoals } = require('./goals');
+const { applyGpuComputeSetting, applyPreferredGpuDevice } = require('./gpu-compute');
 const fs = require('fs');
 const path = require('path');
 
@@ -26,11 +33,26 @@ async function setupGpuConfiguration() {
     
     debugLog(`GPU 가속 설정 상태: ${useHardwareAcceleration ? '활성화됨' : '비활성화됨'}, 모드: ${processingMode}`);
     
+    // 네이티브 GPU 연산을 끈 경우 초기화 전에 먼저 적용
+    applyGpuComputeSetting();
+    // 저장된 선호 GPU 장치도 초기화 전에 적용 (초기화할 때 이 장치를 먼저 선택)
+    applyPreferredGpuDevice();
+    const gpuComputeEnabled = appState.settings?.gpuComputeEnabled !== false;
+    
     // Rust 네이티브 모듈 사용 시도
     try {
       const nativeModule = await import('../native-modules/index.js');
       
       if (nativeModule) {
+        // 셰이더 디스크 캐시 위치 설정 (이전 실행에서 컴파일한 셰이더 재사용)
+        if (typeof nativeModule.setShaderCacheDir === 'function') {
+          try {
+            nativeModule.setShaderCacheDir(path.join(app.getPath('userData'), 'shader-cache'));
+          } catch (cacheError) {
+            debugLog('셰이더 캐시 디렉터리 설정 실패:', cacheError);
+          }
+        }
+
         // 초기화 함수 확인 - 여러 가능한 함수명 시도
         const initFuncNames = [
           'initialize_gpu',
@@ -39,7 +61,7 @@ async function setupGpuConfiguration() {
         ];
         
         let initialized = false;
-        for (const funcName of initFuncNames) {
+        for (const funcName of gpuComputeEnabled ? initFuncNames : []) {
           if (typeof nativeModule[funcName] === 'function') {
             initialized = nativeModule[funcName]();
             if (initialized) {
@@ -101,6 +123,9 @@ async function setupGpuConfiguration() {
       debugLog('네이티브 GPU 모듈 초기화 실패, JS 구현으로 폴백:', nativeError);
     }
     
+    // 첫 GPU/분석 작업이 멈추지 않도록 GPU 컨텍스트, 셰이더, 메모리 풀을 백그라운드에서 예열
+    startNativeWarmUp();
+    
     // 네이티브 모듈 사용 불가능한 경우 기본 Electron 설정 적용
     const { configureGPU } = await import('./electron-config.js');
     
@@ -231,6 +256,12 @@ async function initializeApp() {
       await loadSettings();
     }
     
+    // 앱 잠금 적용 (창이 열리기 전에 잠긴 상태로 전환)
+    initializeAppLock();
+    
+    // 게스트 모드 응답 가림 등록
+    setupGuestMode();
+    
     // 메인 윈도우 생성
     createWindow();
     
@@ -241,10 +272,28 @@ async function initializeApp() {
     setupIpcHandlers();
     
     // 트레이 설정 추가
-    if (appState.settings.minimizeToTray) {
+    if (appState.settings.minimizeToTray || isCaptureOnlyLaunch()) {
       setupTray();
     }
     
+    // 기록 전용 모드: 창을 띄우지 않고 바로 모니터링 시작
+    if (isCaptureOnlyLaunch() && !appState.isTracking) {
+      debugLog('기록 전용 모드로 시작 - 메인 윈도우 숨김');
+      startTracking();
+    }
+    
+    // 시스템 절전/해제 감지
+    setupPowerMonitor();
+    
+    // 메트릭 파일 내보내기 (설정에서 켠 경우)
+    applyMetricsSettings();
+    
+    // 외부 분석 서비스 내보내기 (설정에서 켠 경우)
+    applyAnalyticsSettings();
+    
+    // 작성 목표 진행률 확인 시작
+    initializeGoals();
+    
     // GC가 사용 가능한지 확인
     if (typeof global.gc === 'function') {
       debugLog('GC 사용 가능 - 초기화 후 메모리 정리 실행');
@@ -273,6 +322,26 @@ async function initializeApp() {
   }
 }
 
+/**
+ * 네이티브 모듈 예열 시작 (기다리지 않음, 완료되면 결과 로그)
+ */
+function startNativeWarmUp() {
+  try {
+    const { warmUp } = require('../server/native');
+    warmUp()
+      .then(report => {
+        if (report?.ok) {
+          debugLog(`네이티브 모듈 예열 완료 (${Math.round(report.duration_ms)}ms)`);
+        } else {
+          debugLog('네이티브 모듈 예열 중 일부 단계 실패:', report?.steps);
+        }
+      })
+      .catch(error => debugLog('네이티브 모듈 예열 실패:', error));
+  } catch (error) {
+    debugLog('네이티브 모듈 예열 시작 실패:', error);
+  }
+}
+
 /**
  * 앱 종료 정리 함수 - 메모리 최적화
  */
@@ -283,6 +352,18 @@ function cleanupApp() {
     // 메모리 모니터링 중지
     stopMemoryMonitoring();
     
+    // 메트릭 내보내기 중지
+    stopMetricsExport();
+    
+    // 분석 내보내기 중지 (대기열은 다음 실행 때 이어서 전송)
+    stopAnalyticsExport();
+    
+    // 앱 잠금 유휴 감지 중지
+    cleanupAppLock();
+    
+    // 작성 목표 확인 중지
+    cleanupGoals();
+    
     // 워커 풀 정리
     const { cleanupWorkerPool } = require('./workers/worker-manager');
     if (typeof cleanupWorkerPool === 'function') {
@@ -343,6 +424,27 @@ function cleanupApp() {
 // 메모리 모니터링 관련 변수
 let memoryMonitoringInterval = null;
 
+/**
+ * 시스템 절전/해제 이벤트 처리 설정
+ * 절전 중에는 타이머가 늦게 실행되므로 해제 직후 세션 시간을 보정하고 놓친 작업을 실행합니다.
+ */
+function setupPowerMonitor() {
+  const { handleSystemSuspend, handleSystemResume } = require('./stats.js');
+  
+  powerMonitor.on('suspend', () => {
+    handleSystemSuspend();
+  });
+  
+  powerMonitor.on('resume', () => {
+    handleSystemResume();
+    
+    // 절전 중 밀린 메모리 점검 바로 실행
+    memoryManager.checkMemoryUsage();
+  });
+  
+  debugLog('시스템 절전 감지 설정 완료');
+}
+
 /**
  * 메모리 모니터링 시작
  */
@@ -0,0 +1,395 @@
+/**
+ * 앱 잠금 모듈
+ * PIN(또는 macOS Touch ID)으로 앱을 잠그고, 잠긴 동안에는 허용된 IPC 요청 외에는 처리하지 않습니다
+ * (요청 검사는 ipc-guard 모듈을 통해 모든 핸들러에 적용됨).
+ * 시작 시와 일정 시간 입력이 없을 때, 화면 잠금/절전 시 자동으로 잠깁니다.
+ */
+const crypto = require('crypto');
+const { powerMonitor, safeStorage, systemPreferences } = require('electron');
+const { appState } = require('./constants');
+const { debugLog, withAccessibleDescription } = require('./utils');
+const { addRequestGuard } = require('./ipc-guard');
+
+// PIN 해시 저장 키 (settings 테이블)
+const PIN_SETTING_KEY = 'app_lock_pin';
+
+// 연속 실패 횟수와 시도 제한 시각 저장 키 (재시작해도 제한 유지)
+const ATTEMPTS_SETTING_KEY = 'app_lock_attempts';
+
+// PIN 형식 (숫자 4~12자리)
+const PIN_PATTERN = /^\d{4,12}$/;
+
+// scrypt 매개변수
+const SCRYPT_KEY_LENGTH = 32;
+const SCRYPT_COST = 16384;
+
+// 연속 실패 시 잠금 해제 시도 제한
+const MAX_FAILED_ATTEMPTS = 5;
+const INITIAL_LOCKOUT_MS = 30000;
+const MAX_LOCKOUT_MS = 15 * 60 * 1000;
+
+// 유휴 시간 확인 간격 (ms)
+const IDLE_CHECK_INTERVAL = 15000;
+
+// 잠긴 상태에서도 처리하는 IPC 채널 (잠금 해제, 창 조작, 메모리 관리 등 데이터를 노출하지 않는 요청)
+const UNLOCKED_CHANNELS = new Set([
+  'get-app-lock-status',
+  'unlock-app',
+  'unlock-app-with-system-auth',
+  'window-control',
+  'toggle-window',
+  'get-window-mode',
+  'get-dark-mode',
+  'quit-app',
+  'get-memory-usage',
+  'request-gc',
+  'optimize-memory',
+  'renderer-gc-completed',
+  'check-memory'
+]);
+
+let locked = false;
+let failedAttempts = 0;
+let lockoutUntil = 0;
+let idleTimer = null;
+let guardRegistered = false;
+
+/**
+ * 저장된 PIN 레코드 읽기
+ * @returns {Object|null} { salt, hash } 또는 null
+ */
+function readPinRecord() {
+  const { loadSetting } = require('./database');
+  const stored = loadSetting(PIN_SETTING_KEY, null);
+  if (!stored || typeof stored !== 'object') return null;
+
+  try {
+    // OS 보안 저장소(Keychain, DPAPI, libsecret)로 암호화한 경우 복호화
+    const json = stored.encrypted
+      ? safeStorage.decryptString(Buffer.from(stored.data, 'base64'))
+      : JSON.stringify(stored.data);
+    return JSON.parse(json);
+  } catch (error) {
+    console.error('앱 잠금 PIN 정보 읽기 오류:', error);
+    return null;
+  }
+}
+
+/**
+ * PIN 레코드 저장 (null이면 삭제)
+ * @param {Object|null} record - { salt, hash }
+ * @returns {boolean} 저장 성공 여부
+ */
+function writePinRecord(record) {
+  const { saveSetting } = require('./database');
+  if (!record) {
+    return saveSetting(PIN_SETTING_KEY, '');
+  }
+
+  if (safeStorage.isEncryptionAvailable()) {
+    const encrypted = safeStorage.encryptString(JSON.stringify(record));
+    return saveSetting(PIN_SETTING_KEY, { encrypted: true, data: encrypted.toString('base64') });
+  }
+  return saveSetting(PIN_SETTING_KEY, { encrypted: false, data: record });
+}
+
+/**
+ * 저장된 연속 실패 횟수와 시도 제한 시각 불러오기
+ */
+function loadAttemptState() {
+  const { loadSetting } = require('./database');
+  const stored = loadSetting(ATTEMPTS_SETTING_KEY, null);
+  if (!stored || typeof stored !== 'object') return;
+
+  failedAttempts = Math.max(0, Math.floor(Number(stored.failedAttempts)) || 0);
+  lockoutUntil = Math.max(0, Number(stored.lockoutUntil) || 0);
+}
+
+/**
+ * 연속 실패 횟수와 시도 제한 시각 저장
+ */
+function saveAttemptState() {
+  const { saveSetting } = require('./database');
+  saveSetting(ATTEMPTS_SETTING_KEY, { failedAttempts, lockoutUntil });
+}
+
+/**
+ * PIN 해시 계산
+ * @param {string} pin - PIN
+ * @param {string} salt - base64 솔트
+ * @returns {Buffer} 해시
+ */
+function hashPin(pin, salt) {
+  return crypto.scryptSync(pin, Buffer.from(salt, 'base64'), SCRYPT_KEY_LENGTH, { N: SCRYPT_COST });
+}
+
+/**
+ * PIN 확인 (상수 시간 비교)
+ * @param {string} pin - 입력한 PIN
+ * @returns {boolean} 일치 여부
+ */
+function verifyPin(pin) {
+  const record = readPinRecord();
+  if (!record || typeof pin !== 'string') return false;
+
+  const expected = Buffer.from(record.hash, 'base64');
+  const actual = hashPin(pin, record.salt);
+  return expected.length === actual.length && crypto.timingSafeEqual(expected, actual);
+}
+
+/**
+ * PIN이 설정되어 있는지 확인
+ * @returns {boolean}
+ */
+function hasPin() {
+  return readPinRecord() !== null;
+}
+
+/**
+ * 앱 잠금이 켜져 있는지 확인 (설정 + PIN 모두 필요)
+ * @returns {boolean}
+ */
+function isAppLockEnabled() {
+  return Boolean(appState.settings?.appLockEnabled) && hasPin();
+}
+
+/**
+ * 현재 잠김 여부
+ * @returns {boolean}
+ */
+function isLocked() {
+  return locked;
+}
+
+/**
+ * 잠금 상태 변경을 렌더러에 알림
+ */
+function notifyLockState() {
+  if (appState.mainWindow && !appState.mainWindow.isDestroyed()) {
+    appState.mainWindow.webContents.send('app-lock-changed', withAccessibleDescription(
+      { locked, timestamp: Date.now() },
+      locked ? '앱이 잠겼습니다. PIN을 입력해 잠금을 해제하세요.' : '앱 잠금이 해제되었습니다.'
+    ));
+  }
+}
+
+/**
+ * 앱 잠그기
+ * @param {string} [reason] - 잠근 이유 (로그용)
+ * @returns {boolean} 잠겼는지 여부 (잠금이 꺼져 있으면 false)
+ */
+function lockApp(reason = 'manual') {
+  if (!isAppLockEnabled()) return false;
+  if (!locked) {
+    locked = true;
+    debugLog(`앱 잠금: ${reason}`);
+    notifyLockState();
+  }
+  return true;
+}
+
+// 잠금 해제 처리 (실패 횟수 초기화)
+function markUnlocked() {
+  locked = false;
+  failedAttempts = 0;
+  lockoutUntil = 0;
+  saveAttemptState();
+  debugLog('앱 잠금 해제');
+  notifyLockState();
+}
+
+/**
+ * PIN으로 잠금 해제
+ * 연속으로 실패하면 일정 시간 동안 시도를 거부하며, 실패할 때마다 대기 시간이 늘어납니다.
+ * @param {string} pin - 입력한 PIN
+ * @returns {Object} { success, locked, error?, retryAfterMs? }
+ */
+function unlockApp(pin) {
+  if (!locked) {
+    return { success: true, locked: false };
+  }
+
+  const now = Date.now();
+  if (now < lockoutUntil) {
+    return { success: false, locked: true, error: '시도 횟수를 초과했습니다', retryAfterMs: lockoutUntil - now };
+  }
+
+  if (verifyPin(pin)) {
+    markUnlocked();
+    return { success: true, locked: false };
+  }
+
+  failedAttempts++;
+  if (failedAttempts >= MAX_FAILED_ATTEMPTS) {
+    const multiplier = 2 ** (failedAttempts - MAX_FAILED_ATTEMPTS);
+    lockoutUntil = now + Math.min(INITIAL_LOCKOUT_MS * multiplier, MAX_LOCKOUT_MS);
+    saveAttemptState();
+    return { success: false, locked: true, error: '시도 횟수를 초과했습니다', retryAfterMs: lockoutUntil - now };
+  }
+  saveAttemptState();
+
+  return {
+    success: false,
+    locked: true,
+    error: 'PIN이 올바르지 않습니다',
+    remainingAttempts: MAX_FAILED_ATTEMPTS - failedAttempts
+  };
+}
+
+/**
+ * 시스템 인증 사용 가능 여부 (현재 macOS Touch ID만 지원)
+ * @returns {boolean}
+ */
+function isSystemAuthAvailable() {
+  return process.platform === 'darwin'
+    && typeof systemPreferences.canPromptTouchID === 'function'
+    && systemPreferences.canPromptTouchID();
+}
+
+/**
+ * 시스템 인증(Touch ID)으로 잠금 해제
+ * @returns {Promise<Object>} { success, locked, error? }
+ */
+async function unlockWithSystemAuth() {
+  if (!locked) {
+    return { success: true, locked: false };
+  }
+  if (!appState.settings?.appLockUseSystemAuth || !isSystemAuthAvailable()) {
+    return { success: false, locked: true, error: '시스템 인증을 사용할 수 없습니다' };
+  }
+
+  try {
+    await systemPreferences.promptTouchID('Typing Stats 잠금 해제');
+    markUnlocked();
+    return { success: true, locked: false };
+  } catch (error) {
+    return { success: false, locked: true, error: error.message || String(error) };
+  }
+}
+
+/**
+ * PIN 설정 또는 변경
+ * 이미 PIN이 있으면 현재 PIN을 확인한 뒤 바꿉니다.
+ * @param {string|null} currentPin - 현재 PIN (처음 설정할 때는 null)
+ * @param {string} newPin - 새 PIN (숫자 4~12자리)
+ * @returns {Object} { success, error? }
+ */
+function setAppLockPin(currentPin, newPin) {
+  if (hasPin() && !verifyPin(currentPin)) {
+    return { success: false, error: '현재 PIN이 올바르지 않습니다' };
+  }
+  if (typeof newPin !== 'string' || !PIN_PATTERN.test(newPin)) {
+    return { success: false, error: 'PIN은 숫자 4~12자리여야 합니다' };
+  }
+
+  const salt = crypto.randomBytes(16).toString('base64');
+  const hash = hashPin(newPin, salt).toString('base64');
+  if (!writePinRecord({ salt, hash })) {
+    return { success: false, error: 'PIN을 저장하지 못했습니다' };
+  }
+
+  debugLog('앱 잠금 PIN 설정됨');
+  return { success: true };
+}
+
+/**
+ * PIN 삭제 (앱 잠금 해제)
+ * @param {string} currentPin - 현재 PIN
+ * @returns {Object} { success, error? }
+ */
+function clearAppLockPin(currentPin) {
+  if (!hasPin()) {
+    return { success: true };
+  }
+  if (!verifyPin(currentPin)) {
+    return { success: false, error: '현재 PIN이 올바르지 않습니다' };
+  }
+
+  writePinRecord(null);
+  locked = false;
+  debugLog('앱 잠금 PIN 삭제됨');
+  notifyLockState();
+  return { success: true };
+}
+
+/**
+ * 앱 잠금 상태
+ * @returns {Object} 상태 정보
+ */
+function getAppLockStatus() {
+  return {
+    enabled: isAppLockEnabled(),
+    locked,
+    hasPin: hasPin(),
+    idleMinutes: appState.settings?.appLockIdleMinutes || 0,
+    systemAuthAvailable: isSystemAuthAvailable(),
+    retryAfterMs: Math.max(0, lockoutUntil - Date.now())
+  };
+}
+
+// 잠긴 동안 허용 목록 외의 IPC 요청 거부
+function lockGuard(channel) {
+  if (locked && !UNLOCKED_CHANNELS.has(channel)) {
+    debugLog(`앱 잠금 중 IPC 요청 거부: ${channel}`);
+    return { success: false, locked: true, error: '앱이 잠겨 있습니다' };
+  }
+  return undefined;
+}
+
+// 유휴 시간이 설정값을 넘으면 잠금
+function checkIdleLock() {
+  const idleMinutes = Number(appState.settings?.appLockIdleMinutes) || 0;
+  if (locked || idleMinutes <= 0 || !isAppLockEnabled()) return;
+
+  if (powerMonitor.getSystemIdleTime() >= idleMinutes * 60) {
+    lockApp('idle');
+  }
+}
+
+/**
+ * 앱 잠금 초기화
+ * 잠금이 켜져 있으면 잠긴 상태로 시작하고, 유휴 시간/화면 잠금/절전 감지를 설정합니다.
+ * 이전 실행에서 남은 연속 실패 횟수와 시도 제한도 그대로 이어집니다.
+ */
+function initializeAppLock() {
+  if (!guardRegistered) {
+    addRequestGuard(lockGuard);
+    guardRegistered = true;
+  }
+
+  loadAttemptState();
+
+  if (isAppLockEnabled()) {
+    locked = true;
+    debugLog('앱 잠금 사용 중 - 잠긴 상태로 시작');
+  }
+
+  powerMonitor.on('lock-screen', () => lockApp('lock-screen'));
+  powerMonitor.on('suspend', () => lockApp('suspend'));
+
+  if (!idleTimer) {
+    idleTimer = setInterval(checkIdleLock, IDLE_CHECK_INTERVAL);
+  }
+}
+
+/**
+ * 앱 잠금 정리 (종료 시)
+ */
+function cleanupAppLock() {
+  if (idleTimer) {
+    clearInterval(idleTimer);
+    idleTimer = null;
+  }
+}
+
+module.exports = {
+  initializeAppLock,
+  cleanupAppLock,
+  isLocked,
+  lockApp,
+  unlockApp,
+  unlockWithSystemAuth,
+  setAppLockPin,
+  clearAppLockPin,
+  getAppLockStatus
+};
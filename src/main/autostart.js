@@ -0,0 +1,200 @@
+/**
+ * 로그인 시 자동 실행 모듈
+ * 운영체제별 방식으로 앱을 시작 프로그램에 등록/해제하고,
+ * 창 없이 타이핑 기록만 하는 "기록 전용" 시작 모드를 판별합니다.
+ *
+ * - Windows: 레지스트리 Run 키 (app.setLoginItemSettings)
+ * - macOS: ~/Library/LaunchAgents의 LaunchAgent plist
+ * - Linux: ~/.config/autostart의 .desktop 파일 (XDG 자동 시작)
+ */
+const fs = require('fs');
+const os = require('os');
+const path = require('path');
+const { app } = require('electron');
+const { debugLog } = require('./utils');
+
+// 기록 전용 모드로 시작할 때 넘기는 인자
+const CAPTURE_ONLY_FLAG = '--capture-only';
+
+/**
+ * 파일 이름과 LaunchAgent 라벨에 쓸 앱 식별자
+ * @returns {string}
+ */
+function getAppId() {
+  return app.getName().toLowerCase().replace(/[^a-z0-9]+/g, '-').replace(/^-|-$/g, '') || 'typing-stats';
+}
+
+/**
+ * 앱 실행 명령 (개발 모드에서는 electron 실행 파일 + 앱 경로)
+ * @param {boolean} captureOnly - 기록 전용 모드 인자 추가 여부
+ * @returns {string[]} 실행 파일과 인자 목록
+ */
+function getLaunchCommand(captureOnly) {
+  const args = process.defaultApp ? [path.resolve(process.argv[1] || '.')] : [];
+  if (captureOnly) {
+    args.push(CAPTURE_ONLY_FLAG);
+  }
+  return [process.execPath, ...args];
+}
+
+/**
+ * 이번 실행이 기록 전용 모드인지 확인
+ * @returns {boolean}
+ */
+function isCaptureOnlyLaunch() {
+  return process.argv.includes(CAPTURE_ONLY_FLAG);
+}
+
+// macOS LaunchAgent plist 경로
+function getLaunchAgentPath() {
+  return path.join(os.homedir(), 'Library', 'LaunchAgents', `com.loop.${getAppId()}.plist`);
+}
+
+// Linux XDG 자동 시작 .desktop 파일 경로
+function getDesktopEntryPath() {
+  const configHome = process.env.XDG_CONFIG_HOME || path.join(os.homedir(), '.config');
+  return path.join(configHome, 'autostart', `${getAppId()}.desktop`);
+}
+
+// XML 특수 문자 이스케이프
+function escapeXml(value) {
+  return value
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;');
+}
+
+// .desktop Exec 인자 인용 (공백/특수 문자가 있는 경로 대비)
+function quoteDesktopArg(value) {
+  return `"${value.replace(/(["`$\\])/g, '\\$1')}"`;
+}
+
+/**
+ * macOS LaunchAgent plist 내용
+ * @param {string[]} command - 실행 파일과 인자
+ * @returns {string}
+ */
+function buildLaunchAgent(command) {
+  const programArguments = command
+    .map(arg => `    <string>${escapeXml(arg)}</string>`)
+    .join('\n');
+
+  return `<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+  <key>Label</key>
+  <string>com.loop.${escapeXml(getAppId())}</string>
+  <key>ProgramArguments</key>
+  <array>
+${programArguments}
+  </array>
+  <key>RunAtLoad</key>
+  <true/>
+</dict>
+</plist>
+`;
+}
+
+/**
+ * Linux .desktop 자동 시작 항목 내용
+ * @param {string[]} command - 실행 파일과 인자
+ * @returns {string}
+ */
+function buildDesktopEntry(command) {
+  return `[Desktop Entry]
+Type=Application
+Name=${app.getName()}
+Exec=${command.map(quoteDesktopArg).join(' ')}
+Terminal=false
+X-GNOME-Autostart-enabled=true
+`;
+}
+
+// 파일로 등록하는 방식 (macOS, Linux)의 등록/해제
+function writeAutostartFile(filePath, contents, enabled) {
+  if (enabled) {
+    fs.mkdirSync(path.dirname(filePath), { recursive: true });
+    fs.writeFileSync(filePath, contents, 'utf8');
+  } else {
+    fs.rmSync(filePath, { force: true });
+  }
+}
+
+/**
+ * 로그인 시 자동 실행 등록/해제
+ * @param {boolean} enabled - 등록 여부
+ * @param {Object} [options]
+ * @param {boolean} [options.captureOnly] - 창을 열지 않고 기록만 하는 모드로 시작 (기본값 true)
+ * @returns {Object} 변경 후 상태 (getAutostartStatus와 같은 형식)
+ */
+function setAutostart(enabled, { captureOnly = true } = {}) {
+  const command = getLaunchCommand(captureOnly);
+
+  switch (process.platform) {
+    case 'win32':
+      app.setLoginItemSettings({
+        openAtLogin: Boolean(enabled),
+        path: command[0],
+        args: command.slice(1)
+      });
+      break;
+    case 'darwin':
+      writeAutostartFile(getLaunchAgentPath(), buildLaunchAgent(command), enabled);
+      break;
+    case 'linux':
+      writeAutostartFile(getDesktopEntryPath(), buildDesktopEntry(command), enabled);
+      break;
+    default:
+      throw new Error(`자동 실행을 지원하지 않는 플랫폼입니다: ${process.platform}`);
+  }
+
+  debugLog(`로그인 시 자동 실행 ${enabled ? '등록' : '해제'}${enabled && captureOnly ? ' (기록 전용)' : ''}`);
+  return getAutostartStatus();
+}
+
+/**
+ * 자동 실행 등록 상태
+ * @returns {Object} { enabled, captureOnly, platform, method, location }
+ */
+function getAutostartStatus() {
+  switch (process.platform) {
+    case 'win32': {
+      const command = getLaunchCommand(false);
+      const settings = app.getLoginItemSettings({ path: command[0], args: command.slice(1) });
+      const captureSettings = app.getLoginItemSettings({
+        path: command[0],
+        args: [...command.slice(1), CAPTURE_ONLY_FLAG]
+      });
+      return {
+        enabled: settings.openAtLogin || captureSettings.openAtLogin,
+        captureOnly: captureSettings.openAtLogin,
+        platform: process.platform,
+        method: 'registry-run-key',
+        location: 'HKCU\\Software\\Microsoft\\Windows\\CurrentVersion\\Run'
+      };
+    }
+    case 'darwin':
+    case 'linux': {
+      const location = process.platform === 'darwin' ? getLaunchAgentPath() : getDesktopEntryPath();
+      const contents = fs.existsSync(location) ? fs.readFileSync(location, 'utf8') : null;
+      return {
+        enabled: contents !== null,
+        captureOnly: contents !== null && contents.includes(CAPTURE_ONLY_FLAG),
+        platform: process.platform,
+        method: process.platform === 'darwin' ? 'launch-agent' : 'xdg-autostart',
+        location
+      };
+    }
+    default:
+      return { enabled: false, captureOnly: false, platform: process.platform, method: null, location: null };
+  }
+}
+
+module.exports = {
+  CAPTURE_ONLY_FLAG,
+  isCaptureOnlyLaunch,
+  setAutostart,
+  getAutostartStatus
+};
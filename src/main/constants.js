@@ -1,6 +1,7 @@
 // ES 모듈 import 문을 CommonJS require로 변경
 const path = require('path');
 const { app } = require('electron');
+const { isSandboxMode, SANDBOX_DIR_SUFFIX } = require('./sandbox');
 
 // 개발 모드 확인
 const isDev = process.env.NODE_ENV === 'development';
@@ -39,7 +40,10 @@ const appState = {
     totalWords: 0,
     totalCharsNoSpace: 0,
     pages: 0,
-    accuracy: 100
+    accuracy: 100,
+    keyboardLayout: null,
+    layoutSegments: [],
+    sleepGaps: [] // 세션 중 시스템 절전 구간 ({ start, end, durationMs })
   },
   settings: {
     enabledCategories: {
@@ -51,6 +55,7 @@ const appState = {
     autoStartMonitoring: true,
     resumeAfterIdle: true, // 유휴 상태 후 자동 재시작 (신규 추가)
     idleTimeout: 300, // 유휴 상태 판단 시간 (초) (신규 추가)
+    pauseOnIdle: true, // 유휴 시간이 지나면 세션을 나누어 저장하고 일시 정지
     darkMode: false,
     windowMode: 'windowed',
     minimizeToTray: true, // 트레이로 최소화 설정 (기본값 true)
@@ -59,11 +64,37 @@ const appState = {
     enableMiniView: true, // 미니뷰 활성화 기본값
     useHardwareAcceleration: false, // 하드웨어 가속 사용 여부
     processingMode: 'auto', // 처리 모드 - 'auto', 'normal', 'cpu-intensive', 'gpu-intensive'
+    gpuComputeEnabled: true, // 네이티브 GPU 연산 사용 여부 (재시작 없이 전환)
+    preferredGpuDevice: '', // 선호 GPU 장치 키 (list_gpu_devices의 key, 비우면 자동 선택)
     garbageCollectionInterval: 60000, // 주기적 GC 실행 간격 (ms)
     maxMemoryThreshold: 100, // 메모리 임계치 (MB)
     autoCleanupLogs: true, // 오래된 로그 자동 정리
     maxHistoryItems: 500, // 최대 히스토리 항목 수
-    logRetentionDays: 30 // 로그 보관 일수
+    logRetentionDays: 30, // 로그 보관 일수
+    accessibilityMode: false, // 알림/이벤트에 화면 낭독기용 평문 설명 포함
+    breakReminderEnabled: true, // 피로도가 높아지면 휴식 권장 알림
+    fatigueThreshold: 70, // 휴식 권장 피로도 임계값 (1~100)
+    locale: 'ko-KR', // 숫자/날짜 표시 형식 로케일 (BCP 47)
+    firstDayOfWeek: 1, // 주간 통계의 주 시작 요일 (0: 일요일 ~ 6: 토요일)
+    metricsEnabled: false, // Prometheus 메트릭 텍스트 파일 내보내기
+    metricsTextfilePath: '', // 메트릭 파일 경로 (비우면 userData/metrics/typing_stats.prom)
+    metricsInterval: 15000, // 메트릭 파일 기록 간격 (ms)
+    appLockEnabled: false, // 시작 시와 유휴 후 PIN 잠금
+    appLockIdleMinutes: 5, // 입력이 없을 때 자동 잠금까지의 시간 (분, 0이면 사용 안 함)
+    appLockUseSystemAuth: true, // 가능하면 시스템 인증(Touch ID)으로 잠금 해제 허용
+    reportDirectory: '', // 주간 보고서 저장 폴더 (비우면 userData/reports)
+    reportEmailEnabled: false, // 주간 보고서를 메일로도 발송
+    reportEmailTo: '', // 받는 사람 (쉼표로 구분)
+    reportEmailFrom: '', // 보내는 사람 (비우면 SMTP 사용자)
+    reportSmtpHost: '', // SMTP 서버
+    reportSmtpPort: 587, // SMTP 포트
+    reportSmtpSecure: false, // 처음부터 TLS로 연결 (465 포트), 아니면 STARTTLS
+    reportSmtpUser: '', // SMTP 인증 사용자 (비밀번호는 별도로 암호화해 저장)
+    analyticsExportEnabled: false, // 세션 집계를 외부 분석 서비스로 전송
+    analyticsEndpoint: '', // 전송 주소 (https, 로컬 주소는 http 허용)
+    analyticsInterval: 300000, // 전송 간격 (ms, 최소 1분)
+    analyticsBatchSize: 50, // 한 번에 보내는 세션 수
+    analyticsIncludeTitles: false // 창 제목도 함께 전송 (토큰은 별도로 암호화해 저장)
   },
   inBackgroundMode: false,
   gpuEnabled: false, // GPU 가속 사용 여부
@@ -187,8 +218,8 @@ const GOOGLE_DOCS_TITLE_PATTERNS = [
 
 // 설정 파일 경로
 const userDataPath = process.env.NODE_ENV === 'development' 
-  ? path.join(__dirname, '../../userData') // 개발 환경
-  : app.getPath('userData'); // 프로덕션 환경
+  ? path.join(__dirname, `../../userData${isSandboxMode() ? SANDBOX_DIR_SUFFIX : ''}`) // 개발 환경
+  : app.getPath('userData'); // 프로덕션 환경 (샌드박스 모드에서는 별도 디렉토리)
 
 const settingsPath = path.join(userDataPath, 'settings.json');
 const userDataPathExport = userDataPath;
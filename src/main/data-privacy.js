@@ -0,0 +1,252 @@
+/**
+ * 개인 데이터 내보내기/삭제 모듈
+ * 앱이 이 기기에 저장한 모든 데이터를 ZIP 파일 하나로 내보내거나(데이터 이동권),
+ * 확인 토큰을 받은 뒤 모두 삭제합니다(삭제권). 파일 형식은 docs/user-data-export.md를 참고하세요.
+ */
+const fs = require('fs');
+const os = require('os');
+const path = require('path');
+const crypto = require('crypto');
+const { app, session } = require('electron');
+const { appState, settingsPath } = require('./constants');
+const { debugLog } = require('./utils');
+const { createZip } = require('./zip');
+
+// 내보내기 파일 형식
+const USER_DATA_FORMAT = 'loop-user-data';
+const USER_DATA_FORMAT_VERSION = 1;
+
+// 삭제 확인 토큰 유효 시간
+const WIPE_TOKEN_TTL_MS = 5 * 60 * 1000;
+
+// 덮어쓰기 단위
+const OVERWRITE_CHUNK = 1024 * 1024;
+
+// 발급한 삭제 확인 토큰 (한 번만 사용 가능)
+let pendingWipe = null;
+
+/**
+ * 이 앱이 만드는 데이터 위치 (존재하지 않는 경로 포함)
+ * 사용자가 지정한 보고서 폴더나 메트릭 파일 경로는 폴더 전체가 아니라 앱이 만든 파일만 포함합니다.
+ * @returns {Array<{ name: string, path: string }>} 보관 이름과 실제 경로
+ */
+function getDataLocations() {
+  const { getDatabaseFiles } = require('./database');
+  const userData = app.getPath('userData');
+  const locations = [
+    ...getDatabaseFiles().map(file => ({ name: `database/${path.basename(file)}`, path: file })),
+    { name: 'settings.json', path: settingsPath },
+    { name: 'logs', path: path.join(userData, 'logs') },
+    { name: 'app-logs', path: app.getPath('logs') },
+    { name: 'reports', path: path.join(userData, 'reports') },
+    { name: 'metrics', path: path.join(userData, 'metrics') },
+    { name: 'shader-cache', path: path.join(userData, 'shader-cache') }
+  ];
+
+  const reportDirectory = appState.settings?.reportDirectory;
+  if (reportDirectory && fs.existsSync(reportDirectory)) {
+    for (const file of fs.readdirSync(reportDirectory)) {
+      if (/^weekly-report-\d{4}-\d{2}-\d{2}\.(md|html)$/.test(file)) {
+        locations.push({ name: `reports/${file}`, path: path.join(reportDirectory, file) });
+      }
+    }
+  }
+  if (appState.settings?.metricsTextfilePath) {
+    locations.push({ name: 'metrics/typing_stats.prom', path: appState.settings.metricsTextfilePath });
+  }
+
+  // 같은 경로가 두 번 나오지 않도록 (예: 로그 폴더가 userData/logs인 플랫폼)
+  const seen = new Set();
+  return locations.filter(location => {
+    const resolved = path.resolve(location.path);
+    if (seen.has(resolved)) return false;
+    seen.add(resolved);
+    return true;
+  });
+}
+
+/**
+ * 폴더 안의 파일 목록 (하위 폴더 포함)
+ * @param {string} dir - 폴더 경로
+ * @returns {string[]} 파일 경로 배열
+ */
+function listFiles(dir) {
+  return fs.readdirSync(dir, { withFileTypes: true }).flatMap(entry => {
+    const fullPath = path.join(dir, entry.name);
+    if (entry.isDirectory()) return listFiles(fullPath);
+    return entry.isFile() ? [fullPath] : [];
+  });
+}
+
+/**
+ * 파일 내용을 임의 값으로 덮어쓴 뒤 삭제
+ * SSD나 저널링 파일 시스템에서는 이전 블록이 남을 수 있으므로 최선의 노력입니다.
+ * @param {string} filePath - 파일 경로
+ */
+function secureDeleteFile(filePath) {
+  const { size } = fs.statSync(filePath);
+  const fd = fs.openSync(filePath, 'r+');
+  try {
+    for (let written = 0; written < size; written += OVERWRITE_CHUNK) {
+      fs.writeSync(fd, crypto.randomBytes(Math.min(OVERWRITE_CHUNK, size - written)), 0, undefined, written);
+    }
+    fs.fsyncSync(fd);
+  } finally {
+    fs.closeSync(fd);
+  }
+  fs.unlinkSync(filePath);
+}
+
+/**
+ * 파일 또는 폴더를 덮어쓴 뒤 삭제
+ * @param {string} target - 경로
+ * @returns {number} 삭제한 파일 수
+ */
+function secureDelete(target) {
+  if (!fs.existsSync(target)) return 0;
+
+  if (fs.statSync(target).isDirectory()) {
+    const files = listFiles(target);
+    files.forEach(secureDeleteFile);
+    fs.rmSync(target, { recursive: true, force: true });
+    return files.length;
+  }
+
+  secureDeleteFile(target);
+  return 1;
+}
+
+/**
+ * 모든 사용자 데이터를 ZIP 파일로 내보내기
+ * 데이터베이스 사본(보안 설정 값 제외), 설정, 분석 데이터(JSON, 입력 내용 포함), 로그, 보고서를 담습니다.
+ * 작업 중 만든 임시 파일은 덮어쓴 뒤 삭제합니다.
+ * @param {string} filePath - 저장할 ZIP 파일 경로
+ * @returns {Promise<{ filePath: string, files: number, bytes: number }>} 결과
+ */
+async function exportAllUserData(filePath) {
+  const { backupDatabase } = require('./database');
+  const { exportAnalytics } = require('./session-transfer');
+  const tempDir = fs.mkdtempSync(path.join(os.tmpdir(), 'loop-export-'));
+
+  try {
+    const entries = [];
+
+    const databaseCopy = path.join(tempDir, 'typing-stats-database.sqlite');
+    await backupDatabase(databaseCopy);
+    entries.push({ name: 'database/typing-stats-database.sqlite', data: fs.readFileSync(databaseCopy) });
+
+    const analyticsFile = path.join(tempDir, 'analytics.json');
+    exportAnalytics(analyticsFile, { format: 'json', includeContent: true });
+    entries.push({ name: 'analytics.json', data: fs.readFileSync(analyticsFile) });
+
+    // 데이터베이스 파일은 위의 사본으로 대신하고, 셰이더 캐시는 개인 데이터가 아니므로 제외
+    for (const location of getDataLocations()) {
+      if (location.name.startsWith('database/') || location.name === 'shader-cache') continue;
+      if (!fs.existsSync(location.path)) continue;
+
+      if (fs.statSync(location.path).isDirectory()) {
+        for (const file of listFiles(location.path)) {
+          const relative = path.relative(location.path, file).split(path.sep).join('/');
+          entries.push({ name: `${location.name}/${relative}`, data: fs.readFileSync(file), mtime: fs.statSync(file).mtime });
+        }
+      } else {
+        entries.push({ name: location.name, data: fs.readFileSync(location.path), mtime: fs.statSync(location.path).mtime });
+      }
+    }
+
+    const manifest = {
+      format: USER_DATA_FORMAT,
+      version: USER_DATA_FORMAT_VERSION,
+      exportedAt: new Date().toISOString(),
+      appVersion: app.getVersion(),
+      files: entries.map(entry => ({ name: entry.name, size: entry.data.length }))
+    };
+    const archive = createZip([{ name: 'manifest.json', data: JSON.stringify(manifest, null, 2) }, ...entries]);
+    fs.writeFileSync(filePath, archive);
+
+    debugLog(`사용자 데이터 내보내기 완료 (${entries.length}개 파일):`, filePath);
+    return { filePath, files: entries.length, bytes: archive.length };
+  } finally {
+    secureDelete(tempDir);
+  }
+}
+
+/**
+ * 데이터 삭제 확인 토큰 발급
+ * 삭제할 항목을 사용자에게 보여 준 뒤, 확인하면 이 토큰으로 wipeAllLocalData를 호출합니다.
+ * @returns {{ token: string, expiresAt: number, locations: Array }} 토큰과 삭제 대상
+ */
+function requestDataWipe() {
+  const token = crypto.randomBytes(16).toString('hex');
+  const expiresAt = Date.now() + WIPE_TOKEN_TTL_MS;
+  pendingWipe = { token, expiresAt };
+
+  const locations = getDataLocations()
+    .filter(location => fs.existsSync(location.path))
+    .map(location => ({ name: location.name, path: location.path }));
+
+  return { token, expiresAt, locations };
+}
+
+/**
+ * 확인 토큰 검사 (한 번 사용하면 무효)
+ * @param {string} token - requestDataWipe가 발급한 토큰
+ */
+function consumeWipeToken(token) {
+  const pending = pendingWipe;
+  pendingWipe = null;
+
+  if (!pending || typeof token !== 'string' || Date.now() > pending.expiresAt) {
+    throw new Error('삭제 확인 토큰이 없거나 만료되었습니다');
+  }
+  const expected = Buffer.from(pending.token);
+  const received = Buffer.from(token);
+  if (expected.length !== received.length || !crypto.timingSafeEqual(expected, received)) {
+    throw new Error('삭제 확인 토큰이 올바르지 않습니다');
+  }
+}
+
+/**
+ * 이 기기의 모든 앱 데이터 삭제
+ * 데이터베이스(기록, 설정 테이블의 암호화된 토큰/비밀번호/PIN 포함), 설정 파일, 로그, 보고서,
+ * 메트릭 파일, 셰이더 캐시, 렌더러 저장소(localStorage, IndexedDB, 캐시)를 지웁니다.
+ * exportPath를 지정하면 먼저 내보내고, 내보내기에 실패하면 아무것도 지우지 않습니다.
+ * 삭제 후에는 앱을 다시 시작해야 합니다.
+ * @param {string} token - requestDataWipe가 발급한 확인 토큰
+ * @param {Object} [options]
+ * @param {string} [options.exportPath] - 삭제 전에 내보낼 ZIP 파일 경로
+ * @returns {Promise<{ deletedFiles: number, exported: Object|null }>} 결과
+ */
+async function wipeAllLocalData(token, { exportPath } = {}) {
+  consumeWipeToken(token);
+
+  const exported = exportPath ? await exportAllUserData(exportPath) : null;
+
+  await session.defaultSession.clearStorageData();
+  await session.defaultSession.clearCache();
+
+  // 삭제 중 데이터베이스에 다시 쓰지 않도록 백그라운드 작업을 멈추고 연결 종료
+  const locations = getDataLocations();
+  require('./app-lifecycle').cleanupApp();
+  require('./database').closeDatabase();
+
+  let deletedFiles = 0;
+  for (const location of locations) {
+    try {
+      deletedFiles += secureDelete(location.path);
+    } catch (error) {
+      console.error(`데이터 삭제 오류 (${location.path}):`, error);
+    }
+  }
+
+  debugLog(`모든 로컬 데이터 삭제 완료 (${deletedFiles}개 파일)`);
+  return { deletedFiles, exported };
+}
+
+module.exports = {
+  USER_DATA_FORMAT,
+  USER_DATA_FORMAT_VERSION,
+  exportAllUserData,
+  requestDataWipe,
+  wipeAllLocalData
+};
@@ -4,9 +4,11 @@
  */
 const path = require('path');
 const fs = require('fs');
+const { Worker } = require('worker_threads');
 const { app } = require('electron');
 const BetterSqlite3 = require('better-sqlite3');
-const { debugLog } = require('./utils');
+const { debugLog, calculateWpm } = require('./utils');
+const schemaDump = require('./schema-dump');
 
 // 데이터베이스 파일 경로
 const dbPath = path.join(
@@ -17,6 +19,41 @@ const dbPath = path.join(
 // 데이터베이스 연결
 let db = null;
 
+// 실행 중인 읽기 전용 쿼리 워커 (SQL 콘솔)
+const readonlyQueryWorkers = new Set();
+
+// 다른 연결이 쓰는 중일 때 잠금 해제를 기다리는 최대 시간 (ms)
+const DB_BUSY_TIMEOUT_MS = 5000;
+
+// 읽기 전용 쿼리 제한
+const READONLY_QUERY_MAX_LENGTH = 10000;
+const READONLY_QUERY_DEFAULT_MAX_ROWS = 1000;
+const READONLY_QUERY_MAX_ROWS = 10000;
+const READONLY_QUERY_DEFAULT_TIMEOUT_MS = 2000;
+const READONLY_QUERY_MAX_TIMEOUT_MS = 10000;
+
+// 롤업 집계 버전 (집계 방식이 바뀌면 올려서 다시 채우기)
+const ROLLUP_VERSION = 2;
+
+// 롤업 단위
+const ROLLUP_GRANULARITIES = ['day', 'week', 'month'];
+
+// 한 주의 시작 요일 (0: 일요일 ~ 6: 토요일, 기본값 월요일)
+const DEFAULT_FIRST_DAY_OF_WEEK = 1;
+let firstDayOfWeek = DEFAULT_FIRST_DAY_OF_WEEK;
+
+// 이상치 감지 설정 (지수 가중 이동 평균/분산)
+const ANOMALY_EWMA_ALPHA = 0.3;
+const ANOMALY_WARMUP_DAYS = 7;
+const ANOMALY_DEFAULT_THRESHOLD = 3;
+
+// 조회 기간(일)에 따른 롤업 단위 선택 기준
+const ROLLUP_ROUTING = [
+  { maxDays: 62, granularity: 'day' },
+  { maxDays: 366, granularity: 'week' },
+  { maxDays: Infinity, granularity: 'month' }
+];
+
 /**
  * 데이터베이스 초기화 및 테이블 생성
  */
@@ -39,6 +76,12 @@ function initializeDatabase() {
     // 캐시 크기 제한 (메모리 사용 제한)
     db.pragma('cache_size = -2000'); // 약 2MB 캐시 크기
     
+    // 다른 연결(SQL 콘솔, 백업)이 잠금을 잡고 있으면 바로 SQLITE_BUSY로 실패하지 않고 기다림
+    db.pragma(`busy_timeout = ${DB_BUSY_TIMEOUT_MS}`);
+    
+    // 외래 키 제약 조건 적용 (SQLite 기본값은 꺼짐)
+    db.pragma('foreign_keys = ON');
+    
     // 테이블 생성
     db.exec(`
       CREATE TABLE IF NOT EXISTS typing_stats (
@@ -61,10 +104,72 @@ function initializeDatabase() {
         value TEXT
       );
       
+      -- 일/주/월 단위 롤업 (원본 기록이 정리된 뒤에도 장기 통계를 유지)
+      CREATE TABLE IF NOT EXISTS typing_stats_rollups (
+        granularity TEXT NOT NULL,
+        period_start TEXT NOT NULL,
+        session_count INTEGER DEFAULT 0,
+        key_count INTEGER DEFAULT 0,
+        typing_time INTEGER DEFAULT 0,
+        total_chars INTEGER DEFAULT 0,
+        total_words INTEGER DEFAULT 0,
+        accuracy_sum REAL DEFAULT 0,
+        updated_at TEXT DEFAULT CURRENT_TIMESTAMP,
+        PRIMARY KEY (granularity, period_start)
+      );
+      
+      -- 작성 목표 (전체 또는 문서별 일일 단어 수/시간 목표)
+      CREATE TABLE IF NOT EXISTS typing_goals (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        document TEXT,
+        metric TEXT NOT NULL,
+        target INTEGER NOT NULL,
+        created_at TEXT DEFAULT CURRENT_TIMESTAMP
+      );
+      
+      -- 목표별 일일 진행 기록
+      CREATE TABLE IF NOT EXISTS typing_goal_history (
+        goal_id INTEGER NOT NULL,
+        date TEXT NOT NULL,
+        progress REAL DEFAULT 0,
+        target INTEGER NOT NULL,
+        achieved_at TEXT,
+        PRIMARY KEY (goal_id, date)
+      );
+      
+      -- 일별 피로도 기록 (주간 보고서의 피로도 추세용)
+      CREATE TABLE IF NOT EXISTS typing_fatigue_daily (
+        date TEXT PRIMARY KEY,
+        peak_score REAL DEFAULT 0,
+        break_count INTEGER DEFAULT 0
+      );
+      
+      -- 외부 분석 서비스로 보낼 세션 집계 대기열
+      CREATE TABLE IF NOT EXISTS analytics_outbox (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        session_id INTEGER NOT NULL UNIQUE,
+        payload TEXT NOT NULL,
+        attempts INTEGER DEFAULT 0,
+        next_attempt_at INTEGER DEFAULT 0,
+        last_error TEXT,
+        created_at TEXT DEFAULT CURRENT_TIMESTAMP
+      );
+      
       -- 인덱스 생성
       CREATE INDEX IF NOT EXISTS idx_typing_stats_timestamp ON typing_stats(timestamp);
     `);
     
+    // 이전 버전 데이터베이스에 없는 열 추가
+    ensureColumn('typing_stats', 'end_reason', 'TEXT');
+    
+    // 주 단위 롤업이 계산된 주 시작 요일
+    firstDayOfWeek = Number(loadSetting('first_day_of_week', DEFAULT_FIRST_DAY_OF_WEEK));
+    
+    // 롤업이 없거나 집계 방식이 바뀐 경우 기존 롤업을 지우고 원본 기록으로 다시 채우기
+    if (Number(loadSetting('rollup_version', 0)) !== ROLLUP_VERSION) {
+      backfillRollups({ reset: true });
+    }
+    
     debugLog('데이터베이스 초기화 완료');
     return true;
   } catch (error) {
@@ -73,6 +178,20 @@ function initializeDatabase() {
   }
 }
 
+/**
+ * 테이블에 열이 없으면 추가 (스키마 마이그레이션)
+ * @param {string} table - 테이블 이름
+ * @param {string} column - 열 이름
+ * @param {string} definition - 열 정의 (타입 및 기본값)
+ */
+function ensureColumn(table, column, definition) {
+  const columns = db.prepare(`PRAGMA table_info(${table})`).all();
+  if (!columns.some(info => info.name === column)) {
+    db.exec(`ALTER TABLE ${table} ADD COLUMN ${column} ${definition}`);
+    debugLog(`열 추가: ${table}.${column}`);
+  }
+}
+
 /**
  * 통계 데이터 저장
  * @param {Object} stats - 저장할 통계 데이터
@@ -87,22 +206,29 @@ function saveStats(stats) {
     const stmt = db.prepare(`
       INSERT INTO typing_stats 
       (content, key_count, typing_time, window_title, browser_name, 
-       total_chars, total_words, pages, accuracy, timestamp)
-      VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+       total_chars, total_words, pages, accuracy, timestamp, end_reason)
+      VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
     `);
     
-    const info = stmt.run(
-      stats.content,
-      stats.keyCount,
-      stats.typingTime,
-      stats.windowTitle || null,
-      stats.browserName || null,
-      stats.totalChars || 0,
-      stats.totalWords || 0,
-      stats.pages || 0,
-      stats.accuracy || 100,
-      stats.timestamp
-    );
+    const insertWithRollups = db.transaction(() => {
+      const result = stmt.run(
+        stats.content,
+        stats.keyCount,
+        stats.typingTime,
+        stats.windowTitle || null,
+        stats.browserName || null,
+        stats.totalChars || 0,
+        stats.totalWords || 0,
+        stats.pages || 0,
+        stats.accuracy || 100,
+        stats.timestamp,
+        stats.endReason || null
+      );
+      updateRollups(result.lastInsertRowid);
+      return result;
+    });
+    
+    const info = insertWithRollups();
     
     debugLog(`통계 데이터 저장 완료: ID=${info.lastInsertRowid}`);
     
@@ -115,6 +241,60 @@ function saveStats(stats) {
   }
 }
 
+/**
+ * 여러 통계 기록을 한 번에 가져오기 (가져오기 기능용)
+ * 같은 시각과 타자 수를 가진 기록이 이미 있으면 중복으로 보고 건너뜁니다.
+ * @param {Array} records - saveStats와 같은 형식의 기록 배열
+ * @returns {{ imported: number, skipped: number }} 가져온 수와 건너뛴 수
+ */
+function importStats(records) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  const exists = db.prepare('SELECT 1 FROM typing_stats WHERE timestamp = ? AND key_count = ? LIMIT 1');
+  const insert = db.prepare(`
+    INSERT INTO typing_stats 
+    (content, key_count, typing_time, window_title, browser_name, 
+     total_chars, total_words, pages, accuracy, timestamp, end_reason)
+    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+  `);
+  
+  const importAll = db.transaction((items) => {
+    let imported = 0;
+    let skipped = 0;
+    
+    for (const stats of items) {
+      if (exists.get(stats.timestamp, stats.keyCount)) {
+        skipped++;
+        continue;
+      }
+      
+      const result = insert.run(
+        stats.content || null,
+        stats.keyCount,
+        stats.typingTime,
+        stats.windowTitle || null,
+        stats.browserName || null,
+        stats.totalChars || 0,
+        stats.totalWords || 0,
+        stats.pages || 0,
+        stats.accuracy ?? 100,
+        stats.timestamp,
+        stats.endReason || null
+      );
+      updateRollups(result.lastInsertRowid);
+      imported++;
+    }
+    
+    return { imported, skipped };
+  });
+  
+  const result = importAll(records);
+  debugLog(`통계 기록 가져오기 완료: ${result.imported}개 추가, ${result.skipped}개 중복`);
+  return result;
+}
+
 /**
  * ID로 통계 기록 조회
  * @param {number} id - 통계 기록 ID
@@ -170,42 +350,1020 @@ function getStatsSummaryByPeriod(period = 'day', limit = 7) {
   try {
     let timeFormat;
     
-    // SQLite의 날짜 함수를 사용하여 기간별 그룹화
+    // 롤업 테이블의 기간 시작일을 기존 기간 표기로 변환
     switch(period) {
       case 'day':
         timeFormat = '%Y-%m-%d';
         break;
       case 'week':
-        timeFormat = '%Y-%W';
+        // 주 시작 요일 설정에 따라 경계가 달라지므로 주의 시작일로 표기
+        timeFormat = '%Y-%m-%d';
         break;
       case 'month':
         timeFormat = '%Y-%m';
         break;
       default:
+        period = 'day';
         timeFormat = '%Y-%m-%d';
     }
     
     const stmt = db.prepare(`
       SELECT 
-        strftime('${timeFormat}', timestamp) as period,
-        SUM(key_count) as total_key_count,
-        SUM(typing_time) as total_typing_time,
-        SUM(total_chars) as total_chars,
-        SUM(total_words) as total_words,
-        AVG(accuracy) as avg_accuracy
-      FROM typing_stats
-      GROUP BY period
-      ORDER BY timestamp DESC
+        strftime('${timeFormat}', period_start) as period,
+        key_count as total_key_count,
+        typing_time as total_typing_time,
+        total_chars,
+        total_words,
+        CASE WHEN session_count > 0 THEN accuracy_sum / session_count END as avg_accuracy
+      FROM typing_stats_rollups
+      WHERE granularity = ?
+      ORDER BY period_start DESC
       LIMIT ?
     `);
     
-    return stmt.all(limit);
+    return stmt.all(period, limit);
   } catch (error) {
     console.error('기간별 통계 조회 오류:', error);
     return [];
   }
 }
 
+/**
+ * 롤업 단위별 기간 시작일 계산식 (현지 시간 기준)
+ * @param {string} granularity - day, week, month 중 하나
+ * @param {string} [value] - 시각 값 SQL 식 (기본값: timestamp 열)
+ * @param {Object} [options]
+ * @param {number} [options.weekStart] - 주 시작 요일 (기본값: 현재 설정)
+ * @param {boolean} [options.localtime] - 값을 현지 시간으로 변환할지 여부 (이미 현지 날짜인 값이면 false)
+ * @returns {string} SQLite 날짜 식
+ */
+function rollupPeriodSql(granularity, value = 'timestamp', { weekStart = firstDayOfWeek, localtime = true } = {}) {
+  const base = localtime ? `${value}, 'localtime'` : value;
+  switch (granularity) {
+    case 'week':
+      // 'weekday N'은 다음 N요일(주의 마지막 날)로 이동하므로 6일 전이 주의 시작일
+      return `date(${base}, 'weekday ${(weekStart + 6) % 7}', '-6 days')`;
+    case 'month':
+      return `date(${base}, 'start of month')`;
+    default:
+      return `date(${base})`;
+  }
+}
+
+/**
+ * 한 주의 시작 요일 설정
+ * 저장된 값과 다르면 주 단위 롤업을 일 단위 롤업으로 다시 계산합니다
+ * (원본 기록이 정리된 기간도 일 단위 롤업이 남아 있으므로 그대로 유지됨).
+ * @param {number} day - 0: 일요일 ~ 6: 토요일
+ * @returns {boolean} 성공 여부
+ */
+function setFirstDayOfWeek(day) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  const value = Number(day);
+  if (!Number.isInteger(value) || value < 0 || value > 6) {
+    console.error('잘못된 주 시작 요일:', day);
+    return false;
+  }
+  
+  if (value === firstDayOfWeek) {
+    return true;
+  }
+  
+  try {
+    // 다시 계산에 실패하면 기존 주 단위 롤업과 요일 설정이 그대로 남도록 성공한 뒤에만 반영
+    const rebuild = db.transaction(() => {
+      db.prepare("DELETE FROM typing_stats_rollups WHERE granularity = 'week'").run();
+      db.prepare(`
+        INSERT INTO typing_stats_rollups
+          (granularity, period_start, session_count, key_count, typing_time,
+           total_chars, total_words, accuracy_sum, updated_at)
+        SELECT 'week', ${rollupPeriodSql('week', 'period_start', { weekStart: value, localtime: false })} AS week_start,
+               SUM(session_count), SUM(key_count), SUM(typing_time),
+               SUM(total_chars), SUM(total_words), SUM(accuracy_sum), CURRENT_TIMESTAMP
+        FROM typing_stats_rollups
+        WHERE granularity = 'day'
+        GROUP BY week_start
+      `).run();
+      saveSetting('first_day_of_week', value);
+    });
+    
+    rebuild();
+    firstDayOfWeek = value;
+    debugLog(`주 시작 요일 변경 (${value}), 주 단위 롤업 다시 계산 완료`);
+    return true;
+  } catch (error) {
+    console.error('주 시작 요일 변경 오류:', error);
+    return false;
+  }
+}
+
+/**
+ * 저장된 기록 하나를 모든 단위의 롤업에 더하기
+ * @param {number} id - typing_stats 기록 ID
+ */
+function updateRollups(id) {
+  for (const granularity of ROLLUP_GRANULARITIES) {
+    const periodSql = rollupPeriodSql(granularity);
+    db.prepare(`
+      INSERT INTO typing_stats_rollups
+        (granularity, period_start, session_count, key_count, typing_time,
+         total_chars, total_words, accuracy_sum)
+      SELECT ?, ${periodSql}, 1, key_count, typing_time, total_chars, total_words, accuracy
+      FROM typing_stats
+      WHERE id = ? AND ${periodSql} IS NOT NULL
+      ON CONFLICT (granularity, period_start) DO UPDATE SET
+        session_count = session_count + excluded.session_count,
+        key_count = key_count + excluded.key_count,
+        typing_time = typing_time + excluded.typing_time,
+        total_chars = total_chars + excluded.total_chars,
+        total_words = total_words + excluded.total_words,
+        accuracy_sum = accuracy_sum + excluded.accuracy_sum,
+        updated_at = CURRENT_TIMESTAMP
+    `).run(granularity, id);
+  }
+}
+
+/**
+ * 원본 기록으로 롤업 다시 채우기
+ * 원본 기록이 남아 있는 기간만 다시 계산하므로, 이미 정리된 기간의 롤업은 유지됩니다.
+ * @param {Object} [options]
+ * @param {boolean} [options.reset] - 기존 롤업을 모두 지우고 다시 채울지 여부
+ * @returns {boolean} 성공 여부
+ */
+function backfillRollups({ reset = false } = {}) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  try {
+    const backfill = db.transaction(() => {
+      if (reset) {
+        db.prepare('DELETE FROM typing_stats_rollups').run();
+      }
+      
+      for (const granularity of ROLLUP_GRANULARITIES) {
+        const periodSql = rollupPeriodSql(granularity);
+        db.prepare(`
+          INSERT OR REPLACE INTO typing_stats_rollups
+            (granularity, period_start, session_count, key_count, typing_time,
+             total_chars, total_words, accuracy_sum, updated_at)
+          SELECT ?, ${periodSql} AS period_start, COUNT(*), SUM(key_count), SUM(typing_time),
+                 SUM(total_chars), SUM(total_words), SUM(accuracy), CURRENT_TIMESTAMP
+          FROM typing_stats
+          WHERE ${periodSql} IS NOT NULL
+          GROUP BY period_start
+        `).run(granularity);
+      }
+      saveSetting('rollup_version', ROLLUP_VERSION);
+    });
+    
+    backfill();
+    debugLog('통계 롤업 다시 채우기 완료');
+    return true;
+  } catch (error) {
+    console.error('통계 롤업 다시 채우기 오류:', error);
+    return false;
+  }
+}
+
+/**
+ * 조회 기간에 맞는 롤업 단위 선택
+ * @param {Date} from - 시작 시각
+ * @param {Date} to - 종료 시각
+ * @returns {string} day, week, month 중 하나
+ */
+function selectRollupGranularity(from, to) {
+  const days = Math.max(0, (to.getTime() - from.getTime()) / 86400000);
+  return ROLLUP_ROUTING.find(route => days <= route.maxDays).granularity;
+}
+
+/**
+ * 기간별 타이핑 추세 조회 (조회 기간에 따라 일/주/월 롤업 자동 선택)
+ * @param {string|Date} from - 시작 시각
+ * @param {string|Date} to - 종료 시각 (기본값: 현재)
+ * @param {string} [granularity] - 단위를 직접 지정할 경우 day, week, month
+ * @returns {Object} { granularity, points } 형식의 결과
+ */
+function getTypingTrend(from, to = new Date(), granularity) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  const fromDate = new Date(from);
+  const toDate = new Date(to);
+  if (isNaN(fromDate.getTime()) || isNaN(toDate.getTime())) {
+    console.error('타이핑 추세 조회 오류: 잘못된 기간', from, to);
+    return { granularity: null, points: [] };
+  }
+  
+  const selected = ROLLUP_GRANULARITIES.includes(granularity) 
+    ? granularity 
+    : selectRollupGranularity(fromDate, toDate);
+  
+  try {
+    const rows = db.prepare(`
+      SELECT period_start, session_count, key_count, typing_time,
+             total_chars, total_words, accuracy_sum
+      FROM typing_stats_rollups
+      WHERE granularity = ?
+        AND period_start >= ${rollupPeriodSql(selected, '?')}
+        AND period_start <= date(?, 'localtime')
+      ORDER BY period_start ASC
+    `).all(selected, fromDate.toISOString(), toDate.toISOString());
+    
+    const points = rows.map(row => ({
+      periodStart: row.period_start,
+      sessionCount: row.session_count,
+      keyCount: row.key_count,
+      typingTime: row.typing_time,
+      totalChars: row.total_chars,
+      totalWords: row.total_words,
+      wpm: calculateWpm(row.key_count, row.typing_time),
+      avgAccuracy: row.session_count > 0 ? row.accuracy_sum / row.session_count : 0
+    }));
+    
+    return { granularity: selected, points };
+  } catch (error) {
+    console.error('타이핑 추세 조회 오류:', error);
+    return { granularity: selected, points: [] };
+  }
+}
+
+// 기간 단위별 다음 기간으로 이동하는 날짜 수정자
+const ROLLUP_PERIOD_STEP = {
+  day: '+1 day',
+  week: '+7 days',
+  month: '+1 month'
+};
+
+/**
+ * 세션별 기여도를 제한한 기간별 합계 조회 (차등 프라이버시 내보내기용)
+ * 롤업 대신 원본 기록에서 세션마다 값을 상한으로 자른 뒤 합산하며,
+ * 기록이 없는 기간도 0으로 포함해 기간 존재 여부가 드러나지 않게 합니다.
+ * @param {string} granularity - day, week, month 중 하나
+ * @param {string|Date} from - 시작 시각
+ * @param {string|Date} to - 종료 시각
+ * @param {Object} bounds - 세션당 상한 { keyCount, typingTime, totalChars, totalWords }
+ * @returns {Array} { periodStart, sessionCount, keyCount, typingTime, totalChars, totalWords, accuracySum } 배열
+ */
+function getClampedPeriodTotals(granularity, from, to, bounds) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  if (!ROLLUP_GRANULARITIES.includes(granularity)) {
+    throw new Error(`지원하지 않는 집계 단위입니다: ${granularity}`);
+  }
+  
+  const fromDate = new Date(from);
+  const toDate = new Date(to);
+  if (isNaN(fromDate.getTime()) || isNaN(toDate.getTime()) || fromDate > toDate) {
+    throw new Error('잘못된 조회 기간입니다');
+  }
+  
+  const step = ROLLUP_PERIOD_STEP[granularity];
+  const rows = db.prepare(`
+    WITH RECURSIVE periods(period_start) AS (
+      SELECT ${rollupPeriodSql(granularity, '@from')}
+      UNION ALL
+      SELECT date(period_start, '${step}') FROM periods
+      WHERE date(period_start, '${step}') <= date(@to, 'localtime')
+    ),
+    sessions AS (
+      SELECT ${rollupPeriodSql(granularity)} AS period_start,
+             MAX(0, MIN(key_count, @keyCount)) AS key_count,
+             MAX(0, MIN(typing_time, @typingTime)) AS typing_time,
+             MAX(0, MIN(total_chars, @totalChars)) AS total_chars,
+             MAX(0, MIN(total_words, @totalWords)) AS total_words,
+             MAX(0, MIN(COALESCE(accuracy, 100), 100)) AS accuracy
+      FROM typing_stats
+      WHERE timestamp >= @from AND timestamp <= @to
+    )
+    SELECT p.period_start,
+           COUNT(s.period_start) AS session_count,
+           COALESCE(SUM(s.key_count), 0) AS key_count,
+           COALESCE(SUM(s.typing_time), 0) AS typing_time,
+           COALESCE(SUM(s.total_chars), 0) AS total_chars,
+           COALESCE(SUM(s.total_words), 0) AS total_words,
+           COALESCE(SUM(s.accuracy), 0) AS accuracy_sum
+    FROM periods p
+    LEFT JOIN sessions s ON s.period_start = p.period_start
+    GROUP BY p.period_start
+    ORDER BY p.period_start ASC
+  `).all({
+    from: fromDate.toISOString(),
+    to: toDate.toISOString(),
+    keyCount: bounds.keyCount,
+    typingTime: bounds.typingTime,
+    totalChars: bounds.totalChars,
+    totalWords: bounds.totalWords
+  });
+  
+  return rows.map(row => ({
+    periodStart: row.period_start,
+    sessionCount: row.session_count,
+    keyCount: row.key_count,
+    typingTime: row.typing_time,
+    totalChars: row.total_chars,
+    totalWords: row.total_words,
+    accuracySum: row.accuracy_sum
+  }));
+}
+
+/**
+ * 기간 안의 시간대별 입력 시간 (현지 시간 0~23시, 원본 기록 기준)
+ * 원본 기록이 정리된 기간은 포함되지 않습니다.
+ * @param {Date} from - 시작 시각
+ * @param {Date} to - 종료 시각
+ * @returns {Array<number>} 시간대별 입력 시간 합계 (초, 길이 24)
+ */
+function getActiveHours(from, to) {
+  const hours = new Array(24).fill(0);
+  const rows = db.prepare(`
+    SELECT CAST(strftime('%H', timestamp, 'localtime') AS INTEGER) AS hour,
+           COALESCE(SUM(typing_time), 0) AS typing_time
+    FROM typing_stats
+    WHERE date(timestamp, 'localtime') >= date(?, 'localtime')
+      AND date(timestamp, 'localtime') <= date(?, 'localtime')
+    GROUP BY hour
+  `).all(from.toISOString(), to.toISOString());
+  
+  for (const row of rows) {
+    if (row.hour >= 0 && row.hour < 24) {
+      hours[row.hour] = row.typing_time;
+    }
+  }
+  return hours;
+}
+
+/**
+ * 일 단위 통계 조회 (일 롤업과 시간대별 입력 시간)
+ * @param {string|Date} from - 시작 시각
+ * @param {string|Date} to - 종료 시각 (기본값: 현재)
+ * @returns {Object} { granularity, points, activeHours } - points는 getTypingTrend와 같은 형식
+ */
+function getDailyStats(from, to = new Date()) {
+  return getPeriodStats('day', from, to);
+}
+
+/**
+ * 주 단위 통계 조회 (주 시작 요일 설정 기준 주 롤업과 시간대별 입력 시간)
+ * @param {string|Date} from - 시작 시각
+ * @param {string|Date} to - 종료 시각 (기본값: 현재)
+ * @returns {Object} { granularity, points, activeHours } - points는 getTypingTrend와 같은 형식
+ */
+function getWeeklyStats(from, to = new Date()) {
+  return getPeriodStats('week', from, to);
+}
+
+/**
+ * 단위를 고정한 기간 통계 조회
+ * @param {string} granularity - day 또는 week
+ * @param {string|Date} from - 시작 시각
+ * @param {string|Date} to - 종료 시각
+ * @returns {Object} { granularity, points, activeHours }
+ */
+function getPeriodStats(granularity, from, to) {
+  const trend = getTypingTrend(from, to, granularity);
+  const fromDate = new Date(from);
+  const toDate = new Date(to);
+  let activeHours = new Array(24).fill(0);
+  
+  if (trend.granularity && !isNaN(fromDate.getTime()) && !isNaN(toDate.getTime())) {
+    try {
+      activeHours = getActiveHours(fromDate, toDate);
+    } catch (error) {
+      console.error('시간대별 입력 시간 조회 오류:', error);
+    }
+  }
+  
+  return { ...trend, activeHours };
+}
+
+/**
+ * 일별 작성 단어 수와 타이핑 속도의 이상치 조회
+ * 기록이 있는 날만 순서대로 보며, 직전까지의 지수 가중 이동 평균/분산과 비교한
+ * z-점수가 임계값을 넘는 날을 표시합니다 (데이터 오류 또는 특별히 많이/빠르게 작성한 날).
+ * @param {Object} [options]
+ * @param {number} [options.days] - 조회할 최근 일수 (기본 90일)
+ * @param {number} [options.threshold] - z-점수 임계값 (기본 3)
+ * @returns {Array} { date, metric, value, expected, zScore, direction } 배열 (날짜순)
+ */
+function getAnomalies({ days = 90, threshold = ANOMALY_DEFAULT_THRESHOLD } = {}) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  try {
+    const rows = db.prepare(`
+      SELECT period_start, key_count, typing_time, total_words
+      FROM typing_stats_rollups
+      WHERE granularity = 'day'
+        AND period_start >= date('now', 'localtime', ?)
+      ORDER BY period_start ASC
+    `).all(`-${Math.max(1, Math.floor(days))} days`);
+    
+    const series = {
+      words: rows.map(row => row.total_words || 0),
+      wpm: rows.map(row => calculateWpm(row.key_count, row.typing_time))
+    };
+    
+    const anomalies = [];
+    for (const [metric, values] of Object.entries(series)) {
+      let mean = values[0] || 0;
+      let variance = 0;
+      
+      for (let i = 1; i < values.length; i++) {
+        const value = values[i];
+        const stdDev = Math.sqrt(variance);
+        let update = value;
+        
+        if (i >= ANOMALY_WARMUP_DAYS && stdDev > 0) {
+          const zScore = (value - mean) / stdDev;
+          if (Math.abs(zScore) >= threshold) {
+            anomalies.push({
+              date: rows[i].period_start,
+              metric,
+              value,
+              expected: mean,
+              zScore,
+              direction: zScore > 0 ? 'high' : 'low'
+            });
+            
+            // 이상치는 임계값 경계까지만 반영해 이후 판단 기준이 크게 흔들리지 않도록 함
+            update = mean + Math.sign(zScore) * threshold * stdDev;
+          }
+        }
+        
+        const diff = update - mean;
+        mean += ANOMALY_EWMA_ALPHA * diff;
+        variance = (1 - ANOMALY_EWMA_ALPHA) * (variance + ANOMALY_EWMA_ALPHA * diff * diff);
+      }
+    }
+    
+    return anomalies.sort((a, b) => a.date.localeCompare(b.date));
+  } catch (error) {
+    console.error('이상치 조회 오류:', error);
+    return [];
+  }
+}
+
+/**
+ * 작성 목표 추가
+ * @param {Object} goal
+ * @param {string|null} goal.document - 문서(창 제목), 전체 목표면 null
+ * @param {string} goal.metric - 'words' 또는 'minutes'
+ * @param {number} goal.target - 일일 목표값
+ * @returns {Object} 추가된 목표
+ */
+function createGoal({ document = null, metric, target }) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  const result = db.prepare(
+    'INSERT INTO typing_goals (document, metric, target) VALUES (?, ?, ?)'
+  ).run(document, metric, target);
+  
+  return db.prepare('SELECT * FROM typing_goals WHERE id = ?').get(result.lastInsertRowid);
+}
+
+/**
+ * 작성 목표 삭제 (진행 기록 포함)
+ * @param {number} id - 목표 ID
+ * @returns {boolean} 삭제 여부
+ */
+function deleteGoal(id) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  const removeGoal = db.transaction((goalId) => {
+    db.prepare('DELETE FROM typing_goal_history WHERE goal_id = ?').run(goalId);
+    return db.prepare('DELETE FROM typing_goals WHERE id = ?').run(goalId).changes > 0;
+  });
+  
+  return removeGoal(id);
+}
+
+/**
+ * 모든 작성 목표 조회
+ * @returns {Array} 목표 배열
+ */
+function listGoals() {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  return db.prepare('SELECT * FROM typing_goals ORDER BY id ASC').all();
+}
+
+/**
+ * 하루 동안 저장된 세션 합계 (현지 날짜 기준)
+ * @param {string} date - 날짜 (YYYY-MM-DD)
+ * @param {string|null} [document] - 문서(창 제목)로 한정할 경우
+ * @returns {{ words: number, seconds: number }} 단어 수와 타이핑 시간 (초)
+ */
+function getDailySessionTotals(date, document = null) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  const row = db.prepare(`
+    SELECT COALESCE(SUM(total_words), 0) AS words,
+           COALESCE(SUM(typing_time), 0) AS seconds
+    FROM typing_stats
+    WHERE date(timestamp, 'localtime') = ?
+      AND (? IS NULL OR window_title = ?)
+  `).get(date, document, document);
+  
+  return { words: row.words, seconds: row.seconds };
+}
+
+/**
+ * 목표 일일 진행 기록 저장
+ * 달성 시각은 처음 달성했을 때만 기록합니다.
+ * @param {number} goalId - 목표 ID
+ * @param {string} date - 날짜 (YYYY-MM-DD)
+ * @param {number} progress - 진행값
+ * @param {number} target - 목표값
+ * @param {boolean} achieved - 달성 여부
+ * @returns {Object} 저장된 기록 (이번 저장으로 처음 달성했으면 newlyAchieved: true)
+ */
+function saveGoalProgress(goalId, date, progress, target, achieved) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  const select = db.prepare('SELECT * FROM typing_goal_history WHERE goal_id = ? AND date = ?');
+  const upsert = db.prepare(`
+    INSERT INTO typing_goal_history (goal_id, date, progress, target, achieved_at)
+    VALUES (@goalId, @date, @progress, @target, CASE WHEN @achieved THEN @now END)
+    ON CONFLICT(goal_id, date) DO UPDATE SET
+      progress = excluded.progress,
+      target = excluded.target,
+      achieved_at = COALESCE(typing_goal_history.achieved_at, excluded.achieved_at)
+  `);
+  
+  const save = db.transaction(() => {
+    const previous = select.get(goalId, date);
+    upsert.run({ goalId, date, progress, target, achieved: achieved ? 1 : 0, now: new Date().toISOString() });
+    const row = select.get(goalId, date);
+    return { ...row, newlyAchieved: !previous?.achieved_at && Boolean(row.achieved_at) };
+  });
+  
+  return save();
+}
+
+/**
+ * 목표 진행 기록 조회
+ * @param {number} goalId - 목표 ID
+ * @param {number} [days] - 최근 일수 (기본 30일)
+ * @returns {Array} 날짜순 기록 배열
+ */
+function getGoalHistory(goalId, days = 30) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  return db.prepare(`
+    SELECT * FROM typing_goal_history
+    WHERE goal_id = ? AND date >= date('now', 'localtime', ?)
+    ORDER BY date ASC
+  `).all(goalId, `-${Math.max(0, Math.floor(days) - 1)} days`);
+}
+
+/**
+ * 피로도 확인 결과를 오늘(현지 날짜) 기록에 반영
+ * 최고 피로도와 휴식 권장 횟수만 남깁니다.
+ * @param {number} score - 현재 피로도 (0~100)
+ * @param {boolean} breakRecommended - 이번 확인에서 휴식을 권장했는지 여부
+ */
+function recordFatigueSample(score, breakRecommended) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  try {
+    db.prepare(`
+      INSERT INTO typing_fatigue_daily (date, peak_score, break_count)
+      VALUES (date('now', 'localtime'), ?, ?)
+      ON CONFLICT(date) DO UPDATE SET
+        peak_score = MAX(peak_score, excluded.peak_score),
+        break_count = break_count + excluded.break_count
+    `).run(Number(score) || 0, breakRecommended ? 1 : 0);
+  } catch (error) {
+    console.error('피로도 기록 오류:', error);
+  }
+}
+
+/**
+ * 기간 내 일별 피로도 기록 조회
+ * @param {string} start - 시작 날짜 (YYYY-MM-DD, 포함)
+ * @param {string} end - 끝 날짜 (YYYY-MM-DD, 제외)
+ * @returns {Array} { date, peak_score, break_count } 배열
+ */
+function getFatigueDaily(start, end) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  return db.prepare(`
+    SELECT date, peak_score, break_count FROM typing_fatigue_daily
+    WHERE date >= ? AND date < ?
+    ORDER BY date ASC
+  `).all(start, end);
+}
+
+/**
+ * 주간 보고서용 기간 통계 (현지 날짜 기준)
+ * @param {string} start - 시작 날짜 (YYYY-MM-DD, 포함)
+ * @param {string} end - 끝 날짜 (YYYY-MM-DD, 제외)
+ * @param {number} [topLimit] - 상위 문서 수
+ * @returns {Object} { totals, days, documents }
+ */
+function getReportStats(start, end, topLimit = 5) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  const range = `date(timestamp, 'localtime') >= @start AND date(timestamp, 'localtime') < @end`;
+  
+  const totals = db.prepare(`
+    SELECT COUNT(*) AS sessions,
+           COALESCE(SUM(key_count), 0) AS key_count,
+           COALESCE(SUM(typing_time), 0) AS typing_time,
+           COALESCE(SUM(total_words), 0) AS total_words,
+           COALESCE(AVG(accuracy), 0) AS accuracy
+    FROM typing_stats
+    WHERE ${range}
+  `).get({ start, end });
+  
+  const days = db.prepare(`
+    SELECT date(timestamp, 'localtime') AS date,
+           COUNT(*) AS sessions,
+           SUM(key_count) AS key_count,
+           SUM(typing_time) AS typing_time,
+           SUM(total_words) AS total_words
+    FROM typing_stats
+    WHERE ${range}
+    GROUP BY date(timestamp, 'localtime')
+    ORDER BY date ASC
+  `).all({ start, end });
+  
+  const documents = db.prepare(`
+    SELECT window_title AS title,
+           COUNT(*) AS sessions,
+           SUM(typing_time) AS typing_time,
+           SUM(total_words) AS total_words
+    FROM typing_stats
+    WHERE ${range} AND window_title IS NOT NULL AND window_title != ''
+    GROUP BY window_title
+    ORDER BY total_words DESC, typing_time DESC
+    LIMIT @limit
+  `).all({ start, end, limit: topLimit });
+  
+  return { totals, days, documents };
+}
+
+/**
+ * 마지막 통계 기록 ID
+ * @returns {number} 기록이 없으면 0
+ */
+function getMaxStatId() {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  return db.prepare('SELECT COALESCE(MAX(id), 0) AS id FROM typing_stats').get().id;
+}
+
+/**
+ * 지정한 ID 이후의 통계 기록 (입력 내용 제외)
+ * @param {number} afterId - 이 ID보다 큰 기록만 조회
+ * @param {number} limit - 최대 개수
+ * @returns {Array} 기록 배열 (ID 오름차순)
+ */
+function getStatsAfter(afterId, limit) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  return db.prepare(`
+    SELECT id, key_count, typing_time, window_title, browser_name,
+           total_chars, total_words, accuracy, timestamp, end_reason
+    FROM typing_stats
+    WHERE id > ?
+    ORDER BY id ASC
+    LIMIT ?
+  `).all(afterId, limit);
+}
+
+/**
+ * 분석 내보내기 대기열에 추가
+ * 같은 세션이 이미 있으면 건너뜁니다.
+ * @param {Array} events - { sessionId, payload } 배열
+ * @returns {number} 추가된 수
+ */
+function enqueueAnalyticsEvents(events) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  const insert = db.prepare(
+    'INSERT OR IGNORE INTO analytics_outbox (session_id, payload) VALUES (?, ?)'
+  );
+  const enqueue = db.transaction(() => {
+    let added = 0;
+    for (const event of events) {
+      added += insert.run(event.sessionId, JSON.stringify(event.payload)).changes;
+    }
+    return added;
+  });
+  
+  return enqueue();
+}
+
+/**
+ * 지금 보낼 수 있는 대기열 항목
+ * @param {number} now - 현재 시각 (ms)
+ * @param {number} limit - 최대 개수
+ * @param {number} maxAttempts - 이 횟수만큼 실패한 항목은 제외
+ * @returns {Array} { id, sessionId, payload, attempts } 배열
+ */
+function getDueAnalyticsEvents(now, limit, maxAttempts) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  return db.prepare(`
+    SELECT id, session_id, payload, attempts FROM analytics_outbox
+    WHERE next_attempt_at <= ? AND attempts < ?
+    ORDER BY id ASC
+    LIMIT ?
+  `).all(now, maxAttempts, limit).map(row => ({
+    id: row.id,
+    sessionId: row.session_id,
+    payload: JSON.parse(row.payload),
+    attempts: row.attempts
+  }));
+}
+
+/**
+ * 전송을 마친 대기열 항목 삭제
+ * @param {number[]} ids - 대기열 항목 ID
+ * @returns {number} 삭제된 수
+ */
+function deleteAnalyticsEvents(ids) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  const remove = db.prepare('DELETE FROM analytics_outbox WHERE id = ?');
+  return db.transaction(() => ids.reduce((count, id) => count + remove.run(id).changes, 0))();
+}
+
+/**
+ * 전송에 실패한 대기열 항목 기록
+ * @param {number[]} ids - 대기열 항목 ID
+ * @param {string} error - 오류 메시지
+ * @param {number} nextAttemptAt - 다음 시도 시각 (ms)
+ */
+function markAnalyticsEventsFailed(ids, error, nextAttemptAt) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  const update = db.prepare(`
+    UPDATE analytics_outbox
+    SET attempts = attempts + 1, last_error = ?, next_attempt_at = ?
+    WHERE id = ?
+  `);
+  db.transaction(() => {
+    for (const id of ids) {
+      update.run(error, nextAttemptAt, id);
+    }
+  })();
+}
+
+/**
+ * 분석 내보내기 대기열 현황
+ * @param {number} maxAttempts - 재시도를 멈추는 실패 횟수
+ * @returns {{ pending: number, failed: number, lastError: string|null }}
+ */
+function getAnalyticsOutboxStats(maxAttempts) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  const row = db.prepare(`
+    SELECT SUM(CASE WHEN attempts < @maxAttempts THEN 1 ELSE 0 END) AS pending,
+           SUM(CASE WHEN attempts >= @maxAttempts THEN 1 ELSE 0 END) AS failed
+    FROM analytics_outbox
+  `).get({ maxAttempts });
+  const last = db.prepare(`
+    SELECT last_error FROM analytics_outbox
+    WHERE last_error IS NOT NULL
+    ORDER BY next_attempt_at DESC
+    LIMIT 1
+  `).get();
+  
+  return { pending: row.pending || 0, failed: row.failed || 0, lastError: last ? last.last_error : null };
+}
+
+/**
+ * 재시도를 멈춘 대기열 항목을 다시 보내도록 초기화
+ * @param {number} maxAttempts - 재시도를 멈추는 실패 횟수
+ * @returns {number} 초기화된 수
+ */
+function retryFailedAnalyticsEvents(maxAttempts) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  return db.prepare(`
+    UPDATE analytics_outbox SET attempts = 0, next_attempt_at = 0
+    WHERE attempts >= ?
+  `).run(maxAttempts).changes;
+}
+
+/**
+ * 기간 안의 통계 기록 조회 (시각 오름차순)
+ * @param {string|null} from - 시작 날짜 (YYYY-MM-DD, 현지 시간, 포함, null이면 처음부터)
+ * @param {string|null} to - 끝 날짜 (YYYY-MM-DD, 현지 시간, 포함, null이면 끝까지)
+ * @param {number} limit - 최대 개수
+ * @param {number} offset - 조회 시작 위치
+ * @returns {Array} 기록 배열
+ */
+function getStatsInRange(from, to, limit, offset = 0) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  return db.prepare(`
+    SELECT * FROM typing_stats
+    WHERE (@from IS NULL OR date(timestamp, 'localtime') >= @from)
+      AND (@to IS NULL OR date(timestamp, 'localtime') <= @to)
+    ORDER BY timestamp ASC, id ASC
+    LIMIT @limit OFFSET @offset
+  `).all({ from, to, limit, offset });
+}
+
+/**
+ * 기간 안의 일 단위 롤업 조회 (원본 기록이 정리된 날짜도 포함)
+ * @param {string|null} from - 시작 날짜 (YYYY-MM-DD, 포함, null이면 처음부터)
+ * @param {string|null} to - 끝 날짜 (YYYY-MM-DD, 포함, null이면 끝까지)
+ * @returns {Array} typing_stats_rollups 행 배열 (날짜 오름차순)
+ */
+function getDailyRollups(from, to) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  return db.prepare(`
+    SELECT period_start, session_count, key_count, typing_time,
+           total_chars, total_words, accuracy_sum
+    FROM typing_stats_rollups
+    WHERE granularity = 'day'
+      AND (@from IS NULL OR period_start >= @from)
+      AND (@to IS NULL OR period_start <= @to)
+    ORDER BY period_start ASC
+  `).all({ from, to });
+}
+
+/**
+ * 기간 안의 앱(브라우저)별 합계
+ * @param {string|null} from - 시작 날짜 (YYYY-MM-DD, 현지 시간, 포함, null이면 처음부터)
+ * @param {string|null} to - 끝 날짜 (YYYY-MM-DD, 현지 시간, 포함, null이면 끝까지)
+ * @returns {Array} { application, session_count, key_count, typing_time, total_chars, total_words, accuracy_sum } 배열
+ */
+function getApplicationStats(from, to) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  return db.prepare(`
+    SELECT browser_name AS application,
+           COUNT(*) AS session_count,
+           COALESCE(SUM(key_count), 0) AS key_count,
+           COALESCE(SUM(typing_time), 0) AS typing_time,
+           COALESCE(SUM(total_chars), 0) AS total_chars,
+           COALESCE(SUM(total_words), 0) AS total_words,
+           COALESCE(SUM(accuracy), 0) AS accuracy_sum
+    FROM typing_stats
+    WHERE (@from IS NULL OR date(timestamp, 'localtime') >= @from)
+      AND (@to IS NULL OR date(timestamp, 'localtime') <= @to)
+    GROUP BY browser_name
+    ORDER BY typing_time DESC
+  `).all({ from, to });
+}
+
+/**
+ * 현재 기간의 앱(브라우저)별 타이핑 통계
+ * @param {string} [period] - day(오늘), week(이번 주, 주 시작 요일 설정 기준), month(이번 달), all(전체) 중 하나
+ * @returns {Object} { period, from, applications } - applications는 입력 시간이 긴 순서의
+ *   { application, sessionCount, keyCount, typingTime, totalChars, totalWords, wpm, avgAccuracy } 배열
+ */
+function getTypingStatsByApp(period = 'week') {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  const selected = period === 'all' || ROLLUP_GRANULARITIES.includes(period) ? period : 'week';
+  
+  try {
+    const from = selected === 'all'
+      ? null
+      : db.prepare(`SELECT ${rollupPeriodSql(selected, "'now'")} AS start`).get().start;
+    
+    const applications = getApplicationStats(from, null).map(row => ({
+      application: row.application,
+      sessionCount: row.session_count,
+      keyCount: row.key_count,
+      typingTime: row.typing_time,
+      totalChars: row.total_chars,
+      totalWords: row.total_words,
+      wpm: calculateWpm(row.key_count, row.typing_time),
+      avgAccuracy: row.session_count > 0 ? row.accuracy_sum / row.session_count : 0
+    }));
+    
+    return { period: selected, from, applications };
+  } catch (error) {
+    console.error('앱별 타이핑 통계 조회 오류:', error);
+    return { period: selected, from: null, applications: [] };
+  }
+}
+
+/**
+ * 다른 기기에서 내보낸 일 단위 롤업 가져오기
+ * 이 기기에 이미 롤업이 있는 날짜는 건너뛰므로, 세션을 먼저 가져온 뒤 호출하면
+ * 원본 기록이 정리되어 롤업만 남은 날짜만 추가됩니다. 주/월 롤업에도 함께 더합니다.
+ * @param {Array} rows - { periodStart, sessionCount, keyCount, typingTime, totalChars, totalWords, accuracySum } 배열
+ * @returns {{ imported: number, skipped: number }} 가져온 날짜 수와 건너뛴 날짜 수
+ */
+function importDailyRollups(rows) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  const exists = db.prepare("SELECT 1 FROM typing_stats_rollups WHERE granularity = 'day' AND period_start = ?");
+  const periodSql = {
+    day: '@date',
+    week: `date(@date, 'weekday ${(firstDayOfWeek + 6) % 7}', '-6 days')`,
+    month: "date(@date, 'start of month')"
+  };
+  const insert = Object.fromEntries(ROLLUP_GRANULARITIES.map(granularity => [granularity, db.prepare(`
+    INSERT INTO typing_stats_rollups
+      (granularity, period_start, session_count, key_count, typing_time,
+       total_chars, total_words, accuracy_sum)
+    VALUES ('${granularity}', ${periodSql[granularity]}, @sessionCount, @keyCount, @typingTime,
+            @totalChars, @totalWords, @accuracySum)
+    ON CONFLICT (granularity, period_start) DO UPDATE SET
+      session_count = session_count + excluded.session_count,
+      key_count = key_count + excluded.key_count,
+      typing_time = typing_time + excluded.typing_time,
+      total_chars = total_chars + excluded.total_chars,
+      total_words = total_words + excluded.total_words,
+      accuracy_sum = accuracy_sum + excluded.accuracy_sum,
+      updated_at = CURRENT_TIMESTAMP
+  `)]));
+  
+  const importAll = db.transaction((items) => {
+    let imported = 0;
+    let skipped = 0;
+    
+    for (const row of items) {
+      if (exists.get(row.periodStart)) {
+        skipped++;
+        continue;
+      }
+      
+      const values = {
+        date: row.periodStart,
+        sessionCount: row.sessionCount,
+        keyCount: row.keyCount,
+        typingTime: row.typingTime,
+        totalChars: row.totalChars,
+        totalWords: row.totalWords,
+        accuracySum: row.accuracySum
+      };
+      for (const granularity of ROLLUP_GRANULARITIES) {
+        insert[granularity].run(values);
+      }
+      imported++;
+    }
+    
+    return { imported, skipped };
+  });
+  
+  const result = importAll(rows);
+  debugLog(`일 단위 롤업 가져오기 완료: ${result.imported}일 추가, ${result.skipped}일 건너뜀`);
+  return result;
+}
+
 /**
  * 설정 저장
  * @param {string} key - 설정 키
@@ -270,6 +1428,130 @@ function loadSetting(key, defaultValue = null) {
   }
 }
 
+/**
+ * 쿼리 문자열에서 주석을 제거하고 첫 키워드 확인
+ * @param {string} sql - 쿼리 문자열
+ * @returns {string} 대문자 첫 키워드 (없으면 빈 문자열)
+ */
+function getLeadingKeyword(sql) {
+  const stripped = sql
+    .replace(/\/\*[\s\S]*?\*\//g, ' ')
+    .replace(/--[^\n]*/g, ' ')
+    .trim();
+  const match = stripped.match(/^[A-Za-z]+/);
+  return match ? match[0].toUpperCase() : '';
+}
+
+/**
+ * 사용자 읽기 전용 쿼리 실행 (SQL 콘솔)
+ * SELECT/WITH로 시작하는 단일 문장만 허용하며, 워커 스레드의 별도 읽기 전용 연결에서 실행합니다.
+ * 행 수 제한에 걸리면 그때까지의 결과를 truncated와 함께 반환하고,
+ * 시간 제한에 걸리면 워커를 종료하고 timedOut 오류를 반환합니다.
+ * @param {string} sql - 실행할 쿼리
+ * @param {Object} [options]
+ * @param {number} [options.maxRows] - 최대 행 수 (기본값 1000, 최대 10000)
+ * @param {number} [options.timeoutMs] - 최대 실행 시간 (기본값 2000ms, 최대 10000ms)
+ * @returns {Promise<Object>} { success, columns, rows, rowCount, truncated, timedOut, durationMs } 또는 { success: false, error, timedOut? }
+ */
+function executeReadonlyQuery(sql, { maxRows = READONLY_QUERY_DEFAULT_MAX_ROWS, timeoutMs = READONLY_QUERY_DEFAULT_TIMEOUT_MS } = {}) {
+  if (typeof sql !== 'string' || !sql.trim()) {
+    return Promise.resolve({ success: false, error: '쿼리가 비어 있습니다' });
+  }
+  if (sql.length > READONLY_QUERY_MAX_LENGTH) {
+    return Promise.resolve({ success: false, error: `쿼리가 너무 깁니다 (최대 ${READONLY_QUERY_MAX_LENGTH}자)` });
+  }
+  
+  const keyword = getLeadingKeyword(sql);
+  if (keyword !== 'SELECT' && keyword !== 'WITH') {
+    return Promise.resolve({ success: false, error: 'SELECT 또는 WITH로 시작하는 조회 쿼리만 실행할 수 있습니다' });
+  }
+  
+  const rowLimit = Math.min(Math.max(1, Math.floor(Number(maxRows)) || READONLY_QUERY_DEFAULT_MAX_ROWS), READONLY_QUERY_MAX_ROWS);
+  const timeLimit = Math.min(Math.max(1, Math.floor(Number(timeoutMs)) || READONLY_QUERY_DEFAULT_TIMEOUT_MS), READONLY_QUERY_MAX_TIMEOUT_MS);
+  
+  return new Promise((resolve) => {
+    let worker;
+    try {
+      if (!db) {
+        initializeDatabase();
+      }
+      worker = new Worker(path.join(__dirname, 'workers', 'readonly-query-worker.js'), {
+        workerData: { dbPath, sql, rowLimit }
+      });
+    } catch (error) {
+      resolve({ success: false, error: error.message || String(error) });
+      return;
+    }
+    
+    const started = Date.now();
+    let settled = false;
+    readonlyQueryWorkers.add(worker);
+    
+    const finish = (result) => {
+      if (settled) {
+        return;
+      }
+      settled = true;
+      clearTimeout(timer);
+      readonlyQueryWorkers.delete(worker);
+      resolve(result);
+    };
+    
+    // 시간 제한에 걸리면 워커를 종료해 진행 중인 쿼리 중단
+    const timer = setTimeout(() => {
+      worker.terminate().catch(() => {});
+      finish({
+        success: false,
+        error: `쿼리 실행 시간이 제한(${timeLimit}ms)을 넘어 중단했습니다`,
+        timedOut: true,
+        durationMs: Date.now() - started
+      });
+    }, timeLimit);
+    
+    worker.once('message', (message) => {
+      if (!message.success) {
+        finish({ success: false, error: message.error });
+        return;
+      }
+      finish({
+        success: true,
+        columns: message.columns,
+        rows: message.rows,
+        rowCount: message.rows.length,
+        truncated: message.truncated,
+        timedOut: false,
+        durationMs: Date.now() - started
+      });
+    });
+    
+    worker.once('error', (error) => {
+      finish({ success: false, error: error.message || String(error) });
+    });
+    
+    worker.once('exit', () => {
+      finish({ success: false, error: '쿼리 워커가 결과 없이 종료되었습니다' });
+    });
+  });
+}
+
+/**
+ * 현재 데이터베이스 스키마와 익명화한 샘플 기록 덤프 (DB 관련 문제 보고용)
+ * @param {Object} [options] - { includeSamples, sampleLimit }
+ * @returns {Object|null} 픽스처 객체
+ */
+function dumpSchema(options = {}) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  try {
+    return schemaDump.dumpSchema(db, options);
+  } catch (error) {
+    console.error('스키마 덤프 오류:', error);
+    return null;
+  }
+}
+
 /**
  * 데이터베이스 최적화 실행
  */
@@ -295,6 +1577,7 @@ function optimizeDatabase() {
 
 /**
  * 오래된 데이터 정리 (메모리 사용량 감소)
+ * 롤업은 유지되므로 정리된 기간의 장기 통계는 계속 조회할 수 있습니다.
  * @param {number} days - 보관할 일수 (기본 30일)
  */
 function cleanupOldData(days = 30) {
@@ -318,10 +1601,85 @@ function cleanupOldData(days = 30) {
   }
 }
 
+/**
+ * 데이터베이스 사본 만들기 (사용 중에도 일관된 시점의 사본)
+ * 사본에서는 보안 설정 값(safeStorage 형식으로 저장한 토큰, 비밀번호, PIN)을 지웁니다.
+ * @param {string} destPath - 사본 파일 경로
+ * @returns {Promise<void>}
+ */
+async function backupDatabase(destPath) {
+  if (!db) {
+    initializeDatabase();
+  }
+  
+  await db.backup(destPath);
+  
+  const copy = new BetterSqlite3(destPath);
+  try {
+    copy.prepare(`
+      DELETE FROM settings
+      WHERE json_valid(value) AND json_type(value, '$.encrypted') IS NOT NULL
+    `).run();
+    copy.exec('VACUUM');
+  } finally {
+    copy.close();
+  }
+}
+
+/**
+ * 데이터베이스 파일 경로 (WAL 모드의 보조 파일 포함)
+ * @returns {string[]} 파일 경로 배열
+ */
+function getDatabaseFiles() {
+  return [dbPath, `${dbPath}-wal`, `${dbPath}-shm`, `${dbPath}-journal`];
+}
+
+/**
+ * 데이터베이스 상태 점검 (연결 설정, 파일 크기, 무결성 빠른 검사)
+ * @returns {Object} { healthy, path, journalMode, busyTimeoutMs, foreignKeys, pageCount, pageSize, sizeBytes, walSizeBytes, quickCheck, activeQueryWorkers, durationMs } 또는 { healthy: false, error }
+ */
+function getDatabaseHealth() {
+  const started = Date.now();
+  
+  try {
+    if (!db) {
+      initializeDatabase();
+    }
+    
+    const quickCheck = db.pragma('quick_check', { simple: true });
+    const pageCount = db.pragma('page_count', { simple: true });
+    const pageSize = db.pragma('page_size', { simple: true });
+    const fileSize = (file) => (fs.existsSync(file) ? fs.statSync(file).size : 0);
+    
+    return {
+      healthy: quickCheck === 'ok',
+      path: dbPath,
+      journalMode: db.pragma('journal_mode', { simple: true }),
+      busyTimeoutMs: db.pragma('busy_timeout', { simple: true }),
+      foreignKeys: db.pragma('foreign_keys', { simple: true }) === 1,
+      pageCount,
+      pageSize,
+      sizeBytes: fileSize(dbPath),
+      walSizeBytes: fileSize(`${dbPath}-wal`),
+      quickCheck,
+      activeQueryWorkers: readonlyQueryWorkers.size,
+      durationMs: Date.now() - started
+    };
+  } catch (error) {
+    console.error('데이터베이스 상태 점검 오류:', error);
+    return { healthy: false, path: dbPath, error: error.message || String(error), durationMs: Date.now() - started };
+  }
+}
+
 /**
  * 데이터베이스 연결 종료
  */
 function closeDatabase() {
+  for (const worker of readonlyQueryWorkers) {
+    worker.terminate().catch(() => {});
+  }
+  readonlyQueryWorkers.clear();
+  
   if (db) {
     try {
       db.close();
@@ -339,12 +1697,47 @@ function closeDatabase() {
 module.exports = {
   initializeDatabase,
   saveStats,
+  importStats,
   getStatById,
   getAllStats,
   getStatsSummaryByPeriod,
+  getTypingTrend,
+  getDailyStats,
+  getWeeklyStats,
+  getClampedPeriodTotals,
+  backfillRollups,
+  getAnomalies,
+  setFirstDayOfWeek,
+  dumpSchema,
+  executeReadonlyQuery,
+  createGoal,
+  deleteGoal,
+  listGoals,
+  getDailySessionTotals,
+  saveGoalProgress,
+  getGoalHistory,
+  recordFatigueSample,
+  getFatigueDaily,
+  getReportStats,
+  getMaxStatId,
+  getStatsAfter,
+  enqueueAnalyticsEvents,
+  getDueAnalyticsEvents,
+  deleteAnalyticsEvents,
+  markAnalyticsEventsFailed,
+  getAnalyticsOutboxStats,
+  retryFailedAnalyticsEvents,
+  getStatsInRange,
+  getDailyRollups,
+  getApplicationStats,
+  getTypingStatsByApp,
+  importDailyRollups,
   saveSetting,
   loadSetting,
   optimizeDatabase,
   cleanupOldData,
+  backupDatabase,
+  getDatabaseFiles,
+  getDatabaseHealth,
   closeDatabase
 };
@@ -0,0 +1,84 @@
+/**
+ * 차등 프라이버시 모듈
+ * 내보내는 기간별 합계에 라플라스 잡음을 더해 개별 세션의 정확한 값을 알 수 없게 합니다.
+ * 보호 단위는 타이핑 세션 하나입니다 (docs/aggregate-stats-export.md 참고).
+ */
+const crypto = require('crypto');
+
+// 프라이버시 수준별 엡실론 (작을수록 잡음이 크고 보호가 강함)
+const EPSILON_PRESETS = {
+  strong: 0.1,
+  balanced: 0.5,
+  light: 1.0
+};
+
+const DEFAULT_EPSILON = EPSILON_PRESETS.balanced;
+const MIN_EPSILON = 0.01;
+const MAX_EPSILON = 10;
+
+// 세션 하나가 한 기간의 합계에 기여할 수 있는 최대값 (민감도)
+const SESSION_BOUNDS = {
+  sessionCount: 1,
+  keyCount: 20000,
+  typingTime: 3600, // 초
+  totalChars: 20000,
+  totalWords: 4000,
+  accuracySum: 100
+};
+
+// 잡음을 더하는 합계 항목 (엡실론을 항목 수만큼 나눠 사용)
+const NOISY_FIELDS = Object.keys(SESSION_BOUNDS);
+
+/**
+ * 엡실론 값 또는 프리셋 이름을 숫자로 변환
+ * @param {number|string} [epsilon] - 엡실론 값 또는 strong, balanced, light
+ * @returns {number} 검증된 엡실론
+ */
+function resolveEpsilon(epsilon = DEFAULT_EPSILON) {
+  const value = typeof epsilon === 'string' ? EPSILON_PRESETS[epsilon] : epsilon;
+  if (!Number.isFinite(value) || value < MIN_EPSILON || value > MAX_EPSILON) {
+    throw new Error(`엡실론은 ${MIN_EPSILON}~${MAX_EPSILON} 사이 값이나 프리셋 이름이어야 합니다: ${epsilon}`);
+  }
+  return value;
+}
+
+/**
+ * 라플라스 분포 표본 (평균 0)
+ * @param {number} scale - 척도 (민감도 / 엡실론)
+ * @returns {number} 잡음 값
+ */
+function laplaceNoise(scale) {
+  // (0, 1) 구간의 균등 분포 값을 암호학적 난수로 생성
+  const uniform = (crypto.randomInt(1, 2 ** 48 - 1)) / 2 ** 48 - 0.5;
+  return -scale * Math.sign(uniform) * Math.log(1 - 2 * Math.abs(uniform));
+}
+
+/**
+ * 기간별 합계에 잡음 추가
+ * 합계 항목마다 엡실론을 똑같이 나눠 쓰므로 기간 하나 전체가 엡실론-차등 프라이버시를 만족합니다.
+ * 평균 정확도와 분당 단어 수는 잡음이 더해진 합계에서 다시 계산합니다.
+ * @param {Array} totals - getClampedPeriodTotals 결과
+ * @param {number} epsilon - 기간별 엡실론
+ * @returns {Array} 잡음이 더해진 기간별 집계
+ */
+function privatizePeriodTotals(totals, epsilon) {
+  const perFieldEpsilon = epsilon / NOISY_FIELDS.length;
+
+  return totals.map(period => {
+    const noisy = { periodStart: period.periodStart };
+    for (const field of NOISY_FIELDS) {
+      const scale = SESSION_BOUNDS[field] / perFieldEpsilon;
+      noisy[field] = Math.max(0, Math.round(period[field] + laplaceNoise(scale)));
+    }
+    return noisy;
+  });
+}
+
+module.exports = {
+  EPSILON_PRESETS,
+  DEFAULT_EPSILON,
+  SESSION_BOUNDS,
+  resolveEpsilon,
+  laplaceNoise,
+  privatizePeriodTotals
+};
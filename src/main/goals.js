@@ -0,0 +1,236 @@
+/**
+ * 작성 목표 모듈
+ * 전체 또는 문서(창 제목)별 일일 단어 수/타이핑 시간 목표를 관리하고,
+ * 저장된 세션과 진행 중인 세션으로 진행률을 계산해 렌더러에 알립니다.
+ */
+const { appState } = require('./constants');
+const { debugLog, accessibleText, withAccessibleDescription, formatNumber } = require('./utils');
+const {
+  createGoal: insertGoal,
+  deleteGoal: removeGoal,
+  listGoals,
+  getDailySessionTotals,
+  saveGoalProgress,
+  getGoalHistory
+} = require('./database');
+
+// 목표 종류 (words: 단어 수, minutes: 타이핑 시간(분))
+const GOAL_METRICS = ['words', 'minutes'];
+const MAX_GOAL_TARGET = 1000000;
+
+// 진행률 확인 주기
+const GOAL_CHECK_INTERVAL = 30000; // 30초
+
+let goalCheckTimer = null;
+
+// 마지막으로 알린 진행값 (변화가 없으면 기록/알림 생략)
+const lastProgress = new Map();
+
+/**
+ * 현지 날짜 문자열
+ * @param {Date} [date]
+ * @returns {string} YYYY-MM-DD
+ */
+function localDateString(date = new Date()) {
+  const pad = (value) => String(value).padStart(2, '0');
+  return `${date.getFullYear()}-${pad(date.getMonth() + 1)}-${pad(date.getDate())}`;
+}
+
+/**
+ * 진행 중인 (아직 저장되지 않은) 세션의 기여분
+ * 세션은 저장될 때 현재 창 제목으로 기록되므로 같은 기준으로 문서 목표에 반영합니다.
+ * @param {Object} goal - 목표
+ * @returns {number}
+ */
+function currentSessionContribution(goal) {
+  const stats = appState.currentStats;
+  if (!stats.startTime) return 0;
+  if (goal.document && stats.currentWindow !== goal.document) return 0;
+
+  return goal.metric === 'words' ? (stats.totalWords || 0) : (stats.typingTime || 0) / 60;
+}
+
+/**
+ * 목표의 하루 진행값 계산
+ * @param {Object} goal - 목표
+ * @param {string} date - 날짜 (YYYY-MM-DD)
+ * @param {boolean} includeCurrent - 진행 중인 세션 포함 여부
+ * @returns {number}
+ */
+function computeProgress(goal, date, includeCurrent) {
+  const totals = getDailySessionTotals(date, goal.document);
+  const saved = goal.metric === 'words' ? totals.words : totals.seconds / 60;
+  const progress = saved + (includeCurrent ? currentSessionContribution(goal) : 0);
+  return Math.round(progress * 10) / 10;
+}
+
+/**
+ * 목표 진행 상태 객체
+ * @param {Object} goal - 목표
+ * @param {number} progress - 진행값
+ * @returns {Object}
+ */
+function toGoalStatus(goal, progress) {
+  return {
+    goalId: goal.id,
+    document: goal.document,
+    metric: goal.metric,
+    target: goal.target,
+    progress,
+    percent: Math.min(100, Math.round((progress / goal.target) * 1000) / 10),
+    achieved: progress >= goal.target
+  };
+}
+
+// 목표 달성 알림 (렌더러 이벤트 + 트레이 알림)
+function notifyGoalAchieved(status) {
+  const unit = status.metric === 'words' ? '단어' : '분';
+  const scope = status.document ? `"${status.document}" ` : '';
+  const message = `${scope}오늘 목표 ${formatNumber(status.target)}${unit}를 달성했습니다.`;
+
+  debugLog('작성 목표 달성:', status);
+
+  if (appState.mainWindow && !appState.mainWindow.isDestroyed()) {
+    appState.mainWindow.webContents.send('goal-achieved', withAccessibleDescription(
+      { ...status, timestamp: Date.now() },
+      message
+    ));
+  }
+
+  if (appState.settings?.showTrayNotifications && appState.tray) {
+    appState.tray.displayBalloon({
+      title: 'Loop',
+      content: accessibleText(message),
+      iconType: 'info'
+    });
+  }
+}
+
+/**
+ * 모든 목표의 오늘 진행 상태 확인
+ * 진행값이 바뀐 목표는 진행 기록을 저장하고 'goal-progress' 이벤트를 보내며,
+ * 오늘 처음 달성한 목표는 'goal-achieved' 이벤트를 보냅니다.
+ * @returns {Array} 목표별 진행 상태
+ */
+function checkGoals() {
+  const date = localDateString();
+  const statuses = [];
+  let changed = false;
+
+  try {
+    for (const goal of listGoals()) {
+      const progress = computeProgress(goal, date, true);
+      const status = toGoalStatus(goal, progress);
+      statuses.push(status);
+
+      const key = `${goal.id}:${date}`;
+      if (lastProgress.get(key) === progress) continue;
+      lastProgress.set(key, progress);
+      changed = true;
+
+      const record = saveGoalProgress(goal.id, date, progress, goal.target, status.achieved);
+      if (record.newlyAchieved) {
+        notifyGoalAchieved(status);
+      }
+    }
+  } catch (error) {
+    console.error('작성 목표 확인 오류:', error);
+    return statuses;
+  }
+
+  if (changed && appState.mainWindow && !appState.mainWindow.isDestroyed()) {
+    appState.mainWindow.webContents.send('goal-progress', { date, goals: statuses, timestamp: Date.now() });
+  }
+
+  return statuses;
+}
+
+/**
+ * 작성 목표 추가
+ * @param {Object} options
+ * @param {string} [options.document] - 문서(창 제목), 비우면 전체 목표
+ * @param {string} options.metric - 'words' 또는 'minutes'
+ * @param {number} options.target - 일일 목표값
+ * @returns {Object} { success, goal?, error? }
+ */
+function createGoal({ document = null, metric, target } = {}) {
+  if (!GOAL_METRICS.includes(metric)) {
+    return { success: false, error: `지원하지 않는 목표 종류입니다: ${metric}` };
+  }
+  if (!Number.isInteger(target) || target <= 0 || target > MAX_GOAL_TARGET) {
+    return { success: false, error: `목표값은 1~${MAX_GOAL_TARGET} 사이의 정수여야 합니다` };
+  }
+
+  const normalizedDocument = typeof document === 'string' && document.trim() ? document.trim() : null;
+  const goal = insertGoal({ document: normalizedDocument, metric, target });
+  debugLog('작성 목표 추가:', goal);
+
+  checkGoals();
+  return { success: true, goal };
+}
+
+/**
+ * 작성 목표 삭제
+ * @param {number} id - 목표 ID
+ * @returns {Object} { success }
+ */
+function deleteGoal(id) {
+  const success = removeGoal(id);
+  for (const key of lastProgress.keys()) {
+    if (key.startsWith(`${id}:`)) lastProgress.delete(key);
+  }
+  return { success };
+}
+
+/**
+ * 목표 목록과 오늘 진행 상태
+ * @returns {Array}
+ */
+function getGoals() {
+  return checkGoals();
+}
+
+/**
+ * 목표 진행 기록 조회
+ * @param {number} goalId - 목표 ID
+ * @param {number} [days] - 최근 일수
+ * @returns {Array} { date, progress, target, achievedAt } 배열
+ */
+function getGoalProgressHistory(goalId, days = 30) {
+  return getGoalHistory(goalId, days).map(row => ({
+    date: row.date,
+    progress: row.progress,
+    target: row.target,
+    achievedAt: row.achieved_at
+  }));
+}
+
+/**
+ * 목표 진행 확인 시작 (앱 초기화 시)
+ */
+function initializeGoals() {
+  if (goalCheckTimer) return;
+  checkGoals();
+  goalCheckTimer = setInterval(checkGoals, GOAL_CHECK_INTERVAL);
+}
+
+/**
+ * 목표 진행 확인 중지 (종료 시)
+ */
+function cleanupGoals() {
+  if (goalCheckTimer) {
+    clearInterval(goalCheckTimer);
+    goalCheckTimer = null;
+  }
+}
+
+module.exports = {
+  GOAL_METRICS,
+  initializeGoals,
+  cleanupGoals,
+  checkGoals,
+  createGoal,
+  deleteGoal,
+  getGoals,
+  getGoalProgressHistory
+};
@@ -0,0 +1,101 @@
+/**
+ * 네이티브 GPU 연산 전환 모듈
+ * Electron 하드웨어 가속(useHardwareAcceleration, 재시작 필요)과 별개로,
+ * 네이티브 모듈의 GPU 연산을 재시작 없이 켜고 끕니다.
+ * 드라이버가 불안정한 경우 끄면 GPU 작업이 CPU 경로로 계산됩니다.
+ */
+const { appState } = require('./constants');
+const { debugLog } = require('./utils');
+
+// 마지막으로 네이티브 모듈에 적용한 값 (null: 아직 적용하지 않음, 네이티브 기본값은 켜짐)
+let appliedEnabled = null;
+let lastResult = null;
+
+// 마지막으로 네이티브 모듈에 적용한 선호 장치 키 (''는 자동 선택, 네이티브 기본값)
+let appliedDevice = '';
+
+/**
+ * 네이티브 모듈의 GPU 하위 시스템 켜기/끄기
+ * @param {boolean} enabled - 사용 여부
+ * @returns {Object} { success, enabled, gpu, active_gpu_tasks }
+ */
+function callSetGpuEnabled(enabled) {
+  const { setGpuEnabled } = require('../server/native');
+  const result = setGpuEnabled(enabled);
+  if (!result) {
+    throw new Error('네이티브 모듈을 사용할 수 없습니다');
+  }
+  return typeof result === 'string' ? JSON.parse(result) : result;
+}
+
+/**
+ * 현재 설정(gpuComputeEnabled)을 네이티브 모듈에 적용
+ * 값이 바뀐 경우에만 GPU 컨텍스트를 해제하거나 다시 초기화합니다.
+ * @returns {Object|null} 적용 결과 (바뀐 것이 없으면 null)
+ */
+function applyGpuComputeSetting() {
+  const enabled = appState.settings?.gpuComputeEnabled !== false;
+  if (enabled === (appliedEnabled ?? true)) {
+    return null;
+  }
+
+  try {
+    lastResult = callSetGpuEnabled(enabled);
+    appliedEnabled = enabled;
+    debugLog(`네이티브 GPU 연산 ${enabled ? '켜짐' : '꺼짐'}: ${lastResult.gpu?.status}`);
+    return lastResult;
+  } catch (error) {
+    console.error('네이티브 GPU 연산 전환 오류:', error);
+    lastResult = { success: false, enabled, error: error.message };
+    return lastResult;
+  }
+}
+
+/**
+ * 현재 설정(preferredGpuDevice)을 네이티브 모듈에 적용
+ * 값이 바뀐 경우에만 적용하며, GPU가 이미 초기화되어 있으면 선택한 장치로 컨텍스트를 다시 생성합니다.
+ * @returns {Object|null} 적용 결과 (바뀐 것이 없으면 null)
+ */
+function applyPreferredGpuDevice() {
+  const deviceKey = appState.settings?.preferredGpuDevice || '';
+  if (deviceKey === appliedDevice) {
+    return null;
+  }
+
+  try {
+    const { setPreferredGpuDevice } = require('../server/native');
+    const result = setPreferredGpuDevice(deviceKey || null);
+    if (!result) {
+      throw new Error('네이티브 모듈을 사용할 수 없습니다');
+    }
+    appliedDevice = deviceKey;
+    const parsed = typeof result === 'string' ? JSON.parse(result) : result;
+    debugLog(`선호 GPU 장치 적용: ${deviceKey || '자동 선택'} (사용 중: ${parsed.active_device ?? '없음'})`);
+    return parsed;
+  } catch (error) {
+    console.error('선호 GPU 장치 적용 오류:', error);
+    return { success: false, preferred_device: deviceKey || null, error: error.message };
+  }
+}
+
+/**
+ * 네이티브 GPU 연산 사용 여부 변경 후 설정에 저장
+ * @param {boolean} enabled - 사용 여부
+ * @returns {Object} { success, enabled, gpu, active_gpu_tasks, error? }
+ */
+function setGpuComputeEnabled(enabled) {
+  const { saveSettings } = require('./settings');
+  const value = Boolean(enabled);
+
+  // 저장하면서 applyGpuComputeSetting이 호출됨
+  if (!saveSettings({ gpuComputeEnabled: value })) {
+    return { success: false, enabled: value, error: '설정을 저장하지 못했습니다' };
+  }
+  return lastResult?.enabled === value ? lastResult : { success: true, enabled: value };
+}
+
+module.exports = {
+  applyGpuComputeSetting,
+  applyPreferredGpuDevice,
+  setGpuComputeEnabled
+};
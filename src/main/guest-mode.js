@@ -0,0 +1,167 @@
+/**
+ * 게스트(발표) 모드 모듈
+ * 화면 공유 중에 켜면 렌더러로 가는 모든 응답/이벤트에서 창 제목과 입력 내용을 가리고
+ * 이메일 주소와 홈 디렉터리 경로를 지웁니다. 집계 통계(숫자)는 그대로 보입니다.
+ * 앱을 다시 시작하면 항상 꺼진 상태로 시작합니다.
+ */
+const os = require('os');
+const { appState } = require('./constants');
+const { debugLog, withAccessibleDescription } = require('./utils');
+const { addRequestGuard, addResponseFilter } = require('./ipc-guard');
+
+// 가린 제목 표시
+const MASKED_TITLE = '••••';
+const REDACTED_EMAIL = '[이메일 숨김]';
+
+// 창/문서 제목으로 보고 가리는 키
+const TITLE_KEY_PATTERN = /(^|_)title$|Title$|^currentWindow$|^windowName$|^document$/;
+
+// 입력 내용으로 보고 비우는 키
+const CONTENT_KEYS = new Set(['content']);
+
+const EMAIL_PATTERN = /[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}/g;
+
+// 게스트 모드에서 거부하는 요청 (기록 변경, 원본 조회, 파일 내보내기/가져오기, 보안 설정)
+const GUEST_BLOCKED_CHANNELS = new Set([
+  'save-stats',
+  'save-settings',
+  'execute-readonly-query',
+  'export-typing-sessions',
+  'export-aggregate-stats',
+  'import-typing-sessions',
+  'export-analytics',
+  'import-analytics',
+  'set-app-lock-pin',
+  'clear-app-lock-pin',
+  'set-autostart',
+  'set-gpu-enabled',
+  'create-goal',
+  'delete-goal',
+  'generate-weekly-report',
+  'set-report-smtp-password',
+  'set-analytics-token',
+  'flush-analytics-export',
+  'export-all-user-data',
+  'request-data-wipe',
+  'wipe-all-local-data'
+]);
+
+let active = false;
+let startedAt = null;
+let registered = false;
+
+/**
+ * 문자열에서 이메일 주소와 홈 디렉터리 경로 제거
+ * @param {string} text - 원본 문자열
+ * @returns {string}
+ */
+function redactText(text) {
+  const home = os.homedir();
+  let result = text.replace(EMAIL_PATTERN, REDACTED_EMAIL);
+  if (home && home.length > 1) {
+    result = result.split(home).join('~');
+  }
+  return result;
+}
+
+/**
+ * 값을 재귀적으로 가린 복사본 생성 (원본은 바꾸지 않음)
+ * @param {any} value - 원본 값
+ * @param {string} [key] - 값이 들어 있던 속성 이름
+ * @returns {any}
+ */
+function redactValue(value, key) {
+  if (typeof value === 'string') {
+    if (key && CONTENT_KEYS.has(key)) return null;
+    if (key && TITLE_KEY_PATTERN.test(key)) return value ? MASKED_TITLE : value;
+    return redactText(value);
+  }
+
+  if (Array.isArray(value)) {
+    return value.map(item => redactValue(item, key));
+  }
+
+  // 일반 객체만 순회 (Buffer, Date 등은 그대로)
+  if (value && typeof value === 'object') {
+    const proto = Object.getPrototypeOf(value);
+    if (proto !== Object.prototype && proto !== null) return value;
+
+    const result = {};
+    for (const [childKey, child] of Object.entries(value)) {
+      result[childKey] = redactValue(child, childKey);
+    }
+    return result;
+  }
+
+  return value;
+}
+
+// 게스트 모드에서 응답 가리기
+function guestResponseFilter(channel, payload) {
+  return active ? redactValue(payload) : payload;
+}
+
+// 게스트 모드에서 차단 대상 요청 거부
+function guestRequestGuard(channel) {
+  if (active && GUEST_BLOCKED_CHANNELS.has(channel)) {
+    debugLog(`게스트 모드 중 IPC 요청 거부: ${channel}`);
+    return { success: false, guestMode: true, error: '게스트 모드에서는 사용할 수 없습니다' };
+  }
+  return undefined;
+}
+
+/**
+ * 게스트 모드 검사 등록 (앱 초기화 시 한 번)
+ */
+function setupGuestMode() {
+  if (registered) return;
+  registered = true;
+  addRequestGuard(guestRequestGuard);
+  addResponseFilter(guestResponseFilter);
+}
+
+/**
+ * 게스트 모드 상태
+ * @returns {Object} { active, startedAt }
+ */
+function getGuestModeStatus() {
+  return { active, startedAt };
+}
+
+/**
+ * 게스트 모드 켜기/끄기
+ * @param {boolean} enabled - 켤지 여부
+ * @returns {Object} 변경 후 상태
+ */
+function setGuestMode(enabled) {
+  const next = Boolean(enabled);
+  if (next !== active) {
+    active = next;
+    startedAt = active ? Date.now() : null;
+    debugLog(`게스트 모드 ${active ? '시작' : '종료'}`);
+
+    if (appState.mainWindow && !appState.mainWindow.isDestroyed()) {
+      appState.mainWindow.webContents.send('guest-mode-changed', withAccessibleDescription(
+        { active, timestamp: Date.now() },
+        active ? '게스트 모드가 켜졌습니다. 창 제목과 입력 내용이 가려집니다.' : '게스트 모드가 꺼졌습니다.'
+      ));
+    }
+  }
+  return getGuestModeStatus();
+}
+
+/**
+ * 게스트 모드 여부
+ * @returns {boolean}
+ */
+function isGuestMode() {
+  return active;
+}
+
+module.exports = {
+  setupGuestMode,
+  setGuestMode,
+  getGuestModeStatus,
+  isGuestMode,
+  redactValue
+};
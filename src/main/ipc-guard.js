@@ -0,0 +1,109 @@
+/**
+ * IPC 공통 검사 모듈
+ * ipcMain에 등록되는 모든 핸들러를 감싸서 요청 거부(예: 앱 잠금)와
+ * 응답/이벤트 데이터 변환(예: 게스트 모드 가림)을 한 곳에서 적용합니다.
+ */
+const { app, ipcMain } = require('electron');
+
+// 요청 검사 함수 목록: (channel) => 거부 시 반환할 값, 허용이면 undefined
+const requestGuards = [];
+
+// 응답 변환 함수 목록: (channel, payload) => 변환된 payload
+const responseFilters = [];
+
+let installed = false;
+
+/**
+ * 요청 검사 함수 추가
+ * @param {Function} guard - (channel) => 거부 결과 또는 undefined
+ */
+function addRequestGuard(guard) {
+  requestGuards.push(guard);
+}
+
+/**
+ * 응답 변환 함수 추가 (invoke 응답, event.reply, 렌더러로 보내는 이벤트에 모두 적용)
+ * @param {Function} filter - (channel, payload) => payload
+ */
+function addResponseFilter(filter) {
+  responseFilters.push(filter);
+}
+
+// 요청 검사 (처음으로 거부한 검사의 결과 반환)
+function checkRequest(channel) {
+  for (const guard of requestGuards) {
+    const rejection = guard(channel);
+    if (rejection !== undefined) return rejection;
+  }
+  return undefined;
+}
+
+/**
+ * 응답 데이터 변환
+ * @param {string} channel - IPC 채널
+ * @param {any} payload - 응답 데이터
+ * @returns {any} 변환된 데이터
+ */
+function filterResponse(channel, payload) {
+  return responseFilters.reduce((value, filter) => filter(channel, value), payload);
+}
+
+// 값 또는 Promise 결과에 응답 변환 적용
+function filterResult(channel, result) {
+  if (responseFilters.length === 0) return result;
+  if (result && typeof result.then === 'function') {
+    return result.then(value => filterResponse(channel, value));
+  }
+  return filterResponse(channel, result);
+}
+
+// 핸들러 래퍼 생성
+function wrapListener(channel, listener, isInvoke) {
+  return (event, ...args) => {
+    const rejection = checkRequest(channel);
+    if (rejection !== undefined) {
+      return isInvoke ? rejection : undefined;
+    }
+
+    // event.reply로 보내는 응답도 변환
+    if (event && typeof event.reply === 'function') {
+      const reply = event.reply;
+      event.reply = (replyChannel, ...replyArgs) =>
+        reply.call(event, replyChannel, ...replyArgs.map(arg => filterResponse(replyChannel, arg)));
+    }
+
+    const result = listener(event, ...args);
+    return isInvoke ? filterResult(channel, result) : result;
+  };
+}
+
+/**
+ * IPC 검사 설치
+ * 이후에 등록되는 모든 ipcMain 핸들러와 새로 만들어지는 웹 콘텐츠의 send에 검사/변환을 적용합니다.
+ * 다른 모듈이 핸들러를 등록하거나 창을 만들기 전에 호출해야 합니다.
+ */
+function installIpcGuard() {
+  if (installed) return;
+  installed = true;
+
+  const handle = ipcMain.handle.bind(ipcMain);
+  const on = ipcMain.on.bind(ipcMain);
+  const once = ipcMain.once.bind(ipcMain);
+
+  ipcMain.handle = (channel, listener) => handle(channel, wrapListener(channel, listener, true));
+  ipcMain.on = (channel, listener) => on(channel, wrapListener(channel, listener, false));
+  ipcMain.once = (channel, listener) => once(channel, wrapListener(channel, listener, false));
+
+  // 메인 프로세스에서 렌더러로 보내는 이벤트 변환
+  app.on('web-contents-created', (_event, contents) => {
+    const send = contents.send.bind(contents);
+    contents.send = (channel, ...args) => send(channel, ...args.map(arg => filterResponse(channel, arg)));
+  });
+}
+
+module.exports = {
+  installIpcGuard,
+  addRequestGuard,
+  addResponseFilter,
+  filterResponse
+};
@@ -1,13 +1,35 @@
-const { ipcMain, app } = require('electron');
+const { ipcMain, app, dialog } = require('electron');
 const activeWin = require('active-win');
 const { appState, HIGH_MEMORY_THRESHOLD } = require('./constants');
 const { detectBrowserName, isGoogleDocsWindow } = require('./browser');
 const { startTracking, stopTracking, saveStats, resetStats } = require('./stats');
-const { debugLog } = require('./utils');
+const { debugLog, withAccessibleDescription } = require('./utils');
 const { applyWindowMode } = require('./settings');
 const { saveSettings, getSettings, loadSettings } = require('./settings');
 const { createMiniViewWindow, toggleMiniView } = require('./window');
 const { showRestartPrompt } = require('./dialogs');
+const { getTypingTrend, getDailyStats, getWeeklyStats, getTypingStatsByApp, getAnomalies, executeReadonlyQuery, getDatabaseHealth } = require('./database');
+const { isSandboxMode, getSandboxStatus } = require('./sandbox');
+const { getMetricsStatus } = require('./metrics-exporter');
+const { setAutostart, getAutostartStatus } = require('./autostart');
+const { setGuestMode, getGuestModeStatus } = require('./guest-mode');
+const { getGoals, createGoal, deleteGoal, getGoalProgressHistory } = require('./goals');
+const { generateWeeklyReport, setSmtpPassword } = require('./weekly-report');
+const { setGpuComputeEnabled } = require('./gpu-compute');
+const {
+  flushAnalyticsExport,
+  setAnalyticsToken,
+  retryFailedAnalyticsExports,
+  getAnalyticsExportStatus
+} = require('./analytics-export');
+const { 
+  getAppLockStatus, 
+  lockApp, 
+  unlockApp, 
+  unlockWithSystemAuth, 
+  setAppLockPin, 
+  clearAppLockPin 
+} = require('./app-lock');
 const { 
   forceMemoryOptimization, 
   performGarbageCollection, 
@@ -32,10 +54,10 @@ function setupIpcHandlers() {
         // 렌더러에 모니터링 시작 상태 알림 (지연 추가)
         setTimeout(() => {
           if (appState.mainWindow && !appState.mainWindow.isDestroyed()) {
-            appState.mainWindow.webContents.send('auto-tracking-started', {
+            appState.mainWindow.webContents.send('auto-tracking-started', withAccessibleDescription({
               timestamp: Date.now(),
               isAutoStart: true
-            });
+            }, '타이핑 모니터링이 자동으로 시작되었습니다.'));
           }
         }, 1000); // 1초 지연으로 렌더러가 준비되도록 함
       }
@@ -194,7 +216,8 @@ function setupIpcHandlers() {
       currentStats: { ...appState.currentStats },
       platform: process.platform,
       electronVersion: process.versions.electron,
-      nodeVersion: process.versions.node
+      nodeVersion: process.versions.node,
+      sandbox: isSandboxMode()
     });
   });
 
@@ -209,10 +232,10 @@ function setupIpcHandlers() {
       
       // 설정 변경 시 필요한 업데이트 적용
       if (newSettings.darkMode !== prevSettings.darkMode) {
-        appState.mainWindow.webContents.send('dark-mode-changed', { 
+        appState.mainWindow.webContents.send('dark-mode-changed', withAccessibleDescription({ 
           success: true, 
           darkMode: newSettings.darkMode 
-        });
+        }, newSettings.darkMode ? '어두운 테마가 적용되었습니다.' : '밝은 테마가 적용되었습니다.'));
       }
       
       // 창 모드 설정 변경 시 적용
@@ -369,10 +392,10 @@ function setupIpcHandlers() {
       
       // 모든 사용자에게 창 모드 변경 알림
       if (appState.mainWindow && appState.mainWindow.webContents) {
-        appState.mainWindow.webContents.send('window-mode-status', {
+        appState.mainWindow.webContents.send('window-mode-status', withAccessibleDescription({
           mode: mode,
           autoHideToolbar: appState.autoHideToolbar
-        });
+        }, `창 모드가 ${mode}(으)로 변경되었습니다.`));
       }
       
       debugLog('윈도우 모드 변경 완료:', mode);
@@ -461,13 +484,38 @@ function setupIpcHandlers() {
       startTracking();
       
       if (appState.mainWindow) {
-        appState.mainWindow.webContents.send('auto-tracking-started', {
+        appState.mainWindow.webContents.send('auto-tracking-started', withAccessibleDescription({
           message: '모니터링이 자동으로 시작되었습니다.'
-        });
+        }, '모니터링이 자동으로 시작되었습니다.'));
       }
     }
   });
   
+  // 로그인 시 자동 실행 등록/해제
+  ipcMain.handle('set-autostart', (event, enabled, options = {}) => {
+    try {
+      return { success: true, ...setAutostart(Boolean(enabled), options) };
+    } catch (error) {
+      console.error('자동 실행 설정 중 오류:', error);
+      return { success: false, error: error.message || String(error) };
+    }
+  });
+  
+  // 자동 실행 등록 상태 요청
+  ipcMain.handle('get-autostart-status', () => {
+    return getAutostartStatus();
+  });
+  
+  // 네이티브 GPU 연산 켜기/끄기 (재시작 없이 적용, 설정에 저장)
+  ipcMain.handle('set-gpu-enabled', (event, enabled) => {
+    try {
+      return setGpuComputeEnabled(Boolean(enabled));
+    } catch (error) {
+      console.error('GPU 연산 전환 중 오류:', error);
+      return { success: false, error: error.message || String(error) };
+    }
+  });
+  
   // 트레이에서 타겟 탭으로 이동하는 이벤트 핸들러
   ipcMain.on('switch-to-tab-handled', (event, tab) => {
     // 탭 전환 완료 알림을 받으면 트레이 메뉴 업데이트
@@ -598,6 +646,368 @@ function setupIpcHandlers() {
     }
   });
 
+  // 샌드박스 모드 상태 요청 (렌더러 배너 표시용)
+  ipcMain.handle('get-sandbox-status', () => {
+    return getSandboxStatus();
+  });
+
+  // 메트릭 내보내기 상태 요청
+  ipcMain.handle('get-metrics-status', () => {
+    return getMetricsStatus();
+  });
+
+  // 게스트(발표) 모드 켜기/끄기
+  ipcMain.handle('set-guest-mode', (event, enabled) => {
+    return setGuestMode(enabled);
+  });
+
+  // 게스트 모드 상태 요청
+  ipcMain.handle('get-guest-mode-status', () => {
+    return getGuestModeStatus();
+  });
+
+  // 앱 잠금 상태 요청 (잠긴 동안에도 허용)
+  ipcMain.handle('get-app-lock-status', () => {
+    return getAppLockStatus();
+  });
+
+  // PIN으로 잠금 해제 (잠긴 동안에도 허용)
+  ipcMain.handle('unlock-app', (event, pin) => {
+    return unlockApp(pin);
+  });
+
+  // 시스템 인증으로 잠금 해제 (잠긴 동안에도 허용)
+  ipcMain.handle('unlock-app-with-system-auth', () => {
+    return unlockWithSystemAuth();
+  });
+
+  // 지금 바로 잠그기
+  ipcMain.handle('lock-app', () => {
+    return { success: lockApp('manual'), locked: true };
+  });
+
+  // PIN 설정/변경
+  ipcMain.handle('set-app-lock-pin', (event, { currentPin = null, newPin } = {}) => {
+    return setAppLockPin(currentPin, newPin);
+  });
+
+  // PIN 삭제
+  ipcMain.handle('clear-app-lock-pin', (event, currentPin) => {
+    return clearAppLockPin(currentPin);
+  });
+
+  // 작성 목표 목록과 오늘 진행 상태 요청
+  ipcMain.handle('get-goals', () => {
+    try {
+      return { success: true, goals: getGoals() };
+    } catch (error) {
+      console.error('작성 목표 조회 오류:', error);
+      return { success: false, error: error.message };
+    }
+  });
+
+  // 작성 목표 추가
+  ipcMain.handle('create-goal', (event, options) => {
+    try {
+      return createGoal(options);
+    } catch (error) {
+      console.error('작성 목표 추가 오류:', error);
+      return { success: false, error: error.message };
+    }
+  });
+
+  // 작성 목표 삭제
+  ipcMain.handle('delete-goal', (event, id) => {
+    try {
+      return deleteGoal(id);
+    } catch (error) {
+      console.error('작성 목표 삭제 오류:', error);
+      return { success: false, error: error.message };
+    }
+  });
+
+  // 작성 목표 진행 기록 요청
+  ipcMain.handle('get-goal-history', (event, { goalId, days = 30 } = {}) => {
+    try {
+      return { success: true, history: getGoalProgressHistory(goalId, days) };
+    } catch (error) {
+      console.error('작성 목표 기록 조회 오류:', error);
+      return { success: false, error: error.message };
+    }
+  });
+
+  // 주간 작성 보고서 생성 (파일 저장, 설정에 따라 메일 발송)
+  ipcMain.handle('generate-weekly-report', async (event, { week, format = 'markdown', email } = {}) => {
+    try {
+      return await generateWeeklyReport(week, { format, email });
+    } catch (error) {
+      console.error('주간 보고서 생성 오류:', error);
+      return { success: false, error: error.message };
+    }
+  });
+
+  // 보고서 메일 SMTP 비밀번호 저장 (빈 값이면 삭제)
+  ipcMain.handle('set-report-smtp-password', (event, password) => {
+    return { success: setSmtpPassword(typeof password === 'string' ? password : '') };
+  });
+
+  // 외부 분석 내보내기 상태 요청
+  ipcMain.handle('get-analytics-export-status', () => {
+    try {
+      return getAnalyticsExportStatus();
+    } catch (error) {
+      console.error('분석 내보내기 상태 조회 오류:', error);
+      return { success: false, error: error.message };
+    }
+  });
+
+  // 분석 내보내기 인증 토큰 저장 (빈 값이면 삭제)
+  ipcMain.handle('set-analytics-token', (event, token) => {
+    return { success: setAnalyticsToken(typeof token === 'string' ? token : '') };
+  });
+
+  // 대기열 즉시 전송 (retryFailed: 재시도를 멈춘 항목도 다시 전송)
+  ipcMain.handle('flush-analytics-export', async (event, { retryFailed = false } = {}) => {
+    try {
+      const requeued = retryFailed ? retryFailedAnalyticsExports() : 0;
+      return { ...(await flushAnalyticsExport()), requeued };
+    } catch (error) {
+      console.error('분석 내보내기 전송 오류:', error);
+      return { success: false, error: error.message };
+    }
+  });
+
+  // 기간별 타이핑 추세 요청 처리 (롤업 테이블 사용)
+  ipcMain.handle('get-typing-trend', (event, { from, to, granularity } = {}) => {
+    try {
+      return getTypingTrend(from, to || new Date(), granularity);
+    } catch (error) {
+      console.error('타이핑 추세 조회 중 오류:', error);
+      return { granularity: null, points: [], error: String(error) };
+    }
+  });
+
+  // 일/주 단위 통계 요청 처리 (롤업과 시간대별 입력 시간)
+  ipcMain.handle('get-daily-stats', (event, { from, to } = {}) => {
+    try {
+      return getDailyStats(from, to || new Date());
+    } catch (error) {
+      console.error('일 단위 통계 조회 중 오류:', error);
+      return { granularity: 'day', points: [], activeHours: [], error: String(error) };
+    }
+  });
+
+  ipcMain.handle('get-weekly-stats', (event, { from, to } = {}) => {
+    try {
+      return getWeeklyStats(from, to || new Date());
+    } catch (error) {
+      console.error('주 단위 통계 조회 중 오류:', error);
+      return { granularity: 'week', points: [], activeHours: [], error: String(error) };
+    }
+  });
+
+  // 앱(브라우저)별 타이핑 통계 요청 처리
+  ipcMain.handle('get-typing-stats-by-app', (event, period) => {
+    try {
+      return getTypingStatsByApp(period);
+    } catch (error) {
+      console.error('앱별 타이핑 통계 조회 중 오류:', error);
+      return { period: null, from: null, applications: [], error: String(error) };
+    }
+  });
+
+  // 일별 작성량/속도 이상치 요청 처리
+  ipcMain.handle('get-anomalies', (event, options = {}) => {
+    try {
+      return getAnomalies(options);
+    } catch (error) {
+      console.error('이상치 조회 중 오류:', error);
+      return [];
+    }
+  });
+
+  // 읽기 전용 SQL 콘솔 쿼리 실행
+  ipcMain.handle('execute-readonly-query', (event, sql, options = {}) => {
+    return executeReadonlyQuery(sql, options);
+  });
+
+  // 데이터베이스 상태 점검 요청 처리
+  ipcMain.handle('get-database-health', () => {
+    return getDatabaseHealth();
+  });
+
+  // 타이핑 세션 내보내기 요청 처리 (저장 위치 선택 후 JSON 파일로 저장)
+  ipcMain.handle('export-typing-sessions', async (event, options = {}) => {
+    try {
+      const { exportSessions } = require('./session-transfer');
+      const { canceled, filePath } = await dialog.showSaveDialog(appState.mainWindow, {
+        title: '타이핑 기록 내보내기',
+        defaultPath: `loop-typing-sessions-${new Date().toISOString().slice(0, 10)}.json`,
+        filters: [{ name: 'JSON', extensions: ['json'] }]
+      });
+      
+      if (canceled || !filePath) {
+        return { success: false, canceled: true };
+      }
+      
+      return { success: true, ...exportSessions(filePath, options) };
+    } catch (error) {
+      console.error('타이핑 세션 내보내기 중 오류:', error);
+      return { success: false, error: String(error) };
+    }
+  });
+
+  // 기간별 집계 통계 내보내기 요청 처리 (차등 프라이버시 선택 가능)
+  ipcMain.handle('export-aggregate-stats', async (event, options = {}) => {
+    try {
+      const { exportAggregates } = require('./session-transfer');
+      const { canceled, filePath } = await dialog.showSaveDialog(appState.mainWindow, {
+        title: '기간별 통계 내보내기',
+        defaultPath: `loop-typing-aggregates-${new Date().toISOString().slice(0, 10)}.json`,
+        filters: [{ name: 'JSON', extensions: ['json'] }]
+      });
+      
+      if (canceled || !filePath) {
+        return { success: false, canceled: true };
+      }
+      
+      return { success: true, ...exportAggregates(filePath, options) };
+    } catch (error) {
+      console.error('기간별 통계 내보내기 중 오류:', error);
+      return { success: false, error: error.message || String(error) };
+    }
+  });
+
+  // 타이핑 세션 가져오기 요청 처리 (파일 선택 후 기록 추가)
+  ipcMain.handle('import-typing-sessions', async () => {
+    try {
+      const { importSessions } = require('./session-transfer');
+      const { canceled, filePaths } = await dialog.showOpenDialog(appState.mainWindow, {
+        title: '타이핑 기록 가져오기',
+        properties: ['openFile'],
+        filters: [{ name: 'JSON', extensions: ['json'] }]
+      });
+      
+      if (canceled || !filePaths?.length) {
+        return { success: false, canceled: true };
+      }
+      
+      return { success: true, ...importSessions(filePaths[0]) };
+    } catch (error) {
+      console.error('타이핑 세션 가져오기 중 오류:', error);
+      return { success: false, error: error.message || String(error) };
+    }
+  });
+
+  // 전체 분석 데이터 내보내기 요청 처리 (세션, 일별 롤업, 앱별 합계를 JSON 또는 CSV로 저장)
+  ipcMain.handle('export-analytics', async (event, options = {}) => {
+    try {
+      const { exportAnalytics } = require('./session-transfer');
+      const extension = options.format === 'csv' ? 'csv' : 'json';
+      const { canceled, filePath } = await dialog.showSaveDialog(appState.mainWindow, {
+        title: '분석 데이터 내보내기',
+        defaultPath: `loop-analytics-${new Date().toISOString().slice(0, 10)}.${extension}`,
+        filters: [{ name: extension.toUpperCase(), extensions: [extension] }]
+      });
+      
+      if (canceled || !filePath) {
+        return { success: false, canceled: true };
+      }
+      
+      return { success: true, ...exportAnalytics(filePath, options) };
+    } catch (error) {
+      console.error('분석 데이터 내보내기 중 오류:', error);
+      return { success: false, error: error.message || String(error) };
+    }
+  });
+
+  // 전체 분석 데이터 가져오기 요청 처리 (JSON 파일 또는 CSV 파일 여러 개 선택)
+  ipcMain.handle('import-analytics', async () => {
+    try {
+      const { importAnalytics } = require('./session-transfer');
+      const { canceled, filePaths } = await dialog.showOpenDialog(appState.mainWindow, {
+        title: '분석 데이터 가져오기',
+        properties: ['openFile', 'multiSelections'],
+        filters: [{ name: '분석 데이터', extensions: ['json', 'csv'] }]
+      });
+      
+      if (canceled || !filePaths?.length) {
+        return { success: false, canceled: true };
+      }
+      
+      return { success: true, ...importAnalytics(filePaths) };
+    } catch (error) {
+      console.error('분석 데이터 가져오기 중 오류:', error);
+      return { success: false, error: error.message || String(error) };
+    }
+  });
+
+  // 모든 사용자 데이터 ZIP 내보내기 요청 처리 (데이터베이스, 설정, 분석 데이터, 로그, 보고서)
+  ipcMain.handle('export-all-user-data', async () => {
+    try {
+      const { exportAllUserData } = require('./data-privacy');
+      const { canceled, filePath } = await dialog.showSaveDialog(appState.mainWindow, {
+        title: '모든 데이터 내보내기',
+        defaultPath: `loop-user-data-${new Date().toISOString().slice(0, 10)}.zip`,
+        filters: [{ name: 'ZIP', extensions: ['zip'] }]
+      });
+      
+      if (canceled || !filePath) {
+        return { success: false, canceled: true };
+      }
+      
+      return { success: true, ...(await exportAllUserData(filePath)) };
+    } catch (error) {
+      console.error('사용자 데이터 내보내기 중 오류:', error);
+      return { success: false, error: error.message || String(error) };
+    }
+  });
+
+  // 데이터 삭제 확인 토큰 요청 처리 (삭제 대상 목록 포함)
+  ipcMain.handle('request-data-wipe', () => {
+    try {
+      const { requestDataWipe } = require('./data-privacy');
+      return { success: true, ...requestDataWipe() };
+    } catch (error) {
+      console.error('데이터 삭제 토큰 발급 중 오류:', error);
+      return { success: false, error: error.message || String(error) };
+    }
+  });
+
+  // 모든 로컬 데이터 삭제 요청 처리 (삭제 후 앱 재시작)
+  ipcMain.handle('wipe-all-local-data', async (event, token, options = {}) => {
+    try {
+      const { wipeAllLocalData } = require('./data-privacy');
+      let exportPath;
+      
+      if (options.exportBeforeWipe) {
+        const { canceled, filePath } = await dialog.showSaveDialog(appState.mainWindow, {
+          title: '삭제 전 데이터 내보내기',
+          defaultPath: `loop-user-data-${new Date().toISOString().slice(0, 10)}.zip`,
+          filters: [{ name: 'ZIP', extensions: ['zip'] }]
+        });
+        
+        if (canceled || !filePath) {
+          return { success: false, canceled: true };
+        }
+        exportPath = filePath;
+      }
+      
+      const result = await wipeAllLocalData(token, { exportPath });
+      
+      // 응답을 보낸 뒤 빈 상태로 다시 시작
+      setTimeout(() => {
+        app.relaunch();
+        app.exit(0);
+      }, 300);
+      
+      return { success: true, ...result };
+    } catch (error) {
+      console.error('로컬 데이터 삭제 중 오류:', error);
+      return { success: false, error: error.message || String(error) };
+    }
+  });
+
   // 메모리 사용량 정보 요청 처리
   ipcMain.handle('get-memory-usage', () => {
     try {
@@ -823,10 +1233,10 @@ function restartApplication() {WW;
     
     // 재시작 전에 로딩 화면 표시
     if (appState.mainWindow && !appState.mainWindow.isDestroyed()) {
-      appState.mainWindow.webContents.send('show-restart-loading', { 
+      appState.mainWindow.webContents.send('show-restart-loading', withAccessibleDescription({ 
         message: '재시작 중입니다...',
         timeout: 1500
-      });
+      }, '앱을 다시 시작하는 중입니다. 잠시 기다려 주세요.'));
     }
     
     // 안전한 종료를 위한 플래그 설정
@@ -1,5 +1,12 @@
 const { app } = require('electron');
 const path = require('path');
+
+// 샌드박스 모드는 다른 모듈이 userData 경로를 읽기 전에 적용
+require('./sandbox').applySandboxMode();
+
+// 앱 잠금/게스트 모드 검사는 IPC 핸들러가 등록되기 전에 설치
+require('./ipc-guard').installIpcGuard();
+
 const { setupAppEventListeners } = require('./app-lifecycle');
 const { debugLog } = require('./utils');
 const { createWindow, getMainWindow } = require('./window');
@@ -521,6 +521,7 @@ if (typeof module !== 'undefined' && module.exports) {
     freeUpMemoryResources,
     forceMemoryOptimization,
     stopMemoryMonitoring,
+    checkMemoryUsage,
     // 추가 필요한 함수들 여기에 포함
   };
 }
@@ -0,0 +1,150 @@
+/**
+ * Prometheus 메트릭 텍스트 파일 내보내기
+ *
+ * 설정에서 켜면 네이티브 모듈과 앱 상태의 메트릭을 주기적으로 텍스트 파일에 기록합니다.
+ * node_exporter의 textfile collector가 이 파일을 읽어 수집할 수 있습니다.
+ */
+const fs = require('fs');
+const path = require('path');
+const { app } = require('electron');
+const { appState } = require('./constants');
+const { debugLog } = require('./utils');
+
+// 기본 기록 간격 (ms)
+const DEFAULT_METRICS_INTERVAL = 15000;
+const MIN_METRICS_INTERVAL = 1000;
+
+let metricsTimer = null;
+let lastWrite = null;
+let lastError = null;
+
+/**
+ * 메트릭 파일 경로
+ * @returns {string} 설정된 경로 또는 userData 아래 기본 경로
+ */
+function getMetricsFilePath() {
+  return appState.settings.metricsTextfilePath
+    || path.join(app.getPath('userData'), 'metrics', 'typing_stats.prom');
+}
+
+/**
+ * 앱 상태 메트릭 렌더링
+ * @returns {string} Prometheus 텍스트 형식
+ */
+function renderAppMetrics() {
+  const stats = appState.currentStats;
+  const lines = [
+    '# HELP typing_stats_tracking Whether keystroke tracking is active (1) or not (0).',
+    '# TYPE typing_stats_tracking gauge',
+    `typing_stats_tracking ${appState.isTracking ? 1 : 0}`,
+    '# HELP typing_stats_session_keystrokes Keystrokes in the current typing session.',
+    '# TYPE typing_stats_session_keystrokes gauge',
+    `typing_stats_session_keystrokes ${stats.keyCount || 0}`,
+    '# HELP typing_stats_session_typing_seconds Typing time in the current typing session.',
+    '# TYPE typing_stats_session_typing_seconds gauge',
+    `typing_stats_session_typing_seconds ${stats.typingTime || 0}`
+  ];
+  return lines.join('\n') + '\n';
+}
+
+/**
+ * 전체 메트릭 렌더링 (네이티브 모듈 + 앱 상태)
+ * @returns {string} Prometheus 텍스트 형식
+ */
+function renderMetrics() {
+  let nativeMetrics = '';
+  try {
+    const { loadNativeModule } = require('./native-module-loader');
+    const nativeModule = loadNativeModule();
+    const render = nativeModule?.getPrometheusMetrics || nativeModule?.get_prometheus_metrics;
+    if (typeof render === 'function') {
+      nativeMetrics = render();
+    }
+  } catch (error) {
+    debugLog('네이티브 메트릭 수집 오류:', error);
+  }
+
+  return nativeMetrics + renderAppMetrics();
+}
+
+/**
+ * 메트릭 파일 기록
+ * 수집기가 쓰다 만 파일을 읽지 않도록 임시 파일에 쓴 뒤 이름을 바꿉니다.
+ * @returns {boolean} 기록 성공 여부
+ */
+function writeMetricsFile() {
+  const filePath = getMetricsFilePath();
+  const tempPath = `${filePath}.${process.pid}.tmp`;
+
+  try {
+    fs.mkdirSync(path.dirname(filePath), { recursive: true });
+    fs.writeFileSync(tempPath, renderMetrics(), 'utf8');
+    fs.renameSync(tempPath, filePath);
+    lastWrite = Date.now();
+    lastError = null;
+    return true;
+  } catch (error) {
+    lastError = error.message;
+    debugLog('메트릭 파일 기록 오류:', error);
+    try {
+      fs.rmSync(tempPath, { force: true });
+    } catch (cleanupError) {
+      // 임시 파일 정리 실패는 무시
+    }
+    return false;
+  }
+}
+
+/**
+ * 메트릭 내보내기 중지
+ */
+function stopMetricsExport() {
+  if (metricsTimer) {
+    clearInterval(metricsTimer);
+    metricsTimer = null;
+    debugLog('메트릭 내보내기 중지');
+  }
+}
+
+/**
+ * 현재 설정에 맞춰 메트릭 내보내기 시작/중지
+ * 설정이 바뀔 때마다 호출해도 되며, 간격이나 경로 변경도 반영합니다.
+ */
+function applyMetricsSettings() {
+  stopMetricsExport();
+
+  if (!appState.settings.metricsEnabled) {
+    return;
+  }
+
+  const interval = Math.max(
+    MIN_METRICS_INTERVAL,
+    Number(appState.settings.metricsInterval) || DEFAULT_METRICS_INTERVAL
+  );
+
+  writeMetricsFile();
+  metricsTimer = setInterval(writeMetricsFile, interval);
+  debugLog(`메트릭 내보내기 시작: ${getMetricsFilePath()} (${interval}ms 간격)`);
+}
+
+/**
+ * 메트릭 내보내기 상태
+ * @returns {object} 상태 정보
+ */
+function getMetricsStatus() {
+  return {
+    enabled: Boolean(appState.settings.metricsEnabled),
+    running: metricsTimer !== null,
+    path: getMetricsFilePath(),
+    lastWrite,
+    lastError
+  };
+}
+
+module.exports = {
+  renderMetrics,
+  writeMetricsFile,
+  applyMetricsSettings,
+  stopMetricsExport,
+  getMetricsStatus
+};
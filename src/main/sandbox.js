@@ -0,0 +1,61 @@
+/**
+ * 샌드박스 모드 모듈
+ * --sandbox 플래그(또는 TYPING_STATS_SANDBOX=1)로 실행하면 별도의 데이터 디렉토리를 사용해
+ * 실제 설정과 타이핑 기록을 건드리지 않고 기능을 시험할 수 있습니다.
+ *
+ * 다른 모듈이 userData 경로를 읽기 전에 적용해야 하므로 utils/constants를 불러오지 않습니다.
+ */
+const path = require('path');
+const { app } = require('electron');
+
+// 샌드박스 모드 실행 플래그
+const SANDBOX_FLAG = '--sandbox';
+
+// 샌드박스 데이터 디렉토리 이름 접미사
+const SANDBOX_DIR_SUFFIX = '-sandbox';
+
+let applied = false;
+
+/**
+ * 샌드박스 모드로 실행되었는지 확인
+ * @returns {boolean}
+ */
+function isSandboxMode() {
+  return process.argv.includes(SANDBOX_FLAG) || process.env.TYPING_STATS_SANDBOX === '1';
+}
+
+/**
+ * 샌드박스 모드이면 userData 경로를 별도 디렉토리로 변경
+ * 단일 인스턴스 잠금도 userData 경로 기준이므로 실제 앱과 함께 실행할 수 있습니다.
+ * @returns {boolean} 샌드박스 모드 적용 여부
+ */
+function applySandboxMode() {
+  if (!isSandboxMode()) return false;
+  if (applied) return true;
+
+  const sandboxPath = `${app.getPath('userData')}${SANDBOX_DIR_SUFFIX}`;
+  app.setPath('userData', sandboxPath);
+  applied = true;
+
+  console.log('[샌드박스] 별도 데이터 디렉토리 사용:', sandboxPath);
+  return true;
+}
+
+/**
+ * 렌더러 배너 표시용 샌드박스 상태
+ * @returns {{ sandbox: boolean, userDataPath: string }}
+ */
+function getSandboxStatus() {
+  return {
+    sandbox: isSandboxMode(),
+    userDataPath: app.getPath('userData')
+  };
+}
+
+module.exports = {
+  SANDBOX_FLAG,
+  SANDBOX_DIR_SUFFIX,
+  isSandboxMode,
+  applySandboxMode,
+  getSandboxStatus
+};
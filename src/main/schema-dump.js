@@ -0,0 +1,166 @@
+/**
+ * 데이터베이스 스키마 덤프 모듈
+ * 현재 스키마 DDL과 선택적으로 익명화한 샘플 기록을 결정적인 픽스처로 만듭니다.
+ * 같은 데이터베이스에서는 항상 같은 결과가 나오도록 정렬하고, 덤프 시각 같은 가변 값은 넣지 않습니다.
+ *
+ * Electron에 의존하지 않으므로 개발용 스크립트(scripts/dump-schema.js)에서도 사용합니다.
+ */
+
+// 픽스처 형식 식별자와 버전
+const SCHEMA_FIXTURE_FORMAT = 'typing-stats-schema-fixture';
+const SCHEMA_FIXTURE_VERSION = 1;
+
+// 기본 샘플 기록 수 (테이블별)
+const DEFAULT_SAMPLE_LIMIT = 20;
+
+// 샘플을 덤프할 테이블 (목록에 없는 테이블은 샘플을 남기지 않음)
+// order: 샘플 정렬 기준, plainColumns: 그대로 남길 문자열 열 (나머지 문자열 값은 모두 가림)
+const SAMPLE_TABLES = {
+  typing_stats: {
+    order: 'id ASC',
+    plainColumns: ['timestamp', 'created_at', 'end_reason']
+  },
+  typing_stats_rollups: {
+    order: 'granularity ASC, period_start ASC',
+    plainColumns: ['granularity', 'period_start', 'updated_at']
+  },
+  settings: {
+    order: 'key ASC',
+    plainColumns: ['key']
+  },
+  typing_goals: {
+    order: 'id ASC',
+    plainColumns: ['metric', 'created_at']
+  },
+  typing_goal_history: {
+    order: 'goal_id ASC, date ASC',
+    plainColumns: ['date', 'achieved_at']
+  },
+  typing_fatigue_daily: {
+    order: 'date ASC',
+    plainColumns: ['date']
+  }
+};
+
+/**
+ * 입력 내용을 같은 길이의 자리 표시 문자로 치환 (공백/줄바꿈은 유지)
+ * @param {string|null} text - 원본 내용
+ * @returns {string|null}
+ */
+function maskText(text) {
+  if (typeof text !== 'string') return text ?? null;
+  return text.replace(/\S/g, 'x');
+}
+
+/**
+ * 설정 값을 값의 종류만 나타내는 자리 표시로 치환
+ * @param {string|null} value - 저장된 설정 값
+ * @returns {string|null} '<number>', '<boolean>', '<json>', '<string>' 중 하나
+ */
+function settingPlaceholder(value) {
+  if (value === null || value === undefined) return null;
+  if (value !== '' && !Number.isNaN(Number(value))) return '<number>';
+  if (value === 'true' || value === 'false') return '<boolean>';
+  if (value.startsWith('{') || value.startsWith('[')) return '<json>';
+  return '<string>';
+}
+
+/**
+ * 테이블별 익명화 함수 생성
+ * plainColumns에 없는 문자열 값은 모두 가리고, 일부 열은 관계만 남기도록 따로 변환합니다.
+ * 창 제목은 처음 등장한 순서대로 번호를 붙여 같은 제목끼리의 관계만 남깁니다.
+ * @returns {Object} 테이블 이름 → 행 변환 함수
+ */
+function createAnonymizers() {
+  const windowTitles = new Map();
+
+  const transforms = {
+    typing_stats: {
+      window_title: (title) => {
+        if (!title) return null;
+        if (!windowTitles.has(title)) {
+          windowTitles.set(title, `window-${windowTitles.size + 1}`);
+        }
+        return windowTitles.get(title);
+      }
+    },
+    settings: {
+      value: settingPlaceholder
+    }
+  };
+
+  return Object.fromEntries(Object.entries(SAMPLE_TABLES).map(([table, { plainColumns }]) => {
+    const columnTransforms = transforms[table] || {};
+    return [table, (row) => Object.fromEntries(Object.entries(row).map(([column, value]) => {
+      if (columnTransforms[column]) return [column, columnTransforms[column](value)];
+      if (typeof value !== 'string' || plainColumns.includes(column)) return [column, value];
+      return [column, maskText(value)];
+    }))];
+  }));
+}
+
+/**
+ * 행의 키를 정렬해 직렬화 결과를 고정
+ * @param {Object} row - 원본 행
+ * @returns {Object}
+ */
+function sortKeys(row) {
+  return Object.keys(row).sort().reduce((sorted, key) => {
+    sorted[key] = row[key];
+    return sorted;
+  }, {});
+}
+
+/**
+ * 스키마와 샘플 기록 덤프
+ * @param {Object} db - better-sqlite3 데이터베이스 연결
+ * @param {Object} [options]
+ * @param {boolean} [options.includeSamples] - 익명화한 샘플 기록 포함 여부 (기본값 false, SAMPLE_TABLES의 테이블만)
+ * @param {number} [options.sampleLimit] - 테이블별 최대 샘플 수 (기본값 20)
+ * @returns {Object} 픽스처 객체
+ */
+function dumpSchema(db, { includeSamples = false, sampleLimit = DEFAULT_SAMPLE_LIMIT } = {}) {
+  const schema = db.prepare(`
+    SELECT type, name, tbl_name, sql
+    FROM sqlite_master
+    WHERE sql IS NOT NULL AND name NOT LIKE 'sqlite_%'
+    ORDER BY CASE type WHEN 'table' THEN 0 WHEN 'index' THEN 1 ELSE 2 END, name
+  `).all();
+
+  const fixture = {
+    format: SCHEMA_FIXTURE_FORMAT,
+    version: SCHEMA_FIXTURE_VERSION,
+    schema
+  };
+
+  if (includeSamples) {
+    const limit = Math.max(0, Math.floor(Number(sampleLimit) || 0));
+    const anonymizers = createAnonymizers();
+    fixture.samples = {};
+
+    for (const { name } of schema.filter(entry => entry.type === 'table')) {
+      if (!SAMPLE_TABLES[name]) continue;
+      const rows = db.prepare(`SELECT * FROM "${name}" ORDER BY ${SAMPLE_TABLES[name].order} LIMIT ?`).all(limit);
+      fixture.samples[name] = rows.map(row => sortKeys(anonymizers[name](row)));
+    }
+  }
+
+  return fixture;
+}
+
+/**
+ * 픽스처를 파일에 쓰기 좋은 문자열로 변환 (끝에 줄바꿈 포함)
+ * @param {Object} fixture - dumpSchema 결과
+ * @returns {string}
+ */
+function serializeFixture(fixture) {
+  return `${JSON.stringify(fixture, null, 2)}\n`;
+}
+
+module.exports = {
+  SCHEMA_FIXTURE_FORMAT,
+  SCHEMA_FIXTURE_VERSION,
+  DEFAULT_SAMPLE_LIMIT,
+  dumpSchema,
+  serializeFixture
+};
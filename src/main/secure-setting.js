@@ -0,0 +1,60 @@
+/**
+ * 비밀 값 설정 저장 모듈
+ * 토큰/비밀번호처럼 평문으로 두면 안 되는 값을 settings 테이블에 저장합니다.
+ * OS 보안 저장소(Keychain, DPAPI, libsecret)를 사용할 수 있으면 safeStorage로 암호화합니다.
+ */
+const { safeStorage } = require('electron');
+const { saveSetting, loadSetting } = require('./database');
+
+/**
+ * 비밀 값 저장 (빈 값이면 삭제)
+ * @param {string} key - 설정 키
+ * @param {string} value - 저장할 값
+ * @returns {boolean} 저장 성공 여부
+ */
+function saveSecureSetting(key, value) {
+  if (!value) {
+    return saveSetting(key, '');
+  }
+  if (safeStorage.isEncryptionAvailable()) {
+    return saveSetting(key, {
+      encrypted: true,
+      data: safeStorage.encryptString(value).toString('base64')
+    });
+  }
+  return saveSetting(key, { encrypted: false, data: value });
+}
+
+/**
+ * 비밀 값 읽기
+ * @param {string} key - 설정 키
+ * @returns {string} 저장된 값 (없거나 복호화에 실패하면 빈 문자열)
+ */
+function loadSecureSetting(key) {
+  const stored = loadSetting(key, null);
+  if (!stored || typeof stored !== 'object') return '';
+  try {
+    return stored.encrypted
+      ? safeStorage.decryptString(Buffer.from(stored.data, 'base64'))
+      : String(stored.data);
+  } catch (error) {
+    console.error(`보안 설정 읽기 오류 (${key}):`, error);
+    return '';
+  }
+}
+
+/**
+ * 비밀 값이 저장되어 있는지 확인
+ * @param {string} key - 설정 키
+ * @returns {boolean}
+ */
+function hasSecureSetting(key) {
+  const stored = loadSetting(key, null);
+  return Boolean(stored && typeof stored === 'object' && stored.data);
+}
+
+module.exports = {
+  saveSecureSetting,
+  loadSecureSetting,
+  hasSecureSetting
+};
@@ -0,0 +1,582 @@
+/**
+ * 타이핑 세션 내보내기/가져오기 모듈
+ * 문서화된 JSON 형식(docs/typing-session-format.md)으로 세션 기록을 파일에 쓰고 읽습니다.
+ * 기간별 집계 내보내기 형식은 docs/aggregate-stats-export.md,
+ * 기기 간 이전용 전체 분석 데이터 형식은 docs/analytics-data-format.md를 참고하세요.
+ */
+const fs = require('fs');
+const path = require('path');
+const {
+  getAllStats,
+  importStats,
+  getClampedPeriodTotals,
+  getStatsInRange,
+  getDailyRollups,
+  getApplicationStats,
+  importDailyRollups
+} = require('./database');
+const { resolveEpsilon, privatizePeriodTotals, SESSION_BOUNDS } = require('./differential-privacy');
+const { debugLog, calculateWpm } = require('./utils');
+
+// 파일 형식 식별자와 버전
+const SESSION_FORMAT = 'loop-typing-sessions';
+const SESSION_FORMAT_VERSION = 1;
+const AGGREGATE_FORMAT = 'loop-typing-aggregates';
+
+// 세션 종료 이유 (manual: 직접 저장, idle: 유휴 시간으로 자동 분리)
+const SESSION_END_REASONS = ['manual', 'idle'];
+const AGGREGATE_FORMAT_VERSION = 1;
+
+// 집계 내보내기 기본 기간 (일)
+const AGGREGATE_DEFAULT_DAYS = 90;
+
+// 잡음 없이 내보낼 때 사용하는 세션당 상한 (사실상 제한 없음)
+const UNBOUNDED = Object.fromEntries(
+  Object.keys(SESSION_BOUNDS).map(field => [field, Number.MAX_SAFE_INTEGER])
+);
+
+// 내보낼 때 한 번에 읽을 기록 수
+const EXPORT_PAGE_SIZE = 500;
+
+// 전체 분석 데이터 형식 (세션, 일별 롤업, 앱별 합계)
+const ANALYTICS_FORMAT = 'loop-analytics';
+const ANALYTICS_FORMAT_VERSION = 1;
+const ANALYTICS_FILE_TYPES = ['json', 'csv'];
+
+// CSV 표별 열 (모든 행의 schema_version 열에 형식 버전을 기록)
+const ANALYTICS_CSV_COLUMNS = {
+  sessions: [
+    'schema_version', 'timestamp', 'key_count', 'typing_time_sec', 'wpm', 'accuracy',
+    'total_chars', 'total_words', 'pages', 'window_title', 'browser_name', 'end_reason'
+  ],
+  daily: [
+    'schema_version', 'date', 'session_count', 'key_count', 'typing_time_sec',
+    'total_chars', 'total_words', 'accuracy_sum', 'wpm', 'avg_accuracy'
+  ],
+  applications: [
+    'schema_version', 'application', 'session_count', 'key_count', 'typing_time_sec',
+    'total_chars', 'total_words', 'wpm', 'avg_accuracy'
+  ]
+};
+
+/**
+ * 데이터베이스 기록을 내보내기 형식의 세션으로 변환
+ * @param {Object} row - typing_stats 행
+ * @param {boolean} includeContent - 입력 내용 포함 여부
+ * @returns {Object} 세션 객체
+ */
+function toSession(row, includeContent) {
+  const typingTimeSec = row.typing_time || 0;
+  return {
+    timestamp: row.timestamp,
+    keyCount: row.key_count || 0,
+    typingTimeSec,
+    wpm: calculateWpm(row.key_count, typingTimeSec),
+    accuracy: row.accuracy ?? 100,
+    totalChars: row.total_chars || 0,
+    totalWords: row.total_words || 0,
+    pages: row.pages || 0,
+    application: {
+      windowTitle: row.window_title || null,
+      browserName: row.browser_name || null
+    },
+    endReason: row.end_reason || null,
+    ...(includeContent ? { content: row.content || null } : {})
+  };
+}
+
+/**
+ * 세션 객체를 데이터베이스 기록 형식으로 변환 (유효하지 않으면 null)
+ * @param {Object} session - 가져온 세션 객체
+ * @returns {Object|null} saveStats 형식의 기록
+ */
+function fromSession(session) {
+  if (!session || typeof session !== 'object') return null;
+
+  const timestamp = new Date(session.timestamp);
+  if (isNaN(timestamp.getTime())) return null;
+  if (!Number.isFinite(session.keyCount) || session.keyCount < 0) return null;
+  if (!Number.isFinite(session.typingTimeSec) || session.typingTimeSec < 0) return null;
+
+  return {
+    timestamp: timestamp.toISOString(),
+    keyCount: Math.round(session.keyCount),
+    typingTime: Math.round(session.typingTimeSec),
+    accuracy: Number.isFinite(session.accuracy) ? session.accuracy : 100,
+    totalChars: Number.isFinite(session.totalChars) ? session.totalChars : 0,
+    totalWords: Number.isFinite(session.totalWords) ? session.totalWords : 0,
+    pages: Number.isFinite(session.pages) ? session.pages : 0,
+    windowTitle: session.application?.windowTitle || null,
+    browserName: session.application?.browserName || null,
+    endReason: SESSION_END_REASONS.includes(session.endReason) ? session.endReason : null,
+    content: typeof session.content === 'string' ? session.content : null
+  };
+}
+
+/**
+ * 모든 타이핑 세션을 파일로 내보내기
+ * @param {string} filePath - 저장할 파일 경로
+ * @param {Object} [options]
+ * @param {boolean} [options.includeContent] - 입력 내용 포함 여부 (기본값 false)
+ * @returns {{ exported: number, filePath: string }} 내보낸 세션 수와 경로
+ */
+function exportSessions(filePath, { includeContent = false } = {}) {
+  const sessions = [];
+  for (let offset = 0; ; offset += EXPORT_PAGE_SIZE) {
+    const rows = getAllStats(EXPORT_PAGE_SIZE, offset);
+    sessions.push(...rows.map(row => toSession(row, includeContent)));
+    if (rows.length < EXPORT_PAGE_SIZE) break;
+  }
+
+  // 오래된 기록부터 정렬
+  sessions.sort((a, b) => String(a.timestamp).localeCompare(String(b.timestamp)));
+
+  const document = {
+    format: SESSION_FORMAT,
+    version: SESSION_FORMAT_VERSION,
+    exportedAt: new Date().toISOString(),
+    sessions
+  };
+
+  fs.writeFileSync(filePath, JSON.stringify(document, null, 2), 'utf8');
+  debugLog(`타이핑 세션 ${sessions.length}개 내보내기 완료:`, filePath);
+
+  return { exported: sessions.length, filePath };
+}
+
+/**
+ * 파일에서 타이핑 세션 가져오기
+ * @param {string} filePath - 읽을 파일 경로
+ * @returns {{ imported: number, skipped: number, invalid: number }} 결과 요약
+ */
+function importSessions(filePath) {
+  const document = JSON.parse(fs.readFileSync(filePath, 'utf8'));
+
+  if (document?.format !== SESSION_FORMAT) {
+    throw new Error('지원하지 않는 파일 형식입니다');
+  }
+  if (!Number.isInteger(document.version) || document.version > SESSION_FORMAT_VERSION) {
+    throw new Error(`지원하지 않는 형식 버전입니다: ${document.version}`);
+  }
+  if (!Array.isArray(document.sessions)) {
+    throw new Error('sessions 배열이 없습니다');
+  }
+
+  const records = document.sessions.map(fromSession);
+  const valid = records.filter(Boolean);
+  const { imported, skipped } = importStats(valid);
+
+  return { imported, skipped, invalid: records.length - valid.length };
+}
+
+/**
+ * 기간별 집계를 내보내기 형식으로 변환
+ * @param {Object} period - 기간별 합계
+ * @returns {Object} 집계 객체
+ */
+function toAggregate(period) {
+  return {
+    periodStart: period.periodStart,
+    sessionCount: period.sessionCount,
+    keyCount: period.keyCount,
+    typingTimeSec: period.typingTime,
+    totalChars: period.totalChars,
+    totalWords: period.totalWords,
+    wpm: calculateWpm(period.keyCount, period.typingTime),
+    avgAccuracy: period.sessionCount > 0 ? Math.min(100, period.accuracySum / period.sessionCount) : null
+  };
+}
+
+/**
+ * 기간별 집계 통계를 파일로 내보내기
+ * 차등 프라이버시를 켜면 세션당 기여도를 제한하고 합계에 잡음을 더합니다 (앱 화면의 통계는 그대로).
+ * @param {string} filePath - 저장할 파일 경로
+ * @param {Object} [options]
+ * @param {string} [options.granularity] - day, week, month (기본값 week)
+ * @param {string} [options.from] - 시작 시각 (기본값 90일 전)
+ * @param {string} [options.to] - 종료 시각 (기본값 현재)
+ * @param {boolean} [options.differentialPrivacy] - 잡음 추가 여부 (기본값 false)
+ * @param {number|string} [options.epsilon] - 기간별 엡실론 또는 프리셋 이름 (기본값 balanced)
+ * @returns {{ exported: number, filePath: string, privacy: Object|null }} 내보낸 기간 수와 경로
+ */
+function exportAggregates(filePath, {
+  granularity = 'week',
+  from,
+  to,
+  differentialPrivacy = false,
+  epsilon
+} = {}) {
+  const toDate = to ? new Date(to) : new Date();
+  const fromDate = from ? new Date(from) : new Date(toDate.getTime() - AGGREGATE_DEFAULT_DAYS * 86400000);
+
+  let totals;
+  let privacy = null;
+  if (differentialPrivacy) {
+    const resolved = resolveEpsilon(epsilon);
+    totals = privatizePeriodTotals(getClampedPeriodTotals(granularity, fromDate, toDate, SESSION_BOUNDS), resolved);
+    privacy = {
+      mechanism: 'laplace',
+      unit: 'session',
+      epsilonPerPeriod: resolved,
+      sessionBounds: { ...SESSION_BOUNDS }
+    };
+  } else {
+    totals = getClampedPeriodTotals(granularity, fromDate, toDate, UNBOUNDED);
+  }
+
+  const document = {
+    format: AGGREGATE_FORMAT,
+    version: AGGREGATE_FORMAT_VERSION,
+    exportedAt: new Date().toISOString(),
+    granularity,
+    from: fromDate.toISOString(),
+    to: toDate.toISOString(),
+    privacy,
+    periods: totals.map(toAggregate)
+  };
+
+  fs.writeFileSync(filePath, JSON.stringify(document, null, 2), 'utf8');
+  debugLog(`기간별 집계 ${totals.length}개 내보내기 완료${privacy ? ` (엡실론 ${privacy.epsilonPerPeriod})` : ''}:`, filePath);
+
+  return { exported: totals.length, filePath, privacy };
+}
+
+/**
+ * 내보내기 기간의 날짜 검사
+ * @param {string} [value] - YYYY-MM-DD
+ * @param {string} name - 오류 메시지용 이름
+ * @returns {string|null} 날짜 (없으면 null)
+ */
+function normalizeRangeDate(value, name) {
+  if (value === undefined || value === null || value === '') return null;
+  if (typeof value !== 'string' || !/^\d{4}-\d{2}-\d{2}$/.test(value) || isNaN(new Date(value).getTime())) {
+    throw new Error(`잘못된 ${name} 날짜입니다: ${value}`);
+  }
+  return value;
+}
+
+/**
+ * 일 단위 롤업을 내보내기 형식으로 변환
+ * @param {Object} row - typing_stats_rollups 행
+ * @returns {Object} 일별 합계 객체
+ */
+function toDaily(row) {
+  return {
+    date: row.period_start,
+    sessionCount: row.session_count,
+    keyCount: row.key_count,
+    typingTimeSec: row.typing_time,
+    totalChars: row.total_chars,
+    totalWords: row.total_words,
+    accuracySum: row.accuracy_sum,
+    wpm: calculateWpm(row.key_count, row.typing_time),
+    avgAccuracy: row.session_count > 0 ? Math.min(100, row.accuracy_sum / row.session_count) : null
+  };
+}
+
+/**
+ * 앱별 합계를 내보내기 형식으로 변환
+ * @param {Object} row - getApplicationStats 행
+ * @returns {Object} 앱별 합계 객체
+ */
+function toApplication(row) {
+  return {
+    application: row.application || null,
+    sessionCount: row.session_count,
+    keyCount: row.key_count,
+    typingTimeSec: row.typing_time,
+    totalChars: row.total_chars,
+    totalWords: row.total_words,
+    wpm: calculateWpm(row.key_count, row.typing_time),
+    avgAccuracy: row.session_count > 0 ? Math.min(100, row.accuracy_sum / row.session_count) : null
+  };
+}
+
+/**
+ * 일별 합계 객체를 롤업 형식으로 변환 (유효하지 않으면 null)
+ * @param {Object} daily - 가져온 일별 합계
+ * @returns {Object|null} importDailyRollups 형식의 행
+ */
+function fromDaily(daily) {
+  if (!daily || typeof daily !== 'object') return null;
+  if (typeof daily.date !== 'string' || !/^\d{4}-\d{2}-\d{2}$/.test(daily.date)) return null;
+
+  const fields = ['sessionCount', 'keyCount', 'typingTimeSec', 'totalChars', 'totalWords', 'accuracySum'];
+  if (!fields.every(field => Number.isFinite(daily[field]) && daily[field] >= 0)) return null;
+
+  return {
+    periodStart: daily.date,
+    sessionCount: Math.round(daily.sessionCount),
+    keyCount: Math.round(daily.keyCount),
+    typingTime: Math.round(daily.typingTimeSec),
+    totalChars: Math.round(daily.totalChars),
+    totalWords: Math.round(daily.totalWords),
+    accuracySum: daily.accuracySum
+  };
+}
+
+// CSV 값 하나 (쉼표, 따옴표, 줄바꿈이 있으면 따옴표로 감쌈)
+function csvField(value) {
+  if (value === null || value === undefined) return '';
+  const text = String(value);
+  return /[",\r\n]/.test(text) ? `"${text.replace(/"/g, '""')}"` : text;
+}
+
+/**
+ * CSV 문서 작성 (RFC 4180, 스프레드시트에서 한글이 깨지지 않도록 BOM 포함)
+ * @param {string[]} columns - 열 이름
+ * @param {Array<Array>} rows - 행 배열
+ * @returns {string}
+ */
+function toCsv(columns, rows) {
+  const lines = [columns, ...rows].map(row => row.map(csvField).join(','));
+  return `\ufeff${lines.join('\r\n')}\r\n`;
+}
+
+/**
+ * CSV 문서 읽기
+ * @param {string} text - CSV 내용
+ * @returns {Array<Object>} 첫 줄을 열 이름으로 사용한 행 객체 배열
+ */
+function parseCsv(text) {
+  const rows = [];
+  let row = [];
+  let field = '';
+  let quoted = false;
+  const input = text.replace(/^\ufeff/, '');
+
+  for (let i = 0; i < input.length; i++) {
+    const char = input[i];
+    if (quoted) {
+      if (char === '"' && input[i + 1] === '"') {
+        field += '"';
+        i++;
+      } else if (char === '"') {
+        quoted = false;
+      } else {
+        field += char;
+      }
+    } else if (char === '"') {
+      quoted = true;
+    } else if (char === ',') {
+      row.push(field);
+      field = '';
+    } else if (char === '\n' || char === '\r') {
+      if (char === '\r' && input[i + 1] === '\n') i++;
+      row.push(field);
+      rows.push(row);
+      row = [];
+      field = '';
+    } else {
+      field += char;
+    }
+  }
+  if (field !== '' || row.length > 0) {
+    row.push(field);
+    rows.push(row);
+  }
+
+  const [header = [], ...records] = rows.filter(item => item.length > 1 || item[0] !== '');
+  return records.map(values => Object.fromEntries(header.map((column, index) => [column, values[index] ?? ''])));
+}
+
+// CSV 빈 값은 없는 값으로, 나머지는 숫자로 변환
+function csvNumber(value) {
+  return value === '' || value === undefined ? undefined : Number(value);
+}
+
+/**
+ * CSV 표 하나를 가져오기 형식으로 변환
+ * @param {Array<Object>} records - parseCsv 결과
+ * @returns {{ sessions: Array, daily: Array }} 세션과 일별 합계 (앱별 합계는 세션에서 다시 계산되므로 무시)
+ */
+function fromCsvRecords(records) {
+  const result = { sessions: [], daily: [] };
+  if (records.length === 0) return result;
+
+  const columns = Object.keys(records[0]);
+  if (!columns.includes('schema_version')) {
+    throw new Error('schema_version 열이 없는 CSV 파일입니다');
+  }
+  for (const record of records) {
+    const version = Number(record.schema_version);
+    if (!Number.isInteger(version) || version > ANALYTICS_FORMAT_VERSION) {
+      throw new Error(`지원하지 않는 형식 버전입니다: ${record.schema_version}`);
+    }
+  }
+
+  if (columns.includes('timestamp')) {
+    result.sessions = records.map(record => ({
+      timestamp: record.timestamp,
+      keyCount: csvNumber(record.key_count),
+      typingTimeSec: csvNumber(record.typing_time_sec),
+      accuracy: csvNumber(record.accuracy),
+      totalChars: csvNumber(record.total_chars),
+      totalWords: csvNumber(record.total_words),
+      pages: csvNumber(record.pages),
+      application: {
+        windowTitle: record.window_title || null,
+        browserName: record.browser_name || null
+      },
+      endReason: record.end_reason || null,
+      content: record.content === undefined || record.content === '' ? undefined : record.content
+    }));
+  } else if (columns.includes('date')) {
+    result.daily = records.map(record => ({
+      date: record.date,
+      sessionCount: csvNumber(record.session_count),
+      keyCount: csvNumber(record.key_count),
+      typingTimeSec: csvNumber(record.typing_time_sec),
+      totalChars: csvNumber(record.total_chars),
+      totalWords: csvNumber(record.total_words),
+      accuracySum: csvNumber(record.accuracy_sum)
+    }));
+  }
+  return result;
+}
+
+/**
+ * 전체 분석 데이터 내보내기 (세션, 일별 롤업, 앱별 합계)
+ * JSON은 파일 하나에, CSV는 표마다 `<이름>-sessions.csv`, `-daily.csv`, `-applications.csv` 파일로 저장합니다.
+ * @param {string} filePath - 저장할 파일 경로 (CSV는 파일 이름의 기준)
+ * @param {Object} [options]
+ * @param {string} [options.format] - json 또는 csv (기본값 json)
+ * @param {string} [options.from] - 시작 날짜 (YYYY-MM-DD, 포함, 기본값 처음부터)
+ * @param {string} [options.to] - 끝 날짜 (YYYY-MM-DD, 포함, 기본값 끝까지)
+ * @param {boolean} [options.includeContent] - 입력 내용 포함 여부 (기본값 false)
+ * @returns {{ exported: Object, files: string[] }} 표별 내보낸 수와 저장한 파일 경로
+ */
+function exportAnalytics(filePath, { format = 'json', from, to, includeContent = false } = {}) {
+  if (!ANALYTICS_FILE_TYPES.includes(format)) {
+    throw new Error(`지원하지 않는 내보내기 형식입니다: ${format}`);
+  }
+  const range = { from: normalizeRangeDate(from, '시작'), to: normalizeRangeDate(to, '끝') };
+
+  const sessions = [];
+  for (let offset = 0; ; offset += EXPORT_PAGE_SIZE) {
+    const rows = getStatsInRange(range.from, range.to, EXPORT_PAGE_SIZE, offset);
+    sessions.push(...rows.map(row => toSession(row, includeContent)));
+    if (rows.length < EXPORT_PAGE_SIZE) break;
+  }
+  const daily = getDailyRollups(range.from, range.to).map(toDaily);
+  const applications = getApplicationStats(range.from, range.to).map(toApplication);
+  const exported = { sessions: sessions.length, daily: daily.length, applications: applications.length };
+
+  let files;
+  if (format === 'json') {
+    const document = {
+      format: ANALYTICS_FORMAT,
+      version: ANALYTICS_FORMAT_VERSION,
+      exportedAt: new Date().toISOString(),
+      range,
+      sessions,
+      daily,
+      applications
+    };
+    fs.writeFileSync(filePath, JSON.stringify(document, null, 2), 'utf8');
+    files = [filePath];
+  } else {
+    const base = filePath.replace(/\.csv$/i, '');
+    const sessionColumns = includeContent
+      ? [...ANALYTICS_CSV_COLUMNS.sessions, 'content']
+      : ANALYTICS_CSV_COLUMNS.sessions;
+    const tables = {
+      sessions: toCsv(sessionColumns, sessions.map(session => [
+        ANALYTICS_FORMAT_VERSION, session.timestamp, session.keyCount, session.typingTimeSec, session.wpm,
+        session.accuracy, session.totalChars, session.totalWords, session.pages,
+        session.application.windowTitle, session.application.browserName, session.endReason,
+        ...(includeContent ? [session.content] : [])
+      ])),
+      daily: toCsv(ANALYTICS_CSV_COLUMNS.daily, daily.map(day => [
+        ANALYTICS_FORMAT_VERSION, day.date, day.sessionCount, day.keyCount, day.typingTimeSec,
+        day.totalChars, day.totalWords, day.accuracySum, day.wpm, day.avgAccuracy
+      ])),
+      applications: toCsv(ANALYTICS_CSV_COLUMNS.applications, applications.map(item => [
+        ANALYTICS_FORMAT_VERSION, item.application, item.sessionCount, item.keyCount, item.typingTimeSec,
+        item.totalChars, item.totalWords, item.wpm, item.avgAccuracy
+      ]))
+    };
+
+    files = Object.entries(tables).map(([name, content]) => {
+      const target = `${base}-${name}.csv`;
+      fs.writeFileSync(target, content, 'utf8');
+      return target;
+    });
+  }
+
+  debugLog(`분석 데이터 내보내기 완료 (세션 ${exported.sessions}개, 일별 ${exported.daily}개, 앱 ${exported.applications}개):`, files.join(', '));
+  return { exported, files };
+}
+
+/**
+ * 전체 분석 데이터 가져오기 (다른 기기에서 내보낸 파일)
+ * JSON 파일 하나 또는 CSV 파일 여러 개(세션, 일별)를 받을 수 있으며, 세션 파일 형식(loop-typing-sessions)도 읽습니다.
+ * 세션을 먼저 추가한 뒤, 이 기기에 롤업이 없는 날짜(원본 기록이 정리된 날짜)의 일별 합계만 추가합니다.
+ * 앱별 합계는 세션에서 다시 계산되므로 가져오지 않습니다.
+ * @param {string|string[]} filePaths - 읽을 파일 경로
+ * @returns {{ imported: number, skipped: number, invalid: number, dailyImported: number, dailySkipped: number }} 결과 요약
+ */
+function importAnalytics(filePaths) {
+  const sessions = [];
+  const daily = [];
+
+  for (const filePath of [].concat(filePaths)) {
+    const text = fs.readFileSync(filePath, 'utf8');
+
+    if (path.extname(filePath).toLowerCase() === '.csv') {
+      const tables = fromCsvRecords(parseCsv(text));
+      sessions.push(...tables.sessions);
+      daily.push(...tables.daily);
+      continue;
+    }
+
+    const document = JSON.parse(text);
+    const version = document?.version;
+    if (document?.format === SESSION_FORMAT) {
+      if (!Number.isInteger(version) || version > SESSION_FORMAT_VERSION) {
+        throw new Error(`지원하지 않는 형식 버전입니다: ${version}`);
+      }
+    } else if (document?.format === ANALYTICS_FORMAT) {
+      if (!Number.isInteger(version) || version > ANALYTICS_FORMAT_VERSION) {
+        throw new Error(`지원하지 않는 형식 버전입니다: ${version}`);
+      }
+      if (Array.isArray(document.daily)) daily.push(...document.daily);
+    } else {
+      throw new Error('지원하지 않는 파일 형식입니다');
+    }
+    if (!Array.isArray(document.sessions)) {
+      throw new Error('sessions 배열이 없습니다');
+    }
+    sessions.push(...document.sessions);
+  }
+
+  const records = sessions.map(fromSession);
+  const valid = records.filter(Boolean);
+  const { imported, skipped } = importStats(valid);
+
+  const rollups = daily.map(fromDaily);
+  const validRollups = rollups.filter(Boolean);
+  const { imported: dailyImported, skipped: dailySkipped } = importDailyRollups(validRollups);
+
+  return {
+    imported,
+    skipped,
+    invalid: records.length - valid.length + rollups.length - validRollups.length,
+    dailyImported,
+    dailySkipped
+  };
+}
+
+module.exports = {
+  SESSION_FORMAT,
+  SESSION_FORMAT_VERSION,
+  AGGREGATE_FORMAT,
+  AGGREGATE_FORMAT_VERSION,
+  ANALYTICS_FORMAT,
+  ANALYTICS_FORMAT_VERSION,
+  exportSessions,
+  importSessions,
+  exportAggregates,
+  exportAnalytics,
+  importAnalytics
+};
@@ -1,7 +1,7 @@
 const fs = require('fs');
 const path = require('path');
 const { settingsPath, appState } = require('./constants');
-const { debugLog } = require('./utils');
+const { debugLog, withAccessibleDescription } = require('./utils');
 const { app } = require('electron');
 
 /**
@@ -21,6 +21,7 @@ async function loadSettings() {
       };
       
       debugLog('설정 파일 로드됨:', settingsPath);
+      applyFirstDayOfWeek();
       return true;
     }
   } catch (err) {
@@ -31,6 +32,20 @@ async function loadSettings() {
   return false;
 }
 
+/**
+ * 주 시작 요일 설정을 주 단위 롤업에 반영
+ * 요일이 바뀌면 데이터베이스가 주 단위 집계 경계를 다시 계산합니다.
+ */
+function applyFirstDayOfWeek() {
+  try {
+    // 데이터베이스 모듈은 필요할 때만 로드 (순환 참조 방지)
+    const { setFirstDayOfWeek } = require('./database');
+    setFirstDayOfWeek(appState.settings.firstDayOfWeek);
+  } catch (error) {
+    console.error('주 시작 요일 적용 오류:', error);
+  }
+}
+
 /**
  * 현재 설정을 파일에 저장
  * @returns {boolean} 저장 성공 여부
@@ -53,6 +68,11 @@ function saveSettings(newSettings = null) {
     
     fs.writeFileSync(settingsPath, JSON.stringify(appState.settings, null, 2), 'utf8');
     debugLog('설정 저장됨:', settingsPath);
+    applyFirstDayOfWeek();
+    require('./metrics-exporter').applyMetricsSettings();
+    require('./analytics-export').applyAnalyticsSettings();
+    require('./gpu-compute').applyGpuComputeSetting();
+    require('./gpu-compute').applyPreferredGpuDevice();
     return true;
   } catch (err) {
     console.error('설정 저장 중 오류:', err);
@@ -179,10 +199,10 @@ function applyWindowMode(mode) {
   // 상태 업데이트 - 렌더러에 통지
   if (appState.mainWindow && appState.mainWindow.webContents) {
     // 웹 컨텐츠에 창 모드 변경 이벤트 전송
-    appState.mainWindow.webContents.send('window-mode-status', {
+    appState.mainWindow.webContents.send('window-mode-status', withAccessibleDescription({
       mode: mode,
       autoHideToolbar: appState.autoHideToolbar
-    });
+    }, `창 모드가 ${mode}(으)로 변경되었습니다.`));
   }
 }
 
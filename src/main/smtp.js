@@ -0,0 +1,254 @@
+/**
+ * 간단한 SMTP 전송 모듈
+ * 보고서 메일 발송용으로 EHLO, STARTTLS, AUTH PLAIN, 메시지 한 통 전송만 지원합니다.
+ * 인증 정보는 TLS 연결(암시적 TLS 또는 STARTTLS)에서만 보냅니다.
+ */
+const net = require('net');
+const tls = require('tls');
+const os = require('os');
+const crypto = require('crypto');
+
+// 서버 응답 대기 시간 (ms)
+const SMTP_TIMEOUT_MS = 30000;
+
+// 로컬 릴레이는 TLS 없이 인증 허용
+const LOCAL_HOSTS = new Set(['localhost', '127.0.0.1', '::1']);
+
+/**
+ * SMTP 연결 (응답을 줄 단위로 읽어 명령별 응답으로 묶음)
+ */
+class SmtpConnection {
+  constructor(socket) {
+    this.attach(socket);
+  }
+
+  /**
+   * 소켓 연결 (STARTTLS 후 TLS 소켓으로 교체할 때도 사용)
+   * @param {net.Socket} socket
+   */
+  attach(socket) {
+    if (this.socket) {
+      this.socket.removeAllListeners('data');
+      this.socket.removeAllListeners('error');
+      this.socket.removeAllListeners('close');
+      this.socket.removeAllListeners('timeout');
+    }
+
+    this.socket = socket;
+    this.buffer = '';
+    this.lines = [];
+    this.responses = [];
+    this.waiters = [];
+    this.error = null;
+
+    socket.setEncoding('utf8');
+    socket.setTimeout(SMTP_TIMEOUT_MS, () => socket.destroy(new Error('SMTP 서버 응답 시간 초과')));
+    socket.on('data', chunk => this.onData(chunk));
+    socket.on('error', error => this.fail(error));
+    socket.on('close', () => this.fail(new Error('SMTP 연결이 끊어졌습니다')));
+  }
+
+  // 받은 데이터를 응답 단위로 분리 ("250-..."는 계속, "250 ..."는 마지막 줄)
+  onData(chunk) {
+    this.buffer += chunk;
+    let index;
+    while ((index = this.buffer.indexOf('\r\n')) !== -1) {
+      const line = this.buffer.slice(0, index);
+      this.buffer = this.buffer.slice(index + 2);
+      this.lines.push(line);
+
+      if (/^\d{3}(?: |$)/.test(line)) {
+        const response = {
+          code: Number(line.slice(0, 3)),
+          lines: this.lines.map(item => item.slice(4))
+        };
+        this.lines = [];
+        const waiter = this.waiters.shift();
+        if (waiter) {
+          waiter.resolve(response);
+        } else {
+          this.responses.push(response);
+        }
+      }
+    }
+  }
+
+  fail(error) {
+    if (this.error) return;
+    this.error = error;
+    for (const waiter of this.waiters.splice(0)) {
+      waiter.reject(error);
+    }
+  }
+
+  /**
+   * 다음 응답 읽기
+   * @returns {Promise<{ code: number, lines: string[] }>}
+   */
+  read() {
+    if (this.responses.length > 0) return Promise.resolve(this.responses.shift());
+    if (this.error) return Promise.reject(this.error);
+    return new Promise((resolve, reject) => this.waiters.push({ resolve, reject }));
+  }
+
+  /**
+   * 응답 코드 확인
+   * @param {Object} response - read() 결과
+   * @param {number[]} expected - 성공 코드
+   * @param {string} step - 오류 메시지용 단계 이름
+   * @returns {Object} response
+   */
+  expect(response, expected, step) {
+    if (!expected.includes(response.code)) {
+      throw new Error(`SMTP ${step} 실패: ${response.code} ${response.lines.join(' ')}`);
+    }
+    return response;
+  }
+
+  /**
+   * 명령 전송 후 응답 확인
+   * @param {string} line - 명령 (CRLF 제외)
+   * @param {number[]} expected - 성공 코드
+   * @returns {Promise<Object>} 응답
+   */
+  async command(line, expected) {
+    this.socket.write(`${line}\r\n`);
+    return this.expect(await this.read(), expected, line.split(' ')[0]);
+  }
+
+  close() {
+    this.socket.end();
+  }
+}
+
+// 소켓 연결 대기
+function connectSocket(options, secure) {
+  return new Promise((resolve, reject) => {
+    const socket = secure
+      ? tls.connect(options, () => resolve(socket))
+      : net.connect(options, () => resolve(socket));
+    socket.once('error', reject);
+  });
+}
+
+// 헤더 값 검사 (줄바꿈이 있으면 헤더 삽입이 가능하므로 거부)
+function assertHeaderValue(name, value) {
+  if (typeof value !== 'string' || /[\r\n]/.test(value)) {
+    throw new Error(`잘못된 메일 ${name} 값입니다`);
+  }
+  return value;
+}
+
+// "이름 <주소>" 형식에서 주소만 추출
+function extractAddress(value) {
+  const match = /<([^<>]+)>\s*$/.exec(value);
+  return (match ? match[1] : value).trim();
+}
+
+// ASCII가 아닌 헤더 값 인코딩 (RFC 2047)
+function encodeHeader(value) {
+  return /^[\x20-\x7e]*$/.test(value) ? value : `=?UTF-8?B?${Buffer.from(value, 'utf8').toString('base64')}?=`;
+}
+
+// 본문 base64 인코딩 (76자 줄바꿈)
+function encodeBody(text) {
+  return Buffer.from(text, 'utf8').toString('base64').replace(/.{1,76}/g, '$&\r\n');
+}
+
+/**
+ * 텍스트/HTML 대체 본문을 가진 메시지 작성
+ * @param {Object} mail - { from, to, subject, text, html }
+ * @returns {string} RFC 5322 메시지 (CRLF)
+ */
+function buildMessage({ from, to, subject, text, html }) {
+  const boundary = `loop-${crypto.randomBytes(12).toString('hex')}`;
+  const domain = extractAddress(from).split('@')[1] || os.hostname();
+  const headers = [
+    `From: ${from}`,
+    `To: ${to.join(', ')}`,
+    `Subject: ${encodeHeader(subject)}`,
+    `Date: ${new Date().toUTCString()}`,
+    `Message-ID: <${crypto.randomUUID()}@${domain}>`,
+    'MIME-Version: 1.0',
+    `Content-Type: multipart/alternative; boundary="${boundary}"`
+  ];
+
+  const parts = [];
+  if (text) {
+    parts.push(`--${boundary}\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Transfer-Encoding: base64\r\n\r\n${encodeBody(text)}`);
+  }
+  if (html) {
+    parts.push(`--${boundary}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Transfer-Encoding: base64\r\n\r\n${encodeBody(html)}`);
+  }
+
+  // base64 본문과 헤더에는 '.'으로 시작하는 줄이 없으므로 점 처리(dot-stuffing)가 필요 없음
+  return `${headers.join('\r\n')}\r\n\r\n${parts.join('')}--${boundary}--\r\n`;
+}
+
+/**
+ * 메일 전송
+ * @param {Object} options
+ * @param {string} options.host - SMTP 서버
+ * @param {number} [options.port] - 포트 (기본값: secure면 465, 아니면 587)
+ * @param {boolean} [options.secure] - 처음부터 TLS로 연결 (아니면 가능한 경우 STARTTLS)
+ * @param {string} [options.user] - 인증 사용자
+ * @param {string} [options.pass] - 인증 비밀번호
+ * @param {string} options.from - 보내는 사람
+ * @param {string[]} options.to - 받는 사람 목록
+ * @param {string} options.subject - 제목
+ * @param {string} [options.text] - 텍스트 본문
+ * @param {string} [options.html] - HTML 본문
+ * @returns {Promise<Object>} { accepted, response }
+ */
+async function sendMail({ host, port, secure = false, user = '', pass = '', from, to, subject, text, html }) {
+  if (!host) throw new Error('SMTP 서버가 설정되지 않았습니다');
+  const recipients = (Array.isArray(to) ? to : [to]).filter(Boolean).map(value => assertHeaderValue('받는 사람', value.trim()));
+  if (recipients.length === 0) throw new Error('받는 사람이 없습니다');
+  assertHeaderValue('보내는 사람', from);
+  assertHeaderValue('제목', subject);
+
+  const targetPort = Number(port) || (secure ? 465 : 587);
+  const socket = await connectSocket({ host, port: targetPort, servername: host }, secure);
+  const connection = new SmtpConnection(socket);
+  let encrypted = secure;
+
+  try {
+    connection.expect(await connection.read(), [220], '연결');
+    let ehlo = await connection.command(`EHLO ${os.hostname() || 'localhost'}`, [250]);
+
+    if (!encrypted && ehlo.lines.some(line => /^STARTTLS\b/i.test(line))) {
+      await connection.command('STARTTLS', [220]);
+      const tlsSocket = await connectSocket({ socket: connection.socket, servername: host }, true);
+      connection.attach(tlsSocket);
+      encrypted = true;
+      ehlo = await connection.command(`EHLO ${os.hostname() || 'localhost'}`, [250]);
+    }
+
+    if (user) {
+      if (!encrypted && !LOCAL_HOSTS.has(host)) {
+        throw new Error('암호화되지 않은 연결에서는 SMTP 인증 정보를 보내지 않습니다');
+      }
+      const credentials = Buffer.from(`\u0000${user}\u0000${pass}`, 'utf8').toString('base64');
+      await connection.command(`AUTH PLAIN ${credentials}`, [235]);
+    }
+
+    await connection.command(`MAIL FROM:<${extractAddress(from)}>`, [250]);
+    for (const recipient of recipients) {
+      await connection.command(`RCPT TO:<${extractAddress(recipient)}>`, [250, 251]);
+    }
+    await connection.command('DATA', [354]);
+
+    connection.socket.write(buildMessage({ from, to: recipients, subject, text, html }));
+    const response = await connection.command('.', [250]);
+
+    await connection.command('QUIT', [221]).catch(() => {});
+    return { accepted: recipients, response: response.lines.join(' ') };
+  } finally {
+    connection.close();
+  }
+}
+
+module.exports = {
+  sendMail,
+  buildMessage
+};
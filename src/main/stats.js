@@ -1,8 +1,9 @@
 const { Worker } = require('worker_threads');
 const path = require('path');
+const { powerMonitor } = require('electron');
 const { appState, BROWSER_DISPLAY_NAMES, IDLE_TIMEOUT, HIGH_MEMORY_THRESHOLD } = require('./constants');
-const { debugLog, formatTime } = require('./utils');
-const { saveStats: saveStatsToDb, getStatById } = require('./database');
+const { debugLog, formatTime, accessibleText, withAccessibleDescription } = require('./utils');
+const { saveStats: saveStatsToDb, recordFatigueSample } = require('./database');
 
 // 워커 인스턴스 관리
 let statWorker = null;
@@ -15,6 +16,26 @@ let pendingTasks = [];
 const MEMORY_THRESHOLD = 100 * 1024 * 1024; // 100MB
 let processingMode = 'normal'; // 'normal', 'cpu-intensive', 'gpu-intensive'
 
+// 키보드 레이아웃 확인 주기 및 현재 레이아웃 구간 시작 정보
+const LAYOUT_CHECK_INTERVAL = 5000; // 5초
+let layoutCheckTimer = null;
+let layoutSegmentStart = null;
+
+// 피로도 추적 관련 변수
+const FATIGUE_CHECK_INTERVAL = 30000; // 30초
+let fatigueCheckTimer = null;
+let fatigueSample = null;
+let appliedFatigueThreshold = null;
+
+// 시스템 절전 관련 변수
+const MAX_SLEEP_GAPS = 20; // 세션당 기록할 최대 절전 구간 수
+let suspendedAt = null;
+
+// 유휴 감지 관련 변수 (운영체제 입력 유휴 시간 기준)
+const IDLE_CHECK_INTERVAL = 15000; // 15초
+const MIN_IDLE_PAUSE_SECONDS = 30;
+let idleCheckTimer = null;
+
 /**
  * 워커 초기화 - CPU 집약적 계산을 위한 별도 스레드
  */
@@ -426,6 +447,238 @@ function processKeyInput(windowTitle, browserName) {
   }
 }
 
+/**
+ * 네이티브 모듈에서 현재 키보드 레이아웃 조회
+ * @returns {object|null} { layout, language } 또는 null
+ */
+function getKeyboardLayout() {
+  try {
+    const { loadNativeModule } = require('./native-module-loader');
+    const nativeModule = loadNativeModule();
+    const getLayout = nativeModule?.getKeyboardLayout || nativeModule?.get_keyboard_layout;
+    if (typeof getLayout !== 'function') return null;
+
+    const result = JSON.parse(getLayout());
+    return result.available ? { layout: result.layout, language: result.language } : null;
+  } catch (error) {
+    debugLog('키보드 레이아웃 조회 오류:', error);
+    return null;
+  }
+}
+
+/**
+ * 현재 레이아웃 구간 종료 및 기록
+ * @param {number} now - 현재 시간
+ */
+function closeLayoutSegment(now) {
+  const stats = appState.currentStats;
+  if (!layoutSegmentStart || !stats.keyboardLayout) return;
+
+  const keyCount = stats.keyCount - layoutSegmentStart.keyCount;
+  if (keyCount > 0) {
+    stats.layoutSegments.push({
+      layout: stats.keyboardLayout.layout,
+      language: stats.keyboardLayout.language,
+      keyCount,
+      startTime: layoutSegmentStart.time,
+      endTime: now
+    });
+  }
+  layoutSegmentStart = { keyCount: stats.keyCount, time: now };
+}
+
+/**
+ * 키보드 레이아웃 변경 확인
+ * 세션 중 레이아웃이 바뀌면 이전 레이아웃의 입력을 별도 구간으로 기록
+ */
+function checkKeyboardLayout() {
+  const layoutInfo = getKeyboardLayout();
+  if (!layoutInfo) return;
+
+  const stats = appState.currentStats;
+  if (stats.keyboardLayout && stats.keyboardLayout.layout === layoutInfo.layout) return;
+
+  const now = Date.now();
+  if (stats.keyboardLayout) {
+    debugLog('키보드 레이아웃 변경 감지:', {
+      from: stats.keyboardLayout.layout,
+      to: layoutInfo.layout
+    });
+    closeLayoutSegment(now);
+  } else {
+    layoutSegmentStart = { keyCount: stats.keyCount, time: now };
+  }
+
+  stats.keyboardLayout = layoutInfo;
+}
+
+/**
+ * 마지막 확인 이후의 타이핑 활동을 네이티브 피로도 추적기에 기록
+ * 피로도가 설정한 임계값을 넘으면 렌더러에 break-recommended 이벤트를 보내고 트레이 알림 표시
+ */
+function checkFatigue() {
+  if (appState.settings?.breakReminderEnabled === false) return;
+
+  try {
+    const { loadNativeModule } = require('./native-module-loader');
+    const nativeModule = loadNativeModule();
+    const recordActivity = nativeModule?.recordTypingActivity || nativeModule?.record_typing_activity;
+    if (typeof recordActivity !== 'function') return;
+
+    const threshold = appState.settings?.fatigueThreshold ?? 70;
+    const setThreshold = nativeModule.setFatigueThreshold || nativeModule.set_fatigue_threshold;
+    if (threshold !== appliedFatigueThreshold && typeof setThreshold === 'function') {
+      setThreshold(threshold);
+      appliedFatigueThreshold = threshold;
+    }
+
+    const now = Date.now();
+    const keyCount = appState.currentStats.keyCount;
+    const previous = fatigueSample || { keyCount, time: now };
+    // 통계가 초기화된 경우 현재 값을 그대로 사용
+    const keyDelta = keyCount >= previous.keyCount ? keyCount - previous.keyCount : keyCount;
+
+    // 마지막 입력 후 유휴 판단 시간까지만 입력한 시간으로 계산
+    const lastActive = appState.currentStats.lastActiveTime || previous.time;
+    const activeEnd = Math.min(now, lastActive + IDLE_TIMEOUT);
+    const activeMs = keyDelta > 0 ? Math.max(0, activeEnd - previous.time) : 0;
+
+    fatigueSample = { keyCount, time: now };
+
+    const status = JSON.parse(recordActivity(keyDelta, Math.round(activeMs)));
+
+    // 주간 보고서 피로도 추세용 일별 최고값 기록
+    recordFatigueSample(status.score, status.break_recommended);
+    if (!status.break_recommended) return;
+
+    debugLog('휴식 권장:', { score: status.score, threshold: status.threshold });
+
+    if (appState.mainWindow && !appState.mainWindow.isDestroyed()) {
+      appState.mainWindow.webContents.send('break-recommended', withAccessibleDescription({
+        score: status.score,
+        level: status.level,
+        threshold: status.threshold,
+        continuousTypingMs: status.continuous_typing_ms,
+        timestamp: status.timestamp
+      }, '타이핑 피로도가 높습니다. 잠시 쉬어 가세요.'));
+    }
+
+    if (appState.settings?.showTrayNotifications && appState.tray) {
+      appState.tray.displayBalloon({
+        title: 'Loop',
+        content: accessibleText(`${Math.round(status.continuous_typing_ms / 60000)}분 동안 계속 입력했습니다. 잠시 쉬어 가세요.`),
+        iconType: 'info'
+      });
+    }
+  } catch (error) {
+    debugLog('피로도 확인 오류:', error);
+  }
+}
+
+/**
+ * 시스템 절전 진입 처리
+ * 절전 직전까지의 피로도 구간을 기록해 두고 절전 시작 시각을 저장합니다.
+ */
+function handleSystemSuspend() {
+  suspendedAt = Date.now();
+  
+  if (appState.isTracking) {
+    checkFatigue();
+  }
+  
+  debugLog('시스템 절전 진입');
+}
+
+/**
+ * 시스템 절전 해제 처리
+ * 절전 시간은 유휴 시간이 아니라 세션의 절전 구간으로 기록하고 타이핑 시간에서 제외합니다.
+ * 절전 중 놓친 주기 작업(키보드 레이아웃 확인, 통계 전송)은 바로 실행합니다.
+ * @returns {number} 절전 시간 (ms)
+ */
+function handleSystemResume() {
+  const now = Date.now();
+  const sleptMs = suspendedAt ? Math.max(0, now - suspendedAt) : 0;
+  const stats = appState.currentStats;
+  
+  if (sleptMs > 0 && appState.isTracking && stats.startTime) {
+    // 타이핑 시간은 시작 시각 기준이므로 절전 시간만큼 시작 시각을 뒤로 이동
+    stats.startTime = Math.min(now, stats.startTime + sleptMs);
+    stats.sleepGaps = [...(stats.sleepGaps || []), { start: suspendedAt, end: now, durationMs: sleptMs }]
+      .slice(-MAX_SLEEP_GAPS);
+    
+    // 마지막 입력 시각도 같이 이동 (유휴로 세션을 나눌 때 절전 시간이 타이핑 시간에서 빠지도록)
+    if (stats.lastActiveTime) {
+      stats.lastActiveTime = Math.min(now, stats.lastActiveTime + sleptMs);
+    }
+    
+    // 절전 시간은 피로도 계산에서 휴식으로만 반영
+    fatigueSample = { keyCount: stats.keyCount, time: now };
+    
+    checkKeyboardLayout();
+    updateAndSendStats();
+  }
+  
+  if (appState.mainWindow && !appState.mainWindow.isDestroyed()) {
+    appState.mainWindow.webContents.send('system-resumed', withAccessibleDescription({
+      suspendedAt,
+      resumedAt: now,
+      sleptMs
+    }, `시스템이 ${Math.round(sleptMs / 60000)}분 동안 절전 상태였습니다.`));
+  }
+  
+  debugLog('시스템 절전 해제:', { sleptMs });
+  suspendedAt = null;
+  return sleptMs;
+}
+
+/**
+ * 유휴 시간 확인 및 세션 일시 정지
+ * 운영체제의 마지막 입력 이후 시간(Windows GetLastInputInfo, macOS HIDIdleTime,
+ * Linux XScreenSaver - Electron powerMonitor 제공)이 설정값을 넘으면 진행 중인 세션을
+ * 마지막 입력 시각에 끝난 세션으로 저장하고, 다음 입력부터 새 세션을 시작합니다.
+ * 자동 재시작(resumeAfterIdle)이 꺼져 있으면 모니터링도 멈춥니다.
+ * @returns {boolean} 세션을 일시 정지했는지 여부
+ */
+function checkIdle() {
+  const settings = appState.settings || {};
+  const stats = appState.currentStats;
+  if (!appState.isTracking || settings.pauseOnIdle === false || !stats.startTime) return false;
+  
+  const idleTimeout = Math.max(MIN_IDLE_PAUSE_SECONDS, Number(settings.idleTimeout) || 0);
+  const idleSeconds = powerMonitor.getSystemIdleTime();
+  if (idleSeconds < idleTimeout) return false;
+  
+  const lastActive = stats.lastActiveTime || stats.startTime;
+  debugLog('유휴 상태 감지 - 세션 일시 정지:', { idleSeconds, keyCount: stats.keyCount });
+  
+  let saved = null;
+  if (stats.keyCount > 0) {
+    // 유휴 시간은 빼고 마지막 입력까지를 세션으로 저장
+    stats.typingTime = Math.max(0, Math.floor((lastActive - stats.startTime) / 1000));
+    checkFatigue();
+    saved = saveStats(null, { endReason: 'idle', endTime: lastActive });
+  } else {
+    resetStats();
+  }
+  
+  if (!settings.resumeAfterIdle) {
+    stopTracking();
+  }
+  
+  if (appState.mainWindow && !appState.mainWindow.isDestroyed()) {
+    appState.mainWindow.webContents.send('typing-session-idle', withAccessibleDescription({
+      idleSeconds,
+      lastActiveTime: lastActive,
+      savedSession: saved,
+      trackingStopped: !settings.resumeAfterIdle,
+      timestamp: Date.now()
+    }, `${Math.round(idleSeconds / 60)}분 동안 입력이 없어 타이핑 세션을 일시 정지했습니다.`));
+  }
+  
+  updateAndSendStats();
+  return true;
+}
+
 /**
  * 워커를 사용하여 통계 계산
  * 메모리 최적화: CPU 집약적 작업을 별도 스레드로 분리
@@ -610,7 +863,8 @@ function updateAndSendStats() {
       totalChars: appState.currentStats.totalChars,
       totalWords: appState.currentStats.totalWords,
       pages: appState.currentStats.pages,
-      accuracy: appState.currentStats.accuracy
+      accuracy: appState.currentStats.accuracy,
+      keyboardLayout: appState.currentStats.keyboardLayout
     });
   }
   
@@ -693,36 +947,43 @@ function analyzeTypingPattern() {
 /**
  * 통계 저장 처리
  * @param {string} content - 저장할 내용 설명
+ * @param {Object} [options]
+ * @param {string} [options.endReason] - 세션 종료 이유 ('manual' 또는 'idle')
+ * @param {number} [options.endTime] - 세션 종료 시각 (기본값: 현재)
  * @returns {object} 저장된 통계 데이터
  */
-function saveStats(content) {
-  if (!appState.mainWindow) return null;
-  
+function saveStats(content, { endReason = 'manual', endTime = Date.now() } = {}) {
   try {
-    // 메모리 최적화: 필요한 데이터만 포함
+    // 진행 중인 레이아웃 구간 마감
+    closeLayoutSegment(endTime);
+    
+    // 메모리 최적화: 필요한 데이터만 포함 (데이터베이스 saveStats 형식)
     const stats = {
       content,
-      key_count: appState.currentStats.keyCount,
-      typing_time: appState.currentStats.typingTime,
-      timestamp: new Date().toISOString(),
-      window_title: appState.currentStats.currentWindow,
-      browser_name: appState.currentStats.currentBrowser,
-      total_chars: appState.currentStats.totalChars,
-      total_words: appState.currentStats.totalWords,
+      keyCount: appState.currentStats.keyCount,
+      typingTime: appState.currentStats.typingTime,
+      timestamp: new Date(endTime).toISOString(),
+      windowTitle: appState.currentStats.currentWindow,
+      browserName: appState.currentStats.currentBrowser,
+      totalChars: appState.currentStats.totalChars,
+      totalWords: appState.currentStats.totalWords,
       pages: appState.currentStats.pages,
-      accuracy: appState.currentStats.accuracy
+      accuracy: appState.currentStats.accuracy,
+      endReason,
+      keyboardLayout: appState.currentStats.keyboardLayout?.layout || null,
+      layoutSegments: appState.currentStats.layoutSegments.slice()
     };
     
     debugLog('저장할 통계 데이터:', stats);
     
-    // SQLite 데이터베이스에 저장
-    const savedId = saveStatsToDb(stats);
+    // SQLite 데이터베이스에 저장 (저장된 기록 반환)
+    const saved = saveStatsToDb(stats);
     
     // 메모리 사용량 최적화를 위한 통계 초기화
     resetStats();
     
     // 저장된 데이터 반환
-    return getStatById(savedId) || stats;
+    return saved || stats;
   } catch (error) {
     console.error('통계 저장 중 오류:', error);
     
@@ -748,8 +1009,11 @@ function resetStats() {
   stats.totalCharsNoSpace = 0;
   stats.pages = 0;
   stats.accuracy = 100;
+  stats.layoutSegments = [];
+  stats.sleepGaps = [];
+  layoutSegmentStart = stats.keyboardLayout ? { keyCount: 0, time: Date.now() } : null;
   
-  // 창 정보는 유지 (불필요한 문자열 재생성 방지)
+  // 창 정보와 키보드 레이아웃은 유지 (불필요한 문자열 재생성 방지)
   debugLog('통계 초기화 완료');
   
   // 메모리 정리
@@ -769,6 +1033,23 @@ function startTracking() {
   // 워커 초기화
   initializeWorker();
   
+  // 키보드 레이아웃 변경 감시
+  checkKeyboardLayout();
+  if (!layoutCheckTimer) {
+    layoutCheckTimer = setInterval(checkKeyboardLayout, LAYOUT_CHECK_INTERVAL);
+  }
+  
+  // 피로도 추적 (모니터링을 멈춘 동안은 휴식으로 계산됨)
+  fatigueSample = { keyCount: appState.currentStats.keyCount, time: Date.now() };
+  if (!fatigueCheckTimer) {
+    fatigueCheckTimer = setInterval(checkFatigue, FATIGUE_CHECK_INTERVAL);
+  }
+  
+  // 유휴 시간 감시 (입력이 없으면 세션 일시 정지)
+  if (!idleCheckTimer) {
+    idleCheckTimer = setInterval(checkIdle, IDLE_CHECK_INTERVAL);
+  }
+  
   debugLog('타이핑 모니터링 시작됨');
   return true;
 }
@@ -780,6 +1061,25 @@ function startTracking() {
 function stopTracking() {
   appState.isTracking = false;
   
+  // 키보드 레이아웃 감시 중지
+  if (layoutCheckTimer) {
+    clearInterval(layoutCheckTimer);
+    layoutCheckTimer = null;
+  }
+  
+  // 피로도 추적 중지 (마지막 구간 기록)
+  if (fatigueCheckTimer) {
+    checkFatigue();
+    clearInterval(fatigueCheckTimer);
+    fatigueCheckTimer = null;
+  }
+  
+  // 유휴 시간 감시 중지
+  if (idleCheckTimer) {
+    clearInterval(idleCheckTimer);
+    idleCheckTimer = null;
+  }
+  
   // 워커 종료 (메모리 해제)
   if (statWorker) {
     statWorker.terminate();
@@ -800,6 +1100,16 @@ function cleanup() {
     statWorker = null;
   }
   
+  if (layoutCheckTimer) {
+    clearInterval(layoutCheckTimer);
+    layoutCheckTimer = null;
+  }
+  
+  if (fatigueCheckTimer) {
+    clearInterval(fatigueCheckTimer);
+    fatigueCheckTimer = null;
+  }
+  
   // 대기 작업 정리
   pendingTasks = [];
   workerInitialized = false;
@@ -816,6 +1126,9 @@ module.exports = {
   startTracking,
   stopTracking,
   cleanup,
+  handleSystemSuspend,
+  handleSystemResume,
+  checkIdle,
   initializeWorker,
   analyzeTypingPattern,
   optimizeWorkerMemory,
@@ -1,7 +1,7 @@
 const { Tray, Menu, app, nativeImage } = require('electron');
 const path = require('path');
 const { appState } = require('./constants');
-const { debugLog } = require('./utils');
+const { debugLog, accessibleMenuTemplate, formatNumber } = require('./utils');
 
 let tray = null;
 let currentStatsTab = 'typing'; // 현재 선택된 통계 탭: 'typing', 'document', 'accuracy'
@@ -155,24 +155,24 @@ function updateTrayMenu() {
   switch (currentStatsTab) {
     case 'typing':
       currentTabStats = [
-        { label: `타자 수: ${stats.keyCount.toLocaleString()}` },
+        { label: `타자 수: ${formatNumber(stats.keyCount)}` },
         { label: `타이핑 시간: ${formatTime(stats.typingTime)}` },
-        { label: `평균 속도: ${getTypingSpeed(stats.keyCount, stats.typingTime)} 타/분` }
+        { label: `평균 속도: ${formatNumber(getTypingSpeed(stats.keyCount, stats.typingTime))} 타/분` }
       ];
       break;
       
     case 'document':
       currentTabStats = [
-        { label: `단어 수: ${(stats.totalWords || 0).toLocaleString()}` },
-        { label: `글자 수: ${(stats.totalChars || 0).toLocaleString()}` },
-        { label: `페이지 수: ${(stats.pages || 0).toFixed(1)}` }
+        { label: `단어 수: ${formatNumber(stats.totalWords || 0)}` },
+        { label: `글자 수: ${formatNumber(stats.totalChars || 0)}` },
+        { label: `페이지 수: ${formatNumber(stats.pages || 0, { minimumFractionDigits: 1, maximumFractionDigits: 1 })}` }
       ];
       break;
       
     case 'accuracy':
       currentTabStats = [
         { label: `정확도: ${stats.accuracy || 100}%` },
-        { label: `공백 제외 글자 수: ${(stats.totalCharsNoSpace || 0).toLocaleString()}` }
+        { label: `공백 제외 글자 수: ${formatNumber(stats.totalCharsNoSpace || 0)}` }
       ];
       break;
   }
@@ -192,7 +192,7 @@ function updateTrayMenu() {
       }
     } : null;
   
-  const contextMenu = Menu.buildFromTemplate([
+  const contextMenu = Menu.buildFromTemplate(accessibleMenuTemplate([
     {
       label: `${isTracking ? '✅ 모니터링 중' : '⏸️ 모니터링 중지됨'}`,
       enabled: false
@@ -258,7 +258,7 @@ function updateTrayMenu() {
         app.quit();
       }
     }
-  ]);
+  ]));
   
   tray.setContextMenu(contextMenu);
   
@@ -282,7 +282,7 @@ function updateTrayMenu() {
   const tooltipPrefix = isTracking ? '타이핑 통계 앱 - 모니터링 중' : '타이핑 통계 앱 - 비활성 상태';
   const speed = getTypingSpeed(stats.keyCount, stats.typingTime);
   const tooltipText = isTracking ? 
-    `${tooltipPrefix}\n타자 수: ${stats.keyCount} (${speed} 타/분)` : 
+    `${tooltipPrefix}\n타자 수: ${formatNumber(stats.keyCount)} (${formatNumber(speed)} 타/분)` : 
     tooltipPrefix;
   tray.setToolTip(tooltipText);
 }
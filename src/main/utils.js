@@ -1,4 +1,4 @@
-const { isDev } = require('./constants.js');
+const { isDev, appState } = require('./constants.js');
 const http = require('http');
 
 /**
@@ -28,6 +28,49 @@ function formatTime(seconds) {
     .join(':');
 }
 
+/**
+ * 분당 단어 수 계산 (표준 단어 길이 5타 기준: (키 수 / 5) / 분 단위 시간)
+ * @param {number} keyCount - 키 입력 수
+ * @param {number} typingTimeSec - 입력 시간 (초)
+ * @returns {number} 분당 단어 수 (입력 시간이 없으면 0)
+ */
+function calculateWpm(keyCount, typingTimeSec) {
+  if (!(typingTimeSec > 0)) return 0;
+  return ((keyCount || 0) / 5) / (typingTimeSec / 60);
+}
+
+/**
+ * 설정한 로케일에 맞춰 숫자 형식화
+ * @param {number} value - 형식화할 숫자
+ * @param {Intl.NumberFormatOptions} [options] - Intl.NumberFormat 옵션
+ * @returns {string}
+ */
+function formatNumber(value, options = {}) {
+  const number = Number.isFinite(value) ? value : 0;
+  try {
+    return new Intl.NumberFormat(appState.settings?.locale || undefined, options).format(number);
+  } catch (error) {
+    // 잘못된 로케일이면 시스템 기본값 사용
+    return new Intl.NumberFormat(undefined, options).format(number);
+  }
+}
+
+/**
+ * 설정한 로케일에 맞춰 날짜 형식화
+ * @param {Date|string|number} value - 형식화할 날짜
+ * @param {Intl.DateTimeFormatOptions} [options] - Intl.DateTimeFormat 옵션
+ * @returns {string}
+ */
+function formatDate(value, options = { dateStyle: 'medium' }) {
+  const date = value instanceof Date ? value : new Date(value);
+  if (isNaN(date.getTime())) return '';
+  try {
+    return new Intl.DateTimeFormat(appState.settings?.locale || undefined, options).format(date);
+  } catch (error) {
+    return new Intl.DateTimeFormat(undefined, options).format(date);
+  }
+}
+
 /**
  * 안전하게 모듈 로드
  * @param {string} modulePath - 로드할 모듈 경로
@@ -125,11 +168,71 @@ function logMemoryUsage() {
 }
 
 // 모듈 내보내기
+// 이모지 및 이모지 변형 선택자/결합 문자
+const EMOJI_PATTERN = /[\u{1F000}-\u{1FAFF}\u{2300}-\u{23FF}\u{25A0}-\u{25FF}\u{2600}-\u{27BF}\u{2B00}-\u{2BFF}\u{FE0F}\u{200D}\u{20E3}]/gu;
+
+/**
+ * 접근성 모드 활성화 여부
+ * @returns {boolean}
+ */
+function isAccessibilityMode() {
+  return Boolean(appState.settings?.accessibilityMode);
+}
+
+/**
+ * 화면 낭독기 친화적인 텍스트로 변환
+ * 접근성 모드에서는 이모지를 제거하고 공백을 정리합니다. 이모지만 있는 문자열이면 대체 설명을 사용합니다.
+ * @param {string} text - 원본 텍스트
+ * @param {string} [fallback] - 이모지를 제거한 뒤 남는 글자가 없을 때 사용할 설명
+ * @returns {string}
+ */
+function accessibleText(text, fallback = '') {
+  if (!isAccessibilityMode() || typeof text !== 'string') return text;
+
+  const plain = text.replace(EMOJI_PATTERN, '').replace(/\s+/g, ' ').trim();
+  return plain || fallback;
+}
+
+/**
+ * 이벤트 페이로드에 평문 설명 추가
+ * 접근성 모드에서만 description 필드를 추가하며, 색상이나 아이콘만으로 의미를 전달하지 않도록
+ * 모든 렌더러 이벤트는 이 함수를 거쳐 전송합니다.
+ * @param {Object} payload - 원본 페이로드
+ * @param {string} description - 상태를 설명하는 평문 문장
+ * @returns {Object}
+ */
+function withAccessibleDescription(payload, description) {
+  if (!isAccessibilityMode()) return payload;
+  return { ...payload, description: accessibleText(description) };
+}
+
+/**
+ * 메뉴 템플릿의 라벨을 접근성 모드에 맞게 변환 (하위 메뉴 포함)
+ * @param {Array} template - Menu.buildFromTemplate에 전달할 템플릿
+ * @returns {Array}
+ */
+function accessibleMenuTemplate(template) {
+  if (!isAccessibilityMode()) return template;
+
+  return template.map(item => ({
+    ...item,
+    ...(item.label !== undefined ? { label: accessibleText(item.label) } : {}),
+    ...(Array.isArray(item.submenu) ? { submenu: accessibleMenuTemplate(item.submenu) } : {})
+  }));
+}
+
 module.exports = {
   debugLog,
   formatTime,
+  calculateWpm,
+  formatNumber,
+  formatDate,
   safeRequire,
   isServerRunning,
   waitForServer,
-  logMemoryUsage
+  logMemoryUsage,
+  isAccessibilityMode,
+  accessibleText,
+  withAccessibleDescription,
+  accessibleMenuTemplate
 };
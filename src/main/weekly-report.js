@@ -0,0 +1,370 @@
+/**
+ * 주간 작성 보고서 모듈
+ * 한 주의 작성 통계(단어 수, 상위 문서, 평균 WPM, 피로도 추세)를 Markdown 또는 HTML 보고서로
+ * 저장하고, 설정한 경우 SMTP로 메일을 보냅니다.
+ */
+const fs = require('fs');
+const path = require('path');
+const { app } = require('electron');
+const { appState } = require('./constants');
+const { debugLog, formatNumber, calculateWpm } = require('./utils');
+const { getReportStats, getFatigueDaily } = require('./database');
+const { saveSecureSetting, loadSecureSetting } = require('./secure-setting');
+const { sendMail } = require('./smtp');
+
+const REPORT_FORMATS = ['markdown', 'html'];
+
+// SMTP 비밀번호 저장 키 (settings 테이블)
+const SMTP_PASSWORD_KEY = 'report_smtp_password';
+
+// 상위 문서 수
+const TOP_DOCUMENT_LIMIT = 5;
+
+// 피로도 추세를 '유지'로 보는 평균 최고 피로도 차이
+const FATIGUE_TREND_TOLERANCE = 5;
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+/**
+ * 현지 날짜 문자열
+ * @param {Date} date
+ * @returns {string} YYYY-MM-DD
+ */
+function localDateString(date) {
+  const pad = (value) => String(value).padStart(2, '0');
+  return `${date.getFullYear()}-${pad(date.getMonth() + 1)}-${pad(date.getDate())}`;
+}
+
+// 현지 날짜에 일 수 더하기 (서머타임과 무관하게 달력 기준)
+function addDays(date, days) {
+  return new Date(date.getFullYear(), date.getMonth(), date.getDate() + days);
+}
+
+/**
+ * 보고서 대상 주 계산 (설정의 주 시작 요일 기준)
+ * @param {string} [week] - 주에 포함된 날짜 (YYYY-MM-DD), 생략하면 지난주
+ * @returns {{ start: string, end: string }} 시작일(포함)과 끝 날짜(제외)
+ */
+function resolveWeek(week) {
+  let day;
+  if (week) {
+    const match = /^(\d{4})-(\d{2})-(\d{2})$/.exec(week);
+    day = match ? new Date(Number(match[1]), Number(match[2]) - 1, Number(match[3])) : null;
+    if (!day || isNaN(day.getTime())) {
+      throw new Error(`잘못된 주 지정입니다 (YYYY-MM-DD 형식 날짜 필요): ${week}`);
+    }
+  } else {
+    day = new Date(Date.now() - 7 * DAY_MS);
+  }
+
+  const firstDay = Number(appState.settings?.firstDayOfWeek ?? 1);
+  const start = addDays(day, -((day.getDay() - firstDay + 7) % 7));
+  return { start: localDateString(start), end: localDateString(addDays(start, 7)) };
+}
+
+// 평균 최고 피로도
+function averagePeak(rows) {
+  if (rows.length === 0) return null;
+  return rows.reduce((sum, row) => sum + row.peak_score, 0) / rows.length;
+}
+
+/**
+ * 보고서 데이터 수집
+ * @param {{ start: string, end: string }} period - 대상 주
+ * @returns {Object} 보고서 모델
+ */
+function collectReport(period) {
+  const { totals, days, documents } = getReportStats(period.start, period.end, TOP_DOCUMENT_LIMIT);
+
+  const previousStart = localDateString(addDays(new Date(`${period.start}T00:00:00`), -7));
+  const fatigueDays = getFatigueDaily(period.start, period.end);
+  const current = averagePeak(fatigueDays);
+  const previous = averagePeak(getFatigueDaily(previousStart, period.start));
+
+  let trend = null;
+  if (current !== null && previous !== null) {
+    const diff = current - previous;
+    trend = Math.abs(diff) < FATIGUE_TREND_TOLERANCE ? 'steady' : diff > 0 ? 'rising' : 'falling';
+  }
+
+  const minutes = (seconds) => Math.round((seconds || 0) / 6) / 10;
+  const wpm = (keyCount, seconds) => Math.round(calculateWpm(keyCount, seconds) * 10) / 10;
+
+  return {
+    period,
+    generatedAt: new Date().toISOString(),
+    totals: {
+      sessions: totals.sessions,
+      words: totals.total_words,
+      keyCount: totals.key_count,
+      typingMinutes: minutes(totals.typing_time),
+      averageWpm: wpm(totals.key_count, totals.typing_time),
+      averageAccuracy: Math.round(totals.accuracy * 10) / 10
+    },
+    days: days.map(row => ({
+      date: row.date,
+      sessions: row.sessions,
+      words: row.total_words,
+      typingMinutes: minutes(row.typing_time),
+      wpm: wpm(row.key_count, row.typing_time)
+    })),
+    topDocuments: documents.map(row => ({
+      title: row.title,
+      sessions: row.sessions,
+      words: row.total_words,
+      typingMinutes: minutes(row.typing_time)
+    })),
+    fatigue: {
+      days: fatigueDays.map(row => ({
+        date: row.date,
+        peakScore: Math.round(row.peak_score),
+        breakCount: row.break_count
+      })),
+      averagePeak: current === null ? null : Math.round(current),
+      previousAveragePeak: previous === null ? null : Math.round(previous),
+      trend
+    }
+  };
+}
+
+const TREND_LABELS = {
+  rising: '지난주보다 높아짐',
+  falling: '지난주보다 낮아짐',
+  steady: '지난주와 비슷함'
+};
+
+// 보고서 기간 표시 (끝 날짜는 마지막 날로 표시)
+function formatPeriod(period) {
+  return `${period.start} ~ ${localDateString(addDays(new Date(`${period.end}T00:00:00`), -1))}`;
+}
+
+// 피로도 요약 문장
+function describeFatigue(fatigue) {
+  if (fatigue.averagePeak === null) return '피로도 기록이 없습니다.';
+  const trend = fatigue.trend ? ` (${TREND_LABELS[fatigue.trend]})` : '';
+  return `일 평균 최고 피로도 ${fatigue.averagePeak}점${trend}`;
+}
+
+// Markdown 표 셀 이스케이프
+function escapeMarkdownCell(value) {
+  return String(value).replace(/\|/g, '\\|').replace(/\s+/g, ' ');
+}
+
+/**
+ * Markdown 보고서
+ * @param {Object} report - collectReport 결과
+ * @returns {string}
+ */
+function renderMarkdown(report) {
+  const { totals } = report;
+  const lines = [
+    `# 주간 작성 보고서 (${formatPeriod(report.period)})`,
+    '',
+    '## 요약',
+    '',
+    `- 작성한 단어: ${formatNumber(totals.words)}`,
+    `- 타이핑 시간: ${formatNumber(totals.typingMinutes)}분 (${formatNumber(totals.sessions)}개 세션)`,
+    `- 평균 속도: ${totals.averageWpm} WPM`,
+    `- 평균 정확도: ${totals.averageAccuracy}%`,
+    `- 피로도: ${describeFatigue(report.fatigue)}`,
+    '',
+    '## 상위 문서',
+    ''
+  ];
+
+  if (report.topDocuments.length === 0) {
+    lines.push('기록된 문서가 없습니다.');
+  } else {
+    lines.push('| 문서 | 단어 | 시간(분) | 세션 |', '| --- | ---: | ---: | ---: |');
+    for (const doc of report.topDocuments) {
+      lines.push(`| ${escapeMarkdownCell(doc.title)} | ${formatNumber(doc.words)} | ${doc.typingMinutes} | ${doc.sessions} |`);
+    }
+  }
+
+  lines.push('', '## 일별 기록', '');
+  if (report.days.length === 0) {
+    lines.push('이번 주 기록이 없습니다.');
+  } else {
+    const fatigueByDate = new Map(report.fatigue.days.map(day => [day.date, day]));
+    lines.push('| 날짜 | 단어 | 시간(분) | WPM | 최고 피로도 | 휴식 권장 |', '| --- | ---: | ---: | ---: | ---: | ---: |');
+    for (const day of report.days) {
+      const fatigue = fatigueByDate.get(day.date);
+      lines.push(`| ${day.date} | ${formatNumber(day.words)} | ${day.typingMinutes} | ${day.wpm} | ${fatigue ? fatigue.peakScore : '-'} | ${fatigue ? fatigue.breakCount : '-'} |`);
+    }
+  }
+
+  lines.push('', `_생성 시각: ${report.generatedAt}_`, '');
+  return lines.join('\n');
+}
+
+// HTML 특수 문자 이스케이프
+function escapeHtml(value) {
+  return String(value)
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;')
+    .replace(/'/g, '&#39;');
+}
+
+// HTML 표
+function htmlTable(headers, rows) {
+  const head = headers.map(header => `<th>${escapeHtml(header)}</th>`).join('');
+  const body = rows
+    .map(row => `<tr>${row.map(cell => `<td>${escapeHtml(cell)}</td>`).join('')}</tr>`)
+    .join('\n');
+  return `<table>\n<thead><tr>${head}</tr></thead>\n<tbody>\n${body}\n</tbody>\n</table>`;
+}
+
+/**
+ * HTML 보고서
+ * @param {Object} report - collectReport 결과
+ * @returns {string}
+ */
+function renderHtml(report) {
+  const { totals } = report;
+  const title = `주간 작성 보고서 (${formatPeriod(report.period)})`;
+  const fatigueByDate = new Map(report.fatigue.days.map(day => [day.date, day]));
+
+  const documents = report.topDocuments.length === 0
+    ? '<p>기록된 문서가 없습니다.</p>'
+    : htmlTable(['문서', '단어', '시간(분)', '세션'], report.topDocuments.map(doc => [
+      doc.title, formatNumber(doc.words), doc.typingMinutes, doc.sessions
+    ]));
+
+  const days = report.days.length === 0
+    ? '<p>이번 주 기록이 없습니다.</p>'
+    : htmlTable(['날짜', '단어', '시간(분)', 'WPM', '최고 피로도', '휴식 권장'], report.days.map(day => {
+      const fatigue = fatigueByDate.get(day.date);
+      return [day.date, formatNumber(day.words), day.typingMinutes, day.wpm,
+        fatigue ? fatigue.peakScore : '-', fatigue ? fatigue.breakCount : '-'];
+    }));
+
+  return `<!DOCTYPE html>
+<html lang="ko">
+<head>
+<meta charset="utf-8">
+<title>${escapeHtml(title)}</title>
+<style>
+body { font-family: system-ui, sans-serif; max-width: 720px; margin: 2em auto; color: #222; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border: 1px solid #ddd; padding: 4px 8px; text-align: right; }
+th:first-child, td:first-child { text-align: left; }
+</style>
+</head>
+<body>
+<h1>${escapeHtml(title)}</h1>
+<h2>요약</h2>
+<ul>
+<li>작성한 단어: ${escapeHtml(formatNumber(totals.words))}</li>
+<li>타이핑 시간: ${escapeHtml(formatNumber(totals.typingMinutes))}분 (${escapeHtml(formatNumber(totals.sessions))}개 세션)</li>
+<li>평균 속도: ${totals.averageWpm} WPM</li>
+<li>평균 정확도: ${totals.averageAccuracy}%</li>
+<li>피로도: ${escapeHtml(describeFatigue(report.fatigue))}</li>
+</ul>
+<h2>상위 문서</h2>
+${documents}
+<h2>일별 기록</h2>
+${days}
+<p><small>생성 시각: ${escapeHtml(report.generatedAt)}</small></p>
+</body>
+</html>
+`;
+}
+
+/**
+ * 보고서 저장 폴더
+ * @returns {string} 설정된 폴더 또는 userData 아래 기본 폴더
+ */
+function getReportDirectory() {
+  return appState.settings?.reportDirectory || path.join(app.getPath('userData'), 'reports');
+}
+
+/**
+ * SMTP 비밀번호 저장 (빈 값이면 삭제)
+ * OS 보안 저장소를 사용할 수 있으면 암호화해서 저장합니다.
+ * @param {string} password - 비밀번호
+ * @returns {boolean} 저장 성공 여부
+ */
+function setSmtpPassword(password) {
+  return saveSecureSetting(SMTP_PASSWORD_KEY, password);
+}
+
+/**
+ * 보고서를 설정한 주소로 메일 발송
+ * @param {Object} report - collectReport 결과
+ * @param {string} markdown - Markdown 본문 (텍스트 대체 본문)
+ * @param {string} html - HTML 본문
+ * @returns {Promise<Object>} sendMail 결과
+ */
+function emailReport(report, markdown, html) {
+  const settings = appState.settings || {};
+  const to = String(settings.reportEmailTo || '').split(',').map(value => value.trim()).filter(Boolean);
+
+  return sendMail({
+    host: settings.reportSmtpHost,
+    port: settings.reportSmtpPort,
+    secure: Boolean(settings.reportSmtpSecure),
+    user: settings.reportSmtpUser,
+    pass: loadSecureSetting(SMTP_PASSWORD_KEY),
+    from: settings.reportEmailFrom || settings.reportSmtpUser,
+    to,
+    subject: `주간 작성 보고서 (${formatPeriod(report.period)})`,
+    text: markdown,
+    html
+  });
+}
+
+/**
+ * 주간 보고서 생성
+ * @param {string} [week] - 주에 포함된 날짜 (YYYY-MM-DD), 생략하면 지난주
+ * @param {Object} [options]
+ * @param {string} [options.format] - 'markdown' 또는 'html'
+ * @param {boolean} [options.email] - 메일 발송 여부 (기본값: reportEmailEnabled 설정)
+ * @returns {Promise<Object>} { success, filePath, week, emailed, emailError?, error? }
+ */
+async function generateWeeklyReport(week, { format = 'markdown', email } = {}) {
+  if (!REPORT_FORMATS.includes(format)) {
+    return { success: false, error: `지원하지 않는 보고서 형식입니다: ${format}` };
+  }
+
+  let period;
+  try {
+    period = resolveWeek(week);
+  } catch (error) {
+    return { success: false, error: error.message };
+  }
+
+  const report = collectReport(period);
+  const markdown = renderMarkdown(report);
+  const html = renderHtml(report);
+
+  const directory = getReportDirectory();
+  const filePath = path.join(directory, `weekly-report-${period.start}.${format === 'html' ? 'html' : 'md'}`);
+  fs.mkdirSync(directory, { recursive: true });
+  fs.writeFileSync(filePath, format === 'html' ? html : markdown, 'utf8');
+  debugLog(`주간 보고서 저장: ${filePath}`);
+
+  const result = { success: true, filePath, week: period, totals: report.totals, emailed: false };
+
+  if (email ?? appState.settings?.reportEmailEnabled) {
+    try {
+      await emailReport(report, markdown, html);
+      result.emailed = true;
+      debugLog('주간 보고서 메일 발송 완료');
+    } catch (error) {
+      console.error('주간 보고서 메일 발송 오류:', error);
+      result.emailError = error.message;
+    }
+  }
+
+  return result;
+}
+
+module.exports = {
+  generateWeeklyReport,
+  setSmtpPassword,
+  resolveWeek,
+  renderMarkdown,
+  renderHtml
+};
@@ -4,8 +4,9 @@ const fs = require('fs');
 const url = require('url');
 const { appState, isDev } = require('./constants');
 const { applyWindowMode, loadSettings } = require('./settings');
-const { debugLog } = require('./utils');
+const { debugLog, accessibleText } = require('./utils');
 const { setupTray } = require('./tray');
+const { isCaptureOnlyLaunch } = require('./autostart');
 
 /**
  * 메인 윈도우 생성 함수
@@ -242,17 +243,20 @@ async function createWindow(showErrorScreen = false) {
     // 리소스 존재 여부 확인 및 누락된 리소스 생성
     ensureRequiredResources();
 
-    // 윈도우 준비되면 표시
+    // 윈도우 준비되면 표시 (기록 전용 모드에서는 트레이에서 열 때까지 숨김)
     mainWindow.once('ready-to-show', () => {
-      mainWindow.show();
+      const captureOnly = isCaptureOnlyLaunch();
+      if (!captureOnly) {
+        mainWindow.show();
 
-      // 개발 모드에서만 개발자 도구 열기
-      if (isDev) {
-        mainWindow.webContents.openDevTools();
+        // 개발 모드에서만 개발자 도구 열기
+        if (isDev) {
+          mainWindow.webContents.openDevTools();
+        }
       }
 
-      // 앱 상태에 따라 트레이 아이콘 생성
-      if (appState.settings?.minimizeToTray) {
+      // 앱 상태에 따라 트레이 아이콘 생성 (기록 전용 모드에서는 창을 열 수 있도록 항상 생성)
+      if (appState.settings?.minimizeToTray || captureOnly) {
         setupTray();
       }
 
@@ -270,7 +274,7 @@ async function createWindow(showErrorScreen = false) {
         if (appState.settings.showTrayNotifications && appState.tray) {
           appState.tray.displayBalloon({
             title: 'Loop',
-            content: '앱이 트레이로 최소화되었습니다. 계속 모니터링 중입니다.',
+            content: accessibleText('앱이 트레이로 최소화되었습니다. 계속 모니터링 중입니다.'),
             iconType: 'info'
           });
         }
@@ -218,15 +218,18 @@ function submitTask(taskType, data) {
           // 데이터 JSON 변환
           const dataString = typeof data === 'string' ? data : JSON.stringify(data);
           
-          // 네이티브 모듈에 작업 제출
-          const resultJson = nativeModule.submit_task(taskType, dataString);
-          const result = JSON.parse(resultJson);
-          
-          if (result.success) {
-            resolve(result.result ? JSON.parse(result.result) : result);
-          } else {
-            reject(new Error(result.error || '작업 실패'));
-          }
+          // 네이티브 모듈에 작업 제출 (워커 스레드에서 실행 후 Promise로 결과 반환)
+          Promise.resolve(nativeModule.submit_task(taskType, dataString))
+            .then((resultJson) => {
+              const result = JSON.parse(resultJson);
+
+              if (result.success) {
+                resolve(result.result ? JSON.parse(result.result) : result);
+              } else {
+                reject(new Error(result.error || '작업 실패'));
+              }
+            })
+            .catch(reject);
         } catch (error) {
           reject(error);
         }
@@ -0,0 +1,40 @@
+/**
+ * 읽기 전용 SQL 콘솔 쿼리 워커
+ * 메인 프로세스와 별도의 읽기 전용 연결에서 쿼리를 실행하며,
+ * 시간 제한에 걸리면 메인 프로세스가 이 워커를 종료합니다.
+ */
+const { parentPort, workerData } = require('worker_threads');
+const BetterSqlite3 = require('better-sqlite3');
+
+const { dbPath, sql, rowLimit } = workerData;
+let readonlyDb = null;
+
+try {
+  readonlyDb = new BetterSqlite3(dbPath, { readonly: true, fileMustExist: true });
+
+  // 여러 문장이 포함되어 있으면 prepare 단계에서 오류 발생
+  const stmt = readonlyDb.prepare(sql);
+  if (!stmt.readonly || !stmt.reader) {
+    parentPort.postMessage({ success: false, error: '데이터를 변경하지 않는 조회 쿼리만 실행할 수 있습니다' });
+  } else {
+    const columns = stmt.columns().map(column => column.name);
+    const rows = [];
+    let truncated = false;
+
+    for (const row of stmt.iterate()) {
+      if (rows.length >= rowLimit) {
+        truncated = true;
+        break;
+      }
+      rows.push(row);
+    }
+
+    parentPort.postMessage({ success: true, columns, rows, truncated });
+  }
+} catch (error) {
+  parentPort.postMessage({ success: false, error: error.message || String(error) });
+} finally {
+  if (readonlyDb) {
+    readonlyDb.close();
+  }
+}
@@ -259,10 +259,10 @@ if (parentPort) {
           // 네이티브 통계 계산 시도
           let result = null;
           
-          if (nativeModule && typeof nativeModule.submit_task === 'function') {
-            // 네이티브 워커 시스템에 작업 제출
+          if (nativeModule && typeof nativeModule.submit_task_sync === 'function') {
+            // 네이티브 워커 시스템에 작업 제출 (동기 버전)
             const taskData = JSON.stringify(dataCache);
-            const resultJson = nativeModule.submit_task('typing_stats', taskData);
+            const resultJson = nativeModule.submit_task_sync('typing_stats', taskData);
             result = JSON.parse(resultJson);
             
             if (result && result.success && result.result) {
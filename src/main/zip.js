@@ -0,0 +1,100 @@
+/**
+ * 간단한 ZIP 파일 작성 모듈
+ * 데이터 내보내기용으로 deflate 압축 항목만 지원합니다 (ZIP64 미지원, 항목당 4GB 미만).
+ */
+const zlib = require('zlib');
+
+// ZIP 구조 서명
+const LOCAL_FILE_HEADER = 0x04034b50;
+const CENTRAL_DIRECTORY_HEADER = 0x02014b50;
+const END_OF_CENTRAL_DIRECTORY = 0x06054b50;
+
+// 파일 이름을 UTF-8로 기록했음을 나타내는 플래그
+const FLAG_UTF8 = 0x0800;
+const METHOD_DEFLATE = 8;
+const VERSION = 20;
+const MAX_SIZE = 0xffffffff;
+
+/**
+ * MS-DOS 형식 날짜/시간 (ZIP은 1980년 이후 현지 시간만 표현 가능)
+ * @param {Date} date
+ * @returns {{ time: number, date: number }}
+ */
+function toDosDateTime(date) {
+  const year = Math.max(1980, date.getFullYear());
+  return {
+    time: (date.getHours() << 11) | (date.getMinutes() << 5) | Math.floor(date.getSeconds() / 2),
+    date: ((year - 1980) << 9) | ((date.getMonth() + 1) << 5) | date.getDate()
+  };
+}
+
+/**
+ * ZIP 파일 내용 만들기
+ * @param {Array<{ name: string, data: Buffer|string, mtime?: Date }>} entries - 항목 (이름은 '/' 구분 상대 경로)
+ * @returns {Buffer} ZIP 파일 내용
+ */
+function createZip(entries) {
+  const chunks = [];
+  const central = [];
+  let offset = 0;
+
+  for (const entry of entries) {
+    const name = Buffer.from(entry.name.replace(/\\/g, '/'), 'utf8');
+    const data = Buffer.isBuffer(entry.data) ? entry.data : Buffer.from(String(entry.data), 'utf8');
+    if (data.length >= MAX_SIZE) {
+      throw new Error(`ZIP 항목이 너무 큽니다: ${entry.name}`);
+    }
+
+    const compressed = zlib.deflateRawSync(data);
+    const crc = zlib.crc32(data);
+    const { time, date } = toDosDateTime(entry.mtime || new Date());
+
+    const local = Buffer.alloc(30);
+    local.writeUInt32LE(LOCAL_FILE_HEADER, 0);
+    local.writeUInt16LE(VERSION, 4);
+    local.writeUInt16LE(FLAG_UTF8, 6);
+    local.writeUInt16LE(METHOD_DEFLATE, 8);
+    local.writeUInt16LE(time, 10);
+    local.writeUInt16LE(date, 12);
+    local.writeUInt32LE(crc, 14);
+    local.writeUInt32LE(compressed.length, 18);
+    local.writeUInt32LE(data.length, 22);
+    local.writeUInt16LE(name.length, 26);
+    local.writeUInt16LE(0, 28);
+
+    const header = Buffer.alloc(46);
+    header.writeUInt32LE(CENTRAL_DIRECTORY_HEADER, 0);
+    header.writeUInt16LE(VERSION, 4);
+    header.writeUInt16LE(VERSION, 6);
+    header.writeUInt16LE(FLAG_UTF8, 8);
+    header.writeUInt16LE(METHOD_DEFLATE, 10);
+    header.writeUInt16LE(time, 12);
+    header.writeUInt16LE(date, 14);
+    header.writeUInt32LE(crc, 16);
+    header.writeUInt32LE(compressed.length, 20);
+    header.writeUInt32LE(data.length, 24);
+    header.writeUInt16LE(name.length, 28);
+    header.writeUInt32LE(offset, 42);
+
+    chunks.push(local, name, compressed);
+    central.push(header, name);
+    offset += local.length + name.length + compressed.length;
+    if (offset >= MAX_SIZE) {
+      throw new Error('ZIP 파일이 너무 큽니다 (4GB 이상)');
+    }
+  }
+
+  const centralSize = central.reduce((sum, chunk) => sum + chunk.length, 0);
+  const end = Buffer.alloc(22);
+  end.writeUInt32LE(END_OF_CENTRAL_DIRECTORY, 0);
+  end.writeUInt16LE(entries.length, 8);
+  end.writeUInt16LE(entries.length, 10);
+  end.writeUInt32LE(centralSize, 12);
+  end.writeUInt32LE(offset, 16);
+
+  return Buffer.concat([...chunks, ...central, end]);
+}
+
+module.exports = {
+  createZip
+};
@@ -10,7 +10,10 @@ const os = require('os');
 const state = {
   startTime: Date.now(),
   callCount: 0,
-  gpuEnabled: false
+  gpuEnabled: false,
+  gpuSubsystemEnabled: true,
+  initializedAt: null,
+  warmUpReport: null
 };
 
 // 유틸리티 함수
@@ -235,14 +238,104 @@ function get_native_module_info() {
   return JSON.stringify(info);
 }
 
+/**
+ * 폴백 모듈의 초기화 보고서 (네이티브 모듈과 같은 형식)
+ * @param {number|null} initializedAt - 초기화 시각
+ * @returns {Object} 보고서
+ */
+function buildInitializationReport(initializedAt) {
+  const subsystem = (status, error, capabilities) => ({
+    status: initializedAt ? status : 'not_initialized',
+    error: initializedAt ? error : null,
+    capabilities: initializedAt ? capabilities : {},
+    duration_ms: 0
+  });
+
+  return {
+    initialized: initializedAt !== null,
+    ok: initializedAt !== null,
+    fallback: true,
+    initialized_at: initializedAt,
+    subsystems: {
+      gpu: state.gpuSubsystemEnabled
+        ? subsystem('unavailable', 'JavaScript 폴백 모듈에서는 GPU를 사용할 수 없습니다', { compute: false })
+        : subsystem('disabled', null, { compute: false }),
+      memory: subsystem('ok', null, {
+        memory_pools: false,
+        buffer_lending: false,
+        gc_scheduling: false,
+        process_memory_info: true
+      }),
+      worker: subsystem('unavailable', 'JavaScript 폴백 모듈에는 워커 풀이 없습니다', {})
+    },
+    timestamp: getCurrentTimestamp()
+  };
+}
+
 /**
  * 네이티브 모듈 초기화
- * @returns {boolean} 성공 여부
+ * @returns {string} JSON 형식의 초기화 보고서
  */
 function initialize_native_modules() {
   state.startTime = Date.now();
+  state.initializedAt = state.startTime;
   console.log('[JS-Fallback] JavaScript 폴백 모듈이 초기화되었습니다');
-  return true;
+  return JSON.stringify(buildInitializationReport(state.initializedAt));
+}
+
+/**
+ * 초기화 보고서 조회
+ * @returns {string} JSON 형식의 초기화 보고서
+ */
+function get_initialization_report() {
+  return JSON.stringify(buildInitializationReport(state.initializedAt));
+}
+
+/**
+ * GPU 하위 시스템 켜기/끄기 (폴백 모듈에는 GPU가 없으므로 보고서 상태만 바뀜)
+ * @param {boolean} enabled - 사용 여부
+ * @returns {string} JSON 형식의 결과
+ */
+function set_gpu_enabled(enabled) {
+  state.gpuSubsystemEnabled = Boolean(enabled);
+  return JSON.stringify({
+    success: true,
+    enabled: state.gpuSubsystemEnabled,
+    gpu: buildInitializationReport(state.initializedAt).subsystems.gpu,
+    active_gpu_tasks: 0,
+    timestamp: getCurrentTimestamp()
+  });
+}
+
+/**
+ * 네이티브 모듈 예열 (폴백 모듈에는 준비할 GPU 컨텍스트나 메모리 풀이 없으므로 바로 완료)
+ * @returns {Promise<string>} JSON 형식의 예열 보고서
+ */
+async function warm_up() {
+  const now = getCurrentTimestamp();
+  const skipped = reason => ({ status: 'skipped', error: null, detail: { reason }, duration_ms: 0 });
+  state.warmUpReport = state.warmUpReport || {
+    ok: true,
+    started_at: now,
+    completed_at: now,
+    duration_ms: 0,
+    steps: {
+      gpu: skipped('fallback'),
+      shaders: skipped('fallback'),
+      memory: skipped('fallback')
+    }
+  };
+  return JSON.stringify(state.warmUpReport);
+}
+
+/**
+ * 예열 진행 상태 조회
+ * @returns {string} JSON 형식의 상태
+ */
+function get_warm_up_status() {
+  return JSON.stringify(state.warmUpReport
+    ? { state: 'completed', report: state.warmUpReport }
+    : { state: 'not_started', report: null });
 }
 
 /**
@@ -250,6 +343,10 @@ function initialize_native_modules() {
  * @returns {boolean} 성공 여부
  */
 function cleanup_native_modules() {
+  state.initializedAt = null;
+  state.gpuEnabled = false;
+  state.gpuSubsystemEnabled = true;
+  state.warmUpReport = null;
   console.log('[JS-Fallback] JavaScript 폴백 모듈이 정리되었습니다');
   return true;
 }
@@ -260,6 +357,9 @@ module.exports = {
   get_native_module_version,
   get_native_module_info,
   initialize_native_modules,
+  get_initialization_report,
+  warm_up,
+  get_warm_up_status,
   cleanup_native_modules,
   
   // 메모리 관리 함수
@@ -275,6 +375,7 @@ module.exports = {
   disable_gpu_acceleration,
   get_gpu_info,
   perform_gpu_computation,
+  set_gpu_enabled,
   
   // 상태 확인
   is_native_module_available: () => false
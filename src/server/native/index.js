@@ -260,6 +260,34 @@ function updatePerformanceMetrics(funcName, executionTime) {
   // 함수별 성능 기록 (미구현)
 }
 
+// JS에서 등록한 작업 핸들러 (작업 유형 -> 핸들러, 네이티브 모듈이 없으면 폄백에서 직접 실행)
+const jsTaskHandlers = new Map();
+
+/**
+ * 등록한 JS 작업 핸들러 실행
+ * 작업 데이터는 JSON이면 파싱해서 전달하고, 결과가 문자열이 아니면 success가 포함된 JSON 문자열로 변환합니다.
+ * @param {Function} handler 작업 핸들러
+ * @param {string} data 작업 데이터 문자열
+ * @returns {Promise<string>} 결과 JSON 문자열
+ */
+async function runJsTaskHandler(handler, data) {
+  let input = data;
+  try {
+    input = JSON.parse(data);
+  } catch {
+    // JSON이 아니면 문자열 그대로 전달
+  }
+
+  const result = await handler(input);
+  if (typeof result === 'string') {
+    return result;
+  }
+  if (result && typeof result === 'object' && !Array.isArray(result)) {
+    return JSON.stringify({ success: true, ...result });
+  }
+  return JSON.stringify({ success: true, result: result ?? null });
+}
+
 /**
  * 기본 폄백 구현
  */
@@ -398,14 +426,37 @@ const fallbacks = {
     return true;
   },
 
-  submitTask: async (taskType, data) => ({
-    success: false,
-    task_type: taskType,
-    duration_ms: 0,
-    result: null,
-    error: '네이티브 워커 풀을 사용할 수 없습니다',
-    timestamp: Date.now()
-  }),
+  submitTask: async (taskType, data) => {
+    const handler = jsTaskHandlers.get(taskType);
+    if (handler) {
+      const startTime = Date.now();
+      try {
+        return {
+          ...JSON.parse(await runJsTaskHandler(handler, data)),
+          task_type: taskType,
+          execution_time_ms: Date.now() - startTime,
+          timestamp: Date.now()
+        };
+      } catch (error) {
+        return {
+          success: false,
+          task_type: taskType,
+          execution_time_ms: Date.now() - startTime,
+          error: error.message || String(error),
+          timestamp: Date.now()
+        };
+      }
+    }
+
+    return {
+      success: false,
+      task_type: taskType,
+      duration_ms: 0,
+      result: null,
+      error: '네이티브 워커 풀을 사용할 수 없습니다',
+      timestamp: Date.now()
+    };
+  },
 
   getWorkerPoolStats: () => ({
     thread_count: 1,
@@ -420,6 +471,35 @@ const fallbacks = {
     timestamp: Date.now()
   }),
 
+  // 초기화 보고서 폄백 (네이티브 모듈이 없으므로 GPU/워커는 사용 불가)
+  getInitializationReport: () => JSON.parse(
+    require('./fallback/index.js').get_initialization_report()
+  ),
+
+  setGpuEnabled: (enabled) => JSON.parse(
+    require('./fallback/index.js').set_gpu_enabled(enabled)
+  ),
+
+  warmUp: async () => JSON.parse(
+    await require('./fallback/index.js').warm_up()
+  ),
+
+  getWarmUpStatus: () => JSON.parse(
+    require('./fallback/index.js').get_warm_up_status()
+  ),
+
+  // 전원 상태 폄백 (전원 상태를 알 수 없으므로 AC 전원으로 간주)
+  getPowerState: () => ({
+    on_battery: false,
+    low_power_mode: false,
+    battery_percent: null,
+    source: 'unavailable',
+    policy: 'battery',
+    power_saving: false,
+    worker_thread_ratio: 1,
+    gpu_allowed: true
+  }),
+
   // 모듈 정보 관련 폄백
   getModuleInfo: () => ({
     name: 'typing-stats-native',
@@ -786,6 +866,38 @@ const nativeModuleApi = {
     null
   ),
 
+  /**
+   * 네이티브 모듈 초기화 보고서 가져오기
+   * 하위 시스템(gpu, memory, worker)별 상태(ok, disabled, unavailable, failed, not_initialized),
+   * 오류 메시지, 사용 가능한 기능을 담고 있어 실제로 사용할 수 있는 기능만 켤 수 있습니다.
+   * @returns {string|Object} 초기화 보고서
+   */
+  getInitializationReport: createFunctionWrapper(
+    'get_initialization_report',
+    fallbacks.getInitializationReport,
+    null
+  ),
+
+  /**
+   * 네이티브 모듈 예열 (GPU 컨텍스트, 기본 셰이더, 메모리 풀을 백그라운드에서 미리 준비)
+   * 한 번만 실행되며 다시 호출하면 같은 결과를 반환합니다.
+   * @returns {Promise<Object>} 예열 보고서 { ok, duration_ms, steps: { gpu, shaders, memory } }
+   */
+  warmUp: async () => {
+    const result = await createFunctionWrapper('warm_up', fallbacks.warmUp, null, true)();
+    return typeof result === 'string' ? JSON.parse(result) : result;
+  },
+
+  /**
+   * 예열 진행 상태 가져오기
+   * @returns {string|Object} { state: 'not_started' | 'running' | 'completed', report }
+   */
+  getWarmUpStatus: createFunctionWrapper(
+    'get_warm_up_status',
+    fallbacks.getWarmUpStatus,
+    null
+  ),
+
   // =========== 메모리 관련 함수 ===========
 
   /**
@@ -891,6 +1003,42 @@ const nativeModuleApi = {
    */
   requestGarbageCollection: requestGarbageCollection,
 
+  /**
+   * 메모리 설정 초기화 (이미 초기화되었으면 무시)
+   * @param {string} settingsJson 전체 메모리 설정 JSON
+   * @returns {boolean} 성공 여부
+   */
+  initializeMemorySettings: createFunctionWrapper(
+    'initialize_memory_settings',
+    () => false,
+    false
+  ),
+
+  /**
+   * 메모리 설정 업데이트 (지정한 항목만 변경)
+   * 전원 절약 정책: power_saving_policy ('battery' | 'low-power' | 'off'),
+   * power_saving_thread_ratio (0.0 ~ 1.0), power_saving_disable_gpu
+   * @param {string} settingsJson 변경할 항목 JSON (예: '{"power_saving_policy":"off"}')
+   * @returns {string|null} 변경 후 전체 설정 JSON
+   */
+  updateMemorySettings: createFunctionWrapper(
+    'update_memory_settings',
+    null,
+    null
+  ),
+
+  /**
+   * 전원 상태 가져오기
+   * 배터리 사용 중이거나 저전력 모드이면 정책에 따라 워커 스레드를 줄이고 GPU 가속을 끕니다.
+   * Electron powerMonitor의 on-ac/on-battery 이벤트에서 호출하면 바로 반영됩니다.
+   * @returns {string|Object} { on_battery, low_power_mode, battery_percent, policy, power_saving, worker_thread_ratio, gpu_allowed }
+   */
+  getPowerState: createFunctionWrapper(
+    'get_power_state',
+    fallbacks.getPowerState,
+    null
+  ),
+
   // =========== GPU 관련 함수 ===========
 
   /**
@@ -923,6 +1071,51 @@ const nativeModuleApi = {
     true
   ),
 
+  /**
+   * GPU 하위 시스템 켜기/끄기 (재시작 없이 GPU 컨텍스트를 해제하거나 다시 초기화)
+   * 끄면 GPU 작업은 CPU 경로로 실행됩니다.
+   * @param {boolean} enabled - 사용 여부
+   * @returns {string|Object} { success, enabled, gpu, active_gpu_tasks }
+   */
+  setGpuEnabled: createFunctionWrapper(
+    'set_gpu_enabled',
+    fallbacks.setGpuEnabled,
+    null
+  ),
+
+  /**
+   * 선호 GPU 장치 설정 (GPU가 초기화되어 있으면 선택한 장치로 컨텍스트를 다시 생성)
+   * @param {string|null} deviceKey - list_gpu_devices가 반환한 장치 key (null이면 자동 선택)
+   * @returns {string|Object} { success, preferred_device, active_device, fallback }
+   */
+  setPreferredGpuDevice: createFunctionWrapper(
+    'set_preferred_gpu_device',
+    (deviceKey) => ({ success: false, preferred_device: deviceKey ?? null, active_device: null, fallback: true, timestamp: Date.now() }),
+    null
+  ),
+
+  /**
+   * 캐시된 셰이더 목록 가져오기
+   * 디스크 항목은 소스 해시로 저장되어 다음 실행에서 같은 셰이더를 다시 컴파일하지 않습니다.
+   * @returns {string|Object} { memory: [...], disk: [...], directory }
+   */
+  listCachedShaders: createFunctionWrapper(
+    'list_cached_shaders',
+    () => ({ memory: [], disk: [], directory: null, timestamp: Date.now() }),
+    null
+  ),
+
+  /**
+   * 셰이더 캐시 비우기 (메모리 및 디스크)
+   * @param {string} [name] - 지정하면 해당 이름의 셰이더만 제거
+   * @returns {string|Object} { success, memory_cleared, disk_cleared }
+   */
+  clearShaderCache: createFunctionWrapper(
+    'clear_shader_cache',
+    (name = null) => ({ success: true, name, memory_cleared: 0, disk_cleared: 0, timestamp: Date.now() }),
+    null
+  ),
+
   /**
    * GPU 정보 가져오기
    * @returns {Object} GPU 정보
@@ -1013,22 +1206,26 @@ const nativeModuleApi = {
    * 워커 풀에 작업 제출
    * @param {string} taskType 작업 유형
    * @param {string|Object} data 작업 데이터
-   * @returns {Promise<Object>} 작업 결과
+   * @param {Object} [options] 제출 옵션
+   * @param {string} [options.taskId] 취소용 작업 ID
+   * @param {'high'|'normal'|'low'} [options.priority] 작업 우선순위
+   * @param {number} [options.timeoutMs] 제한 시간 (기본 30초, 초과하면 timed_out이 true인 실패 결과)
+   * @returns {Promise<Object>} 작업 결과 (대기열이 가득 차면 code가 'QueueFull'인 오류로 거부)
    */
-  submitTask: async (taskType, _data) => {
+  submitTask: async (taskType, _data, options = {}) => {
     // 타입 검증 및 변환
     const taskTypeStr = String(taskType || 'echo');
     const dataStr = typeof _data === 'object' ? JSON.stringify(_data) : String(_data);
 
-    const submitFunc = createFunctionWrapper(
-      'submit_task',
-      () => fallbacks.submitTask(taskTypeStr, dataStr),
-      null,
-      true
-    );
+    // 폄백 없이 호출해 백프레셔 오류를 구분할 수 있도록 함
+    const submitFunc = createFunctionWrapper('submit_task', null, null, true);
 
     try {
-      const result = await submitFunc(taskTypeStr, dataStr);
+      const result = await submitFunc(taskTypeStr, dataStr, options.taskId, options.priority, options.timeoutMs);
+
+      if (result === null || result === undefined) {
+        return fallbacks.submitTask(taskTypeStr, dataStr);
+      }
 
       // 문자열 결과 파싱
       if (typeof result === 'string') {
@@ -1036,6 +1233,11 @@ const nativeModuleApi = {
       }
       return result;
     } catch (error) {
+      // 대기열 초과는 호출자가 재시도/지연할 수 있도록 그대로 전달
+      if (error && error.code === 'QueueFull') {
+        throw error;
+      }
+
       logger.error('작업 제출 오류', {
         error: error.message,
         taskType: taskTypeStr
@@ -1044,6 +1246,65 @@ const nativeModuleApi = {
     }
   },
 
+  /**
+   * JS 작업 핸들러 등록
+   * 네이티브 모듈을 다시 빌드하지 않고 워커 풀 작업 유형을 추가합니다.
+   * 핸들러는 메인 스레드에서 실행되므로 오래 걸리는 계산보다는 I/O 중심 작업에 적합합니다.
+   * @param {string} taskType 작업 유형 (네이티브 기본 작업 유형은 사용할 수 없음)
+   * @param {(data: any) => any|Promise<any>} handler 작업 데이터(JSON이면 파싱된 값)를 받아 결과를 반환하는 함수
+   * @returns {boolean} 등록 성공 여부
+   */
+  registerTaskHandler: (taskType, handler) => {
+    if (typeof handler !== 'function') {
+      throw new TypeError('작업 핸들러는 함수여야 합니다');
+    }
+    const taskTypeStr = String(taskType || '');
+
+    if (moduleState.isAvailable && !moduleState.isFallback) {
+      const registerFunc = createFunctionWrapper('register_task_handler', null, false);
+      if (!registerFunc(taskTypeStr, (_err, data) => runJsTaskHandler(handler, data))) {
+        return false;
+      }
+    }
+
+    jsTaskHandlers.set(taskTypeStr, handler);
+    return true;
+  },
+
+  /**
+   * JS 작업 핸들러 해제
+   * @param {string} taskType 작업 유형
+   * @returns {boolean} 등록되어 있던 핸들러를 해제했는지 여부
+   */
+  unregisterTaskHandler: (taskType) => {
+    const taskTypeStr = String(taskType || '');
+    const removed = jsTaskHandlers.delete(taskTypeStr);
+
+    if (moduleState.isAvailable && !moduleState.isFallback) {
+      return Boolean(createFunctionWrapper('unregister_task_handler', null, false)(taskTypeStr)) || removed;
+    }
+    return removed;
+  },
+
+  /**
+   * 등록된 작업 유형 목록
+   * @returns {{ native: string[], javascript: string[] }} 네이티브 기본 작업 유형과 JS 작업 유형
+   */
+  getRegisteredTaskTypes: () => {
+    const fallback = () => ({ native: [], javascript: [...jsTaskHandlers.keys()].sort() });
+    const result = createFunctionWrapper('get_registered_task_types', fallback, null)();
+
+    if (typeof result === 'string') {
+      try {
+        return JSON.parse(result);
+      } catch (parseError) {
+        logger.error('작업 유형 목록 파싱 오류', { error: parseError.message });
+        return fallback();
+      }
+    }
+    return result || fallback();
+  },
+
   /**
    * 워커 풀 통계 가져오기
    * @returns {Object} 워커 풀 통계
@@ -1139,20 +1400,20 @@ function initializeWorkerPool(_threadCount = 0) {
   )(threads);
 }
 
-async function submitTask(taskType, _data = {}) {
+async function submitTask(taskType, _data = {}, options = {}) {
   // 타입 검증 및 변환
   const taskTypeStr = String(taskType || 'echo');
   const dataStr = typeof _data === 'object' ? JSON.stringify(_data) : String(_data);
 
-  const submitFunc = createFunctionWrapper(
-    'submit_task',
-    () => fallbacks.submitTask(taskTypeStr, dataStr),
-    null,
-    true
-  );
+  // 폄백 없이 호출해 백프레셔 오류를 구분할 수 있도록 함
+  const submitFunc = createFunctionWrapper('submit_task', null, null, true);
 
   try {
-    const result = await submitFunc(taskTypeStr, dataStr);
+    const result = await submitFunc(taskTypeStr, dataStr, options.taskId, options.priority, options.timeoutMs);
+
+    if (result === null || result === undefined) {
+      return fallbacks.submitTask(taskTypeStr, dataStr);
+    }
 
     // 문자열 결과 파싱
     if (typeof result === 'string') {
@@ -1160,6 +1421,11 @@ async function submitTask(taskType, _data = {}) {
     }
     return result;
   } catch (error) {
+    // 대기열 초과는 호출자가 재시도/지연할 수 있도록 그대로 전달
+    if (error && error.code === 'QueueFull') {
+      throw error;
+    }
+
     logger.error('작업 제출 오류', {
       error: error.message,
       taskType: taskTypeStr
@@ -118,7 +118,8 @@ export enum GpuTaskType {
   PATTERN_DETECTION = 'PatternDetection',
   IMAGE_PROCESSING = 'ImageProcessing',
   DATA_AGGREGATION = 'DataAggregation',
-  TYPING_STATISTICS = 'TypingStatistics'
+  TYPING_STATISTICS = 'TypingStatistics',
+  KEY_HEATMAP = 'KeyHeatmap'
 }
 
 /**